@@ -0,0 +1,357 @@
+//! Формат переносимого архива проекта
+//!
+//! Архив - tar (ustar): первой записью `manifest.json` от DirectorEngine,
+//! затем содержимое папки проекта под `project/`. Размер каждой записи
+//! известен до её данных, поэтому архив собирается из файлов FileGateway и
+//! разбирается обратно потоково, без буферизации медиа в памяти.
+
+use thiserror::Error;
+
+/// Манифест движка: метаданные, таймлайн и реестр ассетов
+pub const MANIFEST_ENTRY: &str = "manifest.json";
+
+/// Папка архива с файлами проекта
+pub const PROJECT_DIR: &str = "project";
+
+/// Расширение файла архива
+pub const ARCHIVE_EXTENSION: &str = "director.tar";
+
+const BLOCK_SIZE: u64 = 512;
+
+/// Наибольший размер, который помещается в восьмеричное поле заголовка
+const MAX_OCTAL_SIZE: u64 = 0o77777777777;
+
+#[derive(Error, Debug)]
+pub enum ArchiveError {
+    #[error("Путь слишком длинный для архива: {0}")]
+    PathTooLong(String),
+
+    #[error("Недопустимый путь в архиве: {0}")]
+    UnsafePath(String),
+
+    #[error("Повреждённый архив: {0}")]
+    Corrupted(String),
+
+    #[error("Архив оборван")]
+    Truncated,
+}
+
+impl ArchiveError {
+    /// Стабильный идентификатор ошибки для клиентов
+    pub fn reason(&self) -> &'static str {
+        match self {
+            ArchiveError::PathTooLong(_) => "ARCHIVE_PATH_TOO_LONG",
+            ArchiveError::UnsafePath(_) => "ARCHIVE_UNSAFE_PATH",
+            ArchiveError::Corrupted(_) => "ARCHIVE_CORRUPTED",
+            ArchiveError::Truncated => "ARCHIVE_TRUNCATED",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryKind {
+    File,
+    Directory,
+}
+
+/// Заголовок записи архива
+#[derive(Debug, Clone)]
+pub struct EntryHeader {
+    /// Путь внутри архива через `/`, без завершающего `/`
+    pub path: String,
+    pub kind: EntryKind,
+    pub size: u64,
+}
+
+/// Сколько байт займёт в архиве запись с данными размера `size`
+pub fn entry_size(size: u64) -> u64 {
+    BLOCK_SIZE + size.div_ceil(BLOCK_SIZE) * BLOCK_SIZE
+}
+
+/// Размер конца архива (два нулевых блока)
+pub fn end_size() -> u64 {
+    2 * BLOCK_SIZE
+}
+
+/// Нули, дополняющие данные записи размера `size` до целого блока
+pub fn padding(size: u64) -> Vec<u8> {
+    vec![0; (size.div_ceil(BLOCK_SIZE) * BLOCK_SIZE - size) as usize]
+}
+
+/// Конец архива
+pub fn end_of_archive() -> Vec<u8> {
+    vec![0; end_size() as usize]
+}
+
+/// Путь `path` относительно `root` через `/`; `None`, если он вне `root`
+pub fn relative_path(root: &str, path: &str) -> Option<String> {
+    let root = root.trim_end_matches(['/', '\\']);
+    let rest = path.strip_prefix(root)?;
+    if !rest.starts_with(['/', '\\']) {
+        return None;
+    }
+
+    Some(rest.trim_start_matches(['/', '\\']).replace('\\', "/"))
+}
+
+/// Проверить, что путь из архива не выходит за папку распаковки
+pub fn check_path(path: &str) -> Result<(), ArchiveError> {
+    let escapes = path.is_empty()
+        || path.starts_with('/')
+        || path.contains(['\\', ':', '\0'])
+        || path.split('/').any(|part| part.is_empty() || part == "." || part == "..");
+
+    if escapes {
+        return Err(ArchiveError::UnsafePath(path.to_string()));
+    }
+    Ok(())
+}
+
+/// Заголовок записи (один блок)
+pub fn header(entry: &EntryHeader) -> Result<Vec<u8>, ArchiveError> {
+    let mut block = vec![0u8; BLOCK_SIZE as usize];
+
+    let path = match entry.kind {
+        EntryKind::File => entry.path.clone(),
+        EntryKind::Directory => format!("{}/", entry.path),
+    };
+    let (prefix, name) = split_path(&path)?;
+
+    block[..name.len()].copy_from_slice(name.as_bytes());
+    block[345..345 + prefix.len()].copy_from_slice(prefix.as_bytes());
+
+    let (mode, type_flag, size) = match entry.kind {
+        EntryKind::File => ("0000644", b'0', entry.size),
+        EntryKind::Directory => ("0000755", b'5', 0),
+    };
+    write_octal(&mut block[100..108], mode);
+    write_octal(&mut block[108..116], "0000000");
+    write_octal(&mut block[116..124], "0000000");
+    write_size(&mut block[124..136], size);
+    write_octal(&mut block[136..148], &format!("{:011o}", unix_time()));
+    block[156] = type_flag;
+    block[257..263].copy_from_slice(b"ustar\0");
+    block[263..265].copy_from_slice(b"00");
+
+    let checksum = checksum(&block);
+    block[148..156].copy_from_slice(format!("{:06o}\0 ", checksum).as_bytes());
+
+    Ok(block)
+}
+
+/// Разделить путь на поля `prefix` (до 155 байт) и `name` (до 100 байт)
+fn split_path(path: &str) -> Result<(&str, &str), ArchiveError> {
+    if path.len() <= 100 {
+        return Ok(("", path));
+    }
+
+    // Директория (завершающий `/`) не должна делиться по своему же разделителю
+    let search = &path[..path.len() - 1];
+    search
+        .match_indices('/')
+        .map(|(index, _)| (&path[..index], &path[index + 1..]))
+        .find(|(prefix, name)| prefix.len() <= 155 && name.len() <= 100 && !name.is_empty())
+        .ok_or_else(|| ArchiveError::PathTooLong(path.to_string()))
+}
+
+fn write_octal(field: &mut [u8], value: &str) {
+    field[..value.len()].copy_from_slice(value.as_bytes());
+}
+
+/// Размер: восьмеричный или, для файлов от 8 ГиБ, двоичный (расширение GNU)
+fn write_size(field: &mut [u8], size: u64) {
+    if size <= MAX_OCTAL_SIZE {
+        write_octal(field, &format!("{:011o}", size));
+    } else {
+        field.fill(0);
+        field[0] = 0x80;
+        field[4..].copy_from_slice(&size.to_be_bytes());
+    }
+}
+
+fn read_size(field: &[u8]) -> Result<u64, ArchiveError> {
+    if field[0] & 0x80 != 0 {
+        if field[1..4].iter().any(|&b| b != 0) {
+            return Err(ArchiveError::Corrupted("размер записи слишком велик".to_string()));
+        }
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&field[4..]);
+        return Ok(u64::from_be_bytes(bytes));
+    }
+
+    read_octal(field)
+}
+
+fn read_octal(field: &[u8]) -> Result<u64, ArchiveError> {
+    let text = std::str::from_utf8(field)
+        .map_err(|_| ArchiveError::Corrupted("некорректное числовое поле".to_string()))?;
+    let text = text.trim_matches(|c: char| c == '\0' || c == ' ');
+    if text.is_empty() {
+        return Ok(0);
+    }
+
+    u64::from_str_radix(text, 8)
+        .map_err(|_| ArchiveError::Corrupted(format!("некорректное числовое поле `{}`", text)))
+}
+
+/// Сумма байт заголовка, поле самой суммы считается пробелами
+fn checksum(block: &[u8]) -> u64 {
+    block
+        .iter()
+        .enumerate()
+        .map(|(i, &b)| if (148..156).contains(&i) { b' ' as u64 } else { b as u64 })
+        .sum()
+}
+
+fn unix_time() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Строковое поле заголовка до первого нуля
+fn read_str(field: &[u8]) -> Result<&str, ArchiveError> {
+    let end = field.iter().position(|&b| b == 0).unwrap_or(field.len());
+    std::str::from_utf8(&field[..end])
+        .map_err(|_| ArchiveError::Corrupted("путь записи не в UTF-8".to_string()))
+}
+
+fn parse_header(block: &[u8]) -> Result<EntryHeader, ArchiveError> {
+    if !block[257..262].eq(b"ustar") {
+        return Err(ArchiveError::Corrupted("не tar-архив".to_string()));
+    }
+    if read_octal(&block[148..156])? != checksum(block) {
+        return Err(ArchiveError::Corrupted("не сходится контрольная сумма заголовка".to_string()));
+    }
+
+    let name = read_str(&block[..100])?;
+    let prefix = read_str(&block[345..500])?;
+    let path = if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}/{}", prefix, name)
+    };
+
+    let kind = match block[156] {
+        b'0' | 0 => EntryKind::File,
+        b'5' => EntryKind::Directory,
+        other => {
+            return Err(ArchiveError::Corrupted(format!(
+                "неподдерживаемый тип записи `{}` ({})",
+                other as char, path
+            )))
+        }
+    };
+
+    let path = path.trim_end_matches('/').to_string();
+    check_path(&path)?;
+
+    Ok(EntryHeader {
+        path,
+        size: match kind {
+            EntryKind::File => read_size(&block[124..136])?,
+            EntryKind::Directory => 0,
+        },
+        kind,
+    })
+}
+
+/// Событие разбора архива
+#[derive(Debug)]
+pub enum ArchiveEvent {
+    /// Начало записи; за ним следуют её данные
+    Entry(EntryHeader),
+    /// Очередная часть данных текущей записи
+    Data(Vec<u8>),
+    /// Данные текущей записи закончились
+    EntryEnd,
+    /// Конец архива
+    End,
+}
+
+enum ReaderState {
+    Header,
+    Data { remaining: u64, padding: u64 },
+    End,
+}
+
+/// Потоковый разбор архива: данные подаются `feed` по мере получения
+pub struct ArchiveReader {
+    buffer: Vec<u8>,
+    state: ReaderState,
+}
+
+impl Default for ArchiveReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArchiveReader {
+    pub fn new() -> Self {
+        Self {
+            buffer: Vec::new(),
+            state: ReaderState::Header,
+        }
+    }
+
+    /// Добавить полученные данные
+    pub fn feed(&mut self, data: &[u8]) {
+        // После конца архива данные (дополнение до записи tar) не нужны
+        if !matches!(self.state, ReaderState::End) {
+            self.buffer.extend_from_slice(data);
+        }
+    }
+
+    /// Архив прочитан до конца
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state, ReaderState::End)
+    }
+
+    /// Следующее событие; `None` - нужны ещё данные
+    pub fn next_event(&mut self) -> Result<Option<ArchiveEvent>, ArchiveError> {
+        match self.state {
+            ReaderState::Header => {
+                if self.buffer.len() < BLOCK_SIZE as usize {
+                    return Ok(None);
+                }
+                let block: Vec<u8> = self.buffer.drain(..BLOCK_SIZE as usize).collect();
+
+                if block.iter().all(|&b| b == 0) {
+                    self.state = ReaderState::End;
+                    self.buffer.clear();
+                    return Ok(Some(ArchiveEvent::End));
+                }
+
+                let header = parse_header(&block)?;
+                self.state = ReaderState::Data {
+                    remaining: header.size,
+                    padding: padding(header.size).len() as u64,
+                };
+                Ok(Some(ArchiveEvent::Entry(header)))
+            }
+            ReaderState::Data { remaining, padding } if remaining > 0 => {
+                if self.buffer.is_empty() {
+                    return Ok(None);
+                }
+                let take = remaining.min(self.buffer.len() as u64);
+                let data: Vec<u8> = self.buffer.drain(..take as usize).collect();
+                self.state = ReaderState::Data {
+                    remaining: remaining - take,
+                    padding,
+                };
+                Ok(Some(ArchiveEvent::Data(data)))
+            }
+            ReaderState::Data { padding, .. } => {
+                if (self.buffer.len() as u64) < padding {
+                    return Ok(None);
+                }
+                self.buffer.drain(..padding as usize);
+                self.state = ReaderState::Header;
+                Ok(Some(ArchiveEvent::EntryEnd))
+            }
+            ReaderState::End => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Аргументы командной строки
+//!
+//! Приоритет: флаги командной строки > переменные окружения > значения по умолчанию.
+
+use clap::Parser;
+
+use crate::clients::HealthProbe;
+use crate::{
+    BACKEND_TIMEOUT_MS, CONNECT_TIMEOUT_MS, ENGINE_ADDRESS, FILE_GATEWAY_ADDRESS,
+    MIN_PROJECT_SPACE,
+};
+
+#[derive(Debug, Parser)]
+#[command(name = "api-gateway", version, about = "Единая точка входа для сервисов Director")]
+pub struct Args {
+    /// Адрес для входящих подключений (например, `0.0.0.0` или `[::]:50050`)
+    #[arg(long, env = "API_GATEWAY_BIND")]
+    pub bind: Option<String>,
+
+    /// Порт (имеет приоритет над портом из `--bind`)
+    #[arg(long, env = "API_GATEWAY_PORT")]
+    pub port: Option<u16>,
+
+    /// Адрес DirectorEngine
+    #[arg(long, env = "ENGINE_ADDRESS", default_value = ENGINE_ADDRESS)]
+    pub engine_address: String,
+
+    /// Адрес FileGateway
+    #[arg(long, env = "FILE_GATEWAY_ADDRESS", default_value = FILE_GATEWAY_ADDRESS)]
+    pub file_gateway_address: String,
+
+    /// Таймаут подключения к DirectorEngine (мс)
+    #[arg(long, env = "ENGINE_CONNECT_TIMEOUT_MS", default_value_t = CONNECT_TIMEOUT_MS)]
+    pub engine_connect_timeout_ms: u64,
+
+    /// Таймаут подключения к FileGateway (мс)
+    #[arg(long, env = "FILE_GATEWAY_CONNECT_TIMEOUT_MS", default_value_t = CONNECT_TIMEOUT_MS)]
+    pub file_gateway_connect_timeout_ms: u64,
+
+    /// Таймаут вызовов DirectorEngine и FileGateway (мс, 0 - без ограничения)
+    #[arg(long, env = "BACKEND_TIMEOUT_MS", default_value_t = BACKEND_TIMEOUT_MS)]
+    pub backend_timeout_ms: u64,
+
+    /// Проверка бэкендов в `HealthCheck`: `auto` - `grpc.health.v1`, для
+    /// старых бэкендов информационный RPC; `grpc` - только `grpc.health.v1`;
+    /// `rpc` - только информационный RPC
+    #[arg(long, env = "HEALTH_PROBE", value_enum, default_value_t = HealthProbe::Auto)]
+    pub health_probe: HealthProbe,
+
+    /// Минимум свободного места (байты) для создания проекта
+    #[arg(long, env = "MIN_PROJECT_SPACE", default_value_t = MIN_PROJECT_SPACE)]
+    pub min_project_space: u64,
+
+    /// Сообщать об ошибках изменяющих RPC через gRPC статус с кодом ошибки
+    /// вместо полей `success`/`error_message` ответа
+    #[arg(long, env = "STATUS_ERRORS")]
+    pub status_errors: bool,
+}
@@ -0,0 +1,240 @@
+//! Клиент ApiGateway для приложений на Rust
+//!
+//! Обёртка над сгенерированным `ApiGatewayClient`: методы возвращают
+//! готовые данные, неуспешный ответ (`success = false`) становится ошибкой,
+//! а загрузка и скачивание скрывают разбиение файла на сообщения.
+//! Остальные RPC доступны через `inner()`.
+//!
+//! ```rust,ignore
+//! let mut client = GatewayClient::connect("http://[::1]:50050").await?;
+//! let projects = client.list_projects().await?;
+//!
+//! let file = tokio::fs::File::open("clip.mp4").await?;
+//! client.upload("/Videos/clip.mp4", file, false).await?;
+//!
+//! let (metadata, mut chunks) = client.download("/Videos/clip.mp4").await?;
+//! while let Some(chunk) = chunks.next().await {
+//!     output.write_all(&chunk?).await?;
+//! }
+//! ```
+
+use std::io;
+use std::sync::{Arc, Mutex};
+
+use thiserror::Error;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio_stream::{Stream, StreamExt};
+use tonic::transport::Channel;
+use tonic::Status;
+
+use crate::proto::api_gateway::api_gateway_client::ApiGatewayClient;
+use crate::proto::api_gateway::*;
+
+/// Размер части файла в одном сообщении загрузки
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("Не удалось подключиться к шлюзу: {0}")]
+    Connect(#[from] tonic::transport::Error),
+
+    #[error("Ошибка вызова: {0}")]
+    Status(#[from] Status),
+
+    /// Шлюз выполнил запрос, но операция не удалась (`error_message` ответа)
+    #[error("{0}")]
+    Failed(String),
+
+    #[error("Ошибка чтения данных: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("Неожиданный ответ шлюза: {0}")]
+    Protocol(String),
+}
+
+/// Клиент ApiGateway; дёшево клонируется, клоны делят подключение
+#[derive(Clone)]
+pub struct GatewayClient {
+    inner: ApiGatewayClient<Channel>,
+}
+
+impl GatewayClient {
+    /// Подключиться к шлюзу (например, `http://[::1]:50050`)
+    pub async fn connect(address: impl Into<String>) -> Result<Self, ClientError> {
+        let channel = Channel::from_shared(address.into())
+            .map_err(|e| ClientError::Protocol(e.to_string()))?
+            .connect()
+            .await?;
+        Ok(Self::new(ApiGatewayClient::new(channel)))
+    }
+
+    /// Клиент поверх готового сгенерированного клиента (свой канал, перехватчики)
+    pub fn new(inner: ApiGatewayClient<Channel>) -> Self {
+        Self { inner }
+    }
+
+    /// Сгенерированный клиент для RPC без обёртки
+    pub fn inner(&mut self) -> &mut ApiGatewayClient<Channel> {
+        &mut self.inner
+    }
+
+    /// Проекты реестра (без архивных)
+    pub async fn list_projects(&mut self) -> Result<Vec<Project>, ClientError> {
+        let response = self
+            .inner
+            .list_projects(ListProjectsRequest::default())
+            .await?
+            .into_inner();
+        Ok(response.projects)
+    }
+
+    /// Создать проект `name` в директории `path` файлового сервера
+    pub async fn create_project(
+        &mut self,
+        name: impl Into<String>,
+        path: impl Into<String>,
+    ) -> Result<Project, ClientError> {
+        let response = self
+            .inner
+            .create_project(CreateProjectRequest {
+                name: name.into(),
+                path: path.into(),
+                merge: false,
+            })
+            .await?
+            .into_inner();
+
+        if !response.success {
+            return Err(ClientError::Failed(response.error_message));
+        }
+        response
+            .project
+            .ok_or_else(|| ClientError::Protocol("в ответе нет проекта".to_string()))
+    }
+
+    /// Содержимое директории
+    pub async fn browse(
+        &mut self,
+        path: impl Into<String>,
+    ) -> Result<BrowseDirectoryResponse, ClientError> {
+        let response = self
+            .inner
+            .browse_directory(BrowseDirectoryRequest { path: path.into() })
+            .await?
+            .into_inner();
+
+        if !response.success {
+            return Err(ClientError::Failed(response.error_message.clone()));
+        }
+        Ok(response)
+    }
+
+    /// Загрузить данные из `reader` в файл `path`
+    ///
+    /// Данные отправляются частями по мере чтения. Если чтение прервалось
+    /// ошибкой, загрузка отменяется и частичный файл удаляется на сервере.
+    pub async fn upload<R>(
+        &mut self,
+        path: &str,
+        reader: R,
+        overwrite: bool,
+    ) -> Result<UploadFileResponse, ClientError>
+    where
+        R: AsyncRead + Send + Unpin + 'static,
+    {
+        let (destination_path, filename) = split_path(path);
+        let metadata = UploadFileRequest {
+            data: Some(upload_file_request::Data::Metadata(UploadFileMetadata {
+                destination_path: destination_path.to_string(),
+                filename: filename.to_string(),
+                overwrite,
+                ..Default::default()
+            })),
+        };
+
+        let read_error: Arc<Mutex<Option<io::Error>>> = Arc::default();
+        let stream_error = read_error.clone();
+        let requests = async_stream::stream! {
+            yield metadata;
+
+            let mut reader = reader;
+            let mut buffer = vec![0; UPLOAD_CHUNK_SIZE];
+            loop {
+                match reader.read(&mut buffer).await {
+                    Ok(0) => break,
+                    Ok(read) => yield UploadFileRequest {
+                        data: Some(upload_file_request::Data::Chunk(buffer[..read].to_vec())),
+                    },
+                    Err(e) => {
+                        let reason = e.to_string();
+                        *stream_error.lock().unwrap_or_else(|e| e.into_inner()) = Some(e);
+                        yield UploadFileRequest {
+                            data: Some(upload_file_request::Data::Cancel(UploadCancel { reason })),
+                        };
+                        break;
+                    }
+                }
+            }
+        };
+
+        let result = self.inner.upload_file(requests).await;
+
+        // Ответ на отменённую загрузку - следствие, клиенту важнее причина
+        if let Some(e) = read_error.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            return Err(ClientError::Io(e));
+        }
+
+        let response = result?.into_inner();
+        if !response.success {
+            return Err(ClientError::Failed(response.error_message.clone()));
+        }
+        Ok(response)
+    }
+
+    /// Скачать файл `path`: сведения о файле и поток его частей
+    pub async fn download(
+        &mut self,
+        path: impl Into<String>,
+    ) -> Result<
+        (DownloadFileMetadata, impl Stream<Item = Result<Vec<u8>, ClientError>>),
+        ClientError,
+    > {
+        let mut responses = self
+            .inner
+            .download_file(DownloadFileRequest {
+                path: path.into(),
+                ..Default::default()
+            })
+            .await?
+            .into_inner();
+
+        let metadata = match responses.next().await.transpose()?.and_then(|r| r.data) {
+            Some(download_file_response::Data::Metadata(metadata)) => metadata,
+            _ => {
+                return Err(ClientError::Protocol(
+                    "первое сообщение скачивания - не сведения о файле".to_string(),
+                ))
+            }
+        };
+
+        // Контрольная сумма не запрашивалась, поэтому приходят только части
+        let chunks = responses.filter_map(|response| match response {
+            Ok(DownloadFileResponse {
+                data: Some(download_file_response::Data::Chunk(chunk)),
+            }) => Some(Ok(chunk)),
+            Ok(_) => None,
+            Err(e) => Some(Err(ClientError::Status(e))),
+        });
+
+        Ok((metadata, chunks))
+    }
+}
+
+/// Разделить путь на директорию и имя файла (разделители `/` и `\`)
+fn split_path(path: &str) -> (&str, &str) {
+    match path.rfind(['/', '\\']) {
+        Some(0) => (&path[..1], &path[1..]),
+        Some(index) => (&path[..index], &path[index + 1..]),
+        None => ("", path),
+    }
+}
@@ -1,77 +1,424 @@
 //! Клиенты для подключения к внутренним сервисам
+//!
+//! Подключение ленивое: шлюз запускается в любом порядке с бэкендами,
+//! недоступный сервис отражается в `HealthCheck`, а канал переподключается
+//! сам, когда сервис поднимется.
+//!
+//! Клиент бэкенда дёшево клонируется: обработчик берёт свой клон на время
+//! вызова, а клоны делят канал, метрики и размыкатель цепи. Пока цепь
+//! разомкнута, вызовы сразу получают `UNAVAILABLE` и не ждут таймаута
+//! подключения к неработающему сервису.
+//!
+//! Состояние бэкенда проверяется стандартным `grpc.health.v1.Health/Check`,
+//! который не нагружает сервис. Бэкенды старых версий без него проверяются
+//! информационным RPC (`GetEngineInfo`/`GetStorageInfo`), как раньше.
 
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
 use std::time::{Duration, Instant};
+
+use clap::ValueEnum;
+use tonic::body::BoxBody;
+use tonic::codegen::{http, BoxFuture, Service, StdError};
+use tonic::service::interceptor::InterceptedService;
 use tonic::transport::Channel;
-use tracing::{error, info};
+use tonic::{Code, Status};
+use tracing::{error, info, warn};
+
+use crate::deadline::Deadline;
+use crate::status;
+use crate::telemetry::{self, Interceptor};
 
 use crate::proto::director::project_service_client::ProjectServiceClient;
 use crate::proto::file_gateway::file_gateway_client::FileGatewayClient;
+use crate::proto::health::health_check_response::ServingStatus;
+use crate::proto::health::health_client::HealthClient;
+use crate::proto::health::HealthCheckRequest;
+
+/// Неудачных подключений подряд, после которых цепь размыкается
+const BREAKER_THRESHOLD: u64 = 5;
+
+/// Сколько вызовы отклоняются после размыкания цепи
+const BREAKER_COOLDOWN: Duration = Duration::from_secs(10);
+
+/// Канал, передающий контекст трассировки в метаданных каждого вызова
+pub type TracedChannel = InterceptedService<BackendChannel, Interceptor>;
 
 /// Клиент для DirectorEngine
-pub struct EngineClient {
-    pub client: ProjectServiceClient<Channel>,
-    pub address: String,
+pub type EngineClient = BackendClient<ProjectServiceClient<TracedChannel>>;
+
+/// Клиент для FileGateway
+pub type FileClient = BackendClient<FileGatewayClient<TracedChannel>>;
+
+/// Способ проверки состояния бэкендов (`--health-probe`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum HealthProbe {
+    /// `grpc.health.v1`, для бэкендов без него - информационный RPC
+    Auto,
+    /// Только `grpc.health.v1`
+    Grpc,
+    /// Только информационный RPC, как в прежних версиях
+    Rpc,
 }
 
-impl EngineClient {
-    pub async fn connect(address: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
-        let channel = Channel::from_shared(address.to_string())?
-            .connect_timeout(Duration::from_secs(5))
-            .connect()
-            .await?;
+/// Таймауты обращения к бэкендам
+#[derive(Debug, Clone, Copy)]
+pub struct BackendTimeouts {
+    /// Подключение к DirectorEngine
+    pub engine_connect: Duration,
+    /// Подключение к FileGateway
+    pub file_gateway_connect: Duration,
+    /// Вызов любого бэкенда; `None` - без ограничения
+    pub call: Option<Duration>,
+}
+
+/// Метрики вызовов бэкенда на момент снимка
+#[derive(Debug, Clone, Copy)]
+pub struct BackendStats {
+    pub calls: u64,
+    pub errors: u64,
+    /// Суммарное время до заголовков ответа
+    pub total_time: Duration,
+    pub max_time: Duration,
+    /// Отклонено без обращения к сервису
+    pub rejected: u64,
+    pub circuit_open: bool,
+}
 
-        info!("Подключён к DirectorEngine: {}", address);
+/// Состояние размыкателя цепи
+#[derive(Debug, Default)]
+struct Breaker {
+    /// Неудачных подключений подряд
+    failures: u64,
+    /// До какого момента вызовы отклоняются
+    open_until: Option<Instant>,
+}
 
-        Ok(Self {
-            client: ProjectServiceClient::new(channel),
+/// Общее состояние всех клонов клиента одного бэкенда
+struct BackendState {
+    name: &'static str,
+    address: String,
+    probe: HealthProbe,
+    /// Бэкенд ответил `UNIMPLEMENTED` на `Health/Check`; до перезапуска шлюза
+    /// он проверяется информационным RPC
+    health_unimplemented: AtomicBool,
+    /// Версия из последней успешной проверки (пусто, пока сервис не ответил)
+    version: Mutex<String>,
+    breaker: Mutex<Breaker>,
+    calls: AtomicU64,
+    errors: AtomicU64,
+    rejected: AtomicU64,
+    total_micros: AtomicU64,
+    max_micros: AtomicU64,
+}
+
+impl BackendState {
+    fn new(name: &'static str, address: &str, probe: HealthProbe) -> Self {
+        Self {
+            name,
             address: address.to_string(),
-        })
+            probe,
+            health_unimplemented: AtomicBool::new(false),
+            version: Mutex::new(String::new()),
+            breaker: Mutex::new(Breaker::default()),
+            calls: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
+            rejected: AtomicU64::new(0),
+            total_micros: AtomicU64::new(0),
+            max_micros: AtomicU64::new(0),
+        }
     }
 
-    pub async fn health_check(&mut self) -> (bool, i64) {
-        let start = Instant::now();
-        
-        match self.client.list_projects(crate::proto::director::ListProjectsRequest {}).await {
-            Ok(_) => (true, start.elapsed().as_millis() as i64),
-            Err(e) => {
-                error!("DirectorEngine health check failed: {}", e);
-                (false, -1)
+    /// Сколько ещё цепь разомкнута (`None` - вызов можно выполнять)
+    ///
+    /// После паузы вызовы снова проходят; первая же неудача размыкает цепь
+    /// заново, первый успех замыкает её.
+    fn open_for(&self) -> Option<Duration> {
+        let breaker = self.breaker.lock().unwrap_or_else(|e| e.into_inner());
+        let remaining = breaker.open_until?.saturating_duration_since(Instant::now());
+        (!remaining.is_zero()).then_some(remaining)
+    }
+
+    /// Учесть завершённый вызов
+    ///
+    /// `reachable` - сервис ответил (даже ошибкой), то есть подключение работает.
+    fn record(&self, elapsed: Duration, success: bool, reachable: bool) {
+        let micros = elapsed.as_micros() as u64;
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        if !success {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+        self.total_micros.fetch_add(micros, Ordering::Relaxed);
+        self.max_micros.fetch_max(micros, Ordering::Relaxed);
+
+        let mut breaker = self.breaker.lock().unwrap_or_else(|e| e.into_inner());
+        if reachable {
+            breaker.failures = 0;
+            if breaker.open_until.take().is_some() {
+                info!("Circuit to {} closed", self.name);
+            }
+            return;
+        }
+
+        breaker.failures += 1;
+        if breaker.failures >= BREAKER_THRESHOLD {
+            if breaker.open_until.is_none() {
+                warn!(
+                    "Цепь к {} разомкнута после {} неудачных подключений подряд",
+                    self.name, breaker.failures
+                );
             }
+            breaker.open_until = Some(Instant::now() + BREAKER_COOLDOWN);
+        }
+    }
+
+    fn stats(&self) -> BackendStats {
+        BackendStats {
+            calls: self.calls.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            total_time: Duration::from_micros(self.total_micros.load(Ordering::Relaxed)),
+            max_time: Duration::from_micros(self.max_micros.load(Ordering::Relaxed)),
+            rejected: self.rejected.load(Ordering::Relaxed),
+            circuit_open: self.open_for().is_some(),
         }
     }
 }
 
-/// Клиент для FileGateway
-pub struct FileClient {
-    pub client: FileGatewayClient<Channel>,
-    pub address: String,
+/// Канал к бэкенду, который ведёт метрики и размыкатель цепи
+///
+/// Время вызова считается до заголовков ответа. Ошибкой считаются ошибки
+/// транспорта и статус в заголовках ответа (так приходят ошибки унарных
+/// вызовов); статус в трейлерах потокового ответа не учитывается.
+#[derive(Clone)]
+pub struct BackendChannel {
+    inner: Channel,
+    state: Arc<BackendState>,
 }
 
-impl FileClient {
-    pub async fn connect(address: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
-        let channel = Channel::from_shared(address.to_string())?
-            .connect_timeout(Duration::from_secs(5))
-            .connect()
-            .await?;
+impl Service<http::Request<BoxBody>> for BackendChannel {
+    type Response = http::Response<BoxBody>;
+    type Error = StdError;
+    type Future = BoxFuture<Self::Response, Self::Error>;
 
-        info!("Подключён к FileGateway: {}", address);
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
 
-        Ok(Self {
-            client: FileGatewayClient::new(channel),
-            address: address.to_string(),
+    fn call(&mut self, request: http::Request<BoxBody>) -> Self::Future {
+        let state = self.state.clone();
+
+        if let Some(remaining) = state.open_for() {
+            state.rejected.fetch_add(1, Ordering::Relaxed);
+            let status = status::coded(
+                Code::Unavailable,
+                "BACKEND_UNAVAILABLE",
+                format!(
+                    "{} недоступен, повторное подключение через {} с",
+                    state.name,
+                    remaining.as_secs().max(1)
+                ),
+            );
+            return Box::pin(async move { Err(status.into()) });
+        }
+
+        let start = Instant::now();
+        let response = self.inner.call(request);
+
+        Box::pin(async move {
+            let result = response.await;
+            match &result {
+                Ok(response) => {
+                    let code = Status::from_header_map(response.headers())
+                        .map_or(Code::Ok, |status| status.code());
+                    state.record(start.elapsed(), code == Code::Ok, code != Code::Unavailable);
+                }
+                Err(_) => state.record(start.elapsed(), false, false),
+            }
+            result.map_err(Into::into)
         })
     }
+}
 
-    pub async fn health_check(&mut self) -> (bool, i64) {
+/// Клиент бэкенда; клоны делят канал, метрики и размыкатель цепи
+#[derive(Clone)]
+pub struct BackendClient<C> {
+    pub client: C,
+    health: HealthClient<TracedChannel>,
+    state: Arc<BackendState>,
+}
+
+impl<C> BackendClient<C> {
+    pub fn address(&self) -> &str {
+        &self.state.address
+    }
+
+    /// Версия из последней успешной проверки (пусто, пока сервис не ответил)
+    pub fn version(&self) -> String {
+        self.state.version.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    pub fn stats(&self) -> BackendStats {
+        self.state.stats()
+    }
+
+    fn set_version(&self, version: String) {
+        *self.state.version.lock().unwrap_or_else(|e| e.into_inner()) = version;
+    }
+
+    /// Проверить бэкенд; результат - доступность и задержка (мс, -1 при ошибке)
+    ///
+    /// `fetch_version` - информационный RPC бэкенда. Он заменяет `Health/Check`
+    /// для старых бэкендов, а иначе вызывается, только пока версия неизвестна:
+    /// `Health` её не сообщает. Задержка считается по самой проверке.
+    async fn probe<F, Fut>(&mut self, timeout: Option<Duration>, fetch_version: F) -> (bool, i64)
+    where
+        C: Clone,
+        F: FnOnce(C) -> Fut,
+        Fut: Future<Output = Result<String, Status>>,
+    {
         let start = Instant::now();
-        
-        match self.client.get_storage_info(crate::proto::file_gateway::GetStorageInfoRequest {}).await {
-            Ok(_) => (true, start.elapsed().as_millis() as i64),
+        let result = match self.check_health(timeout).await {
+            Some(Ok(())) => {
+                let latency = start.elapsed();
+                if self.version().is_empty() {
+                    if let Ok(version) = fetch_version(self.client.clone()).await {
+                        self.set_version(version);
+                    }
+                }
+                Ok(latency)
+            }
+            Some(Err(e)) => Err(e),
+            None => fetch_version(self.client.clone()).await.map(|version| {
+                self.set_version(version);
+                start.elapsed()
+            }),
+        };
+
+        match result {
+            Ok(latency) => (true, latency.as_millis() as i64),
             Err(e) => {
-                error!("FileGateway health check failed: {}", e);
+                error!("{} health check failed: {}", self.state.name, e);
                 (false, -1)
             }
         }
     }
+
+    /// Вызвать `grpc.health.v1.Health/Check` для сервера целиком
+    ///
+    /// `None` - проверку нужно выполнить информационным RPC: так задано
+    /// `--health-probe rpc` или бэкенд не реализует `Health`.
+    async fn check_health(&mut self, timeout: Option<Duration>) -> Option<Result<(), Status>> {
+        let probe = self.state.probe;
+        let unimplemented = self.state.health_unimplemented.load(Ordering::Relaxed);
+        if probe == HealthProbe::Rpc || (probe == HealthProbe::Auto && unimplemented) {
+            return None;
+        }
+
+        let request = HealthCheckRequest {
+            service: String::new(),
+        };
+        match self.health.check(request).deadline(timeout).await {
+            Ok(response) => {
+                let status = response.into_inner().status;
+                if status == ServingStatus::Serving as i32 {
+                    return Some(Ok(()));
+                }
+
+                let status = ServingStatus::try_from(status)
+                    .map_or("UNKNOWN", |status| status.as_str_name());
+                Some(Err(Status::unavailable(format!("состояние {}", status))))
+            }
+            Err(e) if e.code() == Code::Unimplemented && probe == HealthProbe::Auto => {
+                warn!(
+                    "{} не поддерживает grpc.health.v1, проверка информационным RPC",
+                    self.state.name
+                );
+                self.state.health_unimplemented.store(true, Ordering::Relaxed);
+                None
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
 }
 
+/// Ленивый канал к бэкенду с метриками и контекстом трассировки
+fn connect_channel(
+    name: &'static str,
+    address: &str,
+    connect_timeout: Duration,
+    probe: HealthProbe,
+) -> Result<(TracedChannel, Arc<BackendState>), Box<dyn std::error::Error + Send + Sync>> {
+    let channel = Channel::from_shared(address.to_string())?
+        .connect_timeout(connect_timeout)
+        .connect_lazy();
+
+    info!("Канал к {}: {} (подключение при первом запросе)", name, address);
+
+    let state = Arc::new(BackendState::new(name, address, probe));
+    let channel = BackendChannel {
+        inner: channel,
+        state: state.clone(),
+    };
+
+    Ok((
+        InterceptedService::new(channel, telemetry::inject_context as Interceptor),
+        state,
+    ))
+}
+
+impl EngineClient {
+    pub fn connect(
+        address: &str,
+        connect_timeout: Duration,
+        probe: HealthProbe,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let (channel, state) =
+            connect_channel("DirectorEngine", address, connect_timeout, probe)?;
+
+        Ok(Self {
+            client: ProjectServiceClient::new(channel.clone()),
+            health: HealthClient::new(channel),
+            state,
+        })
+    }
+
+    pub async fn health_check(&mut self, timeout: Option<Duration>) -> (bool, i64) {
+        self.probe(timeout, |mut client| async move {
+            let response = client
+                .get_engine_info(crate::proto::director::GetEngineInfoRequest {})
+                .deadline(timeout)
+                .await?;
+            Ok(response.into_inner().version)
+        })
+        .await
+    }
+}
+
+impl FileClient {
+    pub fn connect(
+        address: &str,
+        connect_timeout: Duration,
+        probe: HealthProbe,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let (channel, state) = connect_channel("FileGateway", address, connect_timeout, probe)?;
+
+        Ok(Self {
+            client: FileGatewayClient::new(channel.clone()),
+            health: HealthClient::new(channel),
+            state,
+        })
+    }
+
+    pub async fn health_check(&mut self, timeout: Option<Duration>) -> (bool, i64) {
+        self.probe(timeout, |mut client| async move {
+            let response = client
+                .get_storage_info(crate::proto::file_gateway::GetStorageInfoRequest {})
+                .deadline(timeout)
+                .await?;
+            Ok(response.into_inner().version)
+        })
+        .await
+    }
+}
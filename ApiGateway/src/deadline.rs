@@ -0,0 +1,65 @@
+//! Таймаут вызовов бэкендов
+//!
+//! Бэкенд, зависший после подключения, держал бы обработчик бесконечно,
+//! если клиент шлюза не задал дедлайн. Поэтому каждый вызов DirectorEngine
+//! и FileGateway ограничивается `--backend-timeout-ms`.
+//!
+//! Для потоковых ответов таймаут ограничивает только получение ответа,
+//! а для потоковых запросов (загрузка файлов) не применяется: их длительность
+//! зависит от объёма данных.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use tonic::{Code, Status};
+
+use crate::status;
+
+/// Вызов бэкенда, ограниченный таймаутом
+pub trait Deadline<T>: Future<Output = Result<T, Status>> + Sized {
+    /// Вернуть `DEADLINE_EXCEEDED`, если ответа нет за `timeout` (`None` - без ограничения)
+    fn deadline<'a>(
+        self,
+        timeout: Option<Duration>,
+    ) -> Pin<Box<dyn Future<Output = Result<T, Status>> + Send + 'a>>
+    where
+        Self: Send + 'a,
+        T: 'a,
+    {
+        Box::pin(async move {
+            let Some(timeout) = timeout else {
+                return self.await;
+            };
+
+            match tokio::time::timeout(timeout, self).await {
+                Ok(result) => result,
+                Err(_) => Err(Status::deadline_exceeded(format!(
+                    "нет ответа за {} мс",
+                    timeout.as_millis()
+                ))),
+            }
+        })
+    }
+}
+
+impl<T, F: Future<Output = Result<T, Status>>> Deadline<T> for F {}
+
+/// Ошибка вызова бэкенда для клиента шлюза
+///
+/// Истёкший таймаут сохраняет код `DEADLINE_EXCEEDED`, остальные ошибки
+/// становятся `INTERNAL`, как и раньше.
+pub fn backend_error(backend: &str, e: Status) -> Status {
+    match e.code() {
+        Code::DeadlineExceeded => status::coded(
+            Code::DeadlineExceeded,
+            "BACKEND_TIMEOUT",
+            format!("{} error: {}", backend, e.message()),
+        ),
+        _ => status::coded(
+            Code::Internal,
+            "BACKEND_ERROR",
+            format!("{} error: {}", backend, e),
+        ),
+    }
+}
@@ -1,10 +1,23 @@
+mod archive;
+mod cli;
+pub mod client;
+mod deadline;
 mod service;
+mod status;
 mod clients;
+mod telemetry;
 
+#[cfg(test)]
+mod testing;
+
+use std::time::Duration;
+
+use clap::Parser;
+use director_common::{bind, logging};
 use tonic::transport::Server;
 use tracing::info;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+use clients::BackendTimeouts;
 use service::ApiGatewayImpl;
 
 pub mod proto {
@@ -17,6 +30,7 @@ pub mod proto {
     pub mod file_gateway {
         tonic::include_proto!("file_gateway");
     }
+    pub use director_common::proto::health;
 }
 
 use proto::api_gateway::api_gateway_server::ApiGatewayServer;
@@ -25,30 +39,45 @@ const GATEWAY_VERSION: &str = env!("CARGO_PKG_VERSION");
 const GATEWAY_PORT: u16 = 50050;
 const ENGINE_ADDRESS: &str = "http://[::1]:50051";
 const FILE_GATEWAY_ADDRESS: &str = "http://[::1]:50052";
+const CONNECT_TIMEOUT_MS: u64 = 5000;
+const BACKEND_TIMEOUT_MS: u64 = 60_000;
+const MIN_PROJECT_SPACE: u64 = 1024 * 1024 * 1024;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    tracing_subscriber::registry()
-        .with(tracing_subscriber::fmt::layer())
-        .with(tracing_subscriber::EnvFilter::from_default_env())
-        .init();
+    let log_handle = logging::init(env!("CARGO_PKG_NAME"));
+    logging::spawn_signal_handler(log_handle);
+
+    let args = cli::Args::parse();
+    let addr = bind::resolve(args.bind.as_deref(), args.port, GATEWAY_PORT)?;
 
-    let addr = format!("[::1]:{}", GATEWAY_PORT).parse()?;
+    info!("Подключение к DirectorEngine: {}", args.engine_address);
+    info!("Подключение к FileGateway: {}", args.file_gateway_address);
 
-    info!("Подключение к DirectorEngine: {}", ENGINE_ADDRESS);
-    info!("Подключение к FileGateway: {}", FILE_GATEWAY_ADDRESS);
+    let timeouts = BackendTimeouts {
+        engine_connect: Duration::from_millis(args.engine_connect_timeout_ms),
+        file_gateway_connect: Duration::from_millis(args.file_gateway_connect_timeout_ms),
+        call: (args.backend_timeout_ms > 0)
+            .then(|| Duration::from_millis(args.backend_timeout_ms)),
+    };
 
     let gateway = ApiGatewayImpl::new(
-        ENGINE_ADDRESS.to_string(),
-        FILE_GATEWAY_ADDRESS.to_string(),
+        args.engine_address,
+        args.file_gateway_address,
+        timeouts,
+        args.health_probe,
         GATEWAY_VERSION.to_string(),
-    ).await?;
+        args.min_project_space,
+        args.status_errors,
+    )?;
 
-    info!("API Gateway v{} запущен на {}", GATEWAY_VERSION, addr);
+    let (incoming, local_addr) = bind::listen(addr).await?;
+    info!("API Gateway v{} запущен на {}", GATEWAY_VERSION, local_addr);
 
     Server::builder()
+        .trace_fn(logging::rpc_span)
         .add_service(ApiGatewayServer::new(gateway))
-        .serve(addr)
+        .serve_with_incoming(incoming)
         .await?;
 
     Ok(())
@@ -1,37 +1,180 @@
 //! Реализация API Gateway сервиса
 
 use std::pin::Pin;
-use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::time::Duration;
 use tokio_stream::{Stream, StreamExt};
-use tonic::{Request, Response, Status, Streaming};
-use tracing::{error, info};
-
-use crate::clients::{EngineClient, FileClient};
+use tonic::{Code, Request, Response, Status, Streaming};
+use tracing::{error, info, warn};
+
+use crate::archive::{self, ArchiveError, ArchiveEvent, ArchiveReader, EntryHeader, EntryKind};
+use crate::clients::{
+    BackendStats, BackendTimeouts, EngineClient, FileClient, HealthProbe, TracedChannel,
+};
+use crate::deadline::{self, Deadline};
+use crate::status;
 use crate::proto::api_gateway::*;
+use crate::proto::file_gateway::file_gateway_client::FileGatewayClient;
 use crate::proto::{director, file_gateway};
 
+/// Как часто отправляется прогресс долгих операций
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Наибольший размер манифеста архива проекта
+const MAX_MANIFEST_SIZE: u64 = 64 * 1024 * 1024;
+
 pub struct ApiGatewayImpl {
-    engine: Arc<Mutex<EngineClient>>,
-    file_gateway: Arc<Mutex<FileClient>>,
+    engine: EngineClient,
+    file_gateway: FileClient,
     version: String,
+    /// Минимум свободного места для создания проекта (байты)
+    min_project_space: u64,
+    /// Ошибки изменяющих RPC через gRPC статус, а не `success`/`error_message`
+    status_errors: bool,
+    /// Таймаут вызовов бэкендов; `None` - без ограничения
+    backend_timeout: Option<Duration>,
 }
 
 impl ApiGatewayImpl {
-    pub async fn new(
+    pub fn new(
         engine_address: String,
         file_gateway_address: String,
+        timeouts: BackendTimeouts,
+        health_probe: HealthProbe,
         version: String,
+        min_project_space: u64,
+        status_errors: bool,
     ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
-        let engine = EngineClient::connect(&engine_address).await?;
-        let file_gateway = FileClient::connect(&file_gateway_address).await?;
+        let engine =
+            EngineClient::connect(&engine_address, timeouts.engine_connect, health_probe)?;
+        let file_gateway = FileClient::connect(
+            &file_gateway_address,
+            timeouts.file_gateway_connect,
+            health_probe,
+        )?;
 
         Ok(Self {
-            engine: Arc::new(Mutex::new(engine)),
-            file_gateway: Arc::new(Mutex::new(file_gateway)),
+            engine,
+            file_gateway,
             version,
+            min_project_space,
+            status_errors,
+            backend_timeout: timeouts.call,
         })
     }
+
+    /// Перепривязать ассеты проектов к новому пути файла; возвращает их число
+    ///
+    /// Ошибка только логируется: файл к этому моменту уже перенесён.
+    async fn relink_assets(&self, old_path: String, new_path: String) -> u32 {
+        let mut engine = self.engine.clone();
+        match engine
+            .client
+            .notify_file_moved(director::NotifyFileMovedRequest { old_path, new_path })
+            .deadline(self.backend_timeout)
+            .await
+        {
+            Ok(response) => {
+                let response = response.into_inner();
+                if response.unchecked > 0 {
+                    warn!(
+                        "Ассеты {} проектов не обновлены после переноса",
+                        response.unchecked
+                    );
+                }
+                response.relinked
+            }
+            Err(e) => {
+                warn!("Не удалось перепривязать ассеты после переноса: {}", e);
+                0
+            }
+        }
+    }
+
+    /// ID зарегистрированных проектов, лежащих в `path` (или совпадающих с ним)
+    async fn projects_under(&self, path: &str) -> Result<Vec<String>, Status> {
+        let mut engine = self.engine.clone();
+        let list = engine
+            .client
+            .list_projects(director::ListProjectsRequest {
+                favorites_first: false,
+                include_archived: true,
+                file_gateway_id: None,
+            })
+            .deadline(self.backend_timeout)
+            .await
+            .map_err(|e| deadline::backend_error("Engine", e))?
+            .into_inner();
+
+        Ok(list
+            .projects
+            .into_iter()
+            .filter(|project| path_contains(path, &project.path))
+            .map(|project| project.id)
+            .collect())
+    }
+}
+
+impl From<director::ProjectInfo> for Project {
+    fn from(p: director::ProjectInfo) -> Self {
+        Project {
+            id: p.id,
+            name: p.name,
+            path: p.path,
+            created_at: p.created_at,
+            updated_at: p.modified_at,
+            color: p.color,
+            is_favorite: p.is_favorite,
+            archived: p.archived,
+            storage_id: p.file_gateway_id,
+            custom: p.custom,
+        }
+    }
+}
+
+impl From<file_gateway::StorageCapabilities> for StorageCapabilities {
+    fn from(caps: file_gateway::StorageCapabilities) -> Self {
+        StorageCapabilities {
+            streaming: caps.streaming,
+            rename: caps.rename,
+            watch: caps.watch,
+            thumbnails: caps.thumbnails,
+            disk_space: caps.disk_space,
+            atomic_writes: caps.atomic_writes,
+        }
+    }
+}
+
+impl From<file_gateway::PathError> for PathError {
+    fn from(error: file_gateway::PathError) -> Self {
+        PathError {
+            path: error.path,
+            error: error.error,
+        }
+    }
+}
+
+/// Состояние бэкенда для `HealthCheck`
+fn service_status(
+    name: &str,
+    connected: bool,
+    address: &str,
+    version: String,
+    latency_ms: i64,
+    stats: BackendStats,
+) -> ServiceStatus {
+    ServiceStatus {
+        name: name.to_string(),
+        connected,
+        address: address.to_string(),
+        version,
+        latency_ms,
+        calls: stats.calls,
+        errors: stats.errors,
+        total_time_us: stats.total_time.as_micros() as u64,
+        max_time_us: stats.max_time.as_micros() as u64,
+        rejected: stats.rejected,
+        circuit_open: stats.circuit_open,
+    }
 }
 
 #[tonic::async_trait]
@@ -44,32 +187,40 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
     ) -> Result<Response<HealthCheckResponse>, Status> {
         info!("Health check");
 
-        let mut engine = self.engine.lock().await;
-        let mut file_gw = self.file_gateway.lock().await;
+        let mut engine = self.engine.clone();
+        let mut file_gw = self.file_gateway.clone();
 
-        let (engine_ok, engine_latency) = engine.health_check().await;
-        let (file_ok, file_latency) = file_gw.health_check().await;
+        let (engine_ok, engine_latency) = engine.health_check(self.backend_timeout).await;
+        let (file_ok, file_latency) = file_gw.health_check(self.backend_timeout).await;
 
         let services = vec![
-            ServiceStatus {
-                name: "DirectorEngine".to_string(),
-                connected: engine_ok,
-                address: engine.address.clone(),
-                version: "0.1.0".to_string(),
-                latency_ms: engine_latency,
-            },
-            ServiceStatus {
-                name: "FileGateway".to_string(),
-                connected: file_ok,
-                address: file_gw.address.clone(),
-                version: "0.1.0".to_string(),
-                latency_ms: file_latency,
-            },
+            service_status(
+                "DirectorEngine",
+                engine_ok,
+                engine.address(),
+                engine.version(),
+                engine_latency,
+                engine.stats(),
+            ),
+            service_status(
+                "FileGateway",
+                file_ok,
+                file_gw.address(),
+                file_gw.version(),
+                file_latency,
+                file_gw.stats(),
+            ),
         ];
 
+        // Недоступный бэкенд снимает готовность, но не живость: перезапуск
+        // шлюза оркестратором его не вернёт
+        let ready = engine_ok && file_ok;
+
         Ok(Response::new(HealthCheckResponse {
-            all_healthy: engine_ok && file_ok,
+            all_healthy: ready,
             services,
+            live: true,
+            ready,
         }))
     }
 
@@ -79,15 +230,31 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
     ) -> Result<Response<GetServicesInfoResponse>, Status> {
         info!("Get services info");
 
-        let mut file_gw = self.file_gateway.lock().await;
+        let mut file_gw = self.file_gateway.clone();
 
         let storage_info = file_gw
             .client
             .get_storage_info(file_gateway::GetStorageInfoRequest {})
+            .deadline(self.backend_timeout)
             .await
-            .map_err(|e| Status::internal(format!("FileGateway error: {}", e)))?
+            .map_err(|e| deadline::backend_error("FileGateway", e))?
             .into_inner();
 
+        // Без DirectorEngine сведения о хранилище всё равно полезны
+        let mut engine = self.engine.clone();
+        let engine_info = match engine
+            .client
+            .get_engine_info(director::GetEngineInfoRequest {})
+            .deadline(self.backend_timeout)
+            .await
+        {
+            Ok(response) => response.into_inner(),
+            Err(e) => {
+                warn!("Не удалось получить информацию о DirectorEngine: {}", e);
+                director::GetEngineInfoResponse::default()
+            }
+        };
+
         Ok(Response::new(GetServicesInfoResponse {
             gateway_version: self.version.clone(),
             engine_hostname: "localhost".to_string(), // TODO: получать от engine
@@ -98,6 +265,12 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
             root_paths: storage_info.root_paths,
             total_space: storage_info.total_space,
             free_space: storage_info.free_space,
+            storage_capabilities: storage_info.capabilities.map(StorageCapabilities::from),
+            storage_type: storage_info.storage_type,
+            engine_started_at: engine_info.started_at,
+            engine_uptime_seconds: engine_info.uptime_seconds,
+            storage_started_at: storage_info.started_at,
+            storage_uptime_seconds: storage_info.uptime_seconds,
         }))
     }
 
@@ -105,28 +278,24 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
 
     async fn list_projects(
         &self,
-        _request: Request<ListProjectsRequest>,
+        request: Request<ListProjectsRequest>,
     ) -> Result<Response<ListProjectsResponse>, Status> {
-        let mut engine = self.engine.lock().await;
+        let req = request.into_inner();
+        let mut engine = self.engine.clone();
 
         let response = engine
             .client
-            .list_projects(director::ListProjectsRequest {})
+            .list_projects(director::ListProjectsRequest {
+                favorites_first: req.favorites_first,
+                include_archived: req.include_archived,
+                file_gateway_id: req.storage_id,
+            })
+            .deadline(self.backend_timeout)
             .await
-            .map_err(|e| Status::internal(format!("Engine error: {}", e)))?
+            .map_err(|e| deadline::backend_error("Engine", e))?
             .into_inner();
 
-        let projects = response
-            .projects
-            .into_iter()
-            .map(|p| Project {
-                id: p.id,
-                name: p.name,
-                path: p.path,
-                created_at: p.created_at,
-                updated_at: p.modified_at,
-            })
-            .collect();
+        let projects = response.projects.into_iter().map(Project::from).collect();
 
         Ok(Response::new(ListProjectsResponse { projects }))
     }
@@ -138,54 +307,170 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
         let req = request.into_inner();
         info!("Create project: {} at {}", req.name, req.path);
 
-        // 1. Создаём структуру папок через FileGateway
-        let mut file_gw = self.file_gateway.lock().await;
+        // 1. Проверяем название до обращения к файловой системе: оно станет именем папки
+        let mut engine = self.engine.clone();
+        match engine
+            .client
+            .validate_project_name(director::ValidateProjectNameRequest {
+                name: req.name.clone(),
+            })
+            .deadline(self.backend_timeout)
+            .await
+        {
+            Ok(response) => {
+                let validation = response.into_inner();
+                if !validation.valid {
+                    return status::reply(self.status_errors, CreateProjectResponse {
+                        success: false,
+                        error_message: validation.error_message,
+                        ..Default::default()
+                    });
+                }
+            }
+            // Старый DirectorEngine без проверки - она всё равно выполнится при регистрации
+            Err(e) => warn!("Не удалось проверить название проекта: {}", e),
+        }
+
+        let mut file_gw = self.file_gateway.clone();
+
+        // 2. Проверяем свободное место, пока ничего не создано
+        match file_gw
+            .client
+            .get_disk_space(file_gateway::GetDiskSpaceRequest {
+                path: req.path.clone(),
+            })
+            .deadline(self.backend_timeout)
+            .await
+        {
+            Ok(response) => {
+                let space = response.into_inner();
+                if !space.success {
+                    warn!("Не удалось проверить свободное место: {}", space.error_message);
+                } else if space.free_space < self.min_project_space {
+                    return Err(status::coded(
+                        Code::ResourceExhausted,
+                        "INSUFFICIENT_SPACE",
+                        format!(
+                            "Недостаточно места для проекта в {}: свободно {} байт, \
+                             требуется {} байт (не хватает {} байт)",
+                            req.path,
+                            space.free_space,
+                            self.min_project_space,
+                            self.min_project_space - space.free_space
+                        ),
+                    ));
+                }
+            }
+            // Старый FileGateway без GetDiskSpace - создаём без проверки
+            Err(e) => warn!("Не удалось проверить свободное место: {}", e),
+        }
+
+        // 3. Создаём структуру папок через FileGateway
         let structure = file_gw
             .client
             .init_project_structure(file_gateway::InitProjectStructureRequest {
                 base_path: req.path.clone(),
                 project_name: req.name.clone(),
+                merge: req.merge,
             })
+            .deadline(self.backend_timeout)
             .await
-            .map_err(|e| Status::internal(format!("FileGateway error: {}", e)))?
+            .map_err(|e| deadline::backend_error("FileGateway", e))?
             .into_inner();
 
         if !structure.success {
-            return Ok(Response::new(CreateProjectResponse {
+            return status::reply(self.status_errors, CreateProjectResponse {
                 success: false,
                 error_message: structure.error_message,
-                project: None,
-            }));
+                ..Default::default()
+            });
         }
 
-        drop(file_gw); // Освобождаем лок
+        // ID хранилища нужен, чтобы потом найти проекты по хранилищу
+        let file_gateway_id = match file_gw
+            .client
+            .get_storage_info(file_gateway::GetStorageInfoRequest {})
+            .deadline(self.backend_timeout)
+            .await
+        {
+            Ok(response) => response.into_inner().storage_id,
+            Err(e) => {
+                warn!("Не удалось получить ID хранилища: {}", e);
+                String::new()
+            }
+        };
+
 
-        // 2. Регистрируем проект в DirectorEngine
-        let mut engine = self.engine.lock().await;
-        let response = engine
+        // 4. Регистрируем проект в DirectorEngine
+        let project_path = structure.project_path;
+        let mut engine = self.engine.clone();
+        let response = match engine
             .client
             .register_project(director::RegisterProjectRequest {
                 name: req.name,
-                path: structure.project_path,
-                file_gateway_id: String::new(), // TODO: передавать ID
+                path: project_path.clone(),
+                file_gateway_id,
             })
+            .deadline(self.backend_timeout)
             .await
-            .map_err(|e| Status::internal(format!("Engine error: {}", e)))?
-            .into_inner();
+        {
+            Ok(response) => response.into_inner(),
+            // Без ответа неизвестно, зарегистрирован ли проект: папки оставляем,
+            // клиент может повторить регистрацию по project_path
+            Err(e) => {
+                let e = deadline::backend_error("Engine", e);
+                warn!(
+                    "Папка проекта {} создана, но регистрация не завершилась: {}",
+                    project_path,
+                    e.message()
+                );
+                return status::reply(self.status_errors, CreateProjectResponse {
+                    success: false,
+                    error_message: e.message().to_string(),
+                    project: None,
+                    project_path,
+                    rolled_back: false,
+                });
+            }
+        };
 
-        let project = response.project.map(|p| Project {
-            id: p.id,
-            name: p.name,
-            path: p.path,
-            created_at: p.created_at,
-            updated_at: p.modified_at,
-        });
+        if response.success {
+            return status::reply(self.status_errors, CreateProjectResponse {
+                success: true,
+                error_message: String::new(),
+                project: response.project.map(Project::from),
+                project_path,
+                rolled_back: false,
+            });
+        }
 
-        Ok(Response::new(CreateProjectResponse {
-            success: response.success,
+        // 5. Движок отказал в регистрации - удаляем созданные папки. При слиянии
+        //    директория существовала и раньше, поэтому её не трогаем
+        let rolled_back = !req.merge
+            && match file_gw
+                .client
+                .delete(file_gateway::DeleteRequest {
+                    path: project_path.clone(),
+                    recursive: true,
+                    dry_run: false,
+                })
+                .deadline(self.backend_timeout)
+                .await
+            {
+                Ok(deleted) => deleted.into_inner().success,
+                Err(e) => {
+                    error!("Failed to roll back project folder {}: {}", project_path, e);
+                    false
+                }
+            };
+
+        status::reply(self.status_errors, CreateProjectResponse {
+            success: false,
             error_message: response.error_message,
-            project,
-        }))
+            project: None,
+            project_path: if rolled_back { String::new() } else { project_path },
+            rolled_back,
+        })
     }
 
     async fn open_project(
@@ -194,29 +479,103 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
     ) -> Result<Response<OpenProjectResponse>, Status> {
         let req = request.into_inner();
 
-        let mut engine = self.engine.lock().await;
+        let mut engine = self.engine.clone();
         let response = engine
             .client
             .open_project(director::OpenProjectRequest {
                 project_id: req.project_id,
             })
+            .deadline(self.backend_timeout)
             .await
-            .map_err(|e| Status::internal(format!("Engine error: {}", e)))?
+            .map_err(|e| deadline::backend_error("Engine", e))?
             .into_inner();
 
-        let project = response.project.map(|p| Project {
-            id: p.id,
-            name: p.name,
-            path: p.path,
-            created_at: p.created_at,
-            updated_at: p.modified_at,
-        });
+        let project = response.project.map(Project::from);
 
-        Ok(Response::new(OpenProjectResponse {
+        status::reply(self.status_errors, OpenProjectResponse {
             success: response.success,
             error_message: response.error_message,
             project,
-        }))
+        })
+    }
+
+    async fn update_project(
+        &self,
+        request: Request<UpdateProjectRequest>,
+    ) -> Result<Response<UpdateProjectResponse>, Status> {
+        let req = request.into_inner();
+        info!("Update project: {}", req.project_id);
+
+        let mut engine = self.engine.clone();
+        let response = engine
+            .client
+            .update_project(director::UpdateProjectRequest {
+                project_id: req.project_id,
+                color: req.color,
+                is_favorite: req.is_favorite,
+                set_custom: req.set_custom,
+                remove_custom: req.remove_custom,
+            })
+            .deadline(self.backend_timeout)
+            .await
+            .map_err(|e| deadline::backend_error("Engine", e))?
+            .into_inner();
+
+        status::reply(self.status_errors, UpdateProjectResponse {
+            success: response.success,
+            error_message: response.error_message,
+            project: response.project.map(Project::from),
+        })
+    }
+
+    async fn archive_project(
+        &self,
+        request: Request<ArchiveProjectRequest>,
+    ) -> Result<Response<ArchiveProjectResponse>, Status> {
+        let req = request.into_inner();
+        info!("Archive project: {}", req.project_id);
+
+        let mut engine = self.engine.clone();
+        let response = engine
+            .client
+            .archive_project(director::ArchiveProjectRequest {
+                project_id: req.project_id,
+            })
+            .deadline(self.backend_timeout)
+            .await
+            .map_err(|e| deadline::backend_error("Engine", e))?
+            .into_inner();
+
+        status::reply(self.status_errors, ArchiveProjectResponse {
+            success: response.success,
+            error_message: response.error_message,
+            project: response.project.map(Project::from),
+        })
+    }
+
+    async fn unarchive_project(
+        &self,
+        request: Request<UnarchiveProjectRequest>,
+    ) -> Result<Response<UnarchiveProjectResponse>, Status> {
+        let req = request.into_inner();
+        info!("Unarchive project: {}", req.project_id);
+
+        let mut engine = self.engine.clone();
+        let response = engine
+            .client
+            .unarchive_project(director::UnarchiveProjectRequest {
+                project_id: req.project_id,
+            })
+            .deadline(self.backend_timeout)
+            .await
+            .map_err(|e| deadline::backend_error("Engine", e))?
+            .into_inner();
+
+        status::reply(self.status_errors, UnarchiveProjectResponse {
+            success: response.success,
+            error_message: response.error_message,
+            project: response.project.map(Project::from),
+        })
     }
 
     async fn delete_project(
@@ -224,16 +583,25 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
         request: Request<DeleteProjectRequest>,
     ) -> Result<Response<DeleteProjectResponse>, Status> {
         let req = request.into_inner();
-        info!("Delete project: {}, delete_files: {}", req.project_id, req.delete_files);
+        info!(
+            "Delete project: {}, delete_files: {}, dry_run: {}",
+            req.project_id, req.delete_files, req.dry_run
+        );
 
         // Получаем информацию о проекте для удаления файлов
-        let project_path = if req.delete_files {
-            let mut engine = self.engine.lock().await;
+        let project_path = if req.delete_files || req.dry_run {
+            let mut engine = self.engine.clone();
             let list = engine
                 .client
-                .list_projects(director::ListProjectsRequest {})
+                .list_projects(director::ListProjectsRequest {
+                    favorites_first: false,
+                    // Архивный проект тоже можно удалить вместе с файлами
+                    include_archived: true,
+                    file_gateway_id: None,
+                })
+                .deadline(self.backend_timeout)
                 .await
-                .map_err(|e| Status::internal(format!("Engine error: {}", e)))?
+                .map_err(|e| deadline::backend_error("Engine", e))?
                 .into_inner();
 
             list.projects
@@ -244,40 +612,451 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
             None
         };
 
+        // Пробный запуск: проект остаётся в реестре, файлы на месте
+        if req.dry_run {
+            let Some(path) = project_path else {
+                return status::reply(self.status_errors, DeleteProjectResponse {
+                    success: false,
+                    error_message: format!("Проект не найден: {}", req.project_id),
+                    ..Default::default()
+                });
+            };
+            if !req.delete_files {
+                return status::reply(self.status_errors, DeleteProjectResponse {
+                    success: true,
+                    ..Default::default()
+                });
+            }
+
+            let mut file_gw = self.file_gateway.clone();
+            let preview = file_gw
+                .client
+                .delete(file_gateway::DeleteRequest {
+                    path,
+                    recursive: true,
+                    dry_run: true,
+                })
+                .deadline(self.backend_timeout)
+                .await
+                .map_err(|e| deadline::backend_error("FileGateway", e))?
+                .into_inner();
+
+            return status::reply(self.status_errors, DeleteProjectResponse {
+                success: preview.success,
+                error_message: preview.error_message,
+                paths: preview.paths,
+                paths_truncated: preview.paths_truncated,
+                total_size: preview.total_size,
+                ..Default::default()
+            });
+        }
+
         // Удаляем из реестра
-        let mut engine = self.engine.lock().await;
+        let mut engine = self.engine.clone();
         let response = engine
             .client
             .unregister_project(director::UnregisterProjectRequest {
                 project_id: req.project_id,
             })
+            .deadline(self.backend_timeout)
             .await
-            .map_err(|e| Status::internal(format!("Engine error: {}", e)))?
+            .map_err(|e| deadline::backend_error("Engine", e))?
             .into_inner();
 
-        drop(engine);
 
-        // Удаляем файлы если нужно
+        // Удаляем файлы если нужно; ошибка не отменяет удаление из реестра,
+        // но сообщается клиенту: файлы остались на диске
+        let mut files_deleted = false;
+        let mut files_error = String::new();
         if response.success && req.delete_files {
-            if let Some(path) = project_path {
-                let mut file_gw = self.file_gateway.lock().await;
-                if let Err(e) = file_gw
+            match project_path {
+                Some(path) => {
+                    let mut file_gw = self.file_gateway.clone();
+                    match file_gw
+                        .client
+                        .delete(file_gateway::DeleteRequest {
+                            path,
+                            recursive: true,
+                            dry_run: false,
+                        })
+                        .deadline(self.backend_timeout)
+                        .await
+                    {
+                        Ok(deleted) => {
+                            let deleted = deleted.into_inner();
+                            files_deleted = deleted.success;
+                            files_error = deleted.error_message;
+                        }
+                        Err(e) => files_error = format!("FileGateway error: {}", e.message()),
+                    }
+                }
+                None => files_error = "Путь проекта не найден в реестре".to_string(),
+            }
+
+            if !files_deleted {
+                error!("Failed to delete project files: {}", files_error);
+            }
+        }
+
+        status::reply(self.status_errors, DeleteProjectResponse {
+            success: response.success,
+            error_message: response.error_message,
+            registry_removed: response.success,
+            files_deleted,
+            files_error,
+            ..Default::default()
+        })
+    }
+
+    type SubscribeProjectEventsStream =
+        Pin<Box<dyn Stream<Item = Result<ProjectEvent, Status>> + Send>>;
+
+    async fn subscribe_project_events(
+        &self,
+        _request: Request<SubscribeProjectEventsRequest>,
+    ) -> Result<Response<Self::SubscribeProjectEventsStream>, Status> {
+        let mut engine = self.engine.clone();
+        let response = engine
+            .client
+            .subscribe_events(director::SubscribeEventsRequest {})
+            .deadline(self.backend_timeout)
+            .await
+            .map_err(|e| deadline::backend_error("Engine", e))?;
+
+        let mut inner_stream = response.into_inner();
+
+        let output_stream = async_stream::try_stream! {
+            while let Some(event) = inner_stream.next().await {
+                // Статус движка (например, DATA_LOSS при отставании) передаём клиенту как есть
+                let event = event?;
+                yield ProjectEvent {
+                    // Значения ProjectEventType совпадают с director.ProjectEventType
+                    r#type: event.r#type,
+                    project: event.project.map(Project::from),
+                    timestamp: event.timestamp,
+                };
+            }
+        };
+
+        Ok(Response::new(Box::pin(output_stream)))
+    }
+
+    type ExportProjectStream =
+        Pin<Box<dyn Stream<Item = Result<ExportProjectResponse, Status>> + Send>>;
+
+    async fn export_project(
+        &self,
+        request: Request<ExportProjectRequest>,
+    ) -> Result<Response<Self::ExportProjectStream>, Status> {
+        let req = request.into_inner();
+        info!("Export project: {}", req.project_id);
+
+        // 1. Манифест: метаданные, таймлайн и реестр ассетов
+        let mut engine = self.engine.clone();
+        let export = engine
+            .client
+            .export_project(director::ExportProjectRequest {
+                project_id: req.project_id,
+            })
+            .deadline(self.backend_timeout)
+            .await
+            .map_err(|e| deadline::backend_error("Engine", e))?
+            .into_inner();
+
+        if !export.success {
+            return Err(status::from_message(&export.error_message));
+        }
+        let project = export.project.ok_or_else(|| {
+            status::coded(Code::Internal, "BACKEND_ERROR", "Engine error: нет проекта в ответе")
+        })?;
+
+        // 2. Список файлов собираем заранее: размер архива уходит первым сообщением
+        let mut file_gw = self.file_gateway.clone();
+        let timeout = self.backend_timeout;
+        let mut entries = project_entries(&mut file_gw.client, &project.path, timeout).await?;
+
+        for external in export.external_files {
+            let info = file_gw
+                .client
+                .get_file_info(file_gateway::GetFileInfoRequest {
+                    path: external.source_path.clone(),
+                    follow_symlinks: None,
+                })
+                .deadline(self.backend_timeout)
+                .await
+                .map_err(|e| deadline::backend_error("FileGateway", e))?
+                .into_inner();
+
+            match info.file_info.filter(|entry| info.success && !entry.is_directory) {
+                Some(entry) => entries.push(ArchiveFile::new(
+                    EntryHeader {
+                        path: format!("{}/{}", archive::PROJECT_DIR, external.archive_path),
+                        kind: EntryKind::File,
+                        size: entry.size,
+                    },
+                    Some(external.source_path),
+                )
+                .map_err(archive_status)?),
+                // Ассет остаётся в манифесте: после импорта его можно перепривязать
+                None => warn!(
+                    "Файл ассета {} не найден и не попадёт в архив: {}",
+                    external.asset_id, external.source_path
+                ),
+            }
+        }
+
+        let mut client = file_gw.client;
+
+        let manifest = export.manifest;
+        let manifest_header = archive::header(&EntryHeader {
+            path: archive::MANIFEST_ENTRY.to_string(),
+            kind: EntryKind::File,
+            size: manifest.len() as u64,
+        })
+        .map_err(archive_status)?;
+
+        let total_size = archive::entry_size(manifest.len() as u64)
+            + entries.iter().map(|entry| archive::entry_size(entry.size)).sum::<u64>()
+            + archive::end_size();
+
+        let metadata = ExportProjectMetadata {
+            filename: format!("{}.{}", project.name, archive::ARCHIVE_EXTENSION),
+            total_size,
+        };
+
+        let output_stream = async_stream::try_stream! {
+            yield ExportProjectResponse {
+                data: Some(export_project_response::Data::Metadata(metadata)),
+            };
+
+            // Прогресс по таймеру, а не по файлам: мелкие файлы не засыпают клиента,
+            // а медленное чтение большого файла не оставляет его без сообщений
+            let mut bytes_done: u64 = 0;
+            let mut ticker = tokio::time::interval(PROGRESS_INTERVAL);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+            let padding = archive::padding(manifest.len() as u64);
+            for chunk in [manifest_header, manifest, padding] {
+                bytes_done += chunk.len() as u64;
+                yield export_chunk(chunk);
+            }
+
+            for entry in entries {
+                bytes_done += entry.header.len() as u64;
+                yield export_chunk(entry.header);
+
+                let Some(source) = entry.source else {
+                    continue;
+                };
+
+                let mut inner_stream = client
+                    .download_file(file_gateway::DownloadFileRequest {
+                        path: source.clone(),
+                        checksum: false,
+                        ..Default::default()
+                    })
+                    .deadline(timeout)
+                    .await
+                    .map_err(|e| deadline::backend_error("FileGateway", e))?
+                    .into_inner();
+
+                // Размер уже записан в заголовок: изменившийся файл испортил бы архив
+                let mut sent: u64 = 0;
+                loop {
+                    let msg = tokio::select! {
+                        msg = inner_stream.next() => msg,
+                        _ = ticker.tick() => {
+                            yield export_progress(bytes_done, total_size, &source);
+                            continue;
+                        }
+                    };
+                    let Some(msg) = msg else {
+                        break;
+                    };
+
+                    let msg = msg.map_err(stream_error)?;
+                    if let Some(file_gateway::download_file_response::Data::Chunk(chunk)) = msg.data {
+                        sent += chunk.len() as u64;
+                        if sent > entry.size {
+                            break;
+                        }
+                        bytes_done += chunk.len() as u64;
+                        yield export_chunk(chunk);
+                    }
+                }
+
+                if sent != entry.size {
+                    Err(status::coded(
+                        Code::Aborted,
+                        "FILE_CHANGED",
+                        format!("Файл {} изменился во время экспорта", source),
+                    ))?;
+                }
+
+                let padding = archive::padding(sent);
+                bytes_done += padding.len() as u64;
+                yield export_chunk(padding);
+            }
+
+            yield export_chunk(archive::end_of_archive());
+        };
+
+        Ok(Response::new(Box::pin(output_stream)))
+    }
+
+    async fn import_project_archive(
+        &self,
+        request: Request<Streaming<ImportProjectArchiveRequest>>,
+    ) -> Result<Response<ImportProjectArchiveResponse>, Status> {
+        let mut stream = request.into_inner();
+
+        // Первое сообщение - метаданные
+        let first_message = stream
+            .next()
+            .await
+            .ok_or_else(|| status::coded(Code::InvalidArgument, "EMPTY_STREAM", "Пустой стрим"))??;
+
+        let metadata = match first_message.data {
+            Some(import_project_archive_request::Data::Metadata(m)) => m,
+            _ => {
+                return Err(status::coded(
+                    Code::InvalidArgument,
+                    "METADATA_EXPECTED",
+                    "Первое сообщение должно содержать метаданные",
+                ))
+            }
+        };
+        info!("Import project archive into {}", metadata.destination_path);
+
+        let failed = |error_message: String| {
+            Response::new(ImportProjectArchiveResponse {
+                success: false,
+                error_message,
+                project: None,
+            })
+        };
+
+        // 1. Манифест - первая запись архива
+        let mut archive = IncomingArchive {
+            stream,
+            reader: ArchiveReader::new(),
+        };
+        let manifest = match archive.read_manifest().await {
+            Ok(manifest) => manifest,
+            Err(e) => return Ok(failed(e)),
+        };
+
+        let name = if metadata.name.is_empty() {
+            match manifest_name(&manifest) {
+                Some(name) => name,
+                None => {
+                    let entry = archive::MANIFEST_ENTRY;
+                    return Ok(failed(format!("В {} нет названия проекта", entry)));
+                }
+            }
+        } else {
+            metadata.name
+        };
+
+        // 2. Проверяем название до создания папки: оно станет её именем
+        let mut engine = self.engine.clone();
+        match engine
+            .client
+            .validate_project_name(director::ValidateProjectNameRequest { name: name.clone() })
+            .deadline(self.backend_timeout)
+            .await
+        {
+            Ok(response) => {
+                let validation = response.into_inner();
+                if !validation.valid {
+                    return Ok(failed(validation.error_message));
+                }
+            }
+            Err(e) => warn!("Не удалось проверить название проекта: {}", e),
+        }
+
+        // 3. Создаём папку проекта: существующая не перезаписывается
+        let mut file_gw = self.file_gateway.clone();
+        let structure = file_gw
+            .client
+            .init_project_structure(file_gateway::InitProjectStructureRequest {
+                base_path: metadata.destination_path,
+                project_name: name.clone(),
+                merge: false,
+            })
+            .deadline(self.backend_timeout)
+            .await
+            .map_err(|e| deadline::backend_error("FileGateway", e))?
+            .into_inner();
+
+        if !structure.success {
+            return Ok(failed(structure.error_message));
+        }
+
+        let file_gateway_id = match file_gw
+            .client
+            .get_storage_info(file_gateway::GetStorageInfoRequest {})
+            .deadline(self.backend_timeout)
+            .await
+        {
+            Ok(response) => response.into_inner().storage_id,
+            Err(e) => {
+                warn!("Не удалось получить ID хранилища: {}", e);
+                String::new()
+            }
+        };
+
+        let mut client = file_gw.client;
+
+        // 4. Распаковываем файлы и регистрируем проект; при ошибке папка удаляется
+        let project_path = structure.project_path;
+        let result = match archive.unpack(&mut client, &project_path, self.backend_timeout).await {
+            Ok(()) => {
+                let mut engine = self.engine.clone();
+                engine
                     .client
+                    .import_project(director::ImportProjectRequest {
+                        manifest,
+                        path: project_path.clone(),
+                        file_gateway_id,
+                        name,
+                    })
+                    .deadline(self.backend_timeout)
+                    .await
+                    .map_err(|e| format!("Engine error: {}", e))
+                    .and_then(|response| {
+                        let response = response.into_inner();
+                        match response.project.filter(|_| response.success) {
+                            Some(project) => Ok(project),
+                            None => Err(response.error_message),
+                        }
+                    })
+            }
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(project) => Ok(Response::new(ImportProjectArchiveResponse {
+                success: true,
+                error_message: String::new(),
+                project: Some(Project::from(project)),
+            })),
+            Err(e) => {
+                error!("Failed to import project archive: {}", e);
+                if let Err(e) = client
                     .delete(file_gateway::DeleteRequest {
-                        path,
+                        path: project_path,
                         recursive: true,
+                        dry_run: false,
                     })
+                    .deadline(self.backend_timeout)
                     .await
                 {
-                    error!("Failed to delete project files: {}", e);
+                    error!("Failed to delete partially imported project: {}", e);
                 }
+                Ok(failed(e))
             }
         }
-
-        Ok(Response::new(DeleteProjectResponse {
-            success: response.success,
-            error_message: response.error_message,
-        }))
     }
 
     // === Файловая система ===
@@ -286,13 +1065,14 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
         &self,
         _request: Request<GetStorageInfoRequest>,
     ) -> Result<Response<GetStorageInfoResponse>, Status> {
-        let mut file_gw = self.file_gateway.lock().await;
+        let mut file_gw = self.file_gateway.clone();
 
         let response = file_gw
             .client
             .get_storage_info(file_gateway::GetStorageInfoRequest {})
+            .deadline(self.backend_timeout)
             .await
-            .map_err(|e| Status::internal(format!("FileGateway error: {}", e)))?
+            .map_err(|e| deadline::backend_error("FileGateway", e))?
             .into_inner();
 
         Ok(Response::new(GetStorageInfoResponse {
@@ -304,6 +1084,10 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
             root_paths: response.root_paths,
             total_space: response.total_space,
             free_space: response.free_space,
+            capabilities: response.capabilities.map(StorageCapabilities::from),
+            storage_type: response.storage_type,
+            quota_bytes: response.quota_bytes,
+            quota_used_bytes: response.quota_used_bytes,
         }))
     }
 
@@ -313,12 +1097,13 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
     ) -> Result<Response<BrowseDirectoryResponse>, Status> {
         let req = request.into_inner();
 
-        let mut file_gw = self.file_gateway.lock().await;
+        let mut file_gw = self.file_gateway.clone();
         let response = file_gw
             .client
             .browse_directory(file_gateway::BrowseDirectoryRequest { path: req.path })
+            .deadline(self.backend_timeout)
             .await
-            .map_err(|e| Status::internal(format!("FileGateway error: {}", e)))?
+            .map_err(|e| deadline::backend_error("FileGateway", e))?
             .into_inner();
 
         let entries = response
@@ -332,6 +1117,7 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
                 created_at: e.created_at,
                 modified_at: e.modified_at,
                 mime_type: e.mime_type,
+                metadata_error: e.metadata_error,
             })
             .collect();
 
@@ -341,6 +1127,8 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
             current_path: response.current_path,
             parent_path: response.parent_path,
             entries,
+            total_space: response.total_space,
+            free_space: response.free_space,
         }))
     }
 
@@ -350,22 +1138,24 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
     ) -> Result<Response<CreateDirectoryResponse>, Status> {
         let req = request.into_inner();
 
-        let mut file_gw = self.file_gateway.lock().await;
+        let mut file_gw = self.file_gateway.clone();
         let response = file_gw
             .client
             .create_directory(file_gateway::CreateDirectoryRequest {
                 path: req.path,
                 create_parents: req.create_parents,
+                mode: req.mode,
             })
+            .deadline(self.backend_timeout)
             .await
-            .map_err(|e| Status::internal(format!("FileGateway error: {}", e)))?
+            .map_err(|e| deadline::backend_error("FileGateway", e))?
             .into_inner();
 
-        Ok(Response::new(CreateDirectoryResponse {
+        status::reply(self.status_errors, CreateDirectoryResponse {
             success: response.success,
             error_message: response.error_message,
             created_path: response.created_path,
-        }))
+        })
     }
 
     async fn delete(
@@ -374,21 +1164,191 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
     ) -> Result<Response<DeleteResponse>, Status> {
         let req = request.into_inner();
 
-        let mut file_gw = self.file_gateway.lock().await;
+        // Удаление папки с проектом оставило бы в реестре запись без файлов
+        let affected_projects = match self.projects_under(&req.path).await {
+            Ok(ids) => ids,
+            Err(e) if req.force => {
+                warn!("Не удалось проверить проекты в {}: {}", req.path, e);
+                Vec::new()
+            }
+            Err(e) => return Err(e),
+        };
+
+        if !affected_projects.is_empty() {
+            if !req.force && !req.dry_run {
+                return status::reply(self.status_errors, DeleteResponse {
+                    success: false,
+                    error_message: format!(
+                        "Путь содержит зарегистрированные проекты: {}",
+                        affected_projects.join(", ")
+                    ),
+                    affected_projects,
+                    ..Default::default()
+                });
+            }
+            if req.force && !req.dry_run {
+                warn!(
+                    "Удаление {} затронет зарегистрированные проекты: {}",
+                    req.path,
+                    affected_projects.join(", ")
+                );
+            }
+        }
+
+        let mut file_gw = self.file_gateway.clone();
         let response = file_gw
             .client
             .delete(file_gateway::DeleteRequest {
                 path: req.path,
                 recursive: req.recursive,
+                dry_run: req.dry_run,
             })
+            .deadline(self.backend_timeout)
             .await
-            .map_err(|e| Status::internal(format!("FileGateway error: {}", e)))?
+            .map_err(|e| deadline::backend_error("FileGateway", e))?
             .into_inner();
 
-        Ok(Response::new(DeleteResponse {
+        status::reply(self.status_errors, DeleteResponse {
             success: response.success,
             error_message: response.error_message,
-        }))
+            paths: response.paths,
+            paths_truncated: response.paths_truncated,
+            total_size: response.total_size,
+            affected_projects,
+            errors: response.errors.into_iter().map(PathError::from).collect(),
+        })
+    }
+
+    async fn move_file(
+        &self,
+        request: Request<MoveFileRequest>,
+    ) -> Result<Response<MoveFileResponse>, Status> {
+        let req = request.into_inner();
+        info!("Move file: {} -> {}", req.source_path, req.destination_path);
+
+        // В пределах одного хранилища FileGateway переименовывает файл на месте
+        let mut file_gw = self.file_gateway.clone();
+        let moved = file_gw
+            .client
+            .transfer(file_gateway::TransferRequest {
+                source_storage_id: String::new(),
+                source_path: req.source_path.clone(),
+                destination_storage_id: String::new(),
+                destination_path: req.destination_path.clone(),
+                overwrite: req.overwrite,
+                delete_source: true,
+            })
+            // Перенос между устройствами копирует данные: без таймаута, как и загрузка
+            .await
+            .map_err(|e| deadline::backend_error("FileGateway", e))?
+            .into_inner();
+
+        // Если источник не удалился, старый путь ещё действителен - ассеты не трогаем
+        if !moved.success || !moved.source_deleted {
+            return status::reply(self.status_errors, MoveFileResponse {
+                success: moved.success,
+                error_message: moved.error_message,
+                relinked_assets: 0,
+            });
+        }
+
+        // Файл уже перенесён: ошибка перепривязки не отменяет перенос
+        let relinked_assets = self.relink_assets(req.source_path, req.destination_path).await;
+
+        status::reply(self.status_errors, MoveFileResponse {
+            success: true,
+            error_message: String::new(),
+            relinked_assets,
+        })
+    }
+
+    async fn rename(
+        &self,
+        request: Request<RenameRequest>,
+    ) -> Result<Response<RenameResponse>, Status> {
+        let req = request.into_inner();
+        info!("Rename: {} -> {}", req.path, req.new_name);
+
+        let mut file_gw = self.file_gateway.clone();
+        let renamed = file_gw
+            .client
+            .rename(file_gateway::RenameRequest {
+                path: req.path.clone(),
+                new_name: req.new_name,
+            })
+            .deadline(self.backend_timeout)
+            .await
+            .map_err(|e| deadline::backend_error("FileGateway", e))?
+            .into_inner();
+
+        // Движок перепривязывает ассеты по точному пути, поэтому файлы внутри
+        // переименованной директории остаются со старыми путями
+        let relinked_assets = if renamed.success && !renamed.is_directory {
+            self.relink_assets(req.path, renamed.new_path.clone()).await
+        } else {
+            0
+        };
+
+        status::reply(self.status_errors, RenameResponse {
+            success: renamed.success,
+            error_message: renamed.error_message,
+            new_path: renamed.new_path,
+            relinked_assets,
+        })
+    }
+
+    type CopyFileStream = Pin<Box<dyn Stream<Item = Result<CopyFileEvent, Status>> + Send>>;
+
+    async fn copy_file(
+        &self,
+        request: Request<CopyFileRequest>,
+    ) -> Result<Response<Self::CopyFileStream>, Status> {
+        let req = request.into_inner();
+        info!("Copy file: {} -> {}", req.source_path, req.destination_path);
+
+        let mut file_gw = self.file_gateway.clone();
+        let response = file_gw
+            .client
+            .transfer_with_progress(file_gateway::TransferRequest {
+                source_storage_id: String::new(),
+                source_path: req.source_path,
+                destination_storage_id: String::new(),
+                destination_path: req.destination_path,
+                overwrite: req.overwrite,
+                delete_source: false,
+            })
+            .deadline(self.backend_timeout)
+            .await
+            .map_err(|e| deadline::backend_error("FileGateway", e))?;
+
+        let mut inner_stream = response.into_inner();
+
+        let output_stream = async_stream::try_stream! {
+            while let Some(msg) = inner_stream.next().await {
+                let msg = msg.map_err(stream_error)?;
+
+                let event = match msg.event {
+                    Some(file_gateway::transfer_event::Event::Progress(p)) => {
+                        copy_file_event::Event::Progress(OperationProgress {
+                            bytes_done: p.bytes_done,
+                            total_bytes: p.total_bytes,
+                            current_file: p.current_file,
+                        })
+                    }
+                    Some(file_gateway::transfer_event::Event::Result(r)) => {
+                        copy_file_event::Event::Result(CopyFileResult {
+                            success: r.success,
+                            error_message: r.error_message,
+                            bytes_transferred: r.bytes_transferred,
+                        })
+                    }
+                    None => continue,
+                };
+                yield CopyFileEvent { event: Some(event) };
+            }
+        };
+
+        Ok(Response::new(Box::pin(output_stream)))
     }
 
     async fn init_project_structure(
@@ -397,18 +1357,20 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
     ) -> Result<Response<InitProjectStructureResponse>, Status> {
         let req = request.into_inner();
 
-        let mut file_gw = self.file_gateway.lock().await;
+        let mut file_gw = self.file_gateway.clone();
         let response = file_gw
             .client
             .init_project_structure(file_gateway::InitProjectStructureRequest {
                 base_path: req.base_path,
                 project_name: req.project_name,
+                merge: req.merge,
             })
+            .deadline(self.backend_timeout)
             .await
-            .map_err(|e| Status::internal(format!("FileGateway error: {}", e)))?
+            .map_err(|e| deadline::backend_error("FileGateway", e))?
             .into_inner();
 
-        Ok(Response::new(InitProjectStructureResponse {
+        status::reply(self.status_errors, InitProjectStructureResponse {
             success: response.success,
             error_message: response.error_message,
             project_path: response.project_path,
@@ -418,7 +1380,7 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
             images_path: response.images_path,
             timeline_path: response.timeline_path,
             exports_path: response.exports_path,
-        }))
+        })
     }
 
     // === Стриминг файлов ===
@@ -427,46 +1389,25 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
         &self,
         request: Request<Streaming<UploadFileRequest>>,
     ) -> Result<Response<UploadFileResponse>, Status> {
-        let mut stream = request.into_inner();
-        let mut file_gw = self.file_gateway.lock().await;
-
-        // Преобразуем стрим
-        let mapped_stream = async_stream::stream! {
-            while let Some(msg) = stream.next().await {
-                match msg {
-                    Ok(req) => {
-                        let fg_req = match req.data {
-                            Some(upload_file_request::Data::Metadata(m)) => {
-                                file_gateway::UploadFileRequest {
-                                    data: Some(file_gateway::upload_file_request::Data::Metadata(
-                                        file_gateway::UploadFileMetadata {
-                                            destination_path: m.destination_path,
-                                            filename: m.filename,
-                                            total_size: m.total_size,
-                                            overwrite: m.overwrite,
-                                        },
-                                    )),
-                                }
-                            }
-                            Some(upload_file_request::Data::Chunk(c)) => {
-                                file_gateway::UploadFileRequest {
-                                    data: Some(file_gateway::upload_file_request::Data::Chunk(c)),
-                                }
-                            }
-                            None => continue,
-                        };
-                        yield fg_req;
-                    }
-                    Err(_) => break,
-                }
-            }
-        };
+        let stream = request.into_inner();
+        let mut file_gw = self.file_gateway.clone();
 
         let response = file_gw
             .client
-            .upload_file(mapped_stream)
+            .upload_file(upload_stream(stream))
             .await
-            .map_err(|e| Status::internal(format!("FileGateway error: {}", e)))?
+            .map_err(|e| match e.code() {
+                // Отмену, занятое имя (повтор с overwrite), конфликт условной
+                // перезаписи, отсутствующую или закрытую директорию и ошибку
+                // в самом стриме (например, пустой стрим) клиент обрабатывает сам
+                Code::Cancelled
+                | Code::AlreadyExists
+                | Code::Aborted
+                | Code::NotFound
+                | Code::PermissionDenied
+                | Code::InvalidArgument => e,
+                _ => deadline::backend_error("FileGateway", e),
+            })?
             .into_inner();
 
         Ok(Response::new(UploadFileResponse {
@@ -477,6 +1418,45 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
         }))
     }
 
+    async fn upload_batch(
+        &self,
+        request: Request<Streaming<UploadFileRequest>>,
+    ) -> Result<Response<UploadBatchResponse>, Status> {
+        let stream = request.into_inner();
+        let mut file_gw = self.file_gateway.clone();
+
+        let response = file_gw
+            .client
+            .upload_batch(upload_stream(stream))
+            .await
+            .map_err(|e| match e.code() {
+                Code::Cancelled | Code::InvalidArgument => e,
+                _ => deadline::backend_error("FileGateway", e),
+            })?
+            .into_inner();
+
+        info!(
+            "Batch upload: {} files, success: {}",
+            response.files.len(),
+            response.success
+        );
+
+        Ok(Response::new(UploadBatchResponse {
+            success: response.success,
+            error_message: response.error_message,
+            files: response
+                .files
+                .into_iter()
+                .map(|file| UploadFileResponse {
+                    success: file.success,
+                    error_message: file.error_message,
+                    file_path: file.file_path,
+                    bytes_written: file.bytes_written,
+                })
+                .collect(),
+        }))
+    }
+
     type DownloadFileStream = Pin<Box<dyn Stream<Item = Result<DownloadFileResponse, Status>> + Send>>;
 
     async fn download_file(
@@ -484,19 +1464,25 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
         request: Request<DownloadFileRequest>,
     ) -> Result<Response<Self::DownloadFileStream>, Status> {
         let req = request.into_inner();
-        let mut file_gw = self.file_gateway.lock().await;
+        let mut file_gw = self.file_gateway.clone();
 
         let response = file_gw
             .client
-            .download_file(file_gateway::DownloadFileRequest { path: req.path })
+            .download_file(file_gateway::DownloadFileRequest {
+                path: req.path,
+                checksum: req.checksum,
+                offset: req.offset,
+                length: req.length,
+            })
+            .deadline(self.backend_timeout)
             .await
-            .map_err(|e| Status::internal(format!("FileGateway error: {}", e)))?;
+            .map_err(|e| deadline::backend_error("FileGateway", e))?;
 
         let mut inner_stream = response.into_inner();
 
         let output_stream = async_stream::try_stream! {
             while let Some(msg) = inner_stream.next().await {
-                let msg = msg.map_err(|e| Status::internal(format!("Stream error: {}", e)))?;
+                let msg = msg.map_err(stream_error)?;
                 
                 let response = match msg.data {
                     Some(file_gateway::download_file_response::Data::Metadata(m)) => {
@@ -506,6 +1492,10 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
                                     filename: m.filename,
                                     total_size: m.total_size,
                                     mime_type: m.mime_type,
+                                    checksum_follows: m.checksum_follows,
+                                    offset: m.offset,
+                                    length: m.length,
+                                    accepts_ranges: m.accepts_ranges,
                                 },
                             )),
                         }
@@ -515,6 +1505,11 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
                             data: Some(download_file_response::Data::Chunk(c)),
                         }
                     }
+                    Some(file_gateway::download_file_response::Data::Checksum(c)) => {
+                        DownloadFileResponse {
+                            data: Some(download_file_response::Data::Checksum(c)),
+                        }
+                    }
                     None => continue,
                 };
                 yield response;
@@ -525,3 +1520,578 @@ impl api_gateway_server::ApiGateway for ApiGatewayImpl {
     }
 }
 
+/// Запись архива проекта: готовый заголовок и источник данных
+struct ArchiveFile {
+    header: Vec<u8>,
+    /// Файл на FileGateway; `None` - директория
+    source: Option<String>,
+    size: u64,
+}
+
+impl ArchiveFile {
+    fn new(entry: EntryHeader, source: Option<String>) -> Result<Self, ArchiveError> {
+        Ok(Self {
+            header: archive::header(&entry)?,
+            source,
+            size: entry.size,
+        })
+    }
+}
+
+/// Ошибка записи архива для клиента
+fn archive_status(e: ArchiveError) -> Status {
+    status::coded(Code::FailedPrecondition, e.reason(), e)
+}
+
+/// Обрыв стрима FileGateway посреди ответа
+fn stream_error(e: Status) -> Status {
+    status::coded(Code::Internal, "BACKEND_STREAM_ERROR", format!("Stream error: {}", e))
+}
+
+fn export_chunk(chunk: Vec<u8>) -> ExportProjectResponse {
+    ExportProjectResponse {
+        data: Some(export_project_response::Data::Chunk(chunk)),
+    }
+}
+
+fn export_progress(bytes_done: u64, total_bytes: u64, current_file: &str) -> ExportProjectResponse {
+    ExportProjectResponse {
+        data: Some(export_project_response::Data::Progress(OperationProgress {
+            bytes_done,
+            total_bytes,
+            current_file: current_file.to_string(),
+        })),
+    }
+}
+
+/// Файлы и директории папки проекта (пути в архиве под `project/`)
+async fn project_entries(
+    client: &mut FileGatewayClient<TracedChannel>,
+    project_path: &str,
+    timeout: Option<Duration>,
+) -> Result<Vec<ArchiveFile>, Status> {
+    let mut entries = Vec::new();
+    let mut pending = vec![project_path.to_string()];
+
+    while let Some(directory) = pending.pop() {
+        let listing = client
+            .browse_directory(file_gateway::BrowseDirectoryRequest {
+                path: directory.clone(),
+            })
+            .deadline(timeout)
+            .await
+            .map_err(|e| deadline::backend_error("FileGateway", e))?
+            .into_inner();
+
+        if !listing.success {
+            let (code, reason) = status::error_code(&listing.error_message);
+            return Err(status::coded(
+                code,
+                reason,
+                format!("Не удалось прочитать {}: {}", directory, listing.error_message),
+            ));
+        }
+
+        for entry in listing.entries {
+            let relative = archive::relative_path(project_path, &entry.path).ok_or_else(|| {
+                status::coded(
+                    Code::Internal,
+                    "BACKEND_ERROR",
+                    format!("FileGateway вернул путь вне проекта: {}", entry.path),
+                )
+            })?;
+            let path = format!("{}/{}", archive::PROJECT_DIR, relative);
+
+            if entry.is_directory {
+                entries.push(ArchiveFile::new(
+                    EntryHeader {
+                        path,
+                        kind: EntryKind::Directory,
+                        size: 0,
+                    },
+                    None,
+                )
+                .map_err(archive_status)?);
+                pending.push(entry.path);
+            } else {
+                entries.push(ArchiveFile::new(
+                    EntryHeader {
+                        path,
+                        kind: EntryKind::File,
+                        size: entry.size,
+                    },
+                    Some(entry.path),
+                )
+                .map_err(archive_status)?);
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Стрим загрузки клиента в виде запросов FileGateway
+fn upload_stream(
+    mut stream: Streaming<UploadFileRequest>,
+) -> impl Stream<Item = file_gateway::UploadFileRequest> {
+    async_stream::stream! {
+        while let Some(msg) = stream.next().await {
+            match msg {
+                Ok(req) => {
+                    let fg_req = match req.data {
+                        Some(upload_file_request::Data::Metadata(m)) => {
+                            file_gateway::UploadFileRequest {
+                                data: Some(file_gateway::upload_file_request::Data::Metadata(
+                                    file_gateway::UploadFileMetadata {
+                                        destination_path: m.destination_path,
+                                        filename: m.filename,
+                                        total_size: m.total_size,
+                                        overwrite: m.overwrite,
+                                        expected_modified_at: m.expected_modified_at,
+                                        mode: m.mode,
+                                    },
+                                )),
+                            }
+                        }
+                        Some(upload_file_request::Data::Chunk(c)) => {
+                            file_gateway::UploadFileRequest {
+                                data: Some(file_gateway::upload_file_request::Data::Chunk(c)),
+                            }
+                        }
+                        Some(upload_file_request::Data::Cancel(c)) => {
+                            file_gateway::UploadFileRequest {
+                                data: Some(file_gateway::upload_file_request::Data::Cancel(
+                                    file_gateway::UploadCancel { reason: c.reason },
+                                )),
+                            }
+                        }
+                        None => continue,
+                    };
+                    yield fg_req;
+                }
+                Err(e) => {
+                    // Обрыв клиентского стрима - отменяем загрузку, иначе
+                    // FileGateway примет частичный файл за целый
+                    yield file_gateway::UploadFileRequest {
+                        data: Some(file_gateway::upload_file_request::Data::Cancel(
+                            file_gateway::UploadCancel { reason: e.message().to_string() },
+                        )),
+                    };
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Лежит ли `path` внутри `parent` (или совпадает с ним)
+///
+/// Пути сравниваются по компонентам, поэтому `/a/b` не содержит `/a/bc`;
+/// разделители `/` и `\`, повторные и завершающие разделители равноправны.
+fn path_contains(parent: &str, path: &str) -> bool {
+    fn components(path: &str) -> Vec<&str> {
+        path.split(['/', '\\'])
+            .filter(|component| !component.is_empty() && *component != ".")
+            .collect()
+    }
+
+    components(path).starts_with(&components(parent))
+}
+
+/// Название проекта из манифеста движка
+fn manifest_name(manifest: &[u8]) -> Option<String> {
+    let manifest: serde_json::Value = serde_json::from_slice(manifest).ok()?;
+    manifest["name"].as_str().map(str::to_string)
+}
+
+/// Загрузка файла архива на FileGateway, идущая параллельно с приёмом архива
+struct PendingUpload {
+    sender: tokio::sync::mpsc::Sender<file_gateway::UploadFileRequest>,
+    task: tokio::task::JoinHandle<Result<file_gateway::UploadFileResponse, Status>>,
+}
+
+impl PendingUpload {
+    fn start(
+        client: &FileGatewayClient<TracedChannel>,
+        destination_path: String,
+        filename: String,
+        total_size: u64,
+    ) -> Self {
+        let (sender, receiver) = tokio::sync::mpsc::channel(4);
+        let metadata = file_gateway::UploadFileRequest {
+            data: Some(file_gateway::upload_file_request::Data::Metadata(
+                file_gateway::UploadFileMetadata {
+                    destination_path,
+                    filename,
+                    total_size,
+                    overwrite: false,
+                    expected_modified_at: None,
+                    mode: None,
+                },
+            )),
+        };
+
+        let mut client = client.clone();
+        let requests = tokio_stream::once(metadata)
+            .chain(tokio_stream::wrappers::ReceiverStream::new(receiver));
+        let task = tokio::spawn(async move {
+            client.upload_file(requests).await.map(Response::into_inner)
+        });
+
+        Self { sender, task }
+    }
+
+    /// Передать часть данных; если загрузка уже завершилась, ошибку вернёт `finish`
+    async fn send(&self, chunk: Vec<u8>) {
+        let _ = self
+            .sender
+            .send(file_gateway::UploadFileRequest {
+                data: Some(file_gateway::upload_file_request::Data::Chunk(chunk)),
+            })
+            .await;
+    }
+
+    /// Закончить загрузку и дождаться ответа FileGateway
+    async fn finish(self) -> Result<(), String> {
+        drop(self.sender);
+        let response = self
+            .task
+            .await
+            .map_err(|e| e.to_string())?
+            .map_err(|e| format!("FileGateway error: {}", e))?;
+
+        if !response.success {
+            return Err(response.error_message);
+        }
+        Ok(())
+    }
+
+    /// Отменить загрузку: FileGateway удалит частичный файл
+    async fn cancel(self, reason: &str) {
+        let _ = self
+            .sender
+            .send(file_gateway::UploadFileRequest {
+                data: Some(file_gateway::upload_file_request::Data::Cancel(
+                    file_gateway::UploadCancel {
+                        reason: reason.to_string(),
+                    },
+                )),
+            })
+            .await;
+        drop(self.sender);
+        let _ = self.task.await;
+    }
+}
+
+/// Архив проекта, принимаемый от клиента
+struct IncomingArchive {
+    stream: Streaming<ImportProjectArchiveRequest>,
+    reader: ArchiveReader,
+}
+
+impl IncomingArchive {
+    /// Следующее событие разбора, при необходимости дочитывая стрим
+    async fn next_event(&mut self) -> Result<ArchiveEvent, String> {
+        loop {
+            if let Some(event) = self.reader.next_event().map_err(|e| e.to_string())? {
+                return Ok(event);
+            }
+            if self.reader.is_finished() {
+                return Ok(ArchiveEvent::End);
+            }
+
+            match self.stream.next().await {
+                Some(message) => {
+                    let message = message.map_err(|e| e.message().to_string())?;
+                    if let Some(import_project_archive_request::Data::Chunk(chunk)) = message.data {
+                        self.reader.feed(&chunk);
+                    }
+                }
+                None => return Err(ArchiveError::Truncated.to_string()),
+            }
+        }
+    }
+
+    /// Прочитать манифест (первая запись архива)
+    async fn read_manifest(&mut self) -> Result<Vec<u8>, String> {
+        let size = match self.next_event().await? {
+            ArchiveEvent::Entry(header)
+                if header.path == archive::MANIFEST_ENTRY && header.kind == EntryKind::File =>
+            {
+                header.size
+            }
+            _ => {
+                return Err(format!(
+                    "Первой записью архива должен быть {}",
+                    archive::MANIFEST_ENTRY
+                ))
+            }
+        };
+        if size > MAX_MANIFEST_SIZE {
+            return Err(format!("{} слишком большой: {} байт", archive::MANIFEST_ENTRY, size));
+        }
+
+        let mut manifest = Vec::with_capacity(size as usize);
+        loop {
+            match self.next_event().await? {
+                ArchiveEvent::Data(data) => manifest.extend_from_slice(&data),
+                ArchiveEvent::EntryEnd => return Ok(manifest),
+                _ => return Err(ArchiveError::Truncated.to_string()),
+            }
+        }
+    }
+
+    /// Распаковать файлы проекта в `project_path`
+    async fn unpack(
+        &mut self,
+        client: &mut FileGatewayClient<TracedChannel>,
+        project_path: &str,
+        timeout: Option<Duration>,
+    ) -> Result<(), String> {
+        let mut upload = None;
+        let result = self.unpack_entries(client, project_path, timeout, &mut upload).await;
+
+        if let Err(e) = &result {
+            if let Some(upload) = upload.take() {
+                upload.cancel(e).await;
+            }
+        }
+        result
+    }
+
+    async fn unpack_entries(
+        &mut self,
+        client: &mut FileGatewayClient<TracedChannel>,
+        project_path: &str,
+        timeout: Option<Duration>,
+        upload: &mut Option<PendingUpload>,
+    ) -> Result<(), String> {
+        let root = project_path.trim_end_matches(['/', '\\']);
+
+        loop {
+            match self.next_event().await? {
+                ArchiveEvent::Entry(header) => {
+                    // Записи вне `project/` (от более новых версий) пропускаются
+                    let Some(relative) = header
+                        .path
+                        .strip_prefix(archive::PROJECT_DIR)
+                        .and_then(|path| path.strip_prefix('/'))
+                    else {
+                        warn!("Запись архива пропущена: {}", header.path);
+                        continue;
+                    };
+
+                    match header.kind {
+                        EntryKind::Directory => {
+                            let created = client
+                                .create_directory(file_gateway::CreateDirectoryRequest {
+                                    path: format!("{}/{}", root, relative),
+                                    create_parents: true,
+                                    mode: None,
+                                })
+                                .deadline(timeout)
+                                .await
+                                .map_err(|e| format!("FileGateway error: {}", e))?
+                                .into_inner();
+                            if !created.success {
+                                return Err(created.error_message);
+                            }
+                        }
+                        EntryKind::File => {
+                            let (directory, filename) = match relative.rsplit_once('/') {
+                                Some((directory, filename)) => {
+                                    (format!("{}/{}", root, directory), filename)
+                                }
+                                None => (root.to_string(), relative),
+                            };
+                            *upload = Some(PendingUpload::start(
+                                client,
+                                directory,
+                                filename.to_string(),
+                                header.size,
+                            ));
+                        }
+                    }
+                }
+                ArchiveEvent::Data(data) => {
+                    if let Some(upload) = upload.as_ref() {
+                        upload.send(data).await;
+                    }
+                }
+                ArchiveEvent::EntryEnd => {
+                    if let Some(upload) = upload.take() {
+                        upload.finish().await?;
+                    }
+                }
+                ArchiveEvent::End => return Ok(()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use director_engine::proto::ListProjectsRequest;
+
+    use super::*;
+    use crate::testing::TestStack;
+
+    #[tokio::test]
+    async fn create_project_creates_folders_and_registers_project() {
+        let mut stack = TestStack::start().await;
+
+        let response = stack
+            .gateway
+            .create_project(CreateProjectRequest {
+                name: "Demo".to_string(),
+                path: stack.files.root().to_string(),
+                merge: false,
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(response.success, "{}", response.error_message);
+
+        let project_dir = Path::new(&response.project_path);
+        assert_eq!(project_dir, Path::new(&stack.files.path("Demo")));
+        for folder in ["assets/video", "assets/audio", "assets/images", "timeline", "exports"] {
+            assert!(project_dir.join(folder).is_dir(), "нет папки {}", folder);
+        }
+
+        let storage_id = stack.files.provider.id().to_string();
+        let projects = stack
+            .engine
+            .client
+            .list_projects(ListProjectsRequest::default())
+            .await
+            .unwrap()
+            .into_inner()
+            .projects;
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name, "Demo");
+        assert_eq!(projects[0].path, response.project_path);
+        assert_eq!(projects[0].file_gateway_id, storage_id);
+        assert_eq!(response.project.map(|p| p.id), Some(projects[0].id.clone()));
+    }
+
+    #[tokio::test]
+    async fn create_project_with_invalid_name_creates_nothing() {
+        let mut stack = TestStack::start().await;
+
+        let response = stack
+            .gateway
+            .create_project(CreateProjectRequest {
+                name: "../Demo".to_string(),
+                path: stack.files.root().to_string(),
+                merge: false,
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(!response.success);
+
+        let entries = std::fs::read_dir(stack.files.root()).unwrap().count();
+        assert_eq!(entries, 0);
+        let projects = stack
+            .engine
+            .client
+            .list_projects(ListProjectsRequest::default())
+            .await
+            .unwrap()
+            .into_inner()
+            .projects;
+        assert!(projects.is_empty());
+    }
+
+    #[tokio::test]
+    async fn delete_project_removes_registry_entry_and_files() {
+        let mut stack = TestStack::start().await;
+
+        let created = stack
+            .gateway
+            .create_project(CreateProjectRequest {
+                name: "Demo".to_string(),
+                path: stack.files.root().to_string(),
+                merge: false,
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        let project = created.project.expect("проект не вернулся");
+
+        let response = stack
+            .gateway
+            .delete_project(DeleteProjectRequest {
+                project_id: project.id,
+                delete_files: true,
+                dry_run: false,
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(response.success, "{}", response.error_message);
+
+        assert!(!Path::new(&created.project_path).exists());
+        let projects = stack
+            .engine
+            .client
+            .list_projects(ListProjectsRequest::default())
+            .await
+            .unwrap()
+            .into_inner()
+            .projects;
+        assert!(projects.is_empty());
+    }
+
+    #[tokio::test]
+    async fn export_project_streams_archive_with_progress() {
+        let mut stack = TestStack::start().await;
+        let created = stack
+            .gateway
+            .create_project(CreateProjectRequest {
+                name: "Demo".to_string(),
+                path: stack.files.root().to_string(),
+                merge: false,
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(created.success, "{}", created.error_message);
+        let clip = Path::new(&created.project_path).join("assets/video/clip.mp4");
+        std::fs::write(&clip, vec![7u8; 300_000]).unwrap();
+
+        let mut stream = stack
+            .gateway
+            .export_project(ExportProjectRequest {
+                project_id: created.project.unwrap().id,
+            })
+            .await
+            .unwrap()
+            .into_inner();
+
+        let mut total_size = None;
+        let mut received: u64 = 0;
+        let mut progress = Vec::new();
+        while let Some(message) = stream.next().await {
+            match message.unwrap().data {
+                Some(export_project_response::Data::Metadata(m)) => total_size = Some(m.total_size),
+                Some(export_project_response::Data::Chunk(chunk)) => {
+                    received += chunk.len() as u64
+                }
+                Some(export_project_response::Data::Progress(p)) => progress.push(p),
+                None => {}
+            }
+        }
+
+        let total_size = total_size.expect("Нет метаданных архива");
+        assert_eq!(received, total_size);
+        assert!(!progress.is_empty(), "Нет сообщений о прогрессе");
+        for p in &progress {
+            assert_eq!(p.total_bytes, total_size);
+            assert!(p.bytes_done <= total_size);
+        }
+        assert!(progress.windows(2).all(|w| w[0].bytes_done <= w[1].bytes_done));
+    }
+}
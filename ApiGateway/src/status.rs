@@ -0,0 +1,107 @@
+//! Ошибки изменяющих RPC через gRPC статус (`--status-errors`)
+//!
+//! По умолчанию ошибка возвращается в полях `success`/`error_message` ответа.
+//! С `--status-errors` неуспешный ответ превращается в `Status` с кодом,
+//! подобранным по тексту ошибки бэкенда, и клиенту приходит только
+//! успешный результат. Оба режима поддерживаются на время перехода.
+//!
+//! Статусы шлюза несут стабильный английский идентификатор ошибки
+//! (метаданные `error-reason` и начало сообщения): текст для людей может
+//! меняться и переводиться, клиенты ветвятся по коду и идентификатору.
+
+use tonic::metadata::{MetadataMap, MetadataValue};
+use tonic::{Code, Response, Status};
+
+use crate::proto::api_gateway::*;
+
+/// Ответ изменяющего RPC с полями `success`/`error_message`
+pub trait Outcome {
+    fn success(&self) -> bool;
+    fn error_message(&self) -> &str;
+}
+
+macro_rules! impl_outcome {
+    ($($response:ty),* $(,)?) => {
+        $(
+            impl Outcome for $response {
+                fn success(&self) -> bool {
+                    self.success
+                }
+
+                fn error_message(&self) -> &str {
+                    &self.error_message
+                }
+            }
+        )*
+    };
+}
+
+impl_outcome!(
+    CreateProjectResponse,
+    OpenProjectResponse,
+    UpdateProjectResponse,
+    ArchiveProjectResponse,
+    UnarchiveProjectResponse,
+    DeleteProjectResponse,
+    CreateDirectoryResponse,
+    DeleteResponse,
+    MoveFileResponse,
+    RenameResponse,
+    InitProjectStructureResponse,
+);
+
+/// Ключ метаданных статуса со стабильным идентификатором ошибки
+pub const ERROR_REASON_KEY: &str = "error-reason";
+
+/// Идентификатор нераспознанной ошибки бэкенда
+const UNKNOWN_REASON: &str = "OPERATION_FAILED";
+
+/// Начала сообщений об ошибках DirectorEngine и FileGateway, их коды и идентификаторы
+const ERROR_CODES: &[(&str, Code, &str)] = &[
+    ("Проект не найден", Code::NotFound, "PROJECT_NOT_FOUND"),
+    ("Путь не существует", Code::NotFound, "PATH_NOT_FOUND"),
+    ("Проект уже зарегистрирован", Code::AlreadyExists, "PROJECT_ALREADY_REGISTERED"),
+    ("Проект с названием", Code::AlreadyExists, "PROJECT_NAME_TAKEN"),
+    ("Файл уже существует", Code::AlreadyExists, "FILE_ALREADY_EXISTS"),
+    ("Недопустимое название проекта", Code::InvalidArgument, "INVALID_PROJECT_NAME"),
+    ("Недопустимое имя", Code::InvalidArgument, "INVALID_NAME"),
+    ("Некорректный цвет", Code::InvalidArgument, "INVALID_COLOR"),
+    ("Пустой ключ", Code::InvalidArgument, "EMPTY_KEY"),
+    ("Путь не является", Code::InvalidArgument, "WRONG_PATH_TYPE"),
+    ("Недостаточно прав", Code::PermissionDenied, "PERMISSION_DENIED"),
+    ("Превышена квота", Code::ResourceExhausted, "QUOTA_EXCEEDED"),
+];
+
+/// Код статуса и идентификатор для сообщения об ошибке
+/// (`FAILED_PRECONDITION` / `OPERATION_FAILED`, если не распознано)
+pub fn error_code(error_message: &str) -> (Code, &'static str) {
+    ERROR_CODES
+        .iter()
+        .find(|(prefix, _, _)| error_message.starts_with(prefix))
+        .map(|(_, code, reason)| (*code, *reason))
+        .unwrap_or((Code::FailedPrecondition, UNKNOWN_REASON))
+}
+
+/// Status с идентификатором ошибки в метаданных и в начале сообщения
+pub fn coded(code: Code, reason: &'static str, message: impl std::fmt::Display) -> Status {
+    let mut metadata = MetadataMap::new();
+    metadata.insert(ERROR_REASON_KEY, MetadataValue::from_static(reason));
+    Status::with_metadata(code, format!("{}: {}", reason, message), metadata)
+}
+
+/// Status для сообщения об ошибке бэкенда из поля `error_message`
+pub fn from_message(error_message: &str) -> Status {
+    let (code, reason) = error_code(error_message);
+    coded(code, reason, error_message)
+}
+
+/// Вернуть ответ как есть или, в режиме статусов, ошибку как `Status`
+// Результат сразу возвращается из обработчика tonic, Status в нём неизбежен
+#[allow(clippy::result_large_err)]
+pub fn reply<T: Outcome>(status_errors: bool, response: T) -> Result<Response<T>, Status> {
+    if status_errors && !response.success() {
+        return Err(from_message(response.error_message()));
+    }
+
+    Ok(Response::new(response))
+}
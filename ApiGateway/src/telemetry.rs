@@ -0,0 +1,40 @@
+//! Передача контекста трассировки во внутренние сервисы
+//!
+//! Контекст передаётся в метаданных gRPC (заголовок `traceparent`,
+//! W3C Trace Context); экспорт span'ов настраивается в `director_common`.
+
+use opentelemetry::propagation::Injector;
+use tonic::metadata::{Ascii, MetadataKey, MetadataMap, MetadataValue};
+use tonic::{Request, Status};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Запись контекста трассировки в метаданные исходящего запроса
+struct MetadataInjector<'a>(&'a mut MetadataMap);
+
+impl Injector for MetadataInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(key), Ok(value)) = (
+            MetadataKey::<Ascii>::from_bytes(key.as_bytes()),
+            MetadataValue::try_from(value.as_str()),
+        ) {
+            self.0.insert(key, value);
+        }
+    }
+}
+
+/// Interceptor для клиентов внутренних сервисов
+pub type Interceptor = fn(Request<()>) -> Result<Request<()>, Status>;
+
+/// Передать контекст текущего span'а во внутренний сервис
+///
+/// Вызывается внутри span'а входящего RPC, поэтому вызовы DirectorEngine
+/// и FileGateway попадают в ту же трассировку, что и запрос к шлюзу.
+// Сигнатура задана tonic (Interceptor), размер Status от нас не зависит
+#[allow(clippy::result_large_err)]
+pub fn inject_context(mut request: Request<()>) -> Result<Request<()>, Status> {
+    let context = tracing::Span::current().context();
+    opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut MetadataInjector(request.metadata_mut()))
+    });
+    Ok(request)
+}
@@ -0,0 +1,82 @@
+//! Запуск всех сервисов Director в процессе теста
+//!
+//! FileGateway (хранилище во временной директории), DirectorEngine (реестр
+//! в памяти) и шлюз поверх них слушают свободные порты на loopback. Тест
+//! получает клиент шлюза и доступ к бэкендам, чтобы проверить результат
+//! напрямую. Всё останавливается вместе с `TestStack`.
+
+use std::net::{Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+use director_common::bind;
+use director_engine::testing::TestEngine;
+use file_gateway::testing::TestFileGateway;
+use tokio::task::JoinHandle;
+use tonic::transport::{Channel, Server};
+
+use crate::clients::{BackendTimeouts, HealthProbe};
+use crate::proto::api_gateway::api_gateway_client::ApiGatewayClient;
+use crate::proto::api_gateway::api_gateway_server::ApiGatewayServer;
+use crate::service::ApiGatewayImpl;
+
+/// Таймаут подключения и вызовов бэкендов в тестах
+const TEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Шлюз и оба бэкенда, запущенные тестом
+pub struct TestStack {
+    pub gateway: ApiGatewayClient<Channel>,
+    pub files: TestFileGateway,
+    pub engine: TestEngine,
+    server: JoinHandle<()>,
+}
+
+impl TestStack {
+    pub async fn start() -> Self {
+        let files = TestFileGateway::start().await;
+        let engine = TestEngine::start(&files.address).await;
+
+        let timeouts = BackendTimeouts {
+            engine_connect: TEST_TIMEOUT,
+            file_gateway_connect: TEST_TIMEOUT,
+            call: Some(TEST_TIMEOUT),
+        };
+        // Временная директория может быть на маленьком томе: место не проверяется
+        let gateway = ApiGatewayImpl::new(
+            engine.address.clone(),
+            files.address.clone(),
+            timeouts,
+            HealthProbe::Auto,
+            "test".to_string(),
+            0,
+            false,
+        )
+        .expect("Не удалось создать шлюз");
+
+        let (incoming, local_addr) = bind::listen(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)))
+            .await
+            .expect("Не удалось открыть сокет шлюза");
+        let server = tokio::spawn(async move {
+            let _ = Server::builder()
+                .add_service(ApiGatewayServer::new(gateway))
+                .serve_with_incoming(incoming)
+                .await;
+        });
+
+        let gateway = ApiGatewayClient::connect(format!("http://{}", local_addr))
+            .await
+            .expect("Не удалось подключиться к шлюзу");
+
+        Self {
+            gateway,
+            files,
+            engine,
+            server,
+        }
+    }
+}
+
+impl Drop for TestStack {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
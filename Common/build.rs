@@ -0,0 +1,5 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Сервисы отвечают на проверку состояния, шлюз проверяет бэкенды
+    tonic_build::compile_protos("../proto/health.proto")?;
+    Ok(())
+}
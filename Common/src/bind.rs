@@ -0,0 +1,73 @@
+//! Адрес, на котором сервис принимает подключения
+//!
+//! Адрес задаётся `--bind`, порт - `--port`. Переменные окружения у каждого
+//! сервиса свои (`ENGINE_PORT`, `FILE_GATEWAY_PORT`, `API_GATEWAY_PORT` и
+//! `*_BIND`), чтобы сервисы с общим окружением не заняли один порт.
+//! Допустимые формы адреса:
+//! - `0.0.0.0:50052`, `[::]:50052` - адрес с портом
+//! - `0.0.0.0`, `::` - только адрес, порт по умолчанию
+//! - `localhost:50052`, `localhost` - имя хоста
+//!
+//! Порт `0` означает свободный порт, выбранный системой; фактический адрес
+//! пишется в лог при запуске.
+
+use std::net::{IpAddr, SocketAddr, ToSocketAddrs};
+
+use tonic::transport::server::TcpIncoming;
+
+/// Адрес по умолчанию - IPv6 loopback
+const DEFAULT_HOST: &str = "::1";
+
+/// Определить адрес из аргументов, подставив значения по умолчанию
+///
+/// Явно заданный `port` имеет приоритет над портом внутри `bind`.
+pub fn resolve(bind: Option<&str>, port: Option<u16>, default_port: u16) -> Result<SocketAddr, String> {
+    let bind = bind.map(str::trim).filter(|b| !b.is_empty()).unwrap_or(DEFAULT_HOST);
+    let mut addr = parse(bind, port.unwrap_or(default_port))?;
+
+    if let Some(port) = port {
+        addr.set_port(port);
+    }
+
+    Ok(addr)
+}
+
+/// Разобрать адрес, подставив порт по умолчанию если он не указан
+pub fn parse(value: &str, default_port: u16) -> Result<SocketAddr, String> {
+    let value = value.trim();
+
+    if let Ok(addr) = value.parse::<SocketAddr>() {
+        return Ok(addr);
+    }
+
+    let host = value.trim_start_matches('[').trim_end_matches(']');
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return Ok(SocketAddr::new(ip, default_port));
+    }
+
+    let with_port = if value.contains(':') {
+        value.to_string()
+    } else {
+        format!("{}:{}", value, default_port)
+    };
+
+    with_port
+        .to_socket_addrs()
+        .map_err(|e| format!("Некорректный адрес '{}': {}", value, e))?
+        .next()
+        .ok_or_else(|| format!("Адрес '{}' не разрешается ни в один IP", value))
+}
+
+/// Открыть сокет до запуска сервера
+///
+/// Возвращает фактический адрес: при порте `0` система выбирает свободный
+/// порт, и узнать его можно только после привязки (например, тестовому
+/// окружению, запускающему несколько сервисов на эфемерных портах).
+pub async fn listen(
+    addr: SocketAddr,
+) -> Result<(TcpIncoming, SocketAddr), Box<dyn std::error::Error + Send + Sync>> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    let local_addr = listener.local_addr()?;
+    let incoming = TcpIncoming::from_listener(listener, true, None)?;
+    Ok((incoming, local_addr))
+}
@@ -0,0 +1,70 @@
+//! Стандартная проверка состояния gRPC (`grpc.health.v1.Health`)
+//!
+//! Дешёвая проверка для шлюза и балансировщиков: отвечает `SERVING`, пока
+//! процесс принимает запросы, и не обращается ни к хранилищу, ни к реестру.
+//! Проверяется сервер целиком (пустое имя) или основной сервис по имени.
+
+use std::pin::Pin;
+
+use tokio_stream::{Stream, StreamExt};
+use tonic::{Request, Response, Status};
+
+use crate::proto::health::health_check_response::ServingStatus;
+use crate::proto::health::health_server::{Health, HealthServer};
+use crate::proto::health::{HealthCheckRequest, HealthCheckResponse};
+
+/// Сервис проверки состояния
+pub struct HealthService {
+    /// Полное имя основного сервиса (`package.Service`)
+    service_name: &'static str,
+}
+
+impl HealthService {
+    pub fn server(service_name: &'static str) -> HealthServer<Self> {
+        HealthServer::new(Self { service_name })
+    }
+
+    fn status(&self, service: &str) -> ServingStatus {
+        if service.is_empty() || service == self.service_name {
+            ServingStatus::Serving
+        } else {
+            ServingStatus::ServiceUnknown
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl Health for HealthService {
+    async fn check(
+        &self,
+        request: Request<HealthCheckRequest>,
+    ) -> Result<Response<HealthCheckResponse>, Status> {
+        let service = request.into_inner().service;
+
+        // По протоколу неизвестный сервис в Check - NOT_FOUND
+        match self.status(&service) {
+            ServingStatus::ServiceUnknown => {
+                Err(Status::not_found(format!("Неизвестный сервис: {}", service)))
+            }
+            status => Ok(Response::new(HealthCheckResponse {
+                status: status as i32,
+            })),
+        }
+    }
+
+    type WatchStream = Pin<Box<dyn Stream<Item = Result<HealthCheckResponse, Status>> + Send>>;
+
+    async fn watch(
+        &self,
+        request: Request<HealthCheckRequest>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        let status = self.status(&request.into_inner().service);
+
+        // Состояние не меняется до остановки процесса: отправляем текущее
+        // и держим поток открытым
+        let current = tokio_stream::once(Ok(HealthCheckResponse {
+            status: status as i32,
+        }));
+        Ok(Response::new(Box::pin(current.chain(tokio_stream::pending()))))
+    }
+}
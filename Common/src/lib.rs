@@ -0,0 +1,15 @@
+//! Общие части сервисов Director
+//!
+//! Логирование, трассировка, адрес для входящих подключений и стандартная
+//! проверка состояния одинаковы в DirectorEngine, FileGateway и ApiGateway.
+
+pub mod bind;
+pub mod health;
+pub mod logging;
+pub mod telemetry;
+
+pub mod proto {
+    pub mod health {
+        tonic::include_proto!("grpc.health.v1");
+    }
+}
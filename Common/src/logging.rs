@@ -0,0 +1,138 @@
+//! Инициализация логирования
+//!
+//! Формат вывода выбирается переменной окружения `LOG_FORMAT`:
+//! - `text` (по умолчанию) - человекочитаемый вывод
+//! - `json` - структурированные JSON-записи для сборщиков логов (ELK, Loki)
+//!
+//! Фильтр уровней (`RUST_LOG`) можно менять без перезапуска:
+//! - `SIGUSR1` - включить фильтр из `RUST_LOG_DEBUG` (по умолчанию `debug`)
+//! - `SIGUSR2` - вернуть исходный фильтр из `RUST_LOG`
+
+use tracing::{info, warn};
+use tracing_subscriber::{
+    layer::SubscriberExt, reload, util::SubscriberInitExt, EnvFilter, Registry,
+};
+
+/// Формат вывода логов
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+impl LogFormat {
+    /// Определить формат из переменной окружения `LOG_FORMAT`
+    pub fn from_env() -> Self {
+        match std::env::var("LOG_FORMAT") {
+            Ok(value) if value.eq_ignore_ascii_case("json") => LogFormat::Json,
+            _ => LogFormat::Text,
+        }
+    }
+}
+
+/// Дескриптор для замены фильтра логов во время работы
+#[derive(Clone)]
+pub struct LogHandle {
+    filter: reload::Handle<EnvFilter, Registry>,
+}
+
+impl LogHandle {
+    /// Установить новый фильтр (синтаксис как у `RUST_LOG`)
+    pub fn set_filter(&self, directives: &str) -> Result<(), String> {
+        let filter = EnvFilter::try_new(directives).map_err(|e| e.to_string())?;
+        self.filter.reload(filter).map_err(|e| e.to_string())?;
+        info!("Фильтр логов изменён: {}", directives);
+        Ok(())
+    }
+
+    /// Вернуть фильтр из `RUST_LOG`
+    pub fn reset(&self) -> Result<(), String> {
+        self.filter
+            .reload(EnvFilter::from_default_env())
+            .map_err(|e| e.to_string())?;
+        info!("Фильтр логов сброшен к RUST_LOG");
+        Ok(())
+    }
+}
+
+/// Установить глобальный subscriber
+///
+/// `service_name` - имя сервиса в экспортируемых трассировках.
+/// В JSON-режиме поля событий и текущего span'а (например, имя RPC)
+/// выводятся отдельными ключами, а не склеиваются в текст сообщения.
+pub fn init(service_name: &'static str) -> LogHandle {
+    let (filter, handle) = reload::Layer::new(EnvFilter::from_default_env());
+    let (otel, otel_error) = match crate::telemetry::layer(service_name) {
+        Ok(otel) => (otel, None),
+        Err(e) => (None, Some(e)),
+    };
+    let registry = tracing_subscriber::registry().with(filter).with(otel);
+
+    match LogFormat::from_env() {
+        LogFormat::Json => registry
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .json()
+                    .flatten_event(true)
+                    .with_current_span(true)
+                    .with_span_list(false),
+            )
+            .init(),
+        LogFormat::Text => registry.with(tracing_subscriber::fmt::layer()).init(),
+    }
+
+    // Ошибку настройки экспорта можно записать только после установки subscriber'а
+    if let Some(e) = otel_error {
+        warn!("Экспорт трассировок OpenTelemetry отключён: {}", e);
+    }
+
+    LogHandle { filter: handle }
+}
+
+/// Запустить обработчик сигналов для переключения уровня логов
+#[cfg(unix)]
+pub fn spawn_signal_handler(handle: LogHandle) {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    tokio::spawn(async move {
+        let (mut usr1, mut usr2) = match (
+            signal(SignalKind::user_defined1()),
+            signal(SignalKind::user_defined2()),
+        ) {
+            (Ok(usr1), Ok(usr2)) => (usr1, usr2),
+            (Err(e), _) | (_, Err(e)) => {
+                warn!("Не удалось установить обработчик сигналов логирования: {}", e);
+                return;
+            }
+        };
+
+        loop {
+            let result = tokio::select! {
+                _ = usr1.recv() => {
+                    let directives = std::env::var("RUST_LOG_DEBUG")
+                        .unwrap_or_else(|_| "debug".to_string());
+                    handle.set_filter(&directives)
+                }
+                _ = usr2.recv() => handle.reset(),
+            };
+
+            if let Err(e) = result {
+                warn!("Не удалось изменить фильтр логов: {}", e);
+            }
+        }
+    });
+}
+
+/// На платформах без POSIX-сигналов уровень меняется только перезапуском
+#[cfg(not(unix))]
+pub fn spawn_signal_handler(_handle: LogHandle) {}
+
+/// Span для входящего RPC - путь метода попадает в логи отдельным полем `rpc`
+///
+/// Если вызывающий сервис передал контекст трассировки, span становится
+/// его дочерним.
+pub fn rpc_span(request: &tonic::codegen::http::Request<()>) -> tracing::Span {
+    let span = tracing::info_span!("request", rpc = %request.uri().path());
+    crate::telemetry::set_parent_from_headers(&span, request.headers());
+    span
+}
@@ -0,0 +1,84 @@
+//! Экспорт трассировок в OpenTelemetry
+//!
+//! Включается переменной окружения `OTEL_EXPORTER_OTLP_ENDPOINT`
+//! (например, `http://localhost:4317`): span'ы отправляются в OTLP-коллектор
+//! по gRPC. Без переменной трассировка остаётся только в логах.
+//!
+//! Контекст трассировки передаётся между сервисами в метаданных gRPC
+//! (заголовок `traceparent`, W3C Trace Context).
+
+use opentelemetry::propagation::Extractor;
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{Tracer, TracerProvider};
+use opentelemetry_sdk::{runtime, Resource};
+use tonic::codegen::http::HeaderMap;
+use tracing::Subscriber;
+use tracing_opentelemetry::{OpenTelemetryLayer, OpenTelemetrySpanExt};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Переменная окружения с адресом OTLP-коллектора
+const ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+
+/// Слой `tracing`, экспортирующий span'ы в коллектор
+///
+/// `service_name` - имя сервиса в трассировках. `Ok(None)` - экспорт не настроен.
+pub fn layer<S>(service_name: &'static str) -> Result<Option<OpenTelemetryLayer<S, Tracer>>, String>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    let endpoint = match std::env::var(ENDPOINT_ENV) {
+        Ok(endpoint) if !endpoint.is_empty() => endpoint,
+        _ => return Ok(None),
+    };
+
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let provider = TracerProvider::builder()
+        .with_batch_exporter(exporter, runtime::Tokio)
+        .with_resource(Resource::new(vec![KeyValue::new("service.name", service_name)]))
+        .build();
+    let tracer = provider.tracer(service_name);
+
+    opentelemetry::global::set_tracer_provider(provider);
+    opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+
+    Ok(Some(tracing_opentelemetry::layer().with_tracer(tracer)))
+}
+
+/// Чтение контекста трассировки из HTTP/2 заголовков запроса
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+/// Продолжить трассировку вызывающего сервиса, если он передал её контекст
+pub fn set_parent_from_headers(span: &tracing::Span, headers: &HeaderMap) {
+    let parent = opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(headers))
+    });
+    span.set_parent(parent);
+}
+
+/// ID трассировки из заголовка `traceparent` (пусто, если вызывающий его не передал)
+pub fn trace_id(metadata: &tonic::metadata::MetadataMap) -> String {
+    metadata
+        .get("traceparent")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split('-').nth(1))
+        .unwrap_or_default()
+        .to_string()
+}
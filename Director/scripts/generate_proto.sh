@@ -19,9 +19,13 @@ uv run python -m grpc_tools.protoc \
     -I"$PROTO_DIR" \
     --python_out="$OUTPUT_DIR" \
     --grpc_python_out="$OUTPUT_DIR" \
-    "$PROTO_DIR/director.proto"
+    "$PROTO_DIR/api_gateway.proto" \
+    "$PROTO_DIR/director.proto" \
+    "$PROTO_DIR/file_gateway.proto"
 
 # Исправляем импорты для Python 3
-sed -i 's/import director_pb2/from app.api.proto import director_pb2/' "$OUTPUT_DIR/director_pb2_grpc.py"
+for name in api_gateway director file_gateway; do
+    sed -i "s/^import ${name}_pb2/from app.api.proto import ${name}_pb2/" "$OUTPUT_DIR/${name}_pb2_grpc.py"
+done
 
 echo "Готово!"
@@ -22,77 +22,140 @@ _runtime_version.ValidateProtobufRuntimeVersion(
 _sym_db = _symbol_database.Default()
 
 
+from google.protobuf import timestamp_pb2 as google_dot_protobuf_dot_timestamp__pb2
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x11\x61pi_gateway.proto\x12\x0b\x61pi_gateway\"\x14\n\x12HealthCheckRequest\"f\n\rServiceStatus\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\x11\n\tconnected\x18\x02 \x01(\x08\x12\x0f\n\x07\x61\x64\x64ress\x18\x03 \x01(\t\x12\x0f\n\x07version\x18\x04 \x01(\t\x12\x12\n\nlatency_ms\x18\x05 \x01(\x03\"X\n\x13HealthCheckResponse\x12\x13\n\x0b\x61ll_healthy\x18\x01 \x01(\x08\x12,\n\x08services\x18\x02 \x03(\x0b\x32\x1a.api_gateway.ServiceStatus\"\x18\n\x16GetServicesInfoRequest\"\xed\x01\n\x17GetServicesInfoResponse\x12\x17\n\x0fgateway_version\x18\x01 \x01(\t\x12\x17\n\x0f\x65ngine_hostname\x18\x02 \x01(\t\x12\x18\n\x10storage_hostname\x18\x03 \x01(\t\x12\x12\n\nstorage_os\x18\x04 \x01(\t\x12\x16\n\x0ehome_directory\x18\x05 \x01(\t\x12\x1d\n\x15\x64\x65\x66\x61ult_projects_path\x18\x06 \x01(\t\x12\x12\n\nroot_paths\x18\x07 \x03(\t\x12\x13\n\x0btotal_space\x18\x08 \x01(\x04\x12\x12\n\nfree_space\x18\t \x01(\x04\"\x15\n\x13ListProjectsRequest\"Y\n\x07Project\x12\n\n\x02id\x18\x01 \x01(\t\x12\x0c\n\x04name\x18\x02 \x01(\t\x12\x0c\n\x04path\x18\x03 \x01(\t\x12\x12\n\ncreated_at\x18\x04 \x01(\x03\x12\x12\n\nupdated_at\x18\x05 \x01(\x03\">\n\x14ListProjectsResponse\x12&\n\x08projects\x18\x01 \x03(\x0b\x32\x14.api_gateway.Project\"2\n\x14\x43reateProjectRequest\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\x0c\n\x04path\x18\x02 \x01(\t\"f\n\x15\x43reateProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12%\n\x07project\x18\x03 \x01(\x0b\x32\x14.api_gateway.Project\"(\n\x12OpenProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\"d\n\x13OpenProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12%\n\x07project\x18\x03 \x01(\x0b\x32\x14.api_gateway.Project\"@\n\x14\x44\x65leteProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\x12\x14\n\x0c\x64\x65lete_files\x18\x02 \x01(\x08\"?\n\x15\x44\x65leteProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\"\x17\n\x15GetStorageInfoRequest\"\xbe\x01\n\x16GetStorageInfoResponse\x12\x12\n\nstorage_id\x18\x01 \x01(\t\x12\x10\n\x08hostname\x18\x02 \x01(\t\x12\n\n\x02os\x18\x03 \x01(\t\x12\x16\n\x0ehome_directory\x18\x04 \x01(\t\x12\x1d\n\x15\x64\x65\x66\x61ult_projects_path\x18\x05 \x01(\t\x12\x12\n\nroot_paths\x18\x06 \x03(\t\x12\x13\n\x0btotal_space\x18\x07 \x01(\x04\x12\x12\n\nfree_space\x18\x08 \x01(\x04\"&\n\x16\x42rowseDirectoryRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\"\x8c\x01\n\x0e\x44irectoryEntry\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\x0c\n\x04path\x18\x02 \x01(\t\x12\x14\n\x0cis_directory\x18\x03 \x01(\x08\x12\x0c\n\x04size\x18\x04 \x01(\x04\x12\x12\n\ncreated_at\x18\x05 \x01(\x03\x12\x13\n\x0bmodified_at\x18\x06 \x01(\x03\x12\x11\n\tmime_type\x18\x07 \x01(\t\"\x9a\x01\n\x17\x42rowseDirectoryResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x14\n\x0c\x63urrent_path\x18\x03 \x01(\t\x12\x13\n\x0bparent_path\x18\x04 \x01(\t\x12,\n\x07\x65ntries\x18\x05 \x03(\x0b\x32\x1b.api_gateway.DirectoryEntry\">\n\x16\x43reateDirectoryRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x16\n\x0e\x63reate_parents\x18\x02 \x01(\x08\"W\n\x17\x43reateDirectoryResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x14\n\x0c\x63reated_path\x18\x03 \x01(\t\"0\n\rDeleteRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x11\n\trecursive\x18\x02 \x01(\x08\"8\n\x0e\x44\x65leteResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\"F\n\x1bInitProjectStructureRequest\x12\x11\n\tbase_path\x18\x01 \x01(\t\x12\x14\n\x0cproject_name\x18\x02 \x01(\t\"\xdb\x01\n\x1cInitProjectStructureResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x14\n\x0cproject_path\x18\x03 \x01(\t\x12\x13\n\x0b\x61ssets_path\x18\x04 \x01(\t\x12\x12\n\nvideo_path\x18\x05 \x01(\t\x12\x12\n\naudio_path\x18\x06 \x01(\t\x12\x13\n\x0bimages_path\x18\x07 \x01(\t\x12\x15\n\rtimeline_path\x18\x08 \x01(\t\x12\x14\n\x0c\x65xports_path\x18\t \x01(\t\"a\n\x11UploadFileRequest\x12\x33\n\x08metadata\x18\x01 \x01(\x0b\x32\x1f.api_gateway.UploadFileMetadataH\x00\x12\x0f\n\x05\x63hunk\x18\x02 \x01(\x0cH\x00\x42\x06\n\x04\x64\x61ta\"g\n\x12UploadFileMetadata\x12\x18\n\x10\x64\x65stination_path\x18\x01 \x01(\t\x12\x10\n\x08\x66ilename\x18\x02 \x01(\t\x12\x12\n\ntotal_size\x18\x03 \x01(\x04\x12\x11\n\toverwrite\x18\x04 \x01(\x08\"f\n\x12UploadFileResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x11\n\tfile_path\x18\x03 \x01(\t\x12\x15\n\rbytes_written\x18\x04 \x01(\x04\"#\n\x13\x44ownloadFileRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\"f\n\x14\x44ownloadFileResponse\x12\x35\n\x08metadata\x18\x01 \x01(\x0b\x32!.api_gateway.DownloadFileMetadataH\x00\x12\x0f\n\x05\x63hunk\x18\x02 \x01(\x0cH\x00\x42\x06\n\x04\x64\x61ta\"O\n\x14\x44ownloadFileMetadata\x12\x10\n\x08\x66ilename\x18\x01 \x01(\t\x12\x12\n\ntotal_size\x18\x02 \x01(\x04\x12\x11\n\tmime_type\x18\x03 \x01(\t2\x82\t\n\nApiGateway\x12P\n\x0bHealthCheck\x12\x1f.api_gateway.HealthCheckRequest\x1a .api_gateway.HealthCheckResponse\x12\\\n\x0fGetServicesInfo\x12#.api_gateway.GetServicesInfoRequest\x1a$.api_gateway.GetServicesInfoResponse\x12S\n\x0cListProjects\x12 .api_gateway.ListProjectsRequest\x1a!.api_gateway.ListProjectsResponse\x12V\n\rCreateProject\x12!.api_gateway.CreateProjectRequest\x1a\".api_gateway.CreateProjectResponse\x12P\n\x0bOpenProject\x12\x1f.api_gateway.OpenProjectRequest\x1a .api_gateway.OpenProjectResponse\x12V\n\rDeleteProject\x12!.api_gateway.DeleteProjectRequest\x1a\".api_gateway.DeleteProjectResponse\x12Y\n\x0eGetStorageInfo\x12\".api_gateway.GetStorageInfoRequest\x1a#.api_gateway.GetStorageInfoResponse\x12\\\n\x0f\x42rowseDirectory\x12#.api_gateway.BrowseDirectoryRequest\x1a$.api_gateway.BrowseDirectoryResponse\x12\\\n\x0f\x43reateDirectory\x12#.api_gateway.CreateDirectoryRequest\x1a$.api_gateway.CreateDirectoryResponse\x12\x41\n\x06\x44\x65lete\x12\x1a.api_gateway.DeleteRequest\x1a\x1b.api_gateway.DeleteResponse\x12k\n\x14InitProjectStructure\x12(.api_gateway.InitProjectStructureRequest\x1a).api_gateway.InitProjectStructureResponse\x12O\n\nUploadFile\x12\x1e.api_gateway.UploadFileRequest\x1a\x1f.api_gateway.UploadFileResponse(\x01\x12U\n\x0c\x44ownloadFile\x12 .api_gateway.DownloadFileRequest\x1a!.api_gateway.DownloadFileResponse0\x01\x62\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x11\x61pi_gateway.proto\x12\x0b\x61pi_gateway\x1a\x1fgoogle/protobuf/timestamp.proto\"\x14\n\x12HealthCheckRequest\"\xd9\x01\n\rServiceStatus\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\x11\n\tconnected\x18\x02 \x01(\x08\x12\x0f\n\x07\x61\x64\x64ress\x18\x03 \x01(\t\x12\x0f\n\x07version\x18\x04 \x01(\t\x12\x12\n\nlatency_ms\x18\x05 \x01(\x03\x12\r\n\x05\x63\x61lls\x18\x06 \x01(\x04\x12\x0e\n\x06\x65rrors\x18\x07 \x01(\x04\x12\x15\n\rtotal_time_us\x18\x08 \x01(\x04\x12\x13\n\x0bmax_time_us\x18\t \x01(\x04\x12\x10\n\x08rejected\x18\n \x01(\x04\x12\x14\n\x0c\x63ircuit_open\x18\x0b \x01(\x08\"u\n\x13HealthCheckResponse\x12\x13\n\x0b\x61ll_healthy\x18\x01 \x01(\x08\x12,\n\x08services\x18\x02 \x03(\x0b\x32\x1a.api_gateway.ServiceStatus\x12\x0c\n\x04live\x18\x03 \x01(\x08\x12\r\n\x05ready\x18\x04 \x01(\x08\"\x18\n\x16GetServicesInfoRequest\"\xf1\x03\n\x17GetServicesInfoResponse\x12\x17\n\x0fgateway_version\x18\x01 \x01(\t\x12\x17\n\x0f\x65ngine_hostname\x18\x02 \x01(\t\x12\x18\n\x10storage_hostname\x18\x03 \x01(\t\x12\x12\n\nstorage_os\x18\x04 \x01(\t\x12\x16\n\x0ehome_directory\x18\x05 \x01(\t\x12\x1d\n\x15\x64\x65\x66\x61ult_projects_path\x18\x06 \x01(\t\x12\x12\n\nroot_paths\x18\x07 \x03(\t\x12\x13\n\x0btotal_space\x18\x08 \x01(\x04\x12\x12\n\nfree_space\x18\t \x01(\x04\x12>\n\x14storage_capabilities\x18\n \x01(\x0b\x32 .api_gateway.StorageCapabilities\x12\x14\n\x0cstorage_type\x18\x0b \x01(\t\x12\x35\n\x11\x65ngine_started_at\x18\x0c \x01(\x0b\x32\x1a.google.protobuf.Timestamp\x12\x1d\n\x15\x65ngine_uptime_seconds\x18\r \x01(\x04\x12\x36\n\x12storage_started_at\x18\x0e \x01(\x0b\x32\x1a.google.protobuf.Timestamp\x12\x1e\n\x16storage_uptime_seconds\x18\x0f \x01(\x04\"p\n\x13ListProjectsRequest\x12\x17\n\x0f\x66\x61vorites_first\x18\x01 \x01(\x08\x12\x18\n\x10include_archived\x18\x02 \x01(\x08\x12\x17\n\nstorage_id\x18\x03 \x01(\tH\x00\x88\x01\x01\x42\r\n\x0b_storage_id\"\xbc\x02\n\x07Project\x12\n\n\x02id\x18\x01 \x01(\t\x12\x0c\n\x04name\x18\x02 \x01(\t\x12\x0c\n\x04path\x18\x03 \x01(\t\x12.\n\ncreated_at\x18\x04 \x01(\x0b\x32\x1a.google.protobuf.Timestamp\x12.\n\nupdated_at\x18\x05 \x01(\x0b\x32\x1a.google.protobuf.Timestamp\x12\r\n\x05\x63olor\x18\x06 \x01(\t\x12\x13\n\x0bis_favorite\x18\x07 \x01(\x08\x12\x10\n\x08\x61rchived\x18\x08 \x01(\x08\x12\x12\n\nstorage_id\x18\t \x01(\t\x12\x30\n\x06\x63ustom\x18\n \x03(\x0b\x32 .api_gateway.Project.CustomEntry\x1a-\n\x0b\x43ustomEntry\x12\x0b\n\x03key\x18\x01 \x01(\t\x12\r\n\x05value\x18\x02 \x01(\t:\x02\x38\x01\">\n\x14ListProjectsResponse\x12&\n\x08projects\x18\x01 \x03(\x0b\x32\x14.api_gateway.Project\"A\n\x14\x43reateProjectRequest\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\x0c\n\x04path\x18\x02 \x01(\t\x12\r\n\x05merge\x18\x03 \x01(\x08\"\x91\x01\n\x15\x43reateProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12%\n\x07project\x18\x03 \x01(\x0b\x32\x14.api_gateway.Project\x12\x14\n\x0cproject_path\x18\x04 \x01(\t\x12\x13\n\x0brolled_back\x18\x05 \x01(\x08\"(\n\x12OpenProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\"d\n\x13OpenProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12%\n\x07project\x18\x03 \x01(\x0b\x32\x14.api_gateway.Project\"\x81\x02\n\x14UpdateProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\x12\x12\n\x05\x63olor\x18\x02 \x01(\tH\x00\x88\x01\x01\x12\x18\n\x0bis_favorite\x18\x03 \x01(\x08H\x01\x88\x01\x01\x12\x44\n\nset_custom\x18\x04 \x03(\x0b\x32\x30.api_gateway.UpdateProjectRequest.SetCustomEntry\x12\x15\n\rremove_custom\x18\x05 \x03(\t\x1a\x30\n\x0eSetCustomEntry\x12\x0b\n\x03key\x18\x01 \x01(\t\x12\r\n\x05value\x18\x02 \x01(\t:\x02\x38\x01\x42\x08\n\x06_colorB\x0e\n\x0c_is_favorite\"f\n\x15UpdateProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12%\n\x07project\x18\x03 \x01(\x0b\x32\x14.api_gateway.Project\"+\n\x15\x41rchiveProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\"g\n\x16\x41rchiveProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12%\n\x07project\x18\x03 \x01(\x0b\x32\x14.api_gateway.Project\"-\n\x17UnarchiveProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\"i\n\x18UnarchiveProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12%\n\x07project\x18\x03 \x01(\x0b\x32\x14.api_gateway.Project\"Q\n\x14\x44\x65leteProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\x12\x14\n\x0c\x64\x65lete_files\x18\x02 \x01(\x08\x12\x0f\n\x07\x64ry_run\x18\x03 \x01(\x08\"\xc1\x01\n\x15\x44\x65leteProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\r\n\x05paths\x18\x03 \x03(\t\x12\x17\n\x0fpaths_truncated\x18\x04 \x01(\x08\x12\x12\n\ntotal_size\x18\x05 \x01(\x04\x12\x18\n\x10registry_removed\x18\x06 \x01(\x08\x12\x15\n\rfiles_deleted\x18\x07 \x01(\x08\x12\x13\n\x0b\x66iles_error\x18\x08 \x01(\t\"\x1f\n\x1dSubscribeProjectEventsRequest\"\x91\x01\n\x0cProjectEvent\x12+\n\x04type\x18\x01 \x01(\x0e\x32\x1d.api_gateway.ProjectEventType\x12%\n\x07project\x18\x02 \x01(\x0b\x32\x14.api_gateway.Project\x12-\n\ttimestamp\x18\x03 \x01(\x0b\x32\x1a.google.protobuf.Timestamp\"*\n\x14\x45xportProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\"\x9c\x01\n\x15\x45xportProjectResponse\x12\x36\n\x08metadata\x18\x01 \x01(\x0b\x32\".api_gateway.ExportProjectMetadataH\x00\x12\x0f\n\x05\x63hunk\x18\x02 \x01(\x0cH\x00\x12\x32\n\x08progress\x18\x03 \x01(\x0b\x32\x1e.api_gateway.OperationProgressH\x00\x42\x06\n\x04\x64\x61ta\"R\n\x11OperationProgress\x12\x12\n\nbytes_done\x18\x01 \x01(\x04\x12\x13\n\x0btotal_bytes\x18\x02 \x01(\x04\x12\x14\n\x0c\x63urrent_file\x18\x03 \x01(\t\"=\n\x15\x45xportProjectMetadata\x12\x10\n\x08\x66ilename\x18\x01 \x01(\t\x12\x12\n\ntotal_size\x18\x02 \x01(\x04\"u\n\x1bImportProjectArchiveRequest\x12=\n\x08metadata\x18\x01 \x01(\x0b\x32).api_gateway.ImportProjectArchiveMetadataH\x00\x12\x0f\n\x05\x63hunk\x18\x02 \x01(\x0cH\x00\x42\x06\n\x04\x64\x61ta\"F\n\x1cImportProjectArchiveMetadata\x12\x18\n\x10\x64\x65stination_path\x18\x01 \x01(\t\x12\x0c\n\x04name\x18\x02 \x01(\t\"m\n\x1cImportProjectArchiveResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12%\n\x07project\x18\x03 \x01(\x0b\x32\x14.api_gateway.Project\"\x17\n\x15GetStorageInfoRequest\"\xbb\x02\n\x16GetStorageInfoResponse\x12\x12\n\nstorage_id\x18\x01 \x01(\t\x12\x10\n\x08hostname\x18\x02 \x01(\t\x12\n\n\x02os\x18\x03 \x01(\t\x12\x16\n\x0ehome_directory\x18\x04 \x01(\t\x12\x1d\n\x15\x64\x65\x66\x61ult_projects_path\x18\x05 \x01(\t\x12\x12\n\nroot_paths\x18\x06 \x03(\t\x12\x13\n\x0btotal_space\x18\x07 \x01(\x04\x12\x12\n\nfree_space\x18\x08 \x01(\x04\x12\x36\n\x0c\x63\x61pabilities\x18\t \x01(\x0b\x32 .api_gateway.StorageCapabilities\x12\x14\n\x0cstorage_type\x18\n \x01(\t\x12\x13\n\x0bquota_bytes\x18\x0b \x01(\x04\x12\x18\n\x10quota_used_bytes\x18\x0c \x01(\x04\"\x86\x01\n\x13StorageCapabilities\x12\x11\n\tstreaming\x18\x01 \x01(\x08\x12\x0e\n\x06rename\x18\x02 \x01(\x08\x12\r\n\x05watch\x18\x03 \x01(\x08\x12\x12\n\nthumbnails\x18\x04 \x01(\x08\x12\x12\n\ndisk_space\x18\x05 \x01(\x08\x12\x15\n\ratomic_writes\x18\x06 \x01(\x08\"&\n\x16\x42rowseDirectoryRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\"\xdc\x01\n\x0e\x44irectoryEntry\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\x0c\n\x04path\x18\x02 \x01(\t\x12\x14\n\x0cis_directory\x18\x03 \x01(\x08\x12\x0c\n\x04size\x18\x04 \x01(\x04\x12.\n\ncreated_at\x18\x05 \x01(\x0b\x32\x1a.google.protobuf.Timestamp\x12/\n\x0bmodified_at\x18\x06 \x01(\x0b\x32\x1a.google.protobuf.Timestamp\x12\x11\n\tmime_type\x18\x07 \x01(\t\x12\x16\n\x0emetadata_error\x18\x08 \x01(\t\"\xc3\x01\n\x17\x42rowseDirectoryResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x14\n\x0c\x63urrent_path\x18\x03 \x01(\t\x12\x13\n\x0bparent_path\x18\x04 \x01(\t\x12,\n\x07\x65ntries\x18\x05 \x03(\x0b\x32\x1b.api_gateway.DirectoryEntry\x12\x13\n\x0btotal_space\x18\x06 \x01(\x04\x12\x12\n\nfree_space\x18\x07 \x01(\x04\"Z\n\x16\x43reateDirectoryRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x16\n\x0e\x63reate_parents\x18\x02 \x01(\x08\x12\x11\n\x04mode\x18\x03 \x01(\rH\x00\x88\x01\x01\x42\x07\n\x05_mode\"W\n\x17\x43reateDirectoryResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x14\n\x0c\x63reated_path\x18\x03 \x01(\t\"P\n\rDeleteRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x11\n\trecursive\x18\x02 \x01(\x08\x12\x0f\n\x07\x64ry_run\x18\x03 \x01(\x08\x12\r\n\x05\x66orce\x18\x04 \x01(\x08\"\xb7\x01\n\x0e\x44\x65leteResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\r\n\x05paths\x18\x03 \x03(\t\x12\x17\n\x0fpaths_truncated\x18\x04 \x01(\x08\x12\x12\n\ntotal_size\x18\x05 \x01(\x04\x12\x19\n\x11\x61\x66\x66\x65\x63ted_projects\x18\x06 \x03(\t\x12&\n\x06\x65rrors\x18\x07 \x03(\x0b\x32\x16.api_gateway.PathError\"(\n\tPathError\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\r\n\x05\x65rror\x18\x02 \x01(\t\"S\n\x0fMoveFileRequest\x12\x13\n\x0bsource_path\x18\x01 \x01(\t\x12\x18\n\x10\x64\x65stination_path\x18\x02 \x01(\t\x12\x11\n\toverwrite\x18\x03 \x01(\x08\"S\n\x10MoveFileResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x17\n\x0frelinked_assets\x18\x03 \x01(\r\"/\n\rRenameRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x10\n\x08new_name\x18\x02 \x01(\t\"c\n\x0eRenameResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x10\n\x08new_path\x18\x03 \x01(\t\x12\x17\n\x0frelinked_assets\x18\x04 \x01(\r\"S\n\x0f\x43opyFileRequest\x12\x13\n\x0bsource_path\x18\x01 \x01(\t\x12\x18\n\x10\x64\x65stination_path\x18\x02 \x01(\t\x12\x11\n\toverwrite\x18\x03 \x01(\x08\"S\n\x0e\x43opyFileResult\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x19\n\x11\x62ytes_transferred\x18\x03 \x01(\x04\"{\n\rCopyFileEvent\x12\x32\n\x08progress\x18\x01 \x01(\x0b\x32\x1e.api_gateway.OperationProgressH\x00\x12-\n\x06result\x18\x02 \x01(\x0b\x32\x1b.api_gateway.CopyFileResultH\x00\x42\x07\n\x05\x65vent\"U\n\x1bInitProjectStructureRequest\x12\x11\n\tbase_path\x18\x01 \x01(\t\x12\x14\n\x0cproject_name\x18\x02 \x01(\t\x12\r\n\x05merge\x18\x03 \x01(\x08\"\xdb\x01\n\x1cInitProjectStructureResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x14\n\x0cproject_path\x18\x03 \x01(\t\x12\x13\n\x0b\x61ssets_path\x18\x04 \x01(\t\x12\x12\n\nvideo_path\x18\x05 \x01(\t\x12\x12\n\naudio_path\x18\x06 \x01(\t\x12\x13\n\x0bimages_path\x18\x07 \x01(\t\x12\x15\n\rtimeline_path\x18\x08 \x01(\t\x12\x14\n\x0c\x65xports_path\x18\t \x01(\t\"\x8e\x01\n\x11UploadFileRequest\x12\x33\n\x08metadata\x18\x01 \x01(\x0b\x32\x1f.api_gateway.UploadFileMetadataH\x00\x12\x0f\n\x05\x63hunk\x18\x02 \x01(\x0cH\x00\x12+\n\x06\x63\x61ncel\x18\x03 \x01(\x0b\x32\x19.api_gateway.UploadCancelH\x00\x42\x06\n\x04\x64\x61ta\"\x1e\n\x0cUploadCancel\x12\x0e\n\x06reason\x18\x01 \x01(\t\"\xbd\x01\n\x12UploadFileMetadata\x12\x18\n\x10\x64\x65stination_path\x18\x01 \x01(\t\x12\x10\n\x08\x66ilename\x18\x02 \x01(\t\x12\x12\n\ntotal_size\x18\x03 \x01(\x04\x12\x11\n\toverwrite\x18\x04 \x01(\x08\x12\x38\n\x14\x65xpected_modified_at\x18\x05 \x01(\x0b\x32\x1a.google.protobuf.Timestamp\x12\x11\n\x04mode\x18\x06 \x01(\rH\x00\x88\x01\x01\x42\x07\n\x05_mode\"f\n\x12UploadFileResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x11\n\tfile_path\x18\x03 \x01(\t\x12\x15\n\rbytes_written\x18\x04 \x01(\x04\"m\n\x13UploadBatchResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12.\n\x05\x66iles\x18\x03 \x03(\x0b\x32\x1f.api_gateway.UploadFileResponse\"e\n\x13\x44ownloadFileRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x10\n\x08\x63hecksum\x18\x02 \x01(\x08\x12\x0e\n\x06offset\x18\x03 \x01(\x04\x12\x13\n\x06length\x18\x04 \x01(\x04H\x00\x88\x01\x01\x42\t\n\x07_length\"z\n\x14\x44ownloadFileResponse\x12\x35\n\x08metadata\x18\x01 \x01(\x0b\x32!.api_gateway.DownloadFileMetadataH\x00\x12\x0f\n\x05\x63hunk\x18\x02 \x01(\x0cH\x00\x12\x12\n\x08\x63hecksum\x18\x03 \x01(\tH\x00\x42\x06\n\x04\x64\x61ta\"\xa1\x01\n\x14\x44ownloadFileMetadata\x12\x10\n\x08\x66ilename\x18\x01 \x01(\t\x12\x12\n\ntotal_size\x18\x02 \x01(\x04\x12\x11\n\tmime_type\x18\x03 \x01(\t\x12\x18\n\x10\x63hecksum_follows\x18\x04 \x01(\x08\x12\x0e\n\x06offset\x18\x05 \x01(\x04\x12\x0e\n\x06length\x18\x06 \x01(\x04\x12\x16\n\x0e\x61\x63\x63\x65pts_ranges\x18\x07 \x01(\x08*\x8c\x01\n\x10ProjectEventType\x12\x1d\n\x19PROJECT_EVENT_UNSPECIFIED\x10\x00\x12\x16\n\x12PROJECT_REGISTERED\x10\x01\x12\x12\n\x0ePROJECT_OPENED\x10\x02\x12\x18\n\x14PROJECT_UNREGISTERED\x10\x03\x12\x13\n\x0fPROJECT_UPDATED\x10\x04\x32\xe9\x0f\n\nApiGateway\x12P\n\x0bHealthCheck\x12\x1f.api_gateway.HealthCheckRequest\x1a .api_gateway.HealthCheckResponse\x12\\\n\x0fGetServicesInfo\x12#.api_gateway.GetServicesInfoRequest\x1a$.api_gateway.GetServicesInfoResponse\x12S\n\x0cListProjects\x12 .api_gateway.ListProjectsRequest\x1a!.api_gateway.ListProjectsResponse\x12V\n\rCreateProject\x12!.api_gateway.CreateProjectRequest\x1a\".api_gateway.CreateProjectResponse\x12P\n\x0bOpenProject\x12\x1f.api_gateway.OpenProjectRequest\x1a .api_gateway.OpenProjectResponse\x12V\n\rUpdateProject\x12!.api_gateway.UpdateProjectRequest\x1a\".api_gateway.UpdateProjectResponse\x12Y\n\x0e\x41rchiveProject\x12\".api_gateway.ArchiveProjectRequest\x1a#.api_gateway.ArchiveProjectResponse\x12_\n\x10UnarchiveProject\x12$.api_gateway.UnarchiveProjectRequest\x1a%.api_gateway.UnarchiveProjectResponse\x12V\n\rDeleteProject\x12!.api_gateway.DeleteProjectRequest\x1a\".api_gateway.DeleteProjectResponse\x12\x61\n\x16SubscribeProjectEvents\x12*.api_gateway.SubscribeProjectEventsRequest\x1a\x19.api_gateway.ProjectEvent0\x01\x12X\n\rExportProject\x12!.api_gateway.ExportProjectRequest\x1a\".api_gateway.ExportProjectResponse0\x01\x12m\n\x14ImportProjectArchive\x12(.api_gateway.ImportProjectArchiveRequest\x1a).api_gateway.ImportProjectArchiveResponse(\x01\x12Y\n\x0eGetStorageInfo\x12\".api_gateway.GetStorageInfoRequest\x1a#.api_gateway.GetStorageInfoResponse\x12\\\n\x0f\x42rowseDirectory\x12#.api_gateway.BrowseDirectoryRequest\x1a$.api_gateway.BrowseDirectoryResponse\x12\\\n\x0f\x43reateDirectory\x12#.api_gateway.CreateDirectoryRequest\x1a$.api_gateway.CreateDirectoryResponse\x12\x41\n\x06\x44\x65lete\x12\x1a.api_gateway.DeleteRequest\x1a\x1b.api_gateway.DeleteResponse\x12G\n\x08MoveFile\x12\x1c.api_gateway.MoveFileRequest\x1a\x1d.api_gateway.MoveFileResponse\x12\x41\n\x06Rename\x12\x1a.api_gateway.RenameRequest\x1a\x1b.api_gateway.RenameResponse\x12\x46\n\x08\x43opyFile\x12\x1c.api_gateway.CopyFileRequest\x1a\x1a.api_gateway.CopyFileEvent0\x01\x12k\n\x14InitProjectStructure\x12(.api_gateway.InitProjectStructureRequest\x1a).api_gateway.InitProjectStructureResponse\x12O\n\nUploadFile\x12\x1e.api_gateway.UploadFileRequest\x1a\x1f.api_gateway.UploadFileResponse(\x01\x12Q\n\x0bUploadBatch\x12\x1e.api_gateway.UploadFileRequest\x1a .api_gateway.UploadBatchResponse(\x01\x12U\n\x0c\x44ownloadFile\x12 .api_gateway.DownloadFileRequest\x1a!.api_gateway.DownloadFileResponse0\x01\x62\x06proto3')
 
 _globals = globals()
 _builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, _globals)
 _builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'api_gateway_pb2', _globals)
 if not _descriptor._USE_C_DESCRIPTORS:
   DESCRIPTOR._loaded_options = None
-  _globals['_HEALTHCHECKREQUEST']._serialized_start=34
-  _globals['_HEALTHCHECKREQUEST']._serialized_end=54
-  _globals['_SERVICESTATUS']._serialized_start=56
-  _globals['_SERVICESTATUS']._serialized_end=158
-  _globals['_HEALTHCHECKRESPONSE']._serialized_start=160
-  _globals['_HEALTHCHECKRESPONSE']._serialized_end=248
-  _globals['_GETSERVICESINFOREQUEST']._serialized_start=250
-  _globals['_GETSERVICESINFOREQUEST']._serialized_end=274
-  _globals['_GETSERVICESINFORESPONSE']._serialized_start=277
-  _globals['_GETSERVICESINFORESPONSE']._serialized_end=514
-  _globals['_LISTPROJECTSREQUEST']._serialized_start=516
-  _globals['_LISTPROJECTSREQUEST']._serialized_end=537
-  _globals['_PROJECT']._serialized_start=539
-  _globals['_PROJECT']._serialized_end=628
-  _globals['_LISTPROJECTSRESPONSE']._serialized_start=630
-  _globals['_LISTPROJECTSRESPONSE']._serialized_end=692
-  _globals['_CREATEPROJECTREQUEST']._serialized_start=694
-  _globals['_CREATEPROJECTREQUEST']._serialized_end=744
-  _globals['_CREATEPROJECTRESPONSE']._serialized_start=746
-  _globals['_CREATEPROJECTRESPONSE']._serialized_end=848
-  _globals['_OPENPROJECTREQUEST']._serialized_start=850
-  _globals['_OPENPROJECTREQUEST']._serialized_end=890
-  _globals['_OPENPROJECTRESPONSE']._serialized_start=892
-  _globals['_OPENPROJECTRESPONSE']._serialized_end=992
-  _globals['_DELETEPROJECTREQUEST']._serialized_start=994
-  _globals['_DELETEPROJECTREQUEST']._serialized_end=1058
-  _globals['_DELETEPROJECTRESPONSE']._serialized_start=1060
-  _globals['_DELETEPROJECTRESPONSE']._serialized_end=1123
-  _globals['_GETSTORAGEINFOREQUEST']._serialized_start=1125
-  _globals['_GETSTORAGEINFOREQUEST']._serialized_end=1148
-  _globals['_GETSTORAGEINFORESPONSE']._serialized_start=1151
-  _globals['_GETSTORAGEINFORESPONSE']._serialized_end=1341
-  _globals['_BROWSEDIRECTORYREQUEST']._serialized_start=1343
-  _globals['_BROWSEDIRECTORYREQUEST']._serialized_end=1381
-  _globals['_DIRECTORYENTRY']._serialized_start=1384
-  _globals['_DIRECTORYENTRY']._serialized_end=1524
-  _globals['_BROWSEDIRECTORYRESPONSE']._serialized_start=1527
-  _globals['_BROWSEDIRECTORYRESPONSE']._serialized_end=1681
-  _globals['_CREATEDIRECTORYREQUEST']._serialized_start=1683
-  _globals['_CREATEDIRECTORYREQUEST']._serialized_end=1745
-  _globals['_CREATEDIRECTORYRESPONSE']._serialized_start=1747
-  _globals['_CREATEDIRECTORYRESPONSE']._serialized_end=1834
-  _globals['_DELETEREQUEST']._serialized_start=1836
-  _globals['_DELETEREQUEST']._serialized_end=1884
-  _globals['_DELETERESPONSE']._serialized_start=1886
-  _globals['_DELETERESPONSE']._serialized_end=1942
-  _globals['_INITPROJECTSTRUCTUREREQUEST']._serialized_start=1944
-  _globals['_INITPROJECTSTRUCTUREREQUEST']._serialized_end=2014
-  _globals['_INITPROJECTSTRUCTURERESPONSE']._serialized_start=2017
-  _globals['_INITPROJECTSTRUCTURERESPONSE']._serialized_end=2236
-  _globals['_UPLOADFILEREQUEST']._serialized_start=2238
-  _globals['_UPLOADFILEREQUEST']._serialized_end=2335
-  _globals['_UPLOADFILEMETADATA']._serialized_start=2337
-  _globals['_UPLOADFILEMETADATA']._serialized_end=2440
-  _globals['_UPLOADFILERESPONSE']._serialized_start=2442
-  _globals['_UPLOADFILERESPONSE']._serialized_end=2544
-  _globals['_DOWNLOADFILEREQUEST']._serialized_start=2546
-  _globals['_DOWNLOADFILEREQUEST']._serialized_end=2581
-  _globals['_DOWNLOADFILERESPONSE']._serialized_start=2583
-  _globals['_DOWNLOADFILERESPONSE']._serialized_end=2685
-  _globals['_DOWNLOADFILEMETADATA']._serialized_start=2687
-  _globals['_DOWNLOADFILEMETADATA']._serialized_end=2766
-  _globals['_APIGATEWAY']._serialized_start=2769
-  _globals['_APIGATEWAY']._serialized_end=3923
+  _globals['_PROJECT_CUSTOMENTRY']._loaded_options = None
+  _globals['_PROJECT_CUSTOMENTRY']._serialized_options = b'8\001'
+  _globals['_UPDATEPROJECTREQUEST_SETCUSTOMENTRY']._loaded_options = None
+  _globals['_UPDATEPROJECTREQUEST_SETCUSTOMENTRY']._serialized_options = b'8\001'
+  _globals['_PROJECTEVENTTYPE']._serialized_start=6922
+  _globals['_PROJECTEVENTTYPE']._serialized_end=7062
+  _globals['_HEALTHCHECKREQUEST']._serialized_start=67
+  _globals['_HEALTHCHECKREQUEST']._serialized_end=87
+  _globals['_SERVICESTATUS']._serialized_start=90
+  _globals['_SERVICESTATUS']._serialized_end=307
+  _globals['_HEALTHCHECKRESPONSE']._serialized_start=309
+  _globals['_HEALTHCHECKRESPONSE']._serialized_end=426
+  _globals['_GETSERVICESINFOREQUEST']._serialized_start=428
+  _globals['_GETSERVICESINFOREQUEST']._serialized_end=452
+  _globals['_GETSERVICESINFORESPONSE']._serialized_start=455
+  _globals['_GETSERVICESINFORESPONSE']._serialized_end=952
+  _globals['_LISTPROJECTSREQUEST']._serialized_start=954
+  _globals['_LISTPROJECTSREQUEST']._serialized_end=1066
+  _globals['_PROJECT']._serialized_start=1069
+  _globals['_PROJECT']._serialized_end=1385
+  _globals['_PROJECT_CUSTOMENTRY']._serialized_start=1340
+  _globals['_PROJECT_CUSTOMENTRY']._serialized_end=1385
+  _globals['_LISTPROJECTSRESPONSE']._serialized_start=1387
+  _globals['_LISTPROJECTSRESPONSE']._serialized_end=1449
+  _globals['_CREATEPROJECTREQUEST']._serialized_start=1451
+  _globals['_CREATEPROJECTREQUEST']._serialized_end=1516
+  _globals['_CREATEPROJECTRESPONSE']._serialized_start=1519
+  _globals['_CREATEPROJECTRESPONSE']._serialized_end=1664
+  _globals['_OPENPROJECTREQUEST']._serialized_start=1666
+  _globals['_OPENPROJECTREQUEST']._serialized_end=1706
+  _globals['_OPENPROJECTRESPONSE']._serialized_start=1708
+  _globals['_OPENPROJECTRESPONSE']._serialized_end=1808
+  _globals['_UPDATEPROJECTREQUEST']._serialized_start=1811
+  _globals['_UPDATEPROJECTREQUEST']._serialized_end=2068
+  _globals['_UPDATEPROJECTREQUEST_SETCUSTOMENTRY']._serialized_start=1994
+  _globals['_UPDATEPROJECTREQUEST_SETCUSTOMENTRY']._serialized_end=2042
+  _globals['_UPDATEPROJECTRESPONSE']._serialized_start=2070
+  _globals['_UPDATEPROJECTRESPONSE']._serialized_end=2172
+  _globals['_ARCHIVEPROJECTREQUEST']._serialized_start=2174
+  _globals['_ARCHIVEPROJECTREQUEST']._serialized_end=2217
+  _globals['_ARCHIVEPROJECTRESPONSE']._serialized_start=2219
+  _globals['_ARCHIVEPROJECTRESPONSE']._serialized_end=2322
+  _globals['_UNARCHIVEPROJECTREQUEST']._serialized_start=2324
+  _globals['_UNARCHIVEPROJECTREQUEST']._serialized_end=2369
+  _globals['_UNARCHIVEPROJECTRESPONSE']._serialized_start=2371
+  _globals['_UNARCHIVEPROJECTRESPONSE']._serialized_end=2476
+  _globals['_DELETEPROJECTREQUEST']._serialized_start=2478
+  _globals['_DELETEPROJECTREQUEST']._serialized_end=2559
+  _globals['_DELETEPROJECTRESPONSE']._serialized_start=2562
+  _globals['_DELETEPROJECTRESPONSE']._serialized_end=2755
+  _globals['_SUBSCRIBEPROJECTEVENTSREQUEST']._serialized_start=2757
+  _globals['_SUBSCRIBEPROJECTEVENTSREQUEST']._serialized_end=2788
+  _globals['_PROJECTEVENT']._serialized_start=2791
+  _globals['_PROJECTEVENT']._serialized_end=2936
+  _globals['_EXPORTPROJECTREQUEST']._serialized_start=2938
+  _globals['_EXPORTPROJECTREQUEST']._serialized_end=2980
+  _globals['_EXPORTPROJECTRESPONSE']._serialized_start=2983
+  _globals['_EXPORTPROJECTRESPONSE']._serialized_end=3139
+  _globals['_OPERATIONPROGRESS']._serialized_start=3141
+  _globals['_OPERATIONPROGRESS']._serialized_end=3223
+  _globals['_EXPORTPROJECTMETADATA']._serialized_start=3225
+  _globals['_EXPORTPROJECTMETADATA']._serialized_end=3286
+  _globals['_IMPORTPROJECTARCHIVEREQUEST']._serialized_start=3288
+  _globals['_IMPORTPROJECTARCHIVEREQUEST']._serialized_end=3405
+  _globals['_IMPORTPROJECTARCHIVEMETADATA']._serialized_start=3407
+  _globals['_IMPORTPROJECTARCHIVEMETADATA']._serialized_end=3477
+  _globals['_IMPORTPROJECTARCHIVERESPONSE']._serialized_start=3479
+  _globals['_IMPORTPROJECTARCHIVERESPONSE']._serialized_end=3588
+  _globals['_GETSTORAGEINFOREQUEST']._serialized_start=3590
+  _globals['_GETSTORAGEINFOREQUEST']._serialized_end=3613
+  _globals['_GETSTORAGEINFORESPONSE']._serialized_start=3616
+  _globals['_GETSTORAGEINFORESPONSE']._serialized_end=3931
+  _globals['_STORAGECAPABILITIES']._serialized_start=3934
+  _globals['_STORAGECAPABILITIES']._serialized_end=4068
+  _globals['_BROWSEDIRECTORYREQUEST']._serialized_start=4070
+  _globals['_BROWSEDIRECTORYREQUEST']._serialized_end=4108
+  _globals['_DIRECTORYENTRY']._serialized_start=4111
+  _globals['_DIRECTORYENTRY']._serialized_end=4331
+  _globals['_BROWSEDIRECTORYRESPONSE']._serialized_start=4334
+  _globals['_BROWSEDIRECTORYRESPONSE']._serialized_end=4529
+  _globals['_CREATEDIRECTORYREQUEST']._serialized_start=4531
+  _globals['_CREATEDIRECTORYREQUEST']._serialized_end=4621
+  _globals['_CREATEDIRECTORYRESPONSE']._serialized_start=4623
+  _globals['_CREATEDIRECTORYRESPONSE']._serialized_end=4710
+  _globals['_DELETEREQUEST']._serialized_start=4712
+  _globals['_DELETEREQUEST']._serialized_end=4792
+  _globals['_DELETERESPONSE']._serialized_start=4795
+  _globals['_DELETERESPONSE']._serialized_end=4978
+  _globals['_PATHERROR']._serialized_start=4980
+  _globals['_PATHERROR']._serialized_end=5020
+  _globals['_MOVEFILEREQUEST']._serialized_start=5022
+  _globals['_MOVEFILEREQUEST']._serialized_end=5105
+  _globals['_MOVEFILERESPONSE']._serialized_start=5107
+  _globals['_MOVEFILERESPONSE']._serialized_end=5190
+  _globals['_RENAMEREQUEST']._serialized_start=5192
+  _globals['_RENAMEREQUEST']._serialized_end=5239
+  _globals['_RENAMERESPONSE']._serialized_start=5241
+  _globals['_RENAMERESPONSE']._serialized_end=5340
+  _globals['_COPYFILEREQUEST']._serialized_start=5342
+  _globals['_COPYFILEREQUEST']._serialized_end=5425
+  _globals['_COPYFILERESULT']._serialized_start=5427
+  _globals['_COPYFILERESULT']._serialized_end=5510
+  _globals['_COPYFILEEVENT']._serialized_start=5512
+  _globals['_COPYFILEEVENT']._serialized_end=5635
+  _globals['_INITPROJECTSTRUCTUREREQUEST']._serialized_start=5637
+  _globals['_INITPROJECTSTRUCTUREREQUEST']._serialized_end=5722
+  _globals['_INITPROJECTSTRUCTURERESPONSE']._serialized_start=5725
+  _globals['_INITPROJECTSTRUCTURERESPONSE']._serialized_end=5944
+  _globals['_UPLOADFILEREQUEST']._serialized_start=5947
+  _globals['_UPLOADFILEREQUEST']._serialized_end=6089
+  _globals['_UPLOADCANCEL']._serialized_start=6091
+  _globals['_UPLOADCANCEL']._serialized_end=6121
+  _globals['_UPLOADFILEMETADATA']._serialized_start=6124
+  _globals['_UPLOADFILEMETADATA']._serialized_end=6313
+  _globals['_UPLOADFILERESPONSE']._serialized_start=6315
+  _globals['_UPLOADFILERESPONSE']._serialized_end=6417
+  _globals['_UPLOADBATCHRESPONSE']._serialized_start=6419
+  _globals['_UPLOADBATCHRESPONSE']._serialized_end=6528
+  _globals['_DOWNLOADFILEREQUEST']._serialized_start=6530
+  _globals['_DOWNLOADFILEREQUEST']._serialized_end=6631
+  _globals['_DOWNLOADFILERESPONSE']._serialized_start=6633
+  _globals['_DOWNLOADFILERESPONSE']._serialized_end=6755
+  _globals['_DOWNLOADFILEMETADATA']._serialized_start=6758
+  _globals['_DOWNLOADFILEMETADATA']._serialized_end=6919
+  _globals['_APIGATEWAY']._serialized_start=7065
+  _globals['_APIGATEWAY']._serialized_end=9090
 # @@protoc_insertion_point(module_scope)
@@ -64,11 +64,41 @@ class ApiGatewayStub(object):
                 request_serializer=api__gateway__pb2.OpenProjectRequest.SerializeToString,
                 response_deserializer=api__gateway__pb2.OpenProjectResponse.FromString,
                 _registered_method=True)
+        self.UpdateProject = channel.unary_unary(
+                '/api_gateway.ApiGateway/UpdateProject',
+                request_serializer=api__gateway__pb2.UpdateProjectRequest.SerializeToString,
+                response_deserializer=api__gateway__pb2.UpdateProjectResponse.FromString,
+                _registered_method=True)
+        self.ArchiveProject = channel.unary_unary(
+                '/api_gateway.ApiGateway/ArchiveProject',
+                request_serializer=api__gateway__pb2.ArchiveProjectRequest.SerializeToString,
+                response_deserializer=api__gateway__pb2.ArchiveProjectResponse.FromString,
+                _registered_method=True)
+        self.UnarchiveProject = channel.unary_unary(
+                '/api_gateway.ApiGateway/UnarchiveProject',
+                request_serializer=api__gateway__pb2.UnarchiveProjectRequest.SerializeToString,
+                response_deserializer=api__gateway__pb2.UnarchiveProjectResponse.FromString,
+                _registered_method=True)
         self.DeleteProject = channel.unary_unary(
                 '/api_gateway.ApiGateway/DeleteProject',
                 request_serializer=api__gateway__pb2.DeleteProjectRequest.SerializeToString,
                 response_deserializer=api__gateway__pb2.DeleteProjectResponse.FromString,
                 _registered_method=True)
+        self.SubscribeProjectEvents = channel.unary_stream(
+                '/api_gateway.ApiGateway/SubscribeProjectEvents',
+                request_serializer=api__gateway__pb2.SubscribeProjectEventsRequest.SerializeToString,
+                response_deserializer=api__gateway__pb2.ProjectEvent.FromString,
+                _registered_method=True)
+        self.ExportProject = channel.unary_stream(
+                '/api_gateway.ApiGateway/ExportProject',
+                request_serializer=api__gateway__pb2.ExportProjectRequest.SerializeToString,
+                response_deserializer=api__gateway__pb2.ExportProjectResponse.FromString,
+                _registered_method=True)
+        self.ImportProjectArchive = channel.stream_unary(
+                '/api_gateway.ApiGateway/ImportProjectArchive',
+                request_serializer=api__gateway__pb2.ImportProjectArchiveRequest.SerializeToString,
+                response_deserializer=api__gateway__pb2.ImportProjectArchiveResponse.FromString,
+                _registered_method=True)
         self.GetStorageInfo = channel.unary_unary(
                 '/api_gateway.ApiGateway/GetStorageInfo',
                 request_serializer=api__gateway__pb2.GetStorageInfoRequest.SerializeToString,
@@ -89,6 +119,21 @@ class ApiGatewayStub(object):
                 request_serializer=api__gateway__pb2.DeleteRequest.SerializeToString,
                 response_deserializer=api__gateway__pb2.DeleteResponse.FromString,
                 _registered_method=True)
+        self.MoveFile = channel.unary_unary(
+                '/api_gateway.ApiGateway/MoveFile',
+                request_serializer=api__gateway__pb2.MoveFileRequest.SerializeToString,
+                response_deserializer=api__gateway__pb2.MoveFileResponse.FromString,
+                _registered_method=True)
+        self.Rename = channel.unary_unary(
+                '/api_gateway.ApiGateway/Rename',
+                request_serializer=api__gateway__pb2.RenameRequest.SerializeToString,
+                response_deserializer=api__gateway__pb2.RenameResponse.FromString,
+                _registered_method=True)
+        self.CopyFile = channel.unary_stream(
+                '/api_gateway.ApiGateway/CopyFile',
+                request_serializer=api__gateway__pb2.CopyFileRequest.SerializeToString,
+                response_deserializer=api__gateway__pb2.CopyFileEvent.FromString,
+                _registered_method=True)
         self.InitProjectStructure = channel.unary_unary(
                 '/api_gateway.ApiGateway/InitProjectStructure',
                 request_serializer=api__gateway__pb2.InitProjectStructureRequest.SerializeToString,
@@ -99,6 +144,11 @@ class ApiGatewayStub(object):
                 request_serializer=api__gateway__pb2.UploadFileRequest.SerializeToString,
                 response_deserializer=api__gateway__pb2.UploadFileResponse.FromString,
                 _registered_method=True)
+        self.UploadBatch = channel.stream_unary(
+                '/api_gateway.ApiGateway/UploadBatch',
+                request_serializer=api__gateway__pb2.UploadFileRequest.SerializeToString,
+                response_deserializer=api__gateway__pb2.UploadBatchResponse.FromString,
+                _registered_method=True)
         self.DownloadFile = channel.unary_stream(
                 '/api_gateway.ApiGateway/DownloadFile',
                 request_serializer=api__gateway__pb2.DownloadFileRequest.SerializeToString,
@@ -148,12 +198,49 @@ class ApiGatewayServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def UpdateProject(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def ArchiveProject(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def UnarchiveProject(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def DeleteProject(self, request, context):
         """Missing associated documentation comment in .proto file."""
         context.set_code(grpc.StatusCode.UNIMPLEMENTED)
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def SubscribeProjectEvents(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def ExportProject(self, request, context):
+        """Переносимый архив проекта (tar: манифест движка + файлы папки проекта)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def ImportProjectArchive(self, request_iterator, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def GetStorageInfo(self, request, context):
         """=== Файловая система (проксирование к FileGateway) ===
 
@@ -180,6 +267,24 @@ class ApiGatewayServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def MoveFile(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def Rename(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def CopyFile(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def InitProjectStructure(self, request, context):
         """Missing associated documentation comment in .proto file."""
         context.set_code(grpc.StatusCode.UNIMPLEMENTED)
@@ -193,6 +298,12 @@ class ApiGatewayServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def UploadBatch(self, request_iterator, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def DownloadFile(self, request, context):
         """Missing associated documentation comment in .proto file."""
         context.set_code(grpc.StatusCode.UNIMPLEMENTED)
@@ -227,11 +338,41 @@ def add_ApiGatewayServicer_to_server(servicer, server):
                     request_deserializer=api__gateway__pb2.OpenProjectRequest.FromString,
                     response_serializer=api__gateway__pb2.OpenProjectResponse.SerializeToString,
             ),
+            'UpdateProject': grpc.unary_unary_rpc_method_handler(
+                    servicer.UpdateProject,
+                    request_deserializer=api__gateway__pb2.UpdateProjectRequest.FromString,
+                    response_serializer=api__gateway__pb2.UpdateProjectResponse.SerializeToString,
+            ),
+            'ArchiveProject': grpc.unary_unary_rpc_method_handler(
+                    servicer.ArchiveProject,
+                    request_deserializer=api__gateway__pb2.ArchiveProjectRequest.FromString,
+                    response_serializer=api__gateway__pb2.ArchiveProjectResponse.SerializeToString,
+            ),
+            'UnarchiveProject': grpc.unary_unary_rpc_method_handler(
+                    servicer.UnarchiveProject,
+                    request_deserializer=api__gateway__pb2.UnarchiveProjectRequest.FromString,
+                    response_serializer=api__gateway__pb2.UnarchiveProjectResponse.SerializeToString,
+            ),
             'DeleteProject': grpc.unary_unary_rpc_method_handler(
                     servicer.DeleteProject,
                     request_deserializer=api__gateway__pb2.DeleteProjectRequest.FromString,
                     response_serializer=api__gateway__pb2.DeleteProjectResponse.SerializeToString,
             ),
+            'SubscribeProjectEvents': grpc.unary_stream_rpc_method_handler(
+                    servicer.SubscribeProjectEvents,
+                    request_deserializer=api__gateway__pb2.SubscribeProjectEventsRequest.FromString,
+                    response_serializer=api__gateway__pb2.ProjectEvent.SerializeToString,
+            ),
+            'ExportProject': grpc.unary_stream_rpc_method_handler(
+                    servicer.ExportProject,
+                    request_deserializer=api__gateway__pb2.ExportProjectRequest.FromString,
+                    response_serializer=api__gateway__pb2.ExportProjectResponse.SerializeToString,
+            ),
+            'ImportProjectArchive': grpc.stream_unary_rpc_method_handler(
+                    servicer.ImportProjectArchive,
+                    request_deserializer=api__gateway__pb2.ImportProjectArchiveRequest.FromString,
+                    response_serializer=api__gateway__pb2.ImportProjectArchiveResponse.SerializeToString,
+            ),
             'GetStorageInfo': grpc.unary_unary_rpc_method_handler(
                     servicer.GetStorageInfo,
                     request_deserializer=api__gateway__pb2.GetStorageInfoRequest.FromString,
@@ -252,6 +393,21 @@ def add_ApiGatewayServicer_to_server(servicer, server):
                     request_deserializer=api__gateway__pb2.DeleteRequest.FromString,
                     response_serializer=api__gateway__pb2.DeleteResponse.SerializeToString,
             ),
+            'MoveFile': grpc.unary_unary_rpc_method_handler(
+                    servicer.MoveFile,
+                    request_deserializer=api__gateway__pb2.MoveFileRequest.FromString,
+                    response_serializer=api__gateway__pb2.MoveFileResponse.SerializeToString,
+            ),
+            'Rename': grpc.unary_unary_rpc_method_handler(
+                    servicer.Rename,
+                    request_deserializer=api__gateway__pb2.RenameRequest.FromString,
+                    response_serializer=api__gateway__pb2.RenameResponse.SerializeToString,
+            ),
+            'CopyFile': grpc.unary_stream_rpc_method_handler(
+                    servicer.CopyFile,
+                    request_deserializer=api__gateway__pb2.CopyFileRequest.FromString,
+                    response_serializer=api__gateway__pb2.CopyFileEvent.SerializeToString,
+            ),
             'InitProjectStructure': grpc.unary_unary_rpc_method_handler(
                     servicer.InitProjectStructure,
                     request_deserializer=api__gateway__pb2.InitProjectStructureRequest.FromString,
@@ -262,6 +418,11 @@ def add_ApiGatewayServicer_to_server(servicer, server):
                     request_deserializer=api__gateway__pb2.UploadFileRequest.FromString,
                     response_serializer=api__gateway__pb2.UploadFileResponse.SerializeToString,
             ),
+            'UploadBatch': grpc.stream_unary_rpc_method_handler(
+                    servicer.UploadBatch,
+                    request_deserializer=api__gateway__pb2.UploadFileRequest.FromString,
+                    response_serializer=api__gateway__pb2.UploadBatchResponse.SerializeToString,
+            ),
             'DownloadFile': grpc.unary_stream_rpc_method_handler(
                     servicer.DownloadFile,
                     request_deserializer=api__gateway__pb2.DownloadFileRequest.FromString,
@@ -418,6 +579,87 @@ class ApiGateway(object):
             metadata,
             _registered_method=True)
 
+    @staticmethod
+    def UpdateProject(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/api_gateway.ApiGateway/UpdateProject',
+            api__gateway__pb2.UpdateProjectRequest.SerializeToString,
+            api__gateway__pb2.UpdateProjectResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def ArchiveProject(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/api_gateway.ApiGateway/ArchiveProject',
+            api__gateway__pb2.ArchiveProjectRequest.SerializeToString,
+            api__gateway__pb2.ArchiveProjectResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def UnarchiveProject(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/api_gateway.ApiGateway/UnarchiveProject',
+            api__gateway__pb2.UnarchiveProjectRequest.SerializeToString,
+            api__gateway__pb2.UnarchiveProjectResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
     @staticmethod
     def DeleteProject(request,
             target,
@@ -445,6 +687,87 @@ class ApiGateway(object):
             metadata,
             _registered_method=True)
 
+    @staticmethod
+    def SubscribeProjectEvents(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_stream(
+            request,
+            target,
+            '/api_gateway.ApiGateway/SubscribeProjectEvents',
+            api__gateway__pb2.SubscribeProjectEventsRequest.SerializeToString,
+            api__gateway__pb2.ProjectEvent.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def ExportProject(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_stream(
+            request,
+            target,
+            '/api_gateway.ApiGateway/ExportProject',
+            api__gateway__pb2.ExportProjectRequest.SerializeToString,
+            api__gateway__pb2.ExportProjectResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def ImportProjectArchive(request_iterator,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.stream_unary(
+            request_iterator,
+            target,
+            '/api_gateway.ApiGateway/ImportProjectArchive',
+            api__gateway__pb2.ImportProjectArchiveRequest.SerializeToString,
+            api__gateway__pb2.ImportProjectArchiveResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
     @staticmethod
     def GetStorageInfo(request,
             target,
@@ -553,6 +876,87 @@ class ApiGateway(object):
             metadata,
             _registered_method=True)
 
+    @staticmethod
+    def MoveFile(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/api_gateway.ApiGateway/MoveFile',
+            api__gateway__pb2.MoveFileRequest.SerializeToString,
+            api__gateway__pb2.MoveFileResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def Rename(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/api_gateway.ApiGateway/Rename',
+            api__gateway__pb2.RenameRequest.SerializeToString,
+            api__gateway__pb2.RenameResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def CopyFile(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_stream(
+            request,
+            target,
+            '/api_gateway.ApiGateway/CopyFile',
+            api__gateway__pb2.CopyFileRequest.SerializeToString,
+            api__gateway__pb2.CopyFileEvent.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
     @staticmethod
     def InitProjectStructure(request,
             target,
@@ -607,6 +1011,33 @@ class ApiGateway(object):
             metadata,
             _registered_method=True)
 
+    @staticmethod
+    def UploadBatch(request_iterator,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.stream_unary(
+            request_iterator,
+            target,
+            '/api_gateway.ApiGateway/UploadBatch',
+            api__gateway__pb2.UploadFileRequest.SerializeToString,
+            api__gateway__pb2.UploadBatchResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
     @staticmethod
     def DownloadFile(request,
             target,
@@ -22,37 +22,142 @@ _runtime_version.ValidateProtobufRuntimeVersion(
 _sym_db = _symbol_database.Default()
 
 
+from google.protobuf import timestamp_pb2 as google_dot_protobuf_dot_timestamp__pb2
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0e\x64irector.proto\x12\x08\x64irector\"\x16\n\x14GetEngineInfoRequest\"V\n\x15GetEngineInfoResponse\x12\x11\n\tengine_id\x18\x01 \x01(\t\x12\x0f\n\x07version\x18\x02 \x01(\t\x12\x19\n\x11supported_formats\x18\x03 \x03(\t\"w\n\x0bProjectInfo\x12\n\n\x02id\x18\x01 \x01(\t\x12\x0c\n\x04name\x18\x02 \x01(\t\x12\x0c\n\x04path\x18\x03 \x01(\t\x12\x17\n\x0f\x66ile_gateway_id\x18\x04 \x01(\t\x12\x12\n\ncreated_at\x18\x05 \x01(\x03\x12\x13\n\x0bmodified_at\x18\x06 \x01(\x03\"\x15\n\x13ListProjectsRequest\"?\n\x14ListProjectsResponse\x12\'\n\x08projects\x18\x01 \x03(\x0b\x32\x15.director.ProjectInfo\"M\n\x16RegisterProjectRequest\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\x0c\n\x04path\x18\x02 \x01(\t\x12\x17\n\x0f\x66ile_gateway_id\x18\x03 \x01(\t\"i\n\x17RegisterProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12&\n\x07project\x18\x03 \x01(\x0b\x32\x15.director.ProjectInfo\"(\n\x12OpenProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\"e\n\x13OpenProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12&\n\x07project\x18\x03 \x01(\x0b\x32\x15.director.ProjectInfo\".\n\x18UnregisterProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\"C\n\x19UnregisterProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t2\xb3\x03\n\x0eProjectService\x12M\n\x0cListProjects\x12\x1d.director.ListProjectsRequest\x1a\x1e.director.ListProjectsResponse\x12V\n\x0fRegisterProject\x12 .director.RegisterProjectRequest\x1a!.director.RegisterProjectResponse\x12J\n\x0bOpenProject\x12\x1c.director.OpenProjectRequest\x1a\x1d.director.OpenProjectResponse\x12\\\n\x11UnregisterProject\x12\".director.UnregisterProjectRequest\x1a#.director.UnregisterProjectResponse\x12P\n\rGetEngineInfo\x12\x1e.director.GetEngineInfoRequest\x1a\x1f.director.GetEngineInfoResponseb\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x0e\x64irector.proto\x12\x08\x64irector\x1a\x1fgoogle/protobuf/timestamp.proto\"\x16\n\x14GetEngineInfoRequest\"\x9e\x01\n\x15GetEngineInfoResponse\x12\x11\n\tengine_id\x18\x01 \x01(\t\x12\x0f\n\x07version\x18\x02 \x01(\t\x12\x19\n\x11supported_formats\x18\x03 \x03(\t\x12.\n\nstarted_at\x18\x04 \x01(\x0b\x32\x1a.google.protobuf.Timestamp\x12\x16\n\x0euptime_seconds\x18\x05 \x01(\x04\",\n\x15GetEngineStatsRequest\x12\x13\n\x0b\x63heck_paths\x18\x01 \x01(\x08\"\xa9\x01\n\x16GetEngineStatsResponse\x12\x15\n\rproject_count\x18\x01 \x01(\x04\x12\x15\n\rpaths_checked\x18\x02 \x01(\x08\x12\x17\n\x0f\x61vailable_count\x18\x03 \x01(\x04\x12\x15\n\rmissing_count\x18\x04 \x01(\x04\x12\x17\n\x0funchecked_count\x18\x05 \x01(\x04\x12\x18\n\x10index_size_bytes\x18\x06 \x01(\x04\"\x18\n\x16\x43ompactRegistryRequest\"j\n\x17\x43ompactRegistryResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x13\n\x0bsize_before\x18\x03 \x01(\x04\x12\x12\n\nsize_after\x18\x04 \x01(\x04\"\xc7\x02\n\x0bProjectInfo\x12\n\n\x02id\x18\x01 \x01(\t\x12\x0c\n\x04name\x18\x02 \x01(\t\x12\x0c\n\x04path\x18\x03 \x01(\t\x12\x17\n\x0f\x66ile_gateway_id\x18\x04 \x01(\t\x12.\n\ncreated_at\x18\x05 \x01(\x0b\x32\x1a.google.protobuf.Timestamp\x12/\n\x0bmodified_at\x18\x06 \x01(\x0b\x32\x1a.google.protobuf.Timestamp\x12\r\n\x05\x63olor\x18\x07 \x01(\t\x12\x13\n\x0bis_favorite\x18\x08 \x01(\x08\x12\x10\n\x08\x61rchived\x18\t \x01(\x08\x12\x31\n\x06\x63ustom\x18\n \x03(\x0b\x32!.director.ProjectInfo.CustomEntry\x1a-\n\x0b\x43ustomEntry\x12\x0b\n\x03key\x18\x01 \x01(\t\x12\r\n\x05value\x18\x02 \x01(\t:\x02\x38\x01\"z\n\x13ListProjectsRequest\x12\x17\n\x0f\x66\x61vorites_first\x18\x01 \x01(\x08\x12\x18\n\x10include_archived\x18\x02 \x01(\x08\x12\x1c\n\x0f\x66ile_gateway_id\x18\x03 \x01(\tH\x00\x88\x01\x01\x42\x12\n\x10_file_gateway_id\"?\n\x14ListProjectsResponse\x12\'\n\x08projects\x18\x01 \x03(\x0b\x32\x15.director.ProjectInfo\"M\n\x16RegisterProjectRequest\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\x0c\n\x04path\x18\x02 \x01(\t\x12\x17\n\x0f\x66ile_gateway_id\x18\x03 \x01(\t\"i\n\x17RegisterProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12&\n\x07project\x18\x03 \x01(\x0b\x32\x15.director.ProjectInfo\"*\n\x1aValidateProjectNameRequest\x12\x0c\n\x04name\x18\x01 \x01(\t\"C\n\x1bValidateProjectNameResponse\x12\r\n\x05valid\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\"(\n\x12OpenProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\"e\n\x13OpenProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12&\n\x07project\x18\x03 \x01(\x0b\x32\x15.director.ProjectInfo\"\xfe\x01\n\x14UpdateProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\x12\x12\n\x05\x63olor\x18\x02 \x01(\tH\x00\x88\x01\x01\x12\x18\n\x0bis_favorite\x18\x03 \x01(\x08H\x01\x88\x01\x01\x12\x41\n\nset_custom\x18\x04 \x03(\x0b\x32-.director.UpdateProjectRequest.SetCustomEntry\x12\x15\n\rremove_custom\x18\x05 \x03(\t\x1a\x30\n\x0eSetCustomEntry\x12\x0b\n\x03key\x18\x01 \x01(\t\x12\r\n\x05value\x18\x02 \x01(\t:\x02\x38\x01\x42\x08\n\x06_colorB\x0e\n\x0c_is_favorite\"g\n\x15UpdateProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12&\n\x07project\x18\x03 \x01(\x0b\x32\x15.director.ProjectInfo\"+\n\x15\x41rchiveProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\"h\n\x16\x41rchiveProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12&\n\x07project\x18\x03 \x01(\x0b\x32\x15.director.ProjectInfo\"-\n\x17UnarchiveProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\"j\n\x18UnarchiveProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12&\n\x07project\x18\x03 \x01(\x0b\x32\x15.director.ProjectInfo\".\n\x18UnregisterProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\"C\n\x19UnregisterProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\"f\n\x0cTimelineClip\x12\n\n\x02id\x18\x01 \x01(\t\x12\r\n\x05\x61sset\x18\x02 \x01(\t\x12\x13\n\x0bin_point_us\x18\x03 \x01(\x04\x12\x14\n\x0cout_point_us\x18\x04 \x01(\x04\x12\x10\n\x08start_us\x18\x05 \x01(\x04\"s\n\rTimelineTrack\x12\n\n\x02id\x18\x01 \x01(\t\x12\x0c\n\x04name\x18\x02 \x01(\t\x12!\n\x04kind\x18\x03 \x01(\x0e\x32\x13.director.TrackKind\x12%\n\x05\x63lips\x18\x04 \x03(\x0b\x32\x16.director.TimelineClip\"D\n\x08Timeline\x12\x0f\n\x07version\x18\x01 \x01(\r\x12\'\n\x06tracks\x18\x02 \x03(\x0b\x32\x17.director.TimelineTrack\"O\n\x13SaveTimelineRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\x12$\n\x08timeline\x18\x02 \x01(\x0b\x32\x12.director.Timeline\">\n\x14SaveTimelineResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\")\n\x13LoadTimelineRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\"d\n\x14LoadTimelineResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12$\n\x08timeline\x18\x03 \x01(\x0b\x32\x12.director.Timeline\"\xc0\x01\n\tAssetInfo\x12\n\n\x02id\x18\x01 \x01(\t\x12\x0c\n\x04path\x18\x02 \x01(\t\x12!\n\x04kind\x18\x03 \x01(\x0e\x32\x13.director.AssetKind\x12\x0c\n\x04size\x18\x04 \x01(\x04\x12\x18\n\x0b\x64uration_us\x18\x05 \x01(\x04H\x00\x88\x01\x01\x12\x10\n\x08\x63hecksum\x18\x06 \x01(\t\x12,\n\x08\x61\x64\x64\x65\x64_at\x18\x07 \x01(\x0b\x32\x1a.google.protobuf.TimestampB\x0e\n\x0c_duration_us\"\x92\x01\n\x0f\x41\x64\x64\x41ssetRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\x12\x0c\n\x04path\x18\x02 \x01(\t\x12!\n\x04kind\x18\x03 \x01(\x0e\x32\x13.director.AssetKind\x12\x18\n\x0b\x64uration_us\x18\x04 \x01(\x04H\x00\x88\x01\x01\x12\x10\n\x08\x63hecksum\x18\x05 \x01(\tB\x0e\n\x0c_duration_us\"^\n\x10\x41\x64\x64\x41ssetResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\"\n\x05\x61sset\x18\x03 \x01(\x0b\x32\x13.director.AssetInfo\"\'\n\x11ListAssetsRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\"a\n\x12ListAssetsResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12#\n\x06\x61ssets\x18\x03 \x03(\x0b\x32\x13.director.AssetInfo\":\n\x12RemoveAssetRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\x12\x10\n\x08\x61sset_id\x18\x02 \x01(\t\"a\n\x13RemoveAssetResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\"\n\x05\x61sset\x18\x03 \x01(\x0b\x32\x13.director.AssetInfo\"L\n\x12RelinkAssetRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\x12\x10\n\x08\x61sset_id\x18\x02 \x01(\t\x12\x10\n\x08new_path\x18\x03 \x01(\t\"a\n\x13RelinkAssetResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\"\n\x05\x61sset\x18\x03 \x01(\x0b\x32\x13.director.AssetInfo\"<\n\x16NotifyFileMovedRequest\x12\x10\n\x08old_path\x18\x01 \x01(\t\x12\x10\n\x08new_path\x18\x02 \x01(\t\"f\n\x17NotifyFileMovedResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x10\n\x08relinked\x18\x03 \x01(\r\x12\x11\n\tunchecked\x18\x04 \x01(\r\",\n\x16ValidateProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\"u\n\tClipIssue\x12\x10\n\x08track_id\x18\x01 \x01(\t\x12\x0f\n\x07\x63lip_id\x18\x02 \x01(\t\x12\x10\n\x08\x61sset_id\x18\x03 \x01(\t\x12%\n\x04kind\x18\x04 \x01(\x0e\x32\x17.director.ClipIssueKind\x12\x0c\n\x04path\x18\x05 \x01(\t\"\x80\x01\n\x17ValidateProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12#\n\x06issues\x18\x03 \x03(\x0b\x32\x13.director.ClipIssue\x12\x18\n\x10unchecked_assets\x18\x04 \x01(\r\"*\n\x14\x45xportProjectRequest\x12\x12\n\nproject_id\x18\x01 \x01(\t\"P\n\x11\x45xternalAssetFile\x12\x10\n\x08\x61sset_id\x18\x01 \x01(\t\x12\x13\n\x0bsource_path\x18\x02 \x01(\t\x12\x14\n\x0c\x61rchive_path\x18\x03 \x01(\t\"\xae\x01\n\x15\x45xportProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12&\n\x07project\x18\x03 \x01(\x0b\x32\x15.director.ProjectInfo\x12\x10\n\x08manifest\x18\x04 \x01(\x0c\x12\x33\n\x0e\x65xternal_files\x18\x05 \x03(\x0b\x32\x1b.director.ExternalAssetFile\"]\n\x14ImportProjectRequest\x12\x10\n\x08manifest\x18\x01 \x01(\x0c\x12\x0c\n\x04path\x18\x02 \x01(\t\x12\x17\n\x0f\x66ile_gateway_id\x18\x03 \x01(\t\x12\x0c\n\x04name\x18\x04 \x01(\t\"g\n\x15ImportProjectResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12&\n\x07project\x18\x03 \x01(\x0b\x32\x15.director.ProjectInfo\"\x14\n\x12\x46indOrphansRequest\"P\n\x13\x46indOrphansResponse\x12&\n\x07orphans\x18\x01 \x03(\x0b\x32\x15.director.ProjectInfo\x12\x11\n\tunchecked\x18\x02 \x01(\r\"\x15\n\x13PruneOrphansRequest\"y\n\x14PruneOrphansResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12&\n\x07removed\x18\x03 \x03(\x0b\x32\x15.director.ProjectInfo\x12\x11\n\tunchecked\x18\x04 \x01(\r\"\x18\n\x16SubscribeEventsRequest\"\x8f\x01\n\x0cProjectEvent\x12(\n\x04type\x18\x01 \x01(\x0e\x32\x1a.director.ProjectEventType\x12&\n\x07project\x18\x02 \x01(\x0b\x32\x15.director.ProjectInfo\x12-\n\ttimestamp\x18\x03 \x01(\x0b\x32\x1a.google.protobuf.Timestamp*S\n\tTrackKind\x12\x1a\n\x16TRACK_KIND_UNSPECIFIED\x10\x00\x12\x14\n\x10TRACK_KIND_VIDEO\x10\x01\x12\x14\n\x10TRACK_KIND_AUDIO\x10\x02*\x7f\n\tAssetKind\x12\x1a\n\x16\x41SSET_KIND_UNSPECIFIED\x10\x00\x12\x14\n\x10\x41SSET_KIND_VIDEO\x10\x01\x12\x14\n\x10\x41SSET_KIND_AUDIO\x10\x02\x12\x14\n\x10\x41SSET_KIND_IMAGE\x10\x03\x12\x14\n\x10\x41SSET_KIND_OTHER\x10\x04*u\n\rClipIssueKind\x12\x1f\n\x1b\x43LIP_ISSUE_KIND_UNSPECIFIED\x10\x00\x12!\n\x1d\x43LIP_ISSUE_KIND_UNKNOWN_ASSET\x10\x01\x12 \n\x1c\x43LIP_ISSUE_KIND_MISSING_FILE\x10\x02*\x8c\x01\n\x10ProjectEventType\x12\x1d\n\x19PROJECT_EVENT_UNSPECIFIED\x10\x00\x12\x16\n\x12PROJECT_REGISTERED\x10\x01\x12\x12\n\x0ePROJECT_OPENED\x10\x02\x12\x18\n\x14PROJECT_UNREGISTERED\x10\x03\x12\x13\n\x0fPROJECT_UPDATED\x10\x04\x32\xc6\x0f\n\x0eProjectService\x12M\n\x0cListProjects\x12\x1d.director.ListProjectsRequest\x1a\x1e.director.ListProjectsResponse\x12V\n\x0fRegisterProject\x12 .director.RegisterProjectRequest\x1a!.director.RegisterProjectResponse\x12\x62\n\x13ValidateProjectName\x12$.director.ValidateProjectNameRequest\x1a%.director.ValidateProjectNameResponse\x12J\n\x0bOpenProject\x12\x1c.director.OpenProjectRequest\x1a\x1d.director.OpenProjectResponse\x12P\n\rUpdateProject\x12\x1e.director.UpdateProjectRequest\x1a\x1f.director.UpdateProjectResponse\x12S\n\x0e\x41rchiveProject\x12\x1f.director.ArchiveProjectRequest\x1a .director.ArchiveProjectResponse\x12Y\n\x10UnarchiveProject\x12!.director.UnarchiveProjectRequest\x1a\".director.UnarchiveProjectResponse\x12\\\n\x11UnregisterProject\x12\".director.UnregisterProjectRequest\x1a#.director.UnregisterProjectResponse\x12M\n\x0cSaveTimeline\x12\x1d.director.SaveTimelineRequest\x1a\x1e.director.SaveTimelineResponse\x12M\n\x0cLoadTimeline\x12\x1d.director.LoadTimelineRequest\x1a\x1e.director.LoadTimelineResponse\x12\x41\n\x08\x41\x64\x64\x41sset\x12\x19.director.AddAssetRequest\x1a\x1a.director.AddAssetResponse\x12G\n\nListAssets\x12\x1b.director.ListAssetsRequest\x1a\x1c.director.ListAssetsResponse\x12J\n\x0bRemoveAsset\x12\x1c.director.RemoveAssetRequest\x1a\x1d.director.RemoveAssetResponse\x12J\n\x0bRelinkAsset\x12\x1c.director.RelinkAssetRequest\x1a\x1d.director.RelinkAssetResponse\x12V\n\x0fNotifyFileMoved\x12 .director.NotifyFileMovedRequest\x1a!.director.NotifyFileMovedResponse\x12V\n\x0fValidateProject\x12 .director.ValidateProjectRequest\x1a!.director.ValidateProjectResponse\x12P\n\rExportProject\x12\x1e.director.ExportProjectRequest\x1a\x1f.director.ExportProjectResponse\x12P\n\rImportProject\x12\x1e.director.ImportProjectRequest\x1a\x1f.director.ImportProjectResponse\x12J\n\x0b\x46indOrphans\x12\x1c.director.FindOrphansRequest\x1a\x1d.director.FindOrphansResponse\x12M\n\x0cPruneOrphans\x12\x1d.director.PruneOrphansRequest\x1a\x1e.director.PruneOrphansResponse\x12M\n\x0fSubscribeEvents\x12 .director.SubscribeEventsRequest\x1a\x16.director.ProjectEvent0\x01\x12P\n\rGetEngineInfo\x12\x1e.director.GetEngineInfoRequest\x1a\x1f.director.GetEngineInfoResponse\x12S\n\x0eGetEngineStats\x12\x1f.director.GetEngineStatsRequest\x1a .director.GetEngineStatsResponse\x12V\n\x0f\x43ompactRegistry\x12 .director.CompactRegistryRequest\x1a!.director.CompactRegistryResponseb\x06proto3')
 
 _globals = globals()
 _builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, _globals)
 _builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'director_pb2', _globals)
 if not _descriptor._USE_C_DESCRIPTORS:
   DESCRIPTOR._loaded_options = None
-  _globals['_GETENGINEINFOREQUEST']._serialized_start=28
-  _globals['_GETENGINEINFOREQUEST']._serialized_end=50
-  _globals['_GETENGINEINFORESPONSE']._serialized_start=52
-  _globals['_GETENGINEINFORESPONSE']._serialized_end=138
-  _globals['_PROJECTINFO']._serialized_start=140
-  _globals['_PROJECTINFO']._serialized_end=259
-  _globals['_LISTPROJECTSREQUEST']._serialized_start=261
-  _globals['_LISTPROJECTSREQUEST']._serialized_end=282
-  _globals['_LISTPROJECTSRESPONSE']._serialized_start=284
-  _globals['_LISTPROJECTSRESPONSE']._serialized_end=347
-  _globals['_REGISTERPROJECTREQUEST']._serialized_start=349
-  _globals['_REGISTERPROJECTREQUEST']._serialized_end=426
-  _globals['_REGISTERPROJECTRESPONSE']._serialized_start=428
-  _globals['_REGISTERPROJECTRESPONSE']._serialized_end=533
-  _globals['_OPENPROJECTREQUEST']._serialized_start=535
-  _globals['_OPENPROJECTREQUEST']._serialized_end=575
-  _globals['_OPENPROJECTRESPONSE']._serialized_start=577
-  _globals['_OPENPROJECTRESPONSE']._serialized_end=678
-  _globals['_UNREGISTERPROJECTREQUEST']._serialized_start=680
-  _globals['_UNREGISTERPROJECTREQUEST']._serialized_end=726
-  _globals['_UNREGISTERPROJECTRESPONSE']._serialized_start=728
-  _globals['_UNREGISTERPROJECTRESPONSE']._serialized_end=795
-  _globals['_PROJECTSERVICE']._serialized_start=798
-  _globals['_PROJECTSERVICE']._serialized_end=1233
+  _globals['_PROJECTINFO_CUSTOMENTRY']._loaded_options = None
+  _globals['_PROJECTINFO_CUSTOMENTRY']._serialized_options = b'8\001'
+  _globals['_UPDATEPROJECTREQUEST_SETCUSTOMENTRY']._loaded_options = None
+  _globals['_UPDATEPROJECTREQUEST_SETCUSTOMENTRY']._serialized_options = b'8\001'
+  _globals['_TRACKKIND']._serialized_start=5230
+  _globals['_TRACKKIND']._serialized_end=5313
+  _globals['_ASSETKIND']._serialized_start=5315
+  _globals['_ASSETKIND']._serialized_end=5442
+  _globals['_CLIPISSUEKIND']._serialized_start=5444
+  _globals['_CLIPISSUEKIND']._serialized_end=5561
+  _globals['_PROJECTEVENTTYPE']._serialized_start=5564
+  _globals['_PROJECTEVENTTYPE']._serialized_end=5704
+  _globals['_GETENGINEINFOREQUEST']._serialized_start=61
+  _globals['_GETENGINEINFOREQUEST']._serialized_end=83
+  _globals['_GETENGINEINFORESPONSE']._serialized_start=86
+  _globals['_GETENGINEINFORESPONSE']._serialized_end=244
+  _globals['_GETENGINESTATSREQUEST']._serialized_start=246
+  _globals['_GETENGINESTATSREQUEST']._serialized_end=290
+  _globals['_GETENGINESTATSRESPONSE']._serialized_start=293
+  _globals['_GETENGINESTATSRESPONSE']._serialized_end=462
+  _globals['_COMPACTREGISTRYREQUEST']._serialized_start=464
+  _globals['_COMPACTREGISTRYREQUEST']._serialized_end=488
+  _globals['_COMPACTREGISTRYRESPONSE']._serialized_start=490
+  _globals['_COMPACTREGISTRYRESPONSE']._serialized_end=596
+  _globals['_PROJECTINFO']._serialized_start=599
+  _globals['_PROJECTINFO']._serialized_end=926
+  _globals['_PROJECTINFO_CUSTOMENTRY']._serialized_start=881
+  _globals['_PROJECTINFO_CUSTOMENTRY']._serialized_end=926
+  _globals['_LISTPROJECTSREQUEST']._serialized_start=928
+  _globals['_LISTPROJECTSREQUEST']._serialized_end=1050
+  _globals['_LISTPROJECTSRESPONSE']._serialized_start=1052
+  _globals['_LISTPROJECTSRESPONSE']._serialized_end=1115
+  _globals['_REGISTERPROJECTREQUEST']._serialized_start=1117
+  _globals['_REGISTERPROJECTREQUEST']._serialized_end=1194
+  _globals['_REGISTERPROJECTRESPONSE']._serialized_start=1196
+  _globals['_REGISTERPROJECTRESPONSE']._serialized_end=1301
+  _globals['_VALIDATEPROJECTNAMEREQUEST']._serialized_start=1303
+  _globals['_VALIDATEPROJECTNAMEREQUEST']._serialized_end=1345
+  _globals['_VALIDATEPROJECTNAMERESPONSE']._serialized_start=1347
+  _globals['_VALIDATEPROJECTNAMERESPONSE']._serialized_end=1414
+  _globals['_OPENPROJECTREQUEST']._serialized_start=1416
+  _globals['_OPENPROJECTREQUEST']._serialized_end=1456
+  _globals['_OPENPROJECTRESPONSE']._serialized_start=1458
+  _globals['_OPENPROJECTRESPONSE']._serialized_end=1559
+  _globals['_UPDATEPROJECTREQUEST']._serialized_start=1562
+  _globals['_UPDATEPROJECTREQUEST']._serialized_end=1816
+  _globals['_UPDATEPROJECTREQUEST_SETCUSTOMENTRY']._serialized_start=1742
+  _globals['_UPDATEPROJECTREQUEST_SETCUSTOMENTRY']._serialized_end=1790
+  _globals['_UPDATEPROJECTRESPONSE']._serialized_start=1818
+  _globals['_UPDATEPROJECTRESPONSE']._serialized_end=1921
+  _globals['_ARCHIVEPROJECTREQUEST']._serialized_start=1923
+  _globals['_ARCHIVEPROJECTREQUEST']._serialized_end=1966
+  _globals['_ARCHIVEPROJECTRESPONSE']._serialized_start=1968
+  _globals['_ARCHIVEPROJECTRESPONSE']._serialized_end=2072
+  _globals['_UNARCHIVEPROJECTREQUEST']._serialized_start=2074
+  _globals['_UNARCHIVEPROJECTREQUEST']._serialized_end=2119
+  _globals['_UNARCHIVEPROJECTRESPONSE']._serialized_start=2121
+  _globals['_UNARCHIVEPROJECTRESPONSE']._serialized_end=2227
+  _globals['_UNREGISTERPROJECTREQUEST']._serialized_start=2229
+  _globals['_UNREGISTERPROJECTREQUEST']._serialized_end=2275
+  _globals['_UNREGISTERPROJECTRESPONSE']._serialized_start=2277
+  _globals['_UNREGISTERPROJECTRESPONSE']._serialized_end=2344
+  _globals['_TIMELINECLIP']._serialized_start=2346
+  _globals['_TIMELINECLIP']._serialized_end=2448
+  _globals['_TIMELINETRACK']._serialized_start=2450
+  _globals['_TIMELINETRACK']._serialized_end=2565
+  _globals['_TIMELINE']._serialized_start=2567
+  _globals['_TIMELINE']._serialized_end=2635
+  _globals['_SAVETIMELINEREQUEST']._serialized_start=2637
+  _globals['_SAVETIMELINEREQUEST']._serialized_end=2716
+  _globals['_SAVETIMELINERESPONSE']._serialized_start=2718
+  _globals['_SAVETIMELINERESPONSE']._serialized_end=2780
+  _globals['_LOADTIMELINEREQUEST']._serialized_start=2782
+  _globals['_LOADTIMELINEREQUEST']._serialized_end=2823
+  _globals['_LOADTIMELINERESPONSE']._serialized_start=2825
+  _globals['_LOADTIMELINERESPONSE']._serialized_end=2925
+  _globals['_ASSETINFO']._serialized_start=2928
+  _globals['_ASSETINFO']._serialized_end=3120
+  _globals['_ADDASSETREQUEST']._serialized_start=3123
+  _globals['_ADDASSETREQUEST']._serialized_end=3269
+  _globals['_ADDASSETRESPONSE']._serialized_start=3271
+  _globals['_ADDASSETRESPONSE']._serialized_end=3365
+  _globals['_LISTASSETSREQUEST']._serialized_start=3367
+  _globals['_LISTASSETSREQUEST']._serialized_end=3406
+  _globals['_LISTASSETSRESPONSE']._serialized_start=3408
+  _globals['_LISTASSETSRESPONSE']._serialized_end=3505
+  _globals['_REMOVEASSETREQUEST']._serialized_start=3507
+  _globals['_REMOVEASSETREQUEST']._serialized_end=3565
+  _globals['_REMOVEASSETRESPONSE']._serialized_start=3567
+  _globals['_REMOVEASSETRESPONSE']._serialized_end=3664
+  _globals['_RELINKASSETREQUEST']._serialized_start=3666
+  _globals['_RELINKASSETREQUEST']._serialized_end=3742
+  _globals['_RELINKASSETRESPONSE']._serialized_start=3744
+  _globals['_RELINKASSETRESPONSE']._serialized_end=3841
+  _globals['_NOTIFYFILEMOVEDREQUEST']._serialized_start=3843
+  _globals['_NOTIFYFILEMOVEDREQUEST']._serialized_end=3903
+  _globals['_NOTIFYFILEMOVEDRESPONSE']._serialized_start=3905
+  _globals['_NOTIFYFILEMOVEDRESPONSE']._serialized_end=4007
+  _globals['_VALIDATEPROJECTREQUEST']._serialized_start=4009
+  _globals['_VALIDATEPROJECTREQUEST']._serialized_end=4053
+  _globals['_CLIPISSUE']._serialized_start=4055
+  _globals['_CLIPISSUE']._serialized_end=4172
+  _globals['_VALIDATEPROJECTRESPONSE']._serialized_start=4175
+  _globals['_VALIDATEPROJECTRESPONSE']._serialized_end=4303
+  _globals['_EXPORTPROJECTREQUEST']._serialized_start=4305
+  _globals['_EXPORTPROJECTREQUEST']._serialized_end=4347
+  _globals['_EXTERNALASSETFILE']._serialized_start=4349
+  _globals['_EXTERNALASSETFILE']._serialized_end=4429
+  _globals['_EXPORTPROJECTRESPONSE']._serialized_start=4432
+  _globals['_EXPORTPROJECTRESPONSE']._serialized_end=4606
+  _globals['_IMPORTPROJECTREQUEST']._serialized_start=4608
+  _globals['_IMPORTPROJECTREQUEST']._serialized_end=4701
+  _globals['_IMPORTPROJECTRESPONSE']._serialized_start=4703
+  _globals['_IMPORTPROJECTRESPONSE']._serialized_end=4806
+  _globals['_FINDORPHANSREQUEST']._serialized_start=4808
+  _globals['_FINDORPHANSREQUEST']._serialized_end=4828
+  _globals['_FINDORPHANSRESPONSE']._serialized_start=4830
+  _globals['_FINDORPHANSRESPONSE']._serialized_end=4910
+  _globals['_PRUNEORPHANSREQUEST']._serialized_start=4912
+  _globals['_PRUNEORPHANSREQUEST']._serialized_end=4933
+  _globals['_PRUNEORPHANSRESPONSE']._serialized_start=4935
+  _globals['_PRUNEORPHANSRESPONSE']._serialized_end=5056
+  _globals['_SUBSCRIBEEVENTSREQUEST']._serialized_start=5058
+  _globals['_SUBSCRIBEEVENTSREQUEST']._serialized_end=5082
+  _globals['_PROJECTEVENT']._serialized_start=5085
+  _globals['_PROJECTEVENT']._serialized_end=5228
+  _globals['_PROJECTSERVICE']._serialized_start=5707
+  _globals['_PROJECTSERVICE']._serialized_end=7697
 # @@protoc_insertion_point(module_scope)
@@ -46,21 +46,116 @@ class ProjectServiceStub(object):
                 request_serializer=director__pb2.RegisterProjectRequest.SerializeToString,
                 response_deserializer=director__pb2.RegisterProjectResponse.FromString,
                 _registered_method=True)
+        self.ValidateProjectName = channel.unary_unary(
+                '/director.ProjectService/ValidateProjectName',
+                request_serializer=director__pb2.ValidateProjectNameRequest.SerializeToString,
+                response_deserializer=director__pb2.ValidateProjectNameResponse.FromString,
+                _registered_method=True)
         self.OpenProject = channel.unary_unary(
                 '/director.ProjectService/OpenProject',
                 request_serializer=director__pb2.OpenProjectRequest.SerializeToString,
                 response_deserializer=director__pb2.OpenProjectResponse.FromString,
                 _registered_method=True)
+        self.UpdateProject = channel.unary_unary(
+                '/director.ProjectService/UpdateProject',
+                request_serializer=director__pb2.UpdateProjectRequest.SerializeToString,
+                response_deserializer=director__pb2.UpdateProjectResponse.FromString,
+                _registered_method=True)
+        self.ArchiveProject = channel.unary_unary(
+                '/director.ProjectService/ArchiveProject',
+                request_serializer=director__pb2.ArchiveProjectRequest.SerializeToString,
+                response_deserializer=director__pb2.ArchiveProjectResponse.FromString,
+                _registered_method=True)
+        self.UnarchiveProject = channel.unary_unary(
+                '/director.ProjectService/UnarchiveProject',
+                request_serializer=director__pb2.UnarchiveProjectRequest.SerializeToString,
+                response_deserializer=director__pb2.UnarchiveProjectResponse.FromString,
+                _registered_method=True)
         self.UnregisterProject = channel.unary_unary(
                 '/director.ProjectService/UnregisterProject',
                 request_serializer=director__pb2.UnregisterProjectRequest.SerializeToString,
                 response_deserializer=director__pb2.UnregisterProjectResponse.FromString,
                 _registered_method=True)
+        self.SaveTimeline = channel.unary_unary(
+                '/director.ProjectService/SaveTimeline',
+                request_serializer=director__pb2.SaveTimelineRequest.SerializeToString,
+                response_deserializer=director__pb2.SaveTimelineResponse.FromString,
+                _registered_method=True)
+        self.LoadTimeline = channel.unary_unary(
+                '/director.ProjectService/LoadTimeline',
+                request_serializer=director__pb2.LoadTimelineRequest.SerializeToString,
+                response_deserializer=director__pb2.LoadTimelineResponse.FromString,
+                _registered_method=True)
+        self.AddAsset = channel.unary_unary(
+                '/director.ProjectService/AddAsset',
+                request_serializer=director__pb2.AddAssetRequest.SerializeToString,
+                response_deserializer=director__pb2.AddAssetResponse.FromString,
+                _registered_method=True)
+        self.ListAssets = channel.unary_unary(
+                '/director.ProjectService/ListAssets',
+                request_serializer=director__pb2.ListAssetsRequest.SerializeToString,
+                response_deserializer=director__pb2.ListAssetsResponse.FromString,
+                _registered_method=True)
+        self.RemoveAsset = channel.unary_unary(
+                '/director.ProjectService/RemoveAsset',
+                request_serializer=director__pb2.RemoveAssetRequest.SerializeToString,
+                response_deserializer=director__pb2.RemoveAssetResponse.FromString,
+                _registered_method=True)
+        self.RelinkAsset = channel.unary_unary(
+                '/director.ProjectService/RelinkAsset',
+                request_serializer=director__pb2.RelinkAssetRequest.SerializeToString,
+                response_deserializer=director__pb2.RelinkAssetResponse.FromString,
+                _registered_method=True)
+        self.NotifyFileMoved = channel.unary_unary(
+                '/director.ProjectService/NotifyFileMoved',
+                request_serializer=director__pb2.NotifyFileMovedRequest.SerializeToString,
+                response_deserializer=director__pb2.NotifyFileMovedResponse.FromString,
+                _registered_method=True)
+        self.ValidateProject = channel.unary_unary(
+                '/director.ProjectService/ValidateProject',
+                request_serializer=director__pb2.ValidateProjectRequest.SerializeToString,
+                response_deserializer=director__pb2.ValidateProjectResponse.FromString,
+                _registered_method=True)
+        self.ExportProject = channel.unary_unary(
+                '/director.ProjectService/ExportProject',
+                request_serializer=director__pb2.ExportProjectRequest.SerializeToString,
+                response_deserializer=director__pb2.ExportProjectResponse.FromString,
+                _registered_method=True)
+        self.ImportProject = channel.unary_unary(
+                '/director.ProjectService/ImportProject',
+                request_serializer=director__pb2.ImportProjectRequest.SerializeToString,
+                response_deserializer=director__pb2.ImportProjectResponse.FromString,
+                _registered_method=True)
+        self.FindOrphans = channel.unary_unary(
+                '/director.ProjectService/FindOrphans',
+                request_serializer=director__pb2.FindOrphansRequest.SerializeToString,
+                response_deserializer=director__pb2.FindOrphansResponse.FromString,
+                _registered_method=True)
+        self.PruneOrphans = channel.unary_unary(
+                '/director.ProjectService/PruneOrphans',
+                request_serializer=director__pb2.PruneOrphansRequest.SerializeToString,
+                response_deserializer=director__pb2.PruneOrphansResponse.FromString,
+                _registered_method=True)
+        self.SubscribeEvents = channel.unary_stream(
+                '/director.ProjectService/SubscribeEvents',
+                request_serializer=director__pb2.SubscribeEventsRequest.SerializeToString,
+                response_deserializer=director__pb2.ProjectEvent.FromString,
+                _registered_method=True)
         self.GetEngineInfo = channel.unary_unary(
                 '/director.ProjectService/GetEngineInfo',
                 request_serializer=director__pb2.GetEngineInfoRequest.SerializeToString,
                 response_deserializer=director__pb2.GetEngineInfoResponse.FromString,
                 _registered_method=True)
+        self.GetEngineStats = channel.unary_unary(
+                '/director.ProjectService/GetEngineStats',
+                request_serializer=director__pb2.GetEngineStatsRequest.SerializeToString,
+                response_deserializer=director__pb2.GetEngineStatsResponse.FromString,
+                _registered_method=True)
+        self.CompactRegistry = channel.unary_unary(
+                '/director.ProjectService/CompactRegistry',
+                request_serializer=director__pb2.CompactRegistryRequest.SerializeToString,
+                response_deserializer=director__pb2.CompactRegistryResponse.FromString,
+                _registered_method=True)
 
 
 class ProjectServiceServicer(object):
@@ -82,6 +177,13 @@ class ProjectServiceServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def ValidateProjectName(self, request, context):
+        """Проверить название проекта до создания его структуры на FileGateway
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def OpenProject(self, request, context):
         """Открыть существующий проект
         """
@@ -89,6 +191,27 @@ class ProjectServiceServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def UpdateProject(self, request, context):
+        """Изменить метаданные проекта (заданные поля)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def ArchiveProject(self, request, context):
+        """Перенести проект в архив (скрыт из ListProjects по умолчанию)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def UnarchiveProject(self, request, context):
+        """Вернуть проект из архива
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def UnregisterProject(self, request, context):
         """Удалить проект из списка
         """
@@ -96,6 +219,97 @@ class ProjectServiceServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def SaveTimeline(self, request, context):
+        """Сохранить таймлайн проекта (в папку timeline проекта на FileGateway)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def LoadTimeline(self, request, context):
+        """Загрузить таймлайн проекта (пустой, если ещё не сохранялся)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def AddAsset(self, request, context):
+        """Зарегистрировать файл как ассет проекта
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def ListAssets(self, request, context):
+        """Получить ассеты проекта
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def RemoveAsset(self, request, context):
+        """Удалить ассет из реестра проекта (файл остаётся на месте)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def RelinkAsset(self, request, context):
+        """Перепривязать ассет к новому пути
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def NotifyFileMoved(self, request, context):
+        """Сообщить о переносе файла: ассеты всех проектов со старым путём перепривязываются
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def ValidateProject(self, request, context):
+        """Найти клипы таймлайна, ссылающиеся на недоступные ассеты
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def ExportProject(self, request, context):
+        """Собрать манифест переносимого архива проекта (метаданные, таймлайн, ассеты)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def ImportProject(self, request, context):
+        """Зарегистрировать распакованный из архива проект под новым ID
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def FindOrphans(self, request, context):
+        """Найти проекты, директорий которых больше нет на FileGateway
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def PruneOrphans(self, request, context):
+        """Удалить такие проекты из реестра (файлы не затрагиваются)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def SubscribeEvents(self, request, context):
+        """Подписаться на изменения реестра проектов (от любых клиентов)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def GetEngineInfo(self, request, context):
         """Получить информацию о движке
         """
@@ -103,6 +317,20 @@ class ProjectServiceServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def GetEngineStats(self, request, context):
+        """Получить статистику реестра проектов
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def CompactRegistry(self, request, context):
+        """Переписать файл реестра в каноническом виде (обслуживание)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
 
 def add_ProjectServiceServicer_to_server(servicer, server):
     rpc_method_handlers = {
@@ -116,21 +344,116 @@ def add_ProjectServiceServicer_to_server(servicer, server):
                     request_deserializer=director__pb2.RegisterProjectRequest.FromString,
                     response_serializer=director__pb2.RegisterProjectResponse.SerializeToString,
             ),
+            'ValidateProjectName': grpc.unary_unary_rpc_method_handler(
+                    servicer.ValidateProjectName,
+                    request_deserializer=director__pb2.ValidateProjectNameRequest.FromString,
+                    response_serializer=director__pb2.ValidateProjectNameResponse.SerializeToString,
+            ),
             'OpenProject': grpc.unary_unary_rpc_method_handler(
                     servicer.OpenProject,
                     request_deserializer=director__pb2.OpenProjectRequest.FromString,
                     response_serializer=director__pb2.OpenProjectResponse.SerializeToString,
             ),
+            'UpdateProject': grpc.unary_unary_rpc_method_handler(
+                    servicer.UpdateProject,
+                    request_deserializer=director__pb2.UpdateProjectRequest.FromString,
+                    response_serializer=director__pb2.UpdateProjectResponse.SerializeToString,
+            ),
+            'ArchiveProject': grpc.unary_unary_rpc_method_handler(
+                    servicer.ArchiveProject,
+                    request_deserializer=director__pb2.ArchiveProjectRequest.FromString,
+                    response_serializer=director__pb2.ArchiveProjectResponse.SerializeToString,
+            ),
+            'UnarchiveProject': grpc.unary_unary_rpc_method_handler(
+                    servicer.UnarchiveProject,
+                    request_deserializer=director__pb2.UnarchiveProjectRequest.FromString,
+                    response_serializer=director__pb2.UnarchiveProjectResponse.SerializeToString,
+            ),
             'UnregisterProject': grpc.unary_unary_rpc_method_handler(
                     servicer.UnregisterProject,
                     request_deserializer=director__pb2.UnregisterProjectRequest.FromString,
                     response_serializer=director__pb2.UnregisterProjectResponse.SerializeToString,
             ),
+            'SaveTimeline': grpc.unary_unary_rpc_method_handler(
+                    servicer.SaveTimeline,
+                    request_deserializer=director__pb2.SaveTimelineRequest.FromString,
+                    response_serializer=director__pb2.SaveTimelineResponse.SerializeToString,
+            ),
+            'LoadTimeline': grpc.unary_unary_rpc_method_handler(
+                    servicer.LoadTimeline,
+                    request_deserializer=director__pb2.LoadTimelineRequest.FromString,
+                    response_serializer=director__pb2.LoadTimelineResponse.SerializeToString,
+            ),
+            'AddAsset': grpc.unary_unary_rpc_method_handler(
+                    servicer.AddAsset,
+                    request_deserializer=director__pb2.AddAssetRequest.FromString,
+                    response_serializer=director__pb2.AddAssetResponse.SerializeToString,
+            ),
+            'ListAssets': grpc.unary_unary_rpc_method_handler(
+                    servicer.ListAssets,
+                    request_deserializer=director__pb2.ListAssetsRequest.FromString,
+                    response_serializer=director__pb2.ListAssetsResponse.SerializeToString,
+            ),
+            'RemoveAsset': grpc.unary_unary_rpc_method_handler(
+                    servicer.RemoveAsset,
+                    request_deserializer=director__pb2.RemoveAssetRequest.FromString,
+                    response_serializer=director__pb2.RemoveAssetResponse.SerializeToString,
+            ),
+            'RelinkAsset': grpc.unary_unary_rpc_method_handler(
+                    servicer.RelinkAsset,
+                    request_deserializer=director__pb2.RelinkAssetRequest.FromString,
+                    response_serializer=director__pb2.RelinkAssetResponse.SerializeToString,
+            ),
+            'NotifyFileMoved': grpc.unary_unary_rpc_method_handler(
+                    servicer.NotifyFileMoved,
+                    request_deserializer=director__pb2.NotifyFileMovedRequest.FromString,
+                    response_serializer=director__pb2.NotifyFileMovedResponse.SerializeToString,
+            ),
+            'ValidateProject': grpc.unary_unary_rpc_method_handler(
+                    servicer.ValidateProject,
+                    request_deserializer=director__pb2.ValidateProjectRequest.FromString,
+                    response_serializer=director__pb2.ValidateProjectResponse.SerializeToString,
+            ),
+            'ExportProject': grpc.unary_unary_rpc_method_handler(
+                    servicer.ExportProject,
+                    request_deserializer=director__pb2.ExportProjectRequest.FromString,
+                    response_serializer=director__pb2.ExportProjectResponse.SerializeToString,
+            ),
+            'ImportProject': grpc.unary_unary_rpc_method_handler(
+                    servicer.ImportProject,
+                    request_deserializer=director__pb2.ImportProjectRequest.FromString,
+                    response_serializer=director__pb2.ImportProjectResponse.SerializeToString,
+            ),
+            'FindOrphans': grpc.unary_unary_rpc_method_handler(
+                    servicer.FindOrphans,
+                    request_deserializer=director__pb2.FindOrphansRequest.FromString,
+                    response_serializer=director__pb2.FindOrphansResponse.SerializeToString,
+            ),
+            'PruneOrphans': grpc.unary_unary_rpc_method_handler(
+                    servicer.PruneOrphans,
+                    request_deserializer=director__pb2.PruneOrphansRequest.FromString,
+                    response_serializer=director__pb2.PruneOrphansResponse.SerializeToString,
+            ),
+            'SubscribeEvents': grpc.unary_stream_rpc_method_handler(
+                    servicer.SubscribeEvents,
+                    request_deserializer=director__pb2.SubscribeEventsRequest.FromString,
+                    response_serializer=director__pb2.ProjectEvent.SerializeToString,
+            ),
             'GetEngineInfo': grpc.unary_unary_rpc_method_handler(
                     servicer.GetEngineInfo,
                     request_deserializer=director__pb2.GetEngineInfoRequest.FromString,
                     response_serializer=director__pb2.GetEngineInfoResponse.SerializeToString,
             ),
+            'GetEngineStats': grpc.unary_unary_rpc_method_handler(
+                    servicer.GetEngineStats,
+                    request_deserializer=director__pb2.GetEngineStatsRequest.FromString,
+                    response_serializer=director__pb2.GetEngineStatsResponse.SerializeToString,
+            ),
+            'CompactRegistry': grpc.unary_unary_rpc_method_handler(
+                    servicer.CompactRegistry,
+                    request_deserializer=director__pb2.CompactRegistryRequest.FromString,
+                    response_serializer=director__pb2.CompactRegistryResponse.SerializeToString,
+            ),
     }
     generic_handler = grpc.method_handlers_generic_handler(
             'director.ProjectService', rpc_method_handlers)
@@ -198,6 +521,33 @@ class ProjectService(object):
             metadata,
             _registered_method=True)
 
+    @staticmethod
+    def ValidateProjectName(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/ValidateProjectName',
+            director__pb2.ValidateProjectNameRequest.SerializeToString,
+            director__pb2.ValidateProjectNameResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
     @staticmethod
     def OpenProject(request,
             target,
@@ -225,6 +575,87 @@ class ProjectService(object):
             metadata,
             _registered_method=True)
 
+    @staticmethod
+    def UpdateProject(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/UpdateProject',
+            director__pb2.UpdateProjectRequest.SerializeToString,
+            director__pb2.UpdateProjectResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def ArchiveProject(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/ArchiveProject',
+            director__pb2.ArchiveProjectRequest.SerializeToString,
+            director__pb2.ArchiveProjectResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def UnarchiveProject(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/UnarchiveProject',
+            director__pb2.UnarchiveProjectRequest.SerializeToString,
+            director__pb2.UnarchiveProjectResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
     @staticmethod
     def UnregisterProject(request,
             target,
@@ -253,7 +684,7 @@ class ProjectService(object):
             _registered_method=True)
 
     @staticmethod
-    def GetEngineInfo(request,
+    def SaveTimeline(request,
             target,
             options=(),
             channel_credentials=None,
@@ -266,9 +697,414 @@ class ProjectService(object):
         return grpc.experimental.unary_unary(
             request,
             target,
-            '/director.ProjectService/GetEngineInfo',
-            director__pb2.GetEngineInfoRequest.SerializeToString,
-            director__pb2.GetEngineInfoResponse.FromString,
+            '/director.ProjectService/SaveTimeline',
+            director__pb2.SaveTimelineRequest.SerializeToString,
+            director__pb2.SaveTimelineResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def LoadTimeline(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/LoadTimeline',
+            director__pb2.LoadTimelineRequest.SerializeToString,
+            director__pb2.LoadTimelineResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def AddAsset(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/AddAsset',
+            director__pb2.AddAssetRequest.SerializeToString,
+            director__pb2.AddAssetResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def ListAssets(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/ListAssets',
+            director__pb2.ListAssetsRequest.SerializeToString,
+            director__pb2.ListAssetsResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def RemoveAsset(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/RemoveAsset',
+            director__pb2.RemoveAssetRequest.SerializeToString,
+            director__pb2.RemoveAssetResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def RelinkAsset(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/RelinkAsset',
+            director__pb2.RelinkAssetRequest.SerializeToString,
+            director__pb2.RelinkAssetResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def NotifyFileMoved(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/NotifyFileMoved',
+            director__pb2.NotifyFileMovedRequest.SerializeToString,
+            director__pb2.NotifyFileMovedResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def ValidateProject(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/ValidateProject',
+            director__pb2.ValidateProjectRequest.SerializeToString,
+            director__pb2.ValidateProjectResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def ExportProject(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/ExportProject',
+            director__pb2.ExportProjectRequest.SerializeToString,
+            director__pb2.ExportProjectResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def ImportProject(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/ImportProject',
+            director__pb2.ImportProjectRequest.SerializeToString,
+            director__pb2.ImportProjectResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def FindOrphans(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/FindOrphans',
+            director__pb2.FindOrphansRequest.SerializeToString,
+            director__pb2.FindOrphansResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def PruneOrphans(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/PruneOrphans',
+            director__pb2.PruneOrphansRequest.SerializeToString,
+            director__pb2.PruneOrphansResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def SubscribeEvents(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_stream(
+            request,
+            target,
+            '/director.ProjectService/SubscribeEvents',
+            director__pb2.SubscribeEventsRequest.SerializeToString,
+            director__pb2.ProjectEvent.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def GetEngineInfo(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/GetEngineInfo',
+            director__pb2.GetEngineInfoRequest.SerializeToString,
+            director__pb2.GetEngineInfoResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def GetEngineStats(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/GetEngineStats',
+            director__pb2.GetEngineStatsRequest.SerializeToString,
+            director__pb2.GetEngineStatsResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def CompactRegistry(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/director.ProjectService/CompactRegistry',
+            director__pb2.CompactRegistryRequest.SerializeToString,
+            director__pb2.CompactRegistryResponse.FromString,
             options,
             channel_credentials,
             insecure,
@@ -22,53 +22,124 @@ _runtime_version.ValidateProtobufRuntimeVersion(
 _sym_db = _symbol_database.Default()
 
 
+from google.protobuf import timestamp_pb2 as google_dot_protobuf_dot_timestamp__pb2
 
 
-DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x12\x66ile_gateway.proto\x12\x0c\x66ile_gateway\"\x17\n\x15GetStorageInfoRequest\"\xbe\x01\n\x16GetStorageInfoResponse\x12\x12\n\nstorage_id\x18\x01 \x01(\t\x12\x10\n\x08hostname\x18\x02 \x01(\t\x12\n\n\x02os\x18\x03 \x01(\t\x12\x16\n\x0ehome_directory\x18\x04 \x01(\t\x12\x1d\n\x15\x64\x65\x66\x61ult_projects_path\x18\x05 \x01(\t\x12\x12\n\nroot_paths\x18\x06 \x03(\t\x12\x13\n\x0btotal_space\x18\x07 \x01(\x04\x12\x12\n\nfree_space\x18\x08 \x01(\x04\"&\n\x16\x42rowseDirectoryRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\"\x8c\x01\n\x0e\x44irectoryEntry\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\x0c\n\x04path\x18\x02 \x01(\t\x12\x14\n\x0cis_directory\x18\x03 \x01(\x08\x12\x0c\n\x04size\x18\x04 \x01(\x04\x12\x12\n\ncreated_at\x18\x05 \x01(\x03\x12\x13\n\x0bmodified_at\x18\x06 \x01(\x03\x12\x11\n\tmime_type\x18\x07 \x01(\t\"\x9b\x01\n\x17\x42rowseDirectoryResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x14\n\x0c\x63urrent_path\x18\x03 \x01(\t\x12\x13\n\x0bparent_path\x18\x04 \x01(\t\x12-\n\x07\x65ntries\x18\x05 \x03(\x0b\x32\x1c.file_gateway.DirectoryEntry\">\n\x16\x43reateDirectoryRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x16\n\x0e\x63reate_parents\x18\x02 \x01(\x08\"W\n\x17\x43reateDirectoryResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x14\n\x0c\x63reated_path\x18\x03 \x01(\t\"0\n\rDeleteRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x11\n\trecursive\x18\x02 \x01(\x08\"8\n\x0e\x44\x65leteResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\"b\n\x11UploadFileRequest\x12\x34\n\x08metadata\x18\x01 \x01(\x0b\x32 .file_gateway.UploadFileMetadataH\x00\x12\x0f\n\x05\x63hunk\x18\x02 \x01(\x0cH\x00\x42\x06\n\x04\x64\x61ta\"g\n\x12UploadFileMetadata\x12\x18\n\x10\x64\x65stination_path\x18\x01 \x01(\t\x12\x10\n\x08\x66ilename\x18\x02 \x01(\t\x12\x12\n\ntotal_size\x18\x03 \x01(\x04\x12\x11\n\toverwrite\x18\x04 \x01(\x08\"f\n\x12UploadFileResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x11\n\tfile_path\x18\x03 \x01(\t\x12\x15\n\rbytes_written\x18\x04 \x01(\x04\"#\n\x13\x44ownloadFileRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\"g\n\x14\x44ownloadFileResponse\x12\x36\n\x08metadata\x18\x01 \x01(\x0b\x32\".file_gateway.DownloadFileMetadataH\x00\x12\x0f\n\x05\x63hunk\x18\x02 \x01(\x0cH\x00\x42\x06\n\x04\x64\x61ta\"O\n\x14\x44ownloadFileMetadata\x12\x10\n\x08\x66ilename\x18\x01 \x01(\t\x12\x12\n\ntotal_size\x18\x02 \x01(\x04\x12\x11\n\tmime_type\x18\x03 \x01(\t\"\"\n\x12GetFileInfoRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\"n\n\x13GetFileInfoResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12/\n\tfile_info\x18\x03 \x01(\x0b\x32\x1c.file_gateway.DirectoryEntry\"F\n\x1bInitProjectStructureRequest\x12\x11\n\tbase_path\x18\x01 \x01(\t\x12\x14\n\x0cproject_name\x18\x02 \x01(\t\"\xdb\x01\n\x1cInitProjectStructureResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x14\n\x0cproject_path\x18\x03 \x01(\t\x12\x13\n\x0b\x61ssets_path\x18\x04 \x01(\t\x12\x12\n\nvideo_path\x18\x05 \x01(\t\x12\x12\n\naudio_path\x18\x06 \x01(\t\x12\x13\n\x0bimages_path\x18\x07 \x01(\t\x12\x15\n\rtimeline_path\x18\x08 \x01(\t\x12\x14\n\x0c\x65xports_path\x18\t \x01(\t2\xde\x05\n\x0b\x46ileGateway\x12[\n\x0eGetStorageInfo\x12#.file_gateway.GetStorageInfoRequest\x1a$.file_gateway.GetStorageInfoResponse\x12^\n\x0f\x42rowseDirectory\x12$.file_gateway.BrowseDirectoryRequest\x1a%.file_gateway.BrowseDirectoryResponse\x12^\n\x0f\x43reateDirectory\x12$.file_gateway.CreateDirectoryRequest\x1a%.file_gateway.CreateDirectoryResponse\x12\x43\n\x06\x44\x65lete\x12\x1b.file_gateway.DeleteRequest\x1a\x1c.file_gateway.DeleteResponse\x12Q\n\nUploadFile\x12\x1f.file_gateway.UploadFileRequest\x1a .file_gateway.UploadFileResponse(\x01\x12W\n\x0c\x44ownloadFile\x12!.file_gateway.DownloadFileRequest\x1a\".file_gateway.DownloadFileResponse0\x01\x12R\n\x0bGetFileInfo\x12 .file_gateway.GetFileInfoRequest\x1a!.file_gateway.GetFileInfoResponse\x12m\n\x14InitProjectStructure\x12).file_gateway.InitProjectStructureRequest\x1a*.file_gateway.InitProjectStructureResponseb\x06proto3')
+DESCRIPTOR = _descriptor_pool.Default().AddSerializedFile(b'\n\x12\x66ile_gateway.proto\x12\x0c\x66ile_gateway\x1a\x1fgoogle/protobuf/timestamp.proto\"\x17\n\x15GetStorageInfoRequest\"\x95\x03\n\x16GetStorageInfoResponse\x12\x12\n\nstorage_id\x18\x01 \x01(\t\x12\x10\n\x08hostname\x18\x02 \x01(\t\x12\n\n\x02os\x18\x03 \x01(\t\x12\x16\n\x0ehome_directory\x18\x04 \x01(\t\x12\x1d\n\x15\x64\x65\x66\x61ult_projects_path\x18\x05 \x01(\t\x12\x12\n\nroot_paths\x18\x06 \x03(\t\x12\x13\n\x0btotal_space\x18\x07 \x01(\x04\x12\x12\n\nfree_space\x18\x08 \x01(\x04\x12\x37\n\x0c\x63\x61pabilities\x18\t \x01(\x0b\x32!.file_gateway.StorageCapabilities\x12\x14\n\x0cstorage_type\x18\n \x01(\t\x12\x13\n\x0bquota_bytes\x18\x0b \x01(\x04\x12\x18\n\x10quota_used_bytes\x18\x0c \x01(\x04\x12\x0f\n\x07version\x18\r \x01(\t\x12.\n\nstarted_at\x18\x0e \x01(\x0b\x32\x1a.google.protobuf.Timestamp\x12\x16\n\x0euptime_seconds\x18\x0f \x01(\x04\"#\n\x13GetDiskSpaceRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\"g\n\x14GetDiskSpaceResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x13\n\x0btotal_space\x18\x03 \x01(\x04\x12\x12\n\nfree_space\x18\x04 \x01(\x04\"\x1a\n\x18GetStorageMetricsRequest\"\xbb\x01\n\x19GetStorageMetricsResponse\x12\x12\n\nstorage_id\x18\x01 \x01(\t\x12\x12\n\nbytes_read\x18\x02 \x01(\x04\x12\x15\n\rbytes_written\x18\x03 \x01(\x04\x12\x14\n\x0cread_time_us\x18\x04 \x01(\x04\x12\x15\n\rwrite_time_us\x18\x05 \x01(\x04\x12\x32\n\noperations\x18\x06 \x03(\x0b\x32\x1e.file_gateway.OperationMetrics\"p\n\x10OperationMetrics\x12\x11\n\toperation\x18\x01 \x01(\t\x12\r\n\x05\x63\x61lls\x18\x02 \x01(\x04\x12\x0e\n\x06\x65rrors\x18\x03 \x01(\x04\x12\x15\n\rtotal_time_us\x18\x04 \x01(\x04\x12\x13\n\x0bmax_time_us\x18\x05 \x01(\x04\"\x86\x01\n\x13StorageCapabilities\x12\x11\n\tstreaming\x18\x01 \x01(\x08\x12\x0e\n\x06rename\x18\x02 \x01(\x08\x12\r\n\x05watch\x18\x03 \x01(\x08\x12\x12\n\nthumbnails\x18\x04 \x01(\x08\x12\x12\n\ndisk_space\x18\x05 \x01(\x08\x12\x15\n\ratomic_writes\x18\x06 \x01(\x08\"&\n\x16\x42rowseDirectoryRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\"\xdc\x01\n\x0e\x44irectoryEntry\x12\x0c\n\x04name\x18\x01 \x01(\t\x12\x0c\n\x04path\x18\x02 \x01(\t\x12\x14\n\x0cis_directory\x18\x03 \x01(\x08\x12\x0c\n\x04size\x18\x04 \x01(\x04\x12.\n\ncreated_at\x18\x05 \x01(\x0b\x32\x1a.google.protobuf.Timestamp\x12/\n\x0bmodified_at\x18\x06 \x01(\x0b\x32\x1a.google.protobuf.Timestamp\x12\x11\n\tmime_type\x18\x07 \x01(\t\x12\x16\n\x0emetadata_error\x18\x08 \x01(\t\"\xc4\x01\n\x17\x42rowseDirectoryResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x14\n\x0c\x63urrent_path\x18\x03 \x01(\t\x12\x13\n\x0bparent_path\x18\x04 \x01(\t\x12-\n\x07\x65ntries\x18\x05 \x03(\x0b\x32\x1c.file_gateway.DirectoryEntry\x12\x13\n\x0btotal_space\x18\x06 \x01(\x04\x12\x12\n\nfree_space\x18\x07 \x01(\x04\"1\n\x0eGetTreeRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x11\n\tmax_depth\x18\x02 \x01(\r\"\x88\x01\n\x0fGetTreeResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12$\n\x04root\x18\x03 \x01(\x0b\x32\x16.file_gateway.TreeNode\x12\'\n\x06\x65rrors\x18\x04 \x03(\x0b\x32\x17.file_gateway.PathError\"(\n\tPathError\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\r\n\x05\x65rror\x18\x02 \x01(\t\"\x9b\x01\n\x08TreeNode\x12+\n\x05\x65ntry\x18\x01 \x01(\x0b\x32\x1c.file_gateway.DirectoryEntry\x12(\n\x08\x63hildren\x18\x02 \x03(\x0b\x32\x16.file_gateway.TreeNode\x12\x12\n\nfile_count\x18\x03 \x01(\x04\x12\x11\n\tdir_count\x18\x04 \x01(\x04\x12\x11\n\ttruncated\x18\x05 \x01(\x08\"Z\n\x16\x43reateDirectoryRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x16\n\x0e\x63reate_parents\x18\x02 \x01(\x08\x12\x11\n\x04mode\x18\x03 \x01(\rH\x00\x88\x01\x01\x42\x07\n\x05_mode\"W\n\x17\x43reateDirectoryResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x14\n\x0c\x63reated_path\x18\x03 \x01(\t\"4\n\x11\x43reateFileRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x11\n\toverwrite\x18\x02 \x01(\x08\"R\n\x12\x43reateFileResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x14\n\x0c\x63reated_path\x18\x03 \x01(\t\"/\n\rRenameRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x10\n\x08new_name\x18\x02 \x01(\t\"`\n\x0eRenameResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x10\n\x08new_path\x18\x03 \x01(\t\x12\x14\n\x0cis_directory\x18\x04 \x01(\x08\"A\n\rDeleteRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x11\n\trecursive\x18\x02 \x01(\x08\x12\x0f\n\x07\x64ry_run\x18\x03 \x01(\x08\"\x9d\x01\n\x0e\x44\x65leteResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\r\n\x05paths\x18\x03 \x03(\t\x12\x17\n\x0fpaths_truncated\x18\x04 \x01(\x08\x12\x12\n\ntotal_size\x18\x05 \x01(\x04\x12\'\n\x06\x65rrors\x18\x06 \x03(\x0b\x32\x17.file_gateway.PathError\"G\n\x11\x44\x65leteTreeRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x13\n\x0b\x63oncurrency\x18\x02 \x01(\r\x12\x0f\n\x07\x64ry_run\x18\x03 \x01(\x08\"_\n\x12\x44\x65leteTreeProgress\x12\x15\n\rfiles_deleted\x18\x01 \x01(\x04\x12\x1b\n\x13\x64irectories_deleted\x18\x02 \x01(\x04\x12\x15\n\rbytes_deleted\x18\x03 \x01(\x04\"\x80\x01\n\x0f\x44\x65leteTreeEvent\x12\x34\n\x08progress\x18\x01 \x01(\x0b\x32 .file_gateway.DeleteTreeProgressH\x00\x12.\n\x06result\x18\x02 \x01(\x0b\x32\x1c.file_gateway.DeleteResponseH\x00\x42\x07\n\x05\x65vent\"\xa5\x01\n\x0fTransferRequest\x12\x19\n\x11source_storage_id\x18\x01 \x01(\t\x12\x13\n\x0bsource_path\x18\x02 \x01(\t\x12\x1e\n\x16\x64\x65stination_storage_id\x18\x03 \x01(\t\x12\x18\n\x10\x64\x65stination_path\x18\x04 \x01(\t\x12\x11\n\toverwrite\x18\x05 \x01(\x08\x12\x15\n\rdelete_source\x18\x06 \x01(\x08\"m\n\x10TransferResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x19\n\x11\x62ytes_transferred\x18\x03 \x01(\x04\x12\x16\n\x0esource_deleted\x18\x04 \x01(\x08\"Q\n\x10TransferProgress\x12\x12\n\nbytes_done\x18\x01 \x01(\x04\x12\x13\n\x0btotal_bytes\x18\x02 \x01(\x04\x12\x14\n\x0c\x63urrent_file\x18\x03 \x01(\t\"~\n\rTransferEvent\x12\x32\n\x08progress\x18\x01 \x01(\x0b\x32\x1e.file_gateway.TransferProgressH\x00\x12\x30\n\x06result\x18\x02 \x01(\x0b\x32\x1e.file_gateway.TransferResponseH\x00\x42\x07\n\x05\x65vent\"\x90\x01\n\x11UploadFileRequest\x12\x34\n\x08metadata\x18\x01 \x01(\x0b\x32 .file_gateway.UploadFileMetadataH\x00\x12\x0f\n\x05\x63hunk\x18\x02 \x01(\x0cH\x00\x12,\n\x06\x63\x61ncel\x18\x03 \x01(\x0b\x32\x1a.file_gateway.UploadCancelH\x00\x42\x06\n\x04\x64\x61ta\"\x1e\n\x0cUploadCancel\x12\x0e\n\x06reason\x18\x01 \x01(\t\"\xbd\x01\n\x12UploadFileMetadata\x12\x18\n\x10\x64\x65stination_path\x18\x01 \x01(\t\x12\x10\n\x08\x66ilename\x18\x02 \x01(\t\x12\x12\n\ntotal_size\x18\x03 \x01(\x04\x12\x11\n\toverwrite\x18\x04 \x01(\x08\x12\x38\n\x14\x65xpected_modified_at\x18\x05 \x01(\x0b\x32\x1a.google.protobuf.Timestamp\x12\x11\n\x04mode\x18\x06 \x01(\rH\x00\x88\x01\x01\x42\x07\n\x05_mode\"f\n\x12UploadFileResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x11\n\tfile_path\x18\x03 \x01(\t\x12\x15\n\rbytes_written\x18\x04 \x01(\x04\"n\n\x13UploadBatchResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12/\n\x05\x66iles\x18\x03 \x03(\x0b\x32 .file_gateway.UploadFileResponse\"e\n\x13\x44ownloadFileRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x10\n\x08\x63hecksum\x18\x02 \x01(\x08\x12\x0e\n\x06offset\x18\x03 \x01(\x04\x12\x13\n\x06length\x18\x04 \x01(\x04H\x00\x88\x01\x01\x42\t\n\x07_length\"{\n\x14\x44ownloadFileResponse\x12\x36\n\x08metadata\x18\x01 \x01(\x0b\x32\".file_gateway.DownloadFileMetadataH\x00\x12\x0f\n\x05\x63hunk\x18\x02 \x01(\x0cH\x00\x12\x12\n\x08\x63hecksum\x18\x03 \x01(\tH\x00\x42\x06\n\x04\x64\x61ta\"\xa1\x01\n\x14\x44ownloadFileMetadata\x12\x10\n\x08\x66ilename\x18\x01 \x01(\t\x12\x12\n\ntotal_size\x18\x02 \x01(\x04\x12\x11\n\tmime_type\x18\x03 \x01(\t\x12\x18\n\x10\x63hecksum_follows\x18\x04 \x01(\x08\x12\x0e\n\x06offset\x18\x05 \x01(\x04\x12\x0e\n\x06length\x18\x06 \x01(\x04\x12\x16\n\x0e\x61\x63\x63\x65pts_ranges\x18\x07 \x01(\x08\">\n\x15GetDownloadUrlRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x17\n\x0f\x65xpires_seconds\x18\x02 \x01(\x04\"\x94\x01\n\x16GetDownloadUrlResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x0b\n\x03url\x18\x03 \x01(\t\x12.\n\nexpires_at\x18\x04 \x01(\x0b\x32\x1a.google.protobuf.Timestamp\x12\x15\n\rnot_supported\x18\x05 \x01(\x08\"T\n\x12GetFileInfoRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\x12\x1c\n\x0f\x66ollow_symlinks\x18\x02 \x01(\x08H\x00\x88\x01\x01\x42\x12\n\x10_follow_symlinks\"\xc2\x01\n\x13GetFileInfoResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12/\n\tfile_info\x18\x03 \x01(\x0b\x32\x1c.file_gateway.DirectoryEntry\x12\x11\n\tnot_found\x18\x04 \x01(\x08\x12\x13\n\x0bis_writable\x18\x05 \x01(\x08\x12\x12\n\nis_symlink\x18\x06 \x01(\x08\x12\x16\n\x0esymlink_target\x18\x07 \x01(\t\"(\n\x18GetDirectoryStatsRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\"\xa7\x01\n\x19GetDirectoryStatsResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x12\n\ntotal_size\x18\x03 \x01(\x04\x12\x12\n\nfile_count\x18\x04 \x01(\x04\x12\x11\n\tdir_count\x18\x05 \x01(\x04\x12\'\n\x06\x65rrors\x18\x06 \x03(\x0b\x32\x17.file_gateway.PathError\"\'\n\x17GetImageMetadataRequest\x12\x0c\n\x04path\x18\x01 \x01(\t\"q\n\x18GetImageMetadataResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12-\n\x08metadata\x18\x03 \x01(\x0b\x32\x1b.file_gateway.ImageMetadata\"\xaf\x01\n\rImageMetadata\x12\x10\n\x08has_exif\x18\x01 \x01(\x08\x12\x13\n\x0borientation\x18\x02 \x01(\r\x12\x19\n\x11\x64\x61tetime_original\x18\x03 \x01(\t\x12\r\n\x05width\x18\x04 \x01(\r\x12\x0e\n\x06height\x18\x05 \x01(\r\x12\x13\n\x0b\x63\x61mera_make\x18\x06 \x01(\t\x12\x14\n\x0c\x63\x61mera_model\x18\x07 \x01(\t\x12\x12\n\nlens_model\x18\x08 \x01(\t\"*\n\x15\x46indDuplicatesRequest\x12\x11\n\troot_path\x18\x01 \x01(\t\";\n\x0e\x44uplicateGroup\x12\x0c\n\x04hash\x18\x01 \x01(\t\x12\x0c\n\x04size\x18\x02 \x01(\x04\x12\r\n\x05paths\x18\x03 \x03(\t\"\x1f\n\x1dSubscribeStorageEventsRequest\"\x81\x02\n\x0cStorageEvent\x12,\n\x04type\x18\x01 \x01(\x0e\x32\x1e.file_gateway.StorageEventType\x12\x0c\n\x04path\x18\x02 \x01(\t\x12\x13\n\x0bsource_path\x18\x03 \x01(\t\x12\x14\n\x0cis_directory\x18\x04 \x01(\x08\x12\x0c\n\x04size\x18\x05 \x01(\x04\x12\x16\n\x0esource_deleted\x18\x06 \x01(\x08\x12\x0b\n\x03rpc\x18\x07 \x01(\t\x12\x10\n\x08trace_id\x18\x08 \x01(\t\x12\x16\n\x0e\x63lient_address\x18\t \x01(\t\x12-\n\ttimestamp\x18\n \x01(\x0b\x32\x1a.google.protobuf.Timestamp\"U\n\x1bInitProjectStructureRequest\x12\x11\n\tbase_path\x18\x01 \x01(\t\x12\x14\n\x0cproject_name\x18\x02 \x01(\t\x12\r\n\x05merge\x18\x03 \x01(\x08\"\xdb\x01\n\x1cInitProjectStructureResponse\x12\x0f\n\x07success\x18\x01 \x01(\x08\x12\x15\n\rerror_message\x18\x02 \x01(\t\x12\x14\n\x0cproject_path\x18\x03 \x01(\t\x12\x13\n\x0b\x61ssets_path\x18\x04 \x01(\t\x12\x12\n\nvideo_path\x18\x05 \x01(\t\x12\x12\n\naudio_path\x18\x06 \x01(\t\x12\x13\n\x0bimages_path\x18\x07 \x01(\t\x12\x15\n\rtimeline_path\x18\x08 \x01(\t\x12\x14\n\x0c\x65xports_path\x18\t \x01(\t*\x98\x01\n\x10StorageEventType\x12\x1d\n\x19STORAGE_EVENT_UNSPECIFIED\x10\x00\x12\x11\n\rFILE_UPLOADED\x10\x01\x12\x15\n\x11\x44IRECTORY_CREATED\x10\x02\x12\x10\n\x0c\x46ILE_CREATED\x10\x03\x12\x0b\n\x07\x44\x45LETED\x10\x04\x12\x0f\n\x0bTRANSFERRED\x10\x05\x12\x0b\n\x07RENAMED\x10\x06\x32\xa1\x0f\n\x0b\x46ileGateway\x12[\n\x0eGetStorageInfo\x12#.file_gateway.GetStorageInfoRequest\x1a$.file_gateway.GetStorageInfoResponse\x12U\n\x0cGetDiskSpace\x12!.file_gateway.GetDiskSpaceRequest\x1a\".file_gateway.GetDiskSpaceResponse\x12\x64\n\x11GetStorageMetrics\x12&.file_gateway.GetStorageMetricsRequest\x1a\'.file_gateway.GetStorageMetricsResponse\x12\x63\n\x16SubscribeStorageEvents\x12+.file_gateway.SubscribeStorageEventsRequest\x1a\x1a.file_gateway.StorageEvent0\x01\x12^\n\x0f\x42rowseDirectory\x12$.file_gateway.BrowseDirectoryRequest\x1a%.file_gateway.BrowseDirectoryResponse\x12\x46\n\x07GetTree\x12\x1c.file_gateway.GetTreeRequest\x1a\x1d.file_gateway.GetTreeResponse\x12^\n\x0f\x43reateDirectory\x12$.file_gateway.CreateDirectoryRequest\x1a%.file_gateway.CreateDirectoryResponse\x12O\n\nCreateFile\x12\x1f.file_gateway.CreateFileRequest\x1a .file_gateway.CreateFileResponse\x12\x43\n\x06\x44\x65lete\x12\x1b.file_gateway.DeleteRequest\x1a\x1c.file_gateway.DeleteResponse\x12N\n\nDeleteTree\x12\x1f.file_gateway.DeleteTreeRequest\x1a\x1d.file_gateway.DeleteTreeEvent0\x01\x12\x43\n\x06Rename\x12\x1b.file_gateway.RenameRequest\x1a\x1c.file_gateway.RenameResponse\x12I\n\x08Transfer\x12\x1d.file_gateway.TransferRequest\x1a\x1e.file_gateway.TransferResponse\x12T\n\x14TransferWithProgress\x12\x1d.file_gateway.TransferRequest\x1a\x1b.file_gateway.TransferEvent0\x01\x12Q\n\nUploadFile\x12\x1f.file_gateway.UploadFileRequest\x1a .file_gateway.UploadFileResponse(\x01\x12S\n\x0bUploadBatch\x12\x1f.file_gateway.UploadFileRequest\x1a!.file_gateway.UploadBatchResponse(\x01\x12W\n\x0c\x44ownloadFile\x12!.file_gateway.DownloadFileRequest\x1a\".file_gateway.DownloadFileResponse0\x01\x12[\n\x0eGetDownloadUrl\x12#.file_gateway.GetDownloadUrlRequest\x1a$.file_gateway.GetDownloadUrlResponse\x12R\n\x0bGetFileInfo\x12 .file_gateway.GetFileInfoRequest\x1a!.file_gateway.GetFileInfoResponse\x12\x64\n\x11GetDirectoryStats\x12&.file_gateway.GetDirectoryStatsRequest\x1a\'.file_gateway.GetDirectoryStatsResponse\x12\x61\n\x10GetImageMetadata\x12%.file_gateway.GetImageMetadataRequest\x1a&.file_gateway.GetImageMetadataResponse\x12U\n\x0e\x46indDuplicates\x12#.file_gateway.FindDuplicatesRequest\x1a\x1c.file_gateway.DuplicateGroup0\x01\x12m\n\x14InitProjectStructure\x12).file_gateway.InitProjectStructureRequest\x1a*.file_gateway.InitProjectStructureResponseb\x06proto3')
 
 _globals = globals()
 _builder.BuildMessageAndEnumDescriptors(DESCRIPTOR, _globals)
 _builder.BuildTopDescriptorsAndMessages(DESCRIPTOR, 'file_gateway_pb2', _globals)
 if not _descriptor._USE_C_DESCRIPTORS:
   DESCRIPTOR._loaded_options = None
-  _globals['_GETSTORAGEINFOREQUEST']._serialized_start=36
-  _globals['_GETSTORAGEINFOREQUEST']._serialized_end=59
-  _globals['_GETSTORAGEINFORESPONSE']._serialized_start=62
-  _globals['_GETSTORAGEINFORESPONSE']._serialized_end=252
-  _globals['_BROWSEDIRECTORYREQUEST']._serialized_start=254
-  _globals['_BROWSEDIRECTORYREQUEST']._serialized_end=292
-  _globals['_DIRECTORYENTRY']._serialized_start=295
-  _globals['_DIRECTORYENTRY']._serialized_end=435
-  _globals['_BROWSEDIRECTORYRESPONSE']._serialized_start=438
-  _globals['_BROWSEDIRECTORYRESPONSE']._serialized_end=593
-  _globals['_CREATEDIRECTORYREQUEST']._serialized_start=595
-  _globals['_CREATEDIRECTORYREQUEST']._serialized_end=657
-  _globals['_CREATEDIRECTORYRESPONSE']._serialized_start=659
-  _globals['_CREATEDIRECTORYRESPONSE']._serialized_end=746
-  _globals['_DELETEREQUEST']._serialized_start=748
-  _globals['_DELETEREQUEST']._serialized_end=796
-  _globals['_DELETERESPONSE']._serialized_start=798
-  _globals['_DELETERESPONSE']._serialized_end=854
-  _globals['_UPLOADFILEREQUEST']._serialized_start=856
-  _globals['_UPLOADFILEREQUEST']._serialized_end=954
-  _globals['_UPLOADFILEMETADATA']._serialized_start=956
-  _globals['_UPLOADFILEMETADATA']._serialized_end=1059
-  _globals['_UPLOADFILERESPONSE']._serialized_start=1061
-  _globals['_UPLOADFILERESPONSE']._serialized_end=1163
-  _globals['_DOWNLOADFILEREQUEST']._serialized_start=1165
-  _globals['_DOWNLOADFILEREQUEST']._serialized_end=1200
-  _globals['_DOWNLOADFILERESPONSE']._serialized_start=1202
-  _globals['_DOWNLOADFILERESPONSE']._serialized_end=1305
-  _globals['_DOWNLOADFILEMETADATA']._serialized_start=1307
-  _globals['_DOWNLOADFILEMETADATA']._serialized_end=1386
-  _globals['_GETFILEINFOREQUEST']._serialized_start=1388
-  _globals['_GETFILEINFOREQUEST']._serialized_end=1422
-  _globals['_GETFILEINFORESPONSE']._serialized_start=1424
-  _globals['_GETFILEINFORESPONSE']._serialized_end=1534
-  _globals['_INITPROJECTSTRUCTUREREQUEST']._serialized_start=1536
-  _globals['_INITPROJECTSTRUCTUREREQUEST']._serialized_end=1606
-  _globals['_INITPROJECTSTRUCTURERESPONSE']._serialized_start=1609
-  _globals['_INITPROJECTSTRUCTURERESPONSE']._serialized_end=1828
-  _globals['_FILEGATEWAY']._serialized_start=1831
-  _globals['_FILEGATEWAY']._serialized_end=2565
+  _globals['_STORAGEEVENTTYPE']._serialized_start=6180
+  _globals['_STORAGEEVENTTYPE']._serialized_end=6332
+  _globals['_GETSTORAGEINFOREQUEST']._serialized_start=69
+  _globals['_GETSTORAGEINFOREQUEST']._serialized_end=92
+  _globals['_GETSTORAGEINFORESPONSE']._serialized_start=95
+  _globals['_GETSTORAGEINFORESPONSE']._serialized_end=500
+  _globals['_GETDISKSPACEREQUEST']._serialized_start=502
+  _globals['_GETDISKSPACEREQUEST']._serialized_end=537
+  _globals['_GETDISKSPACERESPONSE']._serialized_start=539
+  _globals['_GETDISKSPACERESPONSE']._serialized_end=642
+  _globals['_GETSTORAGEMETRICSREQUEST']._serialized_start=644
+  _globals['_GETSTORAGEMETRICSREQUEST']._serialized_end=670
+  _globals['_GETSTORAGEMETRICSRESPONSE']._serialized_start=673
+  _globals['_GETSTORAGEMETRICSRESPONSE']._serialized_end=860
+  _globals['_OPERATIONMETRICS']._serialized_start=862
+  _globals['_OPERATIONMETRICS']._serialized_end=974
+  _globals['_STORAGECAPABILITIES']._serialized_start=977
+  _globals['_STORAGECAPABILITIES']._serialized_end=1111
+  _globals['_BROWSEDIRECTORYREQUEST']._serialized_start=1113
+  _globals['_BROWSEDIRECTORYREQUEST']._serialized_end=1151
+  _globals['_DIRECTORYENTRY']._serialized_start=1154
+  _globals['_DIRECTORYENTRY']._serialized_end=1374
+  _globals['_BROWSEDIRECTORYRESPONSE']._serialized_start=1377
+  _globals['_BROWSEDIRECTORYRESPONSE']._serialized_end=1573
+  _globals['_GETTREEREQUEST']._serialized_start=1575
+  _globals['_GETTREEREQUEST']._serialized_end=1624
+  _globals['_GETTREERESPONSE']._serialized_start=1627
+  _globals['_GETTREERESPONSE']._serialized_end=1763
+  _globals['_PATHERROR']._serialized_start=1765
+  _globals['_PATHERROR']._serialized_end=1805
+  _globals['_TREENODE']._serialized_start=1808
+  _globals['_TREENODE']._serialized_end=1963
+  _globals['_CREATEDIRECTORYREQUEST']._serialized_start=1965
+  _globals['_CREATEDIRECTORYREQUEST']._serialized_end=2055
+  _globals['_CREATEDIRECTORYRESPONSE']._serialized_start=2057
+  _globals['_CREATEDIRECTORYRESPONSE']._serialized_end=2144
+  _globals['_CREATEFILEREQUEST']._serialized_start=2146
+  _globals['_CREATEFILEREQUEST']._serialized_end=2198
+  _globals['_CREATEFILERESPONSE']._serialized_start=2200
+  _globals['_CREATEFILERESPONSE']._serialized_end=2282
+  _globals['_RENAMEREQUEST']._serialized_start=2284
+  _globals['_RENAMEREQUEST']._serialized_end=2331
+  _globals['_RENAMERESPONSE']._serialized_start=2333
+  _globals['_RENAMERESPONSE']._serialized_end=2429
+  _globals['_DELETEREQUEST']._serialized_start=2431
+  _globals['_DELETEREQUEST']._serialized_end=2496
+  _globals['_DELETERESPONSE']._serialized_start=2499
+  _globals['_DELETERESPONSE']._serialized_end=2656
+  _globals['_DELETETREEREQUEST']._serialized_start=2658
+  _globals['_DELETETREEREQUEST']._serialized_end=2729
+  _globals['_DELETETREEPROGRESS']._serialized_start=2731
+  _globals['_DELETETREEPROGRESS']._serialized_end=2826
+  _globals['_DELETETREEEVENT']._serialized_start=2829
+  _globals['_DELETETREEEVENT']._serialized_end=2957
+  _globals['_TRANSFERREQUEST']._serialized_start=2960
+  _globals['_TRANSFERREQUEST']._serialized_end=3125
+  _globals['_TRANSFERRESPONSE']._serialized_start=3127
+  _globals['_TRANSFERRESPONSE']._serialized_end=3236
+  _globals['_TRANSFERPROGRESS']._serialized_start=3238
+  _globals['_TRANSFERPROGRESS']._serialized_end=3319
+  _globals['_TRANSFEREVENT']._serialized_start=3321
+  _globals['_TRANSFEREVENT']._serialized_end=3447
+  _globals['_UPLOADFILEREQUEST']._serialized_start=3450
+  _globals['_UPLOADFILEREQUEST']._serialized_end=3594
+  _globals['_UPLOADCANCEL']._serialized_start=3596
+  _globals['_UPLOADCANCEL']._serialized_end=3626
+  _globals['_UPLOADFILEMETADATA']._serialized_start=3629
+  _globals['_UPLOADFILEMETADATA']._serialized_end=3818
+  _globals['_UPLOADFILERESPONSE']._serialized_start=3820
+  _globals['_UPLOADFILERESPONSE']._serialized_end=3922
+  _globals['_UPLOADBATCHRESPONSE']._serialized_start=3924
+  _globals['_UPLOADBATCHRESPONSE']._serialized_end=4034
+  _globals['_DOWNLOADFILEREQUEST']._serialized_start=4036
+  _globals['_DOWNLOADFILEREQUEST']._serialized_end=4137
+  _globals['_DOWNLOADFILERESPONSE']._serialized_start=4139
+  _globals['_DOWNLOADFILERESPONSE']._serialized_end=4262
+  _globals['_DOWNLOADFILEMETADATA']._serialized_start=4265
+  _globals['_DOWNLOADFILEMETADATA']._serialized_end=4426
+  _globals['_GETDOWNLOADURLREQUEST']._serialized_start=4428
+  _globals['_GETDOWNLOADURLREQUEST']._serialized_end=4490
+  _globals['_GETDOWNLOADURLRESPONSE']._serialized_start=4493
+  _globals['_GETDOWNLOADURLRESPONSE']._serialized_end=4641
+  _globals['_GETFILEINFOREQUEST']._serialized_start=4643
+  _globals['_GETFILEINFOREQUEST']._serialized_end=4727
+  _globals['_GETFILEINFORESPONSE']._serialized_start=4730
+  _globals['_GETFILEINFORESPONSE']._serialized_end=4924
+  _globals['_GETDIRECTORYSTATSREQUEST']._serialized_start=4926
+  _globals['_GETDIRECTORYSTATSREQUEST']._serialized_end=4966
+  _globals['_GETDIRECTORYSTATSRESPONSE']._serialized_start=4969
+  _globals['_GETDIRECTORYSTATSRESPONSE']._serialized_end=5136
+  _globals['_GETIMAGEMETADATAREQUEST']._serialized_start=5138
+  _globals['_GETIMAGEMETADATAREQUEST']._serialized_end=5177
+  _globals['_GETIMAGEMETADATARESPONSE']._serialized_start=5179
+  _globals['_GETIMAGEMETADATARESPONSE']._serialized_end=5292
+  _globals['_IMAGEMETADATA']._serialized_start=5295
+  _globals['_IMAGEMETADATA']._serialized_end=5470
+  _globals['_FINDDUPLICATESREQUEST']._serialized_start=5472
+  _globals['_FINDDUPLICATESREQUEST']._serialized_end=5514
+  _globals['_DUPLICATEGROUP']._serialized_start=5516
+  _globals['_DUPLICATEGROUP']._serialized_end=5575
+  _globals['_SUBSCRIBESTORAGEEVENTSREQUEST']._serialized_start=5577
+  _globals['_SUBSCRIBESTORAGEEVENTSREQUEST']._serialized_end=5608
+  _globals['_STORAGEEVENT']._serialized_start=5611
+  _globals['_STORAGEEVENT']._serialized_end=5868
+  _globals['_INITPROJECTSTRUCTUREREQUEST']._serialized_start=5870
+  _globals['_INITPROJECTSTRUCTUREREQUEST']._serialized_end=5955
+  _globals['_INITPROJECTSTRUCTURERESPONSE']._serialized_start=5958
+  _globals['_INITPROJECTSTRUCTURERESPONSE']._serialized_end=6177
+  _globals['_FILEGATEWAY']._serialized_start=6335
+  _globals['_FILEGATEWAY']._serialized_end=8288
 # @@protoc_insertion_point(module_scope)
@@ -41,36 +41,106 @@ class FileGatewayStub(object):
                 request_serializer=file__gateway__pb2.GetStorageInfoRequest.SerializeToString,
                 response_deserializer=file__gateway__pb2.GetStorageInfoResponse.FromString,
                 _registered_method=True)
+        self.GetDiskSpace = channel.unary_unary(
+                '/file_gateway.FileGateway/GetDiskSpace',
+                request_serializer=file__gateway__pb2.GetDiskSpaceRequest.SerializeToString,
+                response_deserializer=file__gateway__pb2.GetDiskSpaceResponse.FromString,
+                _registered_method=True)
+        self.GetStorageMetrics = channel.unary_unary(
+                '/file_gateway.FileGateway/GetStorageMetrics',
+                request_serializer=file__gateway__pb2.GetStorageMetricsRequest.SerializeToString,
+                response_deserializer=file__gateway__pb2.GetStorageMetricsResponse.FromString,
+                _registered_method=True)
+        self.SubscribeStorageEvents = channel.unary_stream(
+                '/file_gateway.FileGateway/SubscribeStorageEvents',
+                request_serializer=file__gateway__pb2.SubscribeStorageEventsRequest.SerializeToString,
+                response_deserializer=file__gateway__pb2.StorageEvent.FromString,
+                _registered_method=True)
         self.BrowseDirectory = channel.unary_unary(
                 '/file_gateway.FileGateway/BrowseDirectory',
                 request_serializer=file__gateway__pb2.BrowseDirectoryRequest.SerializeToString,
                 response_deserializer=file__gateway__pb2.BrowseDirectoryResponse.FromString,
                 _registered_method=True)
+        self.GetTree = channel.unary_unary(
+                '/file_gateway.FileGateway/GetTree',
+                request_serializer=file__gateway__pb2.GetTreeRequest.SerializeToString,
+                response_deserializer=file__gateway__pb2.GetTreeResponse.FromString,
+                _registered_method=True)
         self.CreateDirectory = channel.unary_unary(
                 '/file_gateway.FileGateway/CreateDirectory',
                 request_serializer=file__gateway__pb2.CreateDirectoryRequest.SerializeToString,
                 response_deserializer=file__gateway__pb2.CreateDirectoryResponse.FromString,
                 _registered_method=True)
+        self.CreateFile = channel.unary_unary(
+                '/file_gateway.FileGateway/CreateFile',
+                request_serializer=file__gateway__pb2.CreateFileRequest.SerializeToString,
+                response_deserializer=file__gateway__pb2.CreateFileResponse.FromString,
+                _registered_method=True)
         self.Delete = channel.unary_unary(
                 '/file_gateway.FileGateway/Delete',
                 request_serializer=file__gateway__pb2.DeleteRequest.SerializeToString,
                 response_deserializer=file__gateway__pb2.DeleteResponse.FromString,
                 _registered_method=True)
+        self.DeleteTree = channel.unary_stream(
+                '/file_gateway.FileGateway/DeleteTree',
+                request_serializer=file__gateway__pb2.DeleteTreeRequest.SerializeToString,
+                response_deserializer=file__gateway__pb2.DeleteTreeEvent.FromString,
+                _registered_method=True)
+        self.Rename = channel.unary_unary(
+                '/file_gateway.FileGateway/Rename',
+                request_serializer=file__gateway__pb2.RenameRequest.SerializeToString,
+                response_deserializer=file__gateway__pb2.RenameResponse.FromString,
+                _registered_method=True)
+        self.Transfer = channel.unary_unary(
+                '/file_gateway.FileGateway/Transfer',
+                request_serializer=file__gateway__pb2.TransferRequest.SerializeToString,
+                response_deserializer=file__gateway__pb2.TransferResponse.FromString,
+                _registered_method=True)
+        self.TransferWithProgress = channel.unary_stream(
+                '/file_gateway.FileGateway/TransferWithProgress',
+                request_serializer=file__gateway__pb2.TransferRequest.SerializeToString,
+                response_deserializer=file__gateway__pb2.TransferEvent.FromString,
+                _registered_method=True)
         self.UploadFile = channel.stream_unary(
                 '/file_gateway.FileGateway/UploadFile',
                 request_serializer=file__gateway__pb2.UploadFileRequest.SerializeToString,
                 response_deserializer=file__gateway__pb2.UploadFileResponse.FromString,
                 _registered_method=True)
+        self.UploadBatch = channel.stream_unary(
+                '/file_gateway.FileGateway/UploadBatch',
+                request_serializer=file__gateway__pb2.UploadFileRequest.SerializeToString,
+                response_deserializer=file__gateway__pb2.UploadBatchResponse.FromString,
+                _registered_method=True)
         self.DownloadFile = channel.unary_stream(
                 '/file_gateway.FileGateway/DownloadFile',
                 request_serializer=file__gateway__pb2.DownloadFileRequest.SerializeToString,
                 response_deserializer=file__gateway__pb2.DownloadFileResponse.FromString,
                 _registered_method=True)
+        self.GetDownloadUrl = channel.unary_unary(
+                '/file_gateway.FileGateway/GetDownloadUrl',
+                request_serializer=file__gateway__pb2.GetDownloadUrlRequest.SerializeToString,
+                response_deserializer=file__gateway__pb2.GetDownloadUrlResponse.FromString,
+                _registered_method=True)
         self.GetFileInfo = channel.unary_unary(
                 '/file_gateway.FileGateway/GetFileInfo',
                 request_serializer=file__gateway__pb2.GetFileInfoRequest.SerializeToString,
                 response_deserializer=file__gateway__pb2.GetFileInfoResponse.FromString,
                 _registered_method=True)
+        self.GetDirectoryStats = channel.unary_unary(
+                '/file_gateway.FileGateway/GetDirectoryStats',
+                request_serializer=file__gateway__pb2.GetDirectoryStatsRequest.SerializeToString,
+                response_deserializer=file__gateway__pb2.GetDirectoryStatsResponse.FromString,
+                _registered_method=True)
+        self.GetImageMetadata = channel.unary_unary(
+                '/file_gateway.FileGateway/GetImageMetadata',
+                request_serializer=file__gateway__pb2.GetImageMetadataRequest.SerializeToString,
+                response_deserializer=file__gateway__pb2.GetImageMetadataResponse.FromString,
+                _registered_method=True)
+        self.FindDuplicates = channel.unary_stream(
+                '/file_gateway.FileGateway/FindDuplicates',
+                request_serializer=file__gateway__pb2.FindDuplicatesRequest.SerializeToString,
+                response_deserializer=file__gateway__pb2.DuplicateGroup.FromString,
+                _registered_method=True)
         self.InitProjectStructure = channel.unary_unary(
                 '/file_gateway.FileGateway/InitProjectStructure',
                 request_serializer=file__gateway__pb2.InitProjectStructureRequest.SerializeToString,
@@ -90,6 +160,27 @@ class FileGatewayServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def GetDiskSpace(self, request, context):
+        """Получить свободное место на томе, где расположен путь
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def GetStorageMetrics(self, request, context):
+        """Получить метрики операций хранилища (с момента запуска)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def SubscribeStorageEvents(self, request, context):
+        """Подписаться на события операций, выполненных FileGateway
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def BrowseDirectory(self, request, context):
         """=== Навигация по файловой системе ===
 
@@ -99,6 +190,13 @@ class FileGatewayServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def GetTree(self, request, context):
+        """Получить дерево директории на несколько уровней вглубь
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def CreateDirectory(self, request, context):
         """Создать директорию
         """
@@ -106,6 +204,13 @@ class FileGatewayServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def CreateFile(self, request, context):
+        """Создать пустой файл
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def Delete(self, request, context):
         """Удалить файл или директорию
         """
@@ -113,6 +218,35 @@ class FileGatewayServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def DeleteTree(self, request, context):
+        """Удалить директорию со всем содержимым с сообщениями о прогрессе
+        (файлы удаляются параллельно, отключение клиента прерывает удаление)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def Rename(self, request, context):
+        """Переименовать файл или директорию на месте (в той же директории)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def Transfer(self, request, context):
+        """Перенести (скопировать) файл между хранилищами потоково
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def TransferWithProgress(self, request, context):
+        """То же, что Transfer, с периодическими сообщениями о прогрессе до результата
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def UploadFile(self, request_iterator, context):
         """=== Загрузка и скачивание файлов ===
 
@@ -122,6 +256,14 @@ class FileGatewayServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def UploadBatch(self, request_iterator, context):
+        """Загрузить несколько файлов одним стримом: каждое сообщение с метаданными
+        начинает следующий файл, чанки после него - его данные
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def DownloadFile(self, request, context):
         """Скачать файл с сервера (стриминг)
         """
@@ -129,6 +271,13 @@ class FileGatewayServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def GetDownloadUrl(self, request, context):
+        """Ссылка на скачивание напрямую из хранилища, минуя шлюз (например, presigned URL S3)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def GetFileInfo(self, request, context):
         """Получить метаданные файла
         """
@@ -136,6 +285,27 @@ class FileGatewayServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def GetDirectoryStats(self, request, context):
+        """Подсчитать файлы, поддиректории и объём директории рекурсивно
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def GetImageMetadata(self, request, context):
+        """Получить EXIF-метаданные изображения
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def FindDuplicates(self, request, context):
+        """Найти файлы с одинаковым содержимым (группы отдаются по мере нахождения)
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def InitProjectStructure(self, request, context):
         """=== Работа с проектами ===
 
@@ -153,36 +323,106 @@ def add_FileGatewayServicer_to_server(servicer, server):
                     request_deserializer=file__gateway__pb2.GetStorageInfoRequest.FromString,
                     response_serializer=file__gateway__pb2.GetStorageInfoResponse.SerializeToString,
             ),
+            'GetDiskSpace': grpc.unary_unary_rpc_method_handler(
+                    servicer.GetDiskSpace,
+                    request_deserializer=file__gateway__pb2.GetDiskSpaceRequest.FromString,
+                    response_serializer=file__gateway__pb2.GetDiskSpaceResponse.SerializeToString,
+            ),
+            'GetStorageMetrics': grpc.unary_unary_rpc_method_handler(
+                    servicer.GetStorageMetrics,
+                    request_deserializer=file__gateway__pb2.GetStorageMetricsRequest.FromString,
+                    response_serializer=file__gateway__pb2.GetStorageMetricsResponse.SerializeToString,
+            ),
+            'SubscribeStorageEvents': grpc.unary_stream_rpc_method_handler(
+                    servicer.SubscribeStorageEvents,
+                    request_deserializer=file__gateway__pb2.SubscribeStorageEventsRequest.FromString,
+                    response_serializer=file__gateway__pb2.StorageEvent.SerializeToString,
+            ),
             'BrowseDirectory': grpc.unary_unary_rpc_method_handler(
                     servicer.BrowseDirectory,
                     request_deserializer=file__gateway__pb2.BrowseDirectoryRequest.FromString,
                     response_serializer=file__gateway__pb2.BrowseDirectoryResponse.SerializeToString,
             ),
+            'GetTree': grpc.unary_unary_rpc_method_handler(
+                    servicer.GetTree,
+                    request_deserializer=file__gateway__pb2.GetTreeRequest.FromString,
+                    response_serializer=file__gateway__pb2.GetTreeResponse.SerializeToString,
+            ),
             'CreateDirectory': grpc.unary_unary_rpc_method_handler(
                     servicer.CreateDirectory,
                     request_deserializer=file__gateway__pb2.CreateDirectoryRequest.FromString,
                     response_serializer=file__gateway__pb2.CreateDirectoryResponse.SerializeToString,
             ),
+            'CreateFile': grpc.unary_unary_rpc_method_handler(
+                    servicer.CreateFile,
+                    request_deserializer=file__gateway__pb2.CreateFileRequest.FromString,
+                    response_serializer=file__gateway__pb2.CreateFileResponse.SerializeToString,
+            ),
             'Delete': grpc.unary_unary_rpc_method_handler(
                     servicer.Delete,
                     request_deserializer=file__gateway__pb2.DeleteRequest.FromString,
                     response_serializer=file__gateway__pb2.DeleteResponse.SerializeToString,
             ),
+            'DeleteTree': grpc.unary_stream_rpc_method_handler(
+                    servicer.DeleteTree,
+                    request_deserializer=file__gateway__pb2.DeleteTreeRequest.FromString,
+                    response_serializer=file__gateway__pb2.DeleteTreeEvent.SerializeToString,
+            ),
+            'Rename': grpc.unary_unary_rpc_method_handler(
+                    servicer.Rename,
+                    request_deserializer=file__gateway__pb2.RenameRequest.FromString,
+                    response_serializer=file__gateway__pb2.RenameResponse.SerializeToString,
+            ),
+            'Transfer': grpc.unary_unary_rpc_method_handler(
+                    servicer.Transfer,
+                    request_deserializer=file__gateway__pb2.TransferRequest.FromString,
+                    response_serializer=file__gateway__pb2.TransferResponse.SerializeToString,
+            ),
+            'TransferWithProgress': grpc.unary_stream_rpc_method_handler(
+                    servicer.TransferWithProgress,
+                    request_deserializer=file__gateway__pb2.TransferRequest.FromString,
+                    response_serializer=file__gateway__pb2.TransferEvent.SerializeToString,
+            ),
             'UploadFile': grpc.stream_unary_rpc_method_handler(
                     servicer.UploadFile,
                     request_deserializer=file__gateway__pb2.UploadFileRequest.FromString,
                     response_serializer=file__gateway__pb2.UploadFileResponse.SerializeToString,
             ),
+            'UploadBatch': grpc.stream_unary_rpc_method_handler(
+                    servicer.UploadBatch,
+                    request_deserializer=file__gateway__pb2.UploadFileRequest.FromString,
+                    response_serializer=file__gateway__pb2.UploadBatchResponse.SerializeToString,
+            ),
             'DownloadFile': grpc.unary_stream_rpc_method_handler(
                     servicer.DownloadFile,
                     request_deserializer=file__gateway__pb2.DownloadFileRequest.FromString,
                     response_serializer=file__gateway__pb2.DownloadFileResponse.SerializeToString,
             ),
+            'GetDownloadUrl': grpc.unary_unary_rpc_method_handler(
+                    servicer.GetDownloadUrl,
+                    request_deserializer=file__gateway__pb2.GetDownloadUrlRequest.FromString,
+                    response_serializer=file__gateway__pb2.GetDownloadUrlResponse.SerializeToString,
+            ),
             'GetFileInfo': grpc.unary_unary_rpc_method_handler(
                     servicer.GetFileInfo,
                     request_deserializer=file__gateway__pb2.GetFileInfoRequest.FromString,
                     response_serializer=file__gateway__pb2.GetFileInfoResponse.SerializeToString,
             ),
+            'GetDirectoryStats': grpc.unary_unary_rpc_method_handler(
+                    servicer.GetDirectoryStats,
+                    request_deserializer=file__gateway__pb2.GetDirectoryStatsRequest.FromString,
+                    response_serializer=file__gateway__pb2.GetDirectoryStatsResponse.SerializeToString,
+            ),
+            'GetImageMetadata': grpc.unary_unary_rpc_method_handler(
+                    servicer.GetImageMetadata,
+                    request_deserializer=file__gateway__pb2.GetImageMetadataRequest.FromString,
+                    response_serializer=file__gateway__pb2.GetImageMetadataResponse.SerializeToString,
+            ),
+            'FindDuplicates': grpc.unary_stream_rpc_method_handler(
+                    servicer.FindDuplicates,
+                    request_deserializer=file__gateway__pb2.FindDuplicatesRequest.FromString,
+                    response_serializer=file__gateway__pb2.DuplicateGroup.SerializeToString,
+            ),
             'InitProjectStructure': grpc.unary_unary_rpc_method_handler(
                     servicer.InitProjectStructure,
                     request_deserializer=file__gateway__pb2.InitProjectStructureRequest.FromString,
@@ -228,6 +468,87 @@ class FileGateway(object):
             metadata,
             _registered_method=True)
 
+    @staticmethod
+    def GetDiskSpace(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/file_gateway.FileGateway/GetDiskSpace',
+            file__gateway__pb2.GetDiskSpaceRequest.SerializeToString,
+            file__gateway__pb2.GetDiskSpaceResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def GetStorageMetrics(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/file_gateway.FileGateway/GetStorageMetrics',
+            file__gateway__pb2.GetStorageMetricsRequest.SerializeToString,
+            file__gateway__pb2.GetStorageMetricsResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def SubscribeStorageEvents(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_stream(
+            request,
+            target,
+            '/file_gateway.FileGateway/SubscribeStorageEvents',
+            file__gateway__pb2.SubscribeStorageEventsRequest.SerializeToString,
+            file__gateway__pb2.StorageEvent.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
     @staticmethod
     def BrowseDirectory(request,
             target,
@@ -255,6 +576,33 @@ class FileGateway(object):
             metadata,
             _registered_method=True)
 
+    @staticmethod
+    def GetTree(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/file_gateway.FileGateway/GetTree',
+            file__gateway__pb2.GetTreeRequest.SerializeToString,
+            file__gateway__pb2.GetTreeResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
     @staticmethod
     def CreateDirectory(request,
             target,
@@ -282,6 +630,33 @@ class FileGateway(object):
             metadata,
             _registered_method=True)
 
+    @staticmethod
+    def CreateFile(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/file_gateway.FileGateway/CreateFile',
+            file__gateway__pb2.CreateFileRequest.SerializeToString,
+            file__gateway__pb2.CreateFileResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
     @staticmethod
     def Delete(request,
             target,
@@ -309,6 +684,114 @@ class FileGateway(object):
             metadata,
             _registered_method=True)
 
+    @staticmethod
+    def DeleteTree(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_stream(
+            request,
+            target,
+            '/file_gateway.FileGateway/DeleteTree',
+            file__gateway__pb2.DeleteTreeRequest.SerializeToString,
+            file__gateway__pb2.DeleteTreeEvent.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def Rename(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/file_gateway.FileGateway/Rename',
+            file__gateway__pb2.RenameRequest.SerializeToString,
+            file__gateway__pb2.RenameResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def Transfer(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/file_gateway.FileGateway/Transfer',
+            file__gateway__pb2.TransferRequest.SerializeToString,
+            file__gateway__pb2.TransferResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def TransferWithProgress(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_stream(
+            request,
+            target,
+            '/file_gateway.FileGateway/TransferWithProgress',
+            file__gateway__pb2.TransferRequest.SerializeToString,
+            file__gateway__pb2.TransferEvent.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
     @staticmethod
     def UploadFile(request_iterator,
             target,
@@ -336,6 +819,33 @@ class FileGateway(object):
             metadata,
             _registered_method=True)
 
+    @staticmethod
+    def UploadBatch(request_iterator,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.stream_unary(
+            request_iterator,
+            target,
+            '/file_gateway.FileGateway/UploadBatch',
+            file__gateway__pb2.UploadFileRequest.SerializeToString,
+            file__gateway__pb2.UploadBatchResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
     @staticmethod
     def DownloadFile(request,
             target,
@@ -363,6 +873,33 @@ class FileGateway(object):
             metadata,
             _registered_method=True)
 
+    @staticmethod
+    def GetDownloadUrl(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/file_gateway.FileGateway/GetDownloadUrl',
+            file__gateway__pb2.GetDownloadUrlRequest.SerializeToString,
+            file__gateway__pb2.GetDownloadUrlResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
     @staticmethod
     def GetFileInfo(request,
             target,
@@ -390,6 +927,87 @@ class FileGateway(object):
             metadata,
             _registered_method=True)
 
+    @staticmethod
+    def GetDirectoryStats(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/file_gateway.FileGateway/GetDirectoryStats',
+            file__gateway__pb2.GetDirectoryStatsRequest.SerializeToString,
+            file__gateway__pb2.GetDirectoryStatsResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def GetImageMetadata(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(
+            request,
+            target,
+            '/file_gateway.FileGateway/GetImageMetadata',
+            file__gateway__pb2.GetImageMetadataRequest.SerializeToString,
+            file__gateway__pb2.GetImageMetadataResponse.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
+    @staticmethod
+    def FindDuplicates(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_stream(
+            request,
+            target,
+            '/file_gateway.FileGateway/FindDuplicates',
+            file__gateway__pb2.FindDuplicatesRequest.SerializeToString,
+            file__gateway__pb2.DuplicateGroup.FromString,
+            options,
+            channel_credentials,
+            insecure,
+            call_credentials,
+            compression,
+            wait_for_ready,
+            timeout,
+            metadata,
+            _registered_method=True)
+
     @staticmethod
     def InitProjectStructure(request,
             target,
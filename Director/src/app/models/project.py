@@ -6,6 +6,11 @@ from enum import Enum, auto
 from typing import Optional
 
 
+def timestamp_seconds(timestamp) -> float:
+    """Unix timestamp с долями секунды из google.protobuf.Timestamp."""
+    return timestamp.seconds + timestamp.nanos / 1e9
+
+
 class ProjectState(Enum):
     """Состояние проекта."""
 
@@ -22,8 +27,8 @@ class Project:
     id: str
     name: str
     path: str
-    created_at: int  # Unix timestamp
-    updated_at: int  # Unix timestamp
+    created_at: float  # Unix timestamp
+    updated_at: float  # Unix timestamp
 
     @property
     def created_datetime(self) -> datetime:
@@ -112,8 +117,16 @@ class DirectoryEntry:
             path=proto.path,
             is_directory=proto.is_directory,
             size=proto.size,
-            created_at=datetime.fromtimestamp(proto.created_at) if proto.created_at else datetime.now(),
-            modified_at=datetime.fromtimestamp(proto.modified_at) if proto.modified_at else datetime.now(),
+            created_at=(
+                datetime.fromtimestamp(timestamp_seconds(proto.created_at))
+                if proto.HasField("created_at")
+                else datetime.now()
+            ),
+            modified_at=(
+                datetime.fromtimestamp(timestamp_seconds(proto.modified_at))
+                if proto.HasField("modified_at")
+                else datetime.now()
+            ),
             mime_type=proto.mime_type,
         )
 
@@ -8,7 +8,13 @@ from reactivex.subject import BehaviorSubject, Subject
 from reactivex.disposable import CompositeDisposable
 
 from app.api import GatewayClient
-from app.models.project import AppState, Project, ProjectState, StorageInfo
+from app.models.project import (
+    AppState,
+    Project,
+    ProjectState,
+    StorageInfo,
+    timestamp_seconds,
+)
 from app.store.actions import Action, ActionType
 
 
@@ -309,8 +315,8 @@ class AppStore:
                 id=p.id,
                 name=p.name,
                 path=p.path,
-                created_at=p.created_at,
-                updated_at=p.updated_at,
+                created_at=timestamp_seconds(p.created_at),
+                updated_at=timestamp_seconds(p.updated_at),
             )
             for p in response.projects
         ]
@@ -325,8 +331,8 @@ class AppStore:
             id=p.id,
             name=p.name,
             path=p.path,
-            created_at=p.created_at,
-            updated_at=p.updated_at,
+            created_at=timestamp_seconds(p.created_at),
+            updated_at=timestamp_seconds(p.updated_at),
         )
 
     def _open_project(self, project_id: str) -> Project:
@@ -339,8 +345,8 @@ class AppStore:
             id=p.id,
             name=p.name,
             path=p.path,
-            created_at=p.created_at,
-            updated_at=p.updated_at,
+            created_at=timestamp_seconds(p.created_at),
+            updated_at=timestamp_seconds(p.updated_at),
         )
 
     def _delete_project(self, project_id: str, delete_files: bool) -> str:
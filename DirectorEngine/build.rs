@@ -1,6 +1,9 @@
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     tonic_build::compile_protos("../proto/director.proto")?;
+
+    // FileGateway нужен только как клиент (проверка путей проектов)
+    tonic_build::configure()
+        .build_server(false)
+        .compile_protos(&["../proto/file_gateway.proto"], &["../proto"])?;
     Ok(())
 }
-
-
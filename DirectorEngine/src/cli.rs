@@ -0,0 +1,42 @@
+//! Аргументы командной строки
+//!
+//! Приоритет: флаги командной строки > переменные окружения > значения по умолчанию.
+
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::FILE_GATEWAY_ADDRESS;
+
+#[derive(Debug, Parser)]
+#[command(name = "director-engine", version, about = "Сервис управления проектами")]
+pub struct Args {
+    /// Адрес для входящих подключений (например, `0.0.0.0` или `[::]:50051`)
+    #[arg(long, env = "ENGINE_BIND")]
+    pub bind: Option<String>,
+
+    /// Порт (имеет приоритет над портом из `--bind`)
+    #[arg(long, env = "ENGINE_PORT")]
+    pub port: Option<u16>,
+
+    /// Путь к JSON-файлу конфигурации движка
+    #[arg(long, env = "ENGINE_CONFIG")]
+    pub config: Option<PathBuf>,
+
+    /// Директория данных (имеет приоритет над `data_dir` из конфигурации;
+    /// без обоих используется `DIRECTOR_DATA_DIR` или директория данных приложения)
+    #[arg(long, env = "DATA_DIR")]
+    pub data_dir: Option<PathBuf>,
+
+    /// Файл реестра проектов (по умолчанию `projects.json` в директории данных)
+    #[arg(long, env = "PROJECTS_INDEX", conflicts_with = "in_memory")]
+    pub projects_index: Option<PathBuf>,
+
+    /// Хранить реестр проектов только в памяти, не записывая на диск
+    #[arg(long, env = "PROJECTS_IN_MEMORY")]
+    pub in_memory: bool,
+
+    /// Адрес FileGateway (проверка директорий проектов)
+    #[arg(long, env = "FILE_GATEWAY_ADDRESS", default_value = FILE_GATEWAY_ADDRESS)]
+    pub file_gateway_address: String,
+}
@@ -0,0 +1,118 @@
+//! Конфигурация движка
+//!
+//! JSON-файл из `--config` / `ENGINE_CONFIG` или `<config_dir>/DirectorEngine/engine.json`;
+//! если файла нет, используются значения по умолчанию.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use tracing::warn;
+
+use crate::formats;
+use crate::project::manager::{normalize_color, ProjectError};
+use crate::project::timeline::TrackKind;
+
+/// Имя файла конфигурации по умолчанию
+pub const DEFAULT_CONFIG_FILE: &str = "engine.json";
+
+/// Дорожка, создаваемая в таймлайне нового проекта
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackTemplate {
+    pub name: String,
+    pub kind: TrackKind,
+}
+
+/// Настройки новых проектов
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectDefaults {
+    /// Цветовая метка новых проектов (`#RRGGBB`)
+    #[serde(default)]
+    pub color: Option<String>,
+
+    /// Дорожки таймлайна, пока он ни разу не сохранялся
+    #[serde(default)]
+    pub tracks: Vec<TrackTemplate>,
+}
+
+/// Конфигурация движка
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EngineConfig {
+    /// Директория данных (реестр проектов); по умолчанию - директория данных приложения
+    #[serde(default)]
+    pub data_dir: Option<PathBuf>,
+
+    /// Поддерживаемые форматы (расширения контейнеров), сообщаются клиентам
+    #[serde(default = "default_supported_formats")]
+    pub supported_formats: Vec<String>,
+
+    /// Определить форматы по установленному ffmpeg вместо `supported_formats`
+    ///
+    /// Если ffmpeg не найден или завершился с ошибкой, используется `supported_formats`.
+    #[serde(default)]
+    pub detect_formats: bool,
+
+    /// Исполняемый файл ffmpeg (по умолчанию ищется в `PATH`)
+    #[serde(default)]
+    pub ffmpeg_path: Option<PathBuf>,
+
+    /// Настройки новых проектов
+    #[serde(default)]
+    pub project_defaults: ProjectDefaults,
+
+    /// Запрещать регистрацию проекта с уже занятым названием (без учёта регистра)
+    #[serde(default)]
+    pub unique_project_names: bool,
+}
+
+fn default_supported_formats() -> Vec<String> {
+    ["mp4", "mov", "avi", "mkv", "webm"]
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+impl Default for EngineConfig {
+    fn default() -> Self {
+        Self {
+            data_dir: None,
+            supported_formats: default_supported_formats(),
+            detect_formats: false,
+            ffmpeg_path: None,
+            project_defaults: ProjectDefaults::default(),
+            unique_project_names: false,
+        }
+    }
+}
+
+impl EngineConfig {
+    /// Путь к конфигурации по умолчанию (`<config_dir>/DirectorEngine/engine.json`)
+    pub fn default_path() -> Option<PathBuf> {
+        directories::ProjectDirs::from("com", "director", "DirectorEngine")
+            .map(|dirs| dirs.config_dir().join(DEFAULT_CONFIG_FILE))
+    }
+
+    /// Загрузить конфигурацию из файла
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = std::fs::read_to_string(path)?;
+        let config: EngineConfig = serde_json::from_str(&content)?;
+        Ok(config)
+    }
+
+    /// Проверить цвет и определить итоговый список форматов
+    ///
+    /// Выполняется при запуске и при перечитывании конфигурации (SIGHUP).
+    pub async fn prepare(mut self) -> Result<Self, ProjectError> {
+        if let Some(color) = &self.project_defaults.color {
+            self.project_defaults.color = Some(normalize_color(color)?);
+        }
+        if self.detect_formats {
+            let ffmpeg = self.ffmpeg_path.clone().unwrap_or_else(|| "ffmpeg".into());
+            match formats::detect_with_ffmpeg(&ffmpeg).await {
+                Ok(detected) => self.supported_formats = detected,
+                Err(e) => warn!("Форматы не определены по ffmpeg, берутся из конфигурации: {}", e),
+            }
+        }
+        self.supported_formats = formats::normalize(self.supported_formats);
+        Ok(self)
+    }
+}
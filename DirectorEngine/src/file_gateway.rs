@@ -0,0 +1,146 @@
+//! Клиент FileGateway: проверка путей и чтение/запись файлов проектов
+//!
+//! Подключение ленивое: движок запускается, даже если FileGateway ещё
+//! недоступен, а ошибки подключения возвращаются из конкретных вызовов.
+
+use std::time::Duration;
+
+use tokio_stream::StreamExt;
+use tonic::transport::{Channel, Endpoint};
+use tonic::{Code, Status};
+
+use crate::proto::file_gateway::{
+    download_file_response, file_gateway_client::FileGatewayClient, upload_file_request,
+    DirectoryEntry, DownloadFileRequest, GetFileInfoRequest, TransferRequest, UploadFileMetadata,
+    UploadFileRequest,
+};
+
+/// Размер чанка при загрузке файла
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Доступ к файлам на FileGateway
+#[derive(Clone)]
+pub struct FileGateway {
+    client: FileGatewayClient<Channel>,
+}
+
+impl FileGateway {
+    pub fn new(address: &str) -> Result<Self, tonic::transport::Error> {
+        let channel = Endpoint::from_shared(address.to_string())?
+            .connect_timeout(Duration::from_secs(5))
+            .connect_lazy();
+
+        Ok(Self {
+            client: FileGatewayClient::new(channel),
+        })
+    }
+
+    /// Существует ли путь (`Err`, если проверить не удалось)
+    pub async fn exists(&self, path: &str) -> Result<bool, Status> {
+        Ok(self.file_info(path).await?.is_some())
+    }
+
+    /// Информация о файле или директории (`None`, если пути нет)
+    pub async fn file_info(&self, path: &str) -> Result<Option<DirectoryEntry>, Status> {
+        let response = self
+            .client
+            .clone()
+            .get_file_info(GetFileInfoRequest {
+                path: path.to_string(),
+                follow_symlinks: None,
+            })
+            .await?
+            .into_inner();
+
+        if response.success {
+            Ok(Some(response.file_info.unwrap_or_default()))
+        } else if response.not_found {
+            Ok(None)
+        } else {
+            Err(Status::internal(response.error_message))
+        }
+    }
+
+    /// Прочитать файл целиком (`None`, если файла нет)
+    pub async fn read_file(&self, path: &str) -> Result<Option<Vec<u8>>, Status> {
+        let request = DownloadFileRequest {
+            path: path.to_string(),
+            checksum: false,
+            ..Default::default()
+        };
+
+        let mut stream = match self.client.clone().download_file(request).await {
+            Ok(response) => response.into_inner(),
+            Err(status) if status.code() == Code::NotFound => return Ok(None),
+            Err(status) => return Err(status),
+        };
+
+        let mut data = Vec::new();
+        while let Some(message) = stream.next().await {
+            match message?.data {
+                Some(download_file_response::Data::Metadata(metadata)) => {
+                    data.reserve(metadata.total_size as usize);
+                }
+                Some(download_file_response::Data::Chunk(chunk)) => data.extend_from_slice(&chunk),
+                _ => {}
+            }
+        }
+
+        Ok(Some(data))
+    }
+
+    /// Записать файл целиком с заменой существующего
+    ///
+    /// Данные загружаются во временный файл рядом и переносятся на место
+    /// только после успешной загрузки: оборванная запись не портит старый файл.
+    pub async fn write_file(
+        &self,
+        directory: &str,
+        filename: &str,
+        data: Vec<u8>,
+    ) -> Result<(), Status> {
+        let directory = directory.trim_end_matches(['/', '\\']);
+        let temp_name = format!(".{}.{}.tmp", filename, uuid::Uuid::new_v4());
+
+        let metadata = UploadFileRequest {
+            data: Some(upload_file_request::Data::Metadata(UploadFileMetadata {
+                destination_path: directory.to_string(),
+                filename: temp_name,
+                total_size: data.len() as u64,
+                overwrite: false,
+                expected_modified_at: None,
+                mode: None,
+            })),
+        };
+        let chunks = data.chunks(UPLOAD_CHUNK_SIZE).map(|chunk| UploadFileRequest {
+            data: Some(upload_file_request::Data::Chunk(chunk.to_vec())),
+        });
+        let messages: Vec<UploadFileRequest> = std::iter::once(metadata).chain(chunks).collect();
+
+        let mut client = self.client.clone();
+        let uploaded = client
+            .upload_file(tokio_stream::iter(messages))
+            .await?
+            .into_inner();
+        if !uploaded.success {
+            return Err(Status::internal(uploaded.error_message));
+        }
+
+        let moved = client
+            .transfer(TransferRequest {
+                source_storage_id: String::new(),
+                source_path: uploaded.file_path,
+                destination_storage_id: String::new(),
+                destination_path: format!("{}/{}", directory, filename),
+                overwrite: true,
+                delete_source: true,
+            })
+            .await?
+            .into_inner();
+        if !moved.success {
+            return Err(Status::internal(moved.error_message));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+//! Поддерживаемые форматы: из конфигурации или по установленному ffmpeg
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use tokio::process::Command;
+
+/// Демультиплексоры ffmpeg и расширения контейнеров, которые они читают
+///
+/// Учитываются только видеоконтейнеры: `ffmpeg -demuxers` перечисляет сотни
+/// форматов (аудио, изображения, устройства), клиентам они не нужны.
+const KNOWN_CONTAINERS: &[(&str, &[&str])] = &[
+    ("mov", &["mp4", "mov", "m4v"]),
+    ("matroska", &["mkv", "webm"]),
+    ("avi", &["avi"]),
+    ("mpegts", &["ts", "m2ts"]),
+    ("mpeg", &["mpg", "mpeg"]),
+    ("mxf", &["mxf"]),
+    ("flv", &["flv"]),
+    ("asf", &["wmv"]),
+    ("ogg", &["ogv"]),
+];
+
+/// Привести список расширений к виду `mp4`: без точки, в нижнем регистре, без повторов
+pub fn normalize(formats: Vec<String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+
+    formats
+        .into_iter()
+        .map(|format| format.trim().trim_start_matches('.').to_ascii_lowercase())
+        .filter(|format| !format.is_empty() && seen.insert(format.clone()))
+        .collect()
+}
+
+/// Определить форматы по демультиплексорам установленного ffmpeg
+pub async fn detect_with_ffmpeg(ffmpeg: &Path) -> Result<Vec<String>, String> {
+    let output = Command::new(ffmpeg)
+        .args(["-hide_banner", "-demuxers"])
+        .output()
+        .await
+        .map_err(|e| format!("не удалось запустить {}: {}", ffmpeg.display(), e))?;
+
+    if !output.status.success() {
+        return Err(format!("{} завершился с ошибкой: {}", ffmpeg.display(), output.status));
+    }
+
+    let demuxers = parse_demuxers(&String::from_utf8_lossy(&output.stdout));
+
+    let formats: Vec<String> = KNOWN_CONTAINERS
+        .iter()
+        .filter(|(demuxer, _)| demuxers.contains(*demuxer))
+        .flat_map(|(_, extensions)| extensions.iter().map(|ext| ext.to_string()))
+        .collect();
+
+    if formats.is_empty() {
+        return Err("ffmpeg не поддерживает ни одного известного видеоконтейнера".to_string());
+    }
+
+    Ok(formats)
+}
+
+/// Имена демультиплексоров из вывода `ffmpeg -demuxers`
+///
+/// Строки вида ` D  mov,mp4,m4a,3gp,3g2,mj2 QuickTime / MOV`; имена до первого
+/// пробела после флагов, несколько имён через запятую.
+fn parse_demuxers(output: &str) -> HashSet<String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let flags = parts.next()?;
+            if !flags.starts_with('D') || flags.chars().any(|c| !matches!(c, 'D' | 'E' | 'd')) {
+                return None;
+            }
+            parts.next()
+        })
+        .flat_map(|names| names.split(',').map(str::to_string))
+        .collect()
+}
@@ -0,0 +1,33 @@
+//! DirectorEngine - реестр и содержимое проектов по gRPC
+//!
+//! Библиотека нужна исполняемому файлу и тестам других сервисов, которые
+//! запускают движок в своём процессе (`testing`).
+
+pub mod config;
+pub mod file_gateway;
+pub mod formats;
+pub mod project;
+pub mod reload;
+
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Utc};
+
+pub mod proto {
+    tonic::include_proto!("director");
+
+    pub mod file_gateway {
+        tonic::include_proto!("file_gateway");
+    }
+}
+
+/// Время запуска процесса
+static STARTED_AT: OnceLock<DateTime<Utc>> = OnceLock::new();
+
+/// Время запуска процесса (задаётся при первом вызове в начале `main`)
+pub fn started_at() -> DateTime<Utc> {
+    *STARTED_AT.get_or_init(Utc::now)
+}
@@ -1,33 +1,95 @@
-mod project;
+mod cli;
 
+use std::sync::Arc;
+
+use clap::Parser;
+use director_common::health::HealthService;
+use director_common::{bind, logging};
 use tonic::transport::Server;
 use tracing::info;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
-
-use project::service::ProjectServiceImpl;
 
-pub mod proto {
-    tonic::include_proto!("director");
-}
+use director_engine::config::EngineConfig;
+use director_engine::file_gateway::FileGateway;
+use director_engine::project::manager::ProjectManager;
+use director_engine::project::orphans;
+use director_engine::project::service::ProjectServiceImpl;
+use director_engine::proto::project_service_server::{self, ProjectServiceServer};
+use director_engine::{reload, started_at};
 
-use proto::project_service_server::ProjectServiceServer;
+const DEFAULT_PORT: u16 = 50051;
+const FILE_GATEWAY_ADDRESS: &str = "http://[::1]:50052";
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    started_at();
+
     // Инициализация логирования
-    tracing_subscriber::registry()
-        .with(tracing_subscriber::fmt::layer())
-        .with(tracing_subscriber::EnvFilter::from_default_env())
-        .init();
+    let log_handle = logging::init(env!("CARGO_PKG_NAME"));
+    logging::spawn_signal_handler(log_handle);
+
+    let args = cli::Args::parse();
+    let addr = bind::resolve(args.bind.as_deref(), args.port, DEFAULT_PORT)?;
+
+    // Конфигурация: --config / ENGINE_CONFIG, затем файл по умолчанию,
+    // иначе значения по умолчанию
+    let config_path = args
+        .config
+        .clone()
+        .or_else(|| EngineConfig::default_path().filter(|p| p.exists()));
+
+    let config = match &config_path {
+        Some(path) => {
+            info!("Загрузка конфигурации движка: {}", path.display());
+            EngineConfig::load(path).map_err(|e| {
+                format!("Не удалось загрузить конфигурацию {}: {}", path.display(), e)
+            })?
+        }
+        None => EngineConfig::default(),
+    };
+
+    let config = config.prepare().await?;
+    info!("Поддерживаемые форматы: {:?}", config.supported_formats);
+
+    let data_dir = args.data_dir.as_ref().or(config.data_dir.as_ref());
+
+    let manager = if args.in_memory {
+        info!("Реестр проектов хранится только в памяти");
+        ProjectManager::in_memory()
+    } else if let Some(path) = &args.projects_index {
+        ProjectManager::with_path(path)?
+    } else if let Some(dir) = data_dir {
+        ProjectManager::with_data_dir(dir)?
+    } else {
+        // DIRECTOR_DATA_DIR или директория данных приложения
+        ProjectManager::new()?
+    };
+
+    if let Some(path) = manager.index_path() {
+        info!("Реестр проектов: {}", path.display());
+    }
+
+    let files = FileGateway::new(&args.file_gateway_address)?;
+    info!("FileGateway: {}", args.file_gateway_address);
+
+    // Проекты, удалённые в обход приложения, отмечаются в логе сразу после запуска
+    tokio::spawn(orphans::report_on_startup(files.clone(), manager.list_projects()));
 
-    let addr = "[::1]:50051".parse()?;
-    let project_service = ProjectServiceImpl::new()?;
+    // Сервис общий с обработчиком SIGHUP, который меняет его настройки
+    let project_service = Arc::new(ProjectServiceImpl::new(manager, files, &config));
+    reload::spawn_reload_handler(reload::Reloader::new(
+        config_path,
+        config,
+        project_service.clone(),
+    ));
 
-    info!("DirectorEngine gRPC сервер запущен на {}", addr);
+    let (incoming, local_addr) = bind::listen(addr).await?;
+    info!("DirectorEngine gRPC сервер запущен на {}", local_addr);
 
     Server::builder()
-        .add_service(ProjectServiceServer::new(project_service))
-        .serve(addr)
+        .trace_fn(logging::rpc_span)
+        .add_service(ProjectServiceServer::from_arc(project_service))
+        .add_service(HealthService::server(project_service_server::SERVICE_NAME))
+        .serve_with_incoming(incoming)
         .await?;
 
     Ok(())
@@ -0,0 +1,332 @@
+//! Реестр медиа-ассетов проекта
+//!
+//! Хранится в `assets.json` в корне папки проекта на FileGateway. Клипы
+//! таймлайна ссылаются на ассеты по стабильному ID, поэтому при переносе
+//! файла достаточно поправить одну запись реестра.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::file_gateway::FileGateway;
+use crate::project::service::to_timestamp;
+use crate::proto;
+use crate::proto::file_gateway::DirectoryEntry;
+
+/// Текущая версия схемы реестра
+pub const ASSETS_VERSION: u32 = 1;
+
+const ASSETS_FILE: &str = "assets.json";
+
+#[derive(Error, Debug)]
+pub enum AssetError {
+    #[error("Ошибка сериализации: {0}")]
+    SerializationError(#[from] serde_json::Error),
+
+    #[error("Версия реестра ассетов {0} не поддерживается (поддерживается до {ASSETS_VERSION})")]
+    UnsupportedVersion(u32),
+
+    #[error("Ассет не найден: {0}")]
+    AssetNotFound(String),
+
+    #[error("Файл уже зарегистрирован как ассет: {0}")]
+    AssetAlreadyExists(String),
+
+    #[error("Файл не найден: {0}")]
+    FileNotFound(String),
+
+    #[error("Некорректный ассет: {0}")]
+    Invalid(String),
+
+    #[error("Ошибка FileGateway: {0}")]
+    FileGateway(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AssetKind {
+    Video,
+    Audio,
+    Image,
+    Other,
+}
+
+impl AssetKind {
+    /// Тип ассета по MIME типу файла
+    pub fn from_mime(mime_type: &str) -> Self {
+        match mime_type.split('/').next() {
+            Some("video") => AssetKind::Video,
+            Some("audio") => AssetKind::Audio,
+            Some("image") => AssetKind::Image,
+            _ => AssetKind::Other,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Asset {
+    pub id: String,
+    /// Путь к файлу на FileGateway
+    pub path: String,
+    pub kind: AssetKind,
+    pub size: u64,
+    /// Длительность (мкс), если известна
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_us: Option<u64>,
+    /// SHA-256 содержимого (hex), если известна
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+    pub added_at: DateTime<Utc>,
+}
+
+/// Данные для регистрации ассета
+pub struct NewAsset {
+    pub path: String,
+    /// `None` - определить по MIME типу файла
+    pub kind: Option<AssetKind>,
+    pub duration_us: Option<u64>,
+    pub checksum: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetRegistry {
+    pub version: u32,
+    #[serde(default)]
+    pub assets: Vec<Asset>,
+}
+
+impl Default for AssetRegistry {
+    fn default() -> Self {
+        Self {
+            version: ASSETS_VERSION,
+            assets: Vec::new(),
+        }
+    }
+}
+
+impl AssetRegistry {
+    /// Разобрать файл реестра с проверкой версии схемы
+    pub fn from_json(data: &[u8]) -> Result<Self, AssetError> {
+        #[derive(Deserialize)]
+        struct Header {
+            version: u32,
+        }
+
+        let header: Header = serde_json::from_slice(data)?;
+        if header.version == 0 || header.version > ASSETS_VERSION {
+            return Err(AssetError::UnsupportedVersion(header.version));
+        }
+
+        Ok(serde_json::from_slice(data)?)
+    }
+
+    pub fn to_json(&self) -> Result<Vec<u8>, AssetError> {
+        Ok(serde_json::to_vec_pretty(self)?)
+    }
+
+    /// Удалить ассет из реестра; возвращает удалённую запись
+    pub fn remove(&mut self, asset_id: &str) -> Result<Asset, AssetError> {
+        let index = self
+            .assets
+            .iter()
+            .position(|asset| asset.id == asset_id)
+            .ok_or_else(|| AssetError::AssetNotFound(asset_id.to_string()))?;
+
+        Ok(self.assets.remove(index))
+    }
+}
+
+/// Проверить контрольную сумму и привести её к нижнему регистру
+fn normalize_checksum(checksum: &str) -> Result<String, AssetError> {
+    if checksum.len() != 64 || !checksum.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(AssetError::Invalid(format!(
+            "контрольная сумма `{}` не является SHA-256 в hex",
+            checksum
+        )));
+    }
+    Ok(checksum.to_ascii_lowercase())
+}
+
+/// Информация о файле, который регистрируется или перепривязывается как ассет
+async fn asset_file(files: &FileGateway, path: &str) -> Result<DirectoryEntry, AssetError> {
+    let entry = files
+        .file_info(path)
+        .await
+        .map_err(|e| AssetError::FileGateway(e.message().to_string()))?
+        .ok_or_else(|| AssetError::FileNotFound(path.to_string()))?;
+
+    if entry.is_directory {
+        return Err(AssetError::Invalid(format!("`{}` - директория", path)));
+    }
+
+    Ok(entry)
+}
+
+/// Загрузить реестр ассетов проекта (пустой, если ещё не сохранялся)
+pub async fn load(files: &FileGateway, project_path: &str) -> Result<AssetRegistry, AssetError> {
+    let path = format!("{}/{}", project_path.trim_end_matches(['/', '\\']), ASSETS_FILE);
+
+    let data = files
+        .read_file(&path)
+        .await
+        .map_err(|e| AssetError::FileGateway(e.message().to_string()))?;
+
+    match data {
+        Some(data) => AssetRegistry::from_json(&data),
+        None => Ok(AssetRegistry::default()),
+    }
+}
+
+/// Сохранить реестр ассетов проекта в текущей версии схемы
+pub async fn save(
+    files: &FileGateway,
+    project_path: &str,
+    mut registry: AssetRegistry,
+) -> Result<(), AssetError> {
+    registry.version = ASSETS_VERSION;
+
+    files
+        .write_file(project_path, ASSETS_FILE, registry.to_json()?)
+        .await
+        .map_err(|e| AssetError::FileGateway(e.message().to_string()))
+}
+
+/// Зарегистрировать файл как ассет проекта
+///
+/// Вызывающий отвечает за то, чтобы реестр одного проекта не менялся параллельно.
+pub async fn add(
+    files: &FileGateway,
+    project_path: &str,
+    new_asset: NewAsset,
+) -> Result<Asset, AssetError> {
+    let checksum = new_asset.checksum.as_deref().map(normalize_checksum).transpose()?;
+
+    let entry = asset_file(files, &new_asset.path).await?;
+
+    let mut registry = load(files, project_path).await?;
+    if registry.assets.iter().any(|asset| asset.path == new_asset.path) {
+        return Err(AssetError::AssetAlreadyExists(new_asset.path));
+    }
+
+    let asset = Asset {
+        id: uuid::Uuid::new_v4().to_string(),
+        kind: new_asset
+            .kind
+            .unwrap_or_else(|| AssetKind::from_mime(&entry.mime_type)),
+        path: new_asset.path,
+        size: entry.size,
+        duration_us: new_asset.duration_us,
+        checksum,
+        added_at: Utc::now(),
+    };
+
+    registry.assets.push(asset.clone());
+    save(files, project_path, registry).await?;
+
+    Ok(asset)
+}
+
+/// Удалить ассет из реестра проекта (файл остаётся на месте)
+pub async fn remove(
+    files: &FileGateway,
+    project_path: &str,
+    asset_id: &str,
+) -> Result<Asset, AssetError> {
+    let mut registry = load(files, project_path).await?;
+    let removed = registry.remove(asset_id)?;
+    save(files, project_path, registry).await?;
+
+    Ok(removed)
+}
+
+/// Перепривязать ассет к новому пути (файл перенесён или заменён)
+pub async fn relink(
+    files: &FileGateway,
+    project_path: &str,
+    asset_id: &str,
+    new_path: String,
+) -> Result<Asset, AssetError> {
+    let entry = asset_file(files, &new_path).await?;
+
+    let mut registry = load(files, project_path).await?;
+    if registry
+        .assets
+        .iter()
+        .any(|asset| asset.path == new_path && asset.id != asset_id)
+    {
+        return Err(AssetError::AssetAlreadyExists(new_path));
+    }
+
+    let asset = registry
+        .assets
+        .iter_mut()
+        .find(|asset| asset.id == asset_id)
+        .ok_or_else(|| AssetError::AssetNotFound(asset_id.to_string()))?;
+
+    asset.path = new_path;
+    asset.size = entry.size;
+    let result = asset.clone();
+
+    save(files, project_path, registry).await?;
+    Ok(result)
+}
+
+/// Обновить ассеты, указывающие на перенесённый файл; возвращает их число
+///
+/// Реестр перезаписывается, только если что-то изменилось.
+pub async fn relink_moved(
+    files: &FileGateway,
+    project_path: &str,
+    old_path: &str,
+    new_path: &str,
+) -> Result<usize, AssetError> {
+    let mut registry = load(files, project_path).await?;
+
+    let mut relinked = 0;
+    for asset in registry.assets.iter_mut().filter(|asset| asset.path == old_path) {
+        asset.path = new_path.to_string();
+        relinked += 1;
+    }
+
+    if relinked > 0 {
+        save(files, project_path, registry).await?;
+    }
+
+    Ok(relinked)
+}
+
+/// Тип ассета из запроса (`None` - не указан)
+pub fn asset_kind(kind: proto::AssetKind) -> Option<AssetKind> {
+    match kind {
+        proto::AssetKind::Unspecified => None,
+        proto::AssetKind::Video => Some(AssetKind::Video),
+        proto::AssetKind::Audio => Some(AssetKind::Audio),
+        proto::AssetKind::Image => Some(AssetKind::Image),
+        proto::AssetKind::Other => Some(AssetKind::Other),
+    }
+}
+
+impl From<AssetKind> for proto::AssetKind {
+    fn from(kind: AssetKind) -> Self {
+        match kind {
+            AssetKind::Video => proto::AssetKind::Video,
+            AssetKind::Audio => proto::AssetKind::Audio,
+            AssetKind::Image => proto::AssetKind::Image,
+            AssetKind::Other => proto::AssetKind::Other,
+        }
+    }
+}
+
+impl From<&Asset> for proto::AssetInfo {
+    fn from(asset: &Asset) -> Self {
+        proto::AssetInfo {
+            id: asset.id.clone(),
+            path: asset.path.clone(),
+            kind: proto::AssetKind::from(asset.kind) as i32,
+            size: asset.size,
+            duration_us: asset.duration_us,
+            checksum: asset.checksum.clone().unwrap_or_default(),
+            added_at: Some(to_timestamp(&asset.added_at)),
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -14,6 +14,9 @@ pub enum ProjectError {
     #[error("Проект уже зарегистрирован: {0}")]
     ProjectAlreadyExists(String),
 
+    #[error("Проект с названием {name} уже существует (ID {id})")]
+    NameAlreadyExists { name: String, id: String },
+
     #[error("Проект не найден: {0}")]
     ProjectNotFound(String),
 
@@ -22,6 +25,83 @@ pub enum ProjectError {
 
     #[error("Не удалось определить директорию данных приложения")]
     DataDirNotFound,
+
+    #[error("Недопустимое название проекта: {0}")]
+    InvalidProjectName(String),
+
+    #[error("Реестр хранится только в памяти")]
+    InMemoryRegistry,
+
+    #[error("Некорректный цвет: {0} (ожидается #RRGGBB)")]
+    InvalidColor(String),
+
+    #[error("Пустой ключ пользовательских метаданных")]
+    EmptyCustomKey,
+}
+
+/// Проверить цветовую метку и привести её к виду `#rrggbb`
+pub fn normalize_color(color: &str) -> Result<String, ProjectError> {
+    let hex = color.strip_prefix('#').unwrap_or(color);
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ProjectError::InvalidColor(color.to_string()));
+    }
+    Ok(format!("#{}", hex.to_ascii_lowercase()))
+}
+
+/// Зарезервированные имена устройств Windows (в том числе с расширением: `CON.txt`)
+const RESERVED_WINDOWS_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL",
+    "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8", "COM9",
+    "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Переменная окружения, переопределяющая директорию данных по умолчанию
+const DATA_DIR_ENV: &str = "DIRECTOR_DATA_DIR";
+
+/// Максимальная длина названия (байты) - ограничение имени файла в большинстве ФС
+const MAX_PROJECT_NAME_LEN: usize = 255;
+
+/// Проверить, что название проекта можно использовать как имя папки
+///
+/// Название становится директорией проекта, поэтому запрещены разделители
+/// путей, управляющие символы и имена, недопустимые на Windows.
+pub fn validate_project_name(name: &str) -> Result<(), ProjectError> {
+    let invalid = |reason: &str| Err(ProjectError::InvalidProjectName(reason.to_string()));
+
+    if name.trim().is_empty() {
+        return invalid("название не может быть пустым");
+    }
+    if name.len() > MAX_PROJECT_NAME_LEN {
+        return invalid("название длиннее 255 байт");
+    }
+    if name == "." || name == ".." {
+        return invalid("название не может быть `.` или `..`");
+    }
+    if name.chars().any(|c| c == '/' || c == '\\') {
+        return invalid("название не может содержать разделители путей `/` и `\\`");
+    }
+    if name.chars().any(char::is_control) {
+        return invalid("название не может содержать управляющие символы");
+    }
+    if let Some(c) = name.chars().find(|c| matches!(c, '<' | '>' | ':' | '"' | '|' | '?' | '*')) {
+        return Err(ProjectError::InvalidProjectName(format!(
+            "символ `{}` недопустим в именах папок Windows",
+            c
+        )));
+    }
+    if name.ends_with('.') || name.ends_with(' ') {
+        return invalid("название не может заканчиваться точкой или пробелом");
+    }
+
+    let stem = name.split('.').next().unwrap_or(name).trim_end();
+    if RESERVED_WINDOWS_NAMES.iter().any(|reserved| stem.eq_ignore_ascii_case(reserved)) {
+        return Err(ProjectError::InvalidProjectName(format!(
+            "`{}` - зарезервированное имя Windows",
+            stem
+        )));
+    }
+
+    Ok(())
 }
 
 /// Метаданные проекта
@@ -31,53 +111,195 @@ pub struct ProjectMetadata {
     pub name: String,
     pub path: String,
     pub file_gateway_id: String,
-    pub created_at: DateTime<Utc>,
+    /// Время регистрации; задаётся только в `register_project` и дальше не меняется
+    /// (поле закрыто, снаружи модуля доступно только чтение через `created_at()`)
+    created_at: DateTime<Utc>,
     pub modified_at: DateTime<Utc>,
+    /// Цветовая метка для UI (`#rrggbb`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Проект в избранном (показывается в начале списка)
+    #[serde(default)]
+    pub is_favorite: bool,
+    /// Проект в архиве (скрыт из основного списка, но не удаляется)
+    #[serde(default)]
+    pub archived: bool,
+    /// Произвольные данные интеграций (внешние ID, ссылки на клиента и т.п.)
+    ///
+    /// `BTreeMap`, чтобы индекс на диске не зависел от порядка вставки.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom: BTreeMap<String, String>,
+}
+
+impl ProjectMetadata {
+    /// Время регистрации проекта
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+/// Изменения метаданных проекта (`None` - поле не меняется)
+///
+/// Время регистрации среди изменяемых полей намеренно отсутствует.
+#[derive(Debug, Default)]
+pub struct ProjectUpdate {
+    /// `Some(None)` - снять метку
+    pub color: Option<Option<String>>,
+    pub is_favorite: Option<bool>,
+    /// Установить ключи пользовательских метаданных (после удаления `remove_custom`)
+    pub set_custom: HashMap<String, String>,
+    /// Удалить ключи пользовательских метаданных
+    pub remove_custom: Vec<String>,
+}
+
+/// Сериализованный индекс проектов для записи вне блокировки менеджера
+pub struct IndexSnapshot {
+    path: PathBuf,
+    content: String,
+    /// Номер изменения реестра, которому соответствует снимок
+    pub generation: u64,
+}
+
+impl IndexSnapshot {
+    /// Файл индекса
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Записать индекс в файл (блокирующая операция)
+    pub fn write(&self) -> Result<(), ProjectError> {
+        fs::write(&self.path, &self.content)?;
+        Ok(())
+    }
 }
 
 /// Менеджер проектов - управляет реестром проектов
+///
+/// Методы работают только с памятью; после изменения вызывающий берёт
+/// `index_snapshot` и записывает его на диск, уже отпустив блокировку.
 pub struct ProjectManager {
     projects: HashMap<String, ProjectMetadata>,
-    projects_index_path: PathBuf,
+    /// ID проектов по хранилищу (`file_gateway_id`)
+    by_gateway: HashMap<String, HashSet<String>>,
+    /// Файл индекса; `None` - реестр живёт только в памяти
+    projects_index_path: Option<PathBuf>,
+    /// Счётчик изменений реестра
+    generation: u64,
+    /// Запрещать проекты с одинаковым названием (без учёта регистра)
+    unique_names: bool,
 }
 
 impl ProjectManager {
+    /// Реестр в директории данных приложения (`projects.json`)
+    ///
+    /// Директорию можно переопределить переменной `DIRECTOR_DATA_DIR`
+    /// (например, для второго экземпляра движка или временных данных тестов).
     pub fn new() -> Result<Self, ProjectError> {
-        let app_data_dir = directories::ProjectDirs::from("com", "director", "DirectorEngine")
+        Self::with_data_dir(&Self::default_data_dir()?)
+    }
+
+    /// Директория данных по умолчанию: `DIRECTOR_DATA_DIR`, если задана,
+    /// иначе директория данных приложения
+    fn default_data_dir() -> Result<PathBuf, ProjectError> {
+        if let Some(dir) = std::env::var_os(DATA_DIR_ENV).filter(|dir| !dir.is_empty()) {
+            return Ok(PathBuf::from(dir));
+        }
+
+        Ok(directories::ProjectDirs::from("com", "director", "DirectorEngine")
             .ok_or(ProjectError::DataDirNotFound)?
             .data_dir()
-            .to_path_buf();
+            .to_path_buf())
+    }
 
-        fs::create_dir_all(&app_data_dir)?;
+    /// Реестр в указанной директории данных (`projects.json`)
+    pub fn with_data_dir(data_dir: &Path) -> Result<Self, ProjectError> {
+        Self::with_path(data_dir.join("projects.json"))
+    }
 
-        let projects_index_path = app_data_dir.join("projects.json");
+    /// Реестр в указанном файле индекса
+    pub fn with_path(projects_index_path: impl Into<PathBuf>) -> Result<Self, ProjectError> {
+        let projects_index_path = projects_index_path.into();
 
-        let mut manager = Self {
-            projects: HashMap::new(),
-            projects_index_path,
-        };
+        if let Some(parent) = projects_index_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+
+        let projects = Self::load_projects_index(&projects_index_path)?;
 
-        manager.load_projects_index()?;
+        let mut by_gateway: HashMap<String, HashSet<String>> = HashMap::new();
+        for project in projects.values() {
+            by_gateway
+                .entry(project.file_gateway_id.clone())
+                .or_default()
+                .insert(project.id.clone());
+        }
 
-        Ok(manager)
+        Ok(Self {
+            projects,
+            by_gateway,
+            projects_index_path: Some(projects_index_path),
+            generation: 0,
+            unique_names: false,
+        })
+    }
+
+    /// Реестр без файла - изменения теряются при остановке сервиса
+    pub fn in_memory() -> Self {
+        Self {
+            projects: HashMap::new(),
+            by_gateway: HashMap::new(),
+            projects_index_path: None,
+            generation: 0,
+            unique_names: false,
+        }
     }
 
     /// Загрузить индекс проектов из файла
-    fn load_projects_index(&mut self) -> Result<(), ProjectError> {
-        if self.projects_index_path.exists() {
-            let content = fs::read_to_string(&self.projects_index_path)?;
-            let projects: Vec<ProjectMetadata> = serde_json::from_str(&content)?;
-            self.projects = projects.into_iter().map(|p| (p.id.clone(), p)).collect();
+    fn load_projects_index(path: &Path) -> Result<HashMap<String, ProjectMetadata>, ProjectError> {
+        if !path.exists() {
+            return Ok(HashMap::new());
         }
-        Ok(())
+
+        let content = fs::read_to_string(path)?;
+        let projects: Vec<ProjectMetadata> = serde_json::from_str(&content)?;
+        Ok(projects.into_iter().map(|p| (p.id.clone(), p)).collect())
     }
 
-    /// Сохранить индекс проектов в файл
-    fn save_projects_index(&self) -> Result<(), ProjectError> {
-        let projects: Vec<&ProjectMetadata> = self.projects.values().collect();
+    /// Снимок индекса для сохранения (`None`, если реестр только в памяти)
+    ///
+    /// Проекты упорядочены по времени создания, поэтому одинаковый реестр
+    /// всегда даёт одинаковый файл.
+    pub fn index_snapshot(&self) -> Result<Option<IndexSnapshot>, ProjectError> {
+        let Some(path) = &self.projects_index_path else {
+            return Ok(None);
+        };
+
+        let mut projects: Vec<&ProjectMetadata> = self.projects.values().collect();
+        projects.sort_by(|a, b| a.created_at.cmp(&b.created_at).then_with(|| a.id.cmp(&b.id)));
         let content = serde_json::to_string_pretty(&projects)?;
-        fs::write(&self.projects_index_path, content)?;
-        Ok(())
+
+        Ok(Some(IndexSnapshot {
+            path: path.clone(),
+            content,
+            generation: self.generation,
+        }))
+    }
+
+    /// Включить или выключить проверку уникальности названий проектов
+    ///
+    /// Уже зарегистрированные проекты с одинаковыми названиями не затрагиваются.
+    pub fn set_unique_names(&mut self, unique_names: bool) {
+        self.unique_names = unique_names;
+    }
+
+    /// Файл индекса (`None`, если реестр только в памяти)
+    pub fn index_path(&self) -> Option<&Path> {
+        self.projects_index_path.as_deref()
+    }
+
+    /// Число проектов в реестре
+    pub fn project_count(&self) -> usize {
+        self.projects.len()
     }
 
     /// Получить список всех проектов
@@ -85,18 +307,53 @@ impl ProjectManager {
         self.projects.values().cloned().collect()
     }
 
+    /// Получить проекты одного хранилища
+    pub fn list_projects_on(&self, file_gateway_id: &str) -> Vec<ProjectMetadata> {
+        self.by_gateway
+            .get(file_gateway_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.projects.get(id))
+            .cloned()
+            .collect()
+    }
+
+    /// Убрать проект из индекса по хранилищу
+    fn unindex_gateway(&mut self, project: &ProjectMetadata) {
+        if let Some(ids) = self.by_gateway.get_mut(&project.file_gateway_id) {
+            ids.remove(&project.id);
+            if ids.is_empty() {
+                self.by_gateway.remove(&project.file_gateway_id);
+            }
+        }
+    }
+
     /// Зарегистрировать проект (после создания структуры через FileGateway)
     pub fn register_project(
         &mut self,
         name: &str,
         path: &str,
         file_gateway_id: &str,
+        color: Option<String>,
     ) -> Result<ProjectMetadata, ProjectError> {
+        validate_project_name(name)?;
+
         // Проверяем, не зарегистрирован ли уже проект с таким путём
         if self.projects.values().any(|p| p.path == path) {
             return Err(ProjectError::ProjectAlreadyExists(path.to_string()));
         }
 
+        if self.unique_names {
+            let lowercase = name.to_lowercase();
+            let existing = self.projects.values().find(|p| p.name.to_lowercase() == lowercase);
+            if let Some(existing) = existing {
+                return Err(ProjectError::NameAlreadyExists {
+                    name: existing.name.clone(),
+                    id: existing.id.clone(),
+                });
+            }
+        }
+
         let now = Utc::now();
         let id = Uuid::new_v4().to_string();
 
@@ -107,14 +364,30 @@ impl ProjectManager {
             file_gateway_id: file_gateway_id.to_string(),
             created_at: now,
             modified_at: now,
+            color,
+            is_favorite: false,
+            archived: false,
+            custom: BTreeMap::new(),
         };
 
+        self.by_gateway
+            .entry(metadata.file_gateway_id.clone())
+            .or_default()
+            .insert(id.clone());
         self.projects.insert(id, metadata.clone());
-        self.save_projects_index()?;
+        self.generation += 1;
 
         Ok(metadata)
     }
 
+    /// Найти проект по ID
+    pub fn get_project(&self, project_id: &str) -> Result<ProjectMetadata, ProjectError> {
+        self.projects
+            .get(project_id)
+            .cloned()
+            .ok_or_else(|| ProjectError::ProjectNotFound(project_id.to_string()))
+    }
+
     /// Открыть проект по ID
     pub fn open_project(&mut self, project_id: &str) -> Result<ProjectMetadata, ProjectError> {
         let project = self
@@ -125,18 +398,296 @@ impl ProjectManager {
         // Обновляем время последнего доступа
         project.modified_at = Utc::now();
         let result = project.clone();
-        self.save_projects_index()?;
+        self.generation += 1;
 
         Ok(result)
     }
 
-    /// Удалить проект из реестра (не удаляет файлы)
-    pub fn unregister_project(&mut self, project_id: &str) -> Result<(), ProjectError> {
-        self.projects
+    /// Изменить метаданные проекта
+    pub fn update_project(
+        &mut self,
+        project_id: &str,
+        update: ProjectUpdate,
+    ) -> Result<ProjectMetadata, ProjectError> {
+        // Проверяем до изменений, чтобы ошибка не оставила проект обновлённым наполовину
+        let color = update
+            .color
+            .map(|color| color.as_deref().map(normalize_color).transpose())
+            .transpose()?;
+        if update.set_custom.keys().any(|key| key.is_empty()) {
+            return Err(ProjectError::EmptyCustomKey);
+        }
+
+        let project = self
+            .projects
+            .get_mut(project_id)
+            .ok_or_else(|| ProjectError::ProjectNotFound(project_id.to_string()))?;
+
+        if let Some(color) = color {
+            project.color = color;
+        }
+        if let Some(is_favorite) = update.is_favorite {
+            project.is_favorite = is_favorite;
+        }
+        for key in &update.remove_custom {
+            project.custom.remove(key);
+        }
+        project.custom.extend(update.set_custom);
+
+        project.modified_at = Utc::now();
+        let result = project.clone();
+        self.generation += 1;
+
+        Ok(result)
+    }
+
+    /// Перенести проект в архив или вернуть из него
+    pub fn set_archived(
+        &mut self,
+        project_id: &str,
+        archived: bool,
+    ) -> Result<ProjectMetadata, ProjectError> {
+        let project = self
+            .projects
+            .get_mut(project_id)
+            .ok_or_else(|| ProjectError::ProjectNotFound(project_id.to_string()))?;
+
+        project.archived = archived;
+        project.modified_at = Utc::now();
+        let result = project.clone();
+        self.generation += 1;
+
+        Ok(result)
+    }
+
+    /// Удалить проект из реестра (не удаляет файлы); возвращает удалённый проект
+    pub fn unregister_project(&mut self, project_id: &str) -> Result<ProjectMetadata, ProjectError> {
+        let removed = self
+            .projects
             .remove(project_id)
             .ok_or_else(|| ProjectError::ProjectNotFound(project_id.to_string()))?;
 
-        self.save_projects_index()?;
-        Ok(())
+        self.unindex_gateway(&removed);
+        self.generation += 1;
+        Ok(removed)
+    }
+
+    /// Удалить из реестра несколько проектов; возвращает удалённые
+    ///
+    /// Отсутствующие ID пропускаются (проект мог быть удалён параллельно).
+    pub fn remove_projects(&mut self, project_ids: &[String]) -> Vec<ProjectMetadata> {
+        let removed: Vec<ProjectMetadata> = project_ids
+            .iter()
+            .filter_map(|id| self.projects.remove(id))
+            .collect();
+        for project in &removed {
+            self.unindex_gateway(project);
+        }
+
+        if !removed.is_empty() {
+            self.generation += 1;
+        }
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn register(manager: &mut ProjectManager, name: &str) -> ProjectMetadata {
+        let path = format!("/projects/{}", name);
+        manager
+            .register_project(name, &path, "local", None)
+            .expect("Проект должен зарегистрироваться")
+    }
+
+    #[test]
+    fn register_then_list_returns_project() {
+        let mut manager = ProjectManager::in_memory();
+        let project = register(&mut manager, "Alpha");
+
+        let projects = manager.list_projects();
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].id, project.id);
+        assert_eq!(projects[0].name, "Alpha");
+        assert_eq!(projects[0].path, "/projects/Alpha");
+        assert_eq!(manager.list_projects_on("local").len(), 1);
+        assert!(manager.list_projects_on("other").is_empty());
+    }
+
+    #[test]
+    fn register_rejects_duplicate_path() {
+        let mut manager = ProjectManager::in_memory();
+        register(&mut manager, "Alpha");
+
+        let result = manager.register_project("Beta", "/projects/Alpha", "local", None);
+        assert!(matches!(result, Err(ProjectError::ProjectAlreadyExists(_))));
+        assert_eq!(manager.project_count(), 1);
+    }
+
+    #[test]
+    fn register_rejects_invalid_name() {
+        let mut manager = ProjectManager::in_memory();
+
+        let result = manager.register_project("a/b", "/projects/a", "local", None);
+        assert!(matches!(result, Err(ProjectError::InvalidProjectName(_))));
+        assert_eq!(manager.project_count(), 0);
+    }
+
+    #[test]
+    fn unique_names_rejects_same_name_ignoring_case() {
+        let mut manager = ProjectManager::in_memory();
+        manager.set_unique_names(true);
+        let project = register(&mut manager, "Alpha");
+
+        let result = manager.register_project("ALPHA", "/projects/other", "local", None);
+        match result {
+            Err(ProjectError::NameAlreadyExists { id, .. }) => assert_eq!(id, project.id),
+            other => panic!("Ожидалась ошибка NameAlreadyExists, получено {:?}", other),
+        }
+    }
+
+    #[test]
+    fn open_updates_modified_at_and_keeps_created_at() {
+        let mut manager = ProjectManager::in_memory();
+        let project = register(&mut manager, "Alpha");
+
+        let opened = manager.open_project(&project.id).unwrap();
+        assert_eq!(opened.created_at(), project.created_at());
+        assert!(opened.modified_at >= project.modified_at);
+    }
+
+    #[test]
+    fn created_at_survives_opens_and_updates() {
+        let mut manager = ProjectManager::in_memory();
+        let project = register(&mut manager, "Alpha");
+
+        manager.open_project(&project.id).unwrap();
+        let update = ProjectUpdate {
+            color: Some(Some("#112233".to_string())),
+            is_favorite: Some(true),
+            set_custom: HashMap::from([("crm".to_string(), "42".to_string())]),
+            ..Default::default()
+        };
+        manager.update_project(&project.id, update).unwrap();
+        manager.set_archived(&project.id, true).unwrap();
+        manager.set_archived(&project.id, false).unwrap();
+        manager.open_project(&project.id).unwrap();
+
+        let stored = manager.get_project(&project.id).unwrap();
+        assert_eq!(stored.created_at(), project.created_at());
+        assert!(stored.modified_at >= project.modified_at);
+    }
+
+    #[test]
+    fn duplicate_register_keeps_original_created_at() {
+        let mut manager = ProjectManager::in_memory();
+        let project = register(&mut manager, "Alpha");
+
+        let result = manager.register_project("Alpha", "/projects/Alpha", "local", None);
+        assert!(result.is_err());
+
+        let projects = manager.list_projects();
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].created_at(), project.created_at());
+    }
+
+    #[test]
+    fn open_missing_project_is_not_found() {
+        let mut manager = ProjectManager::in_memory();
+
+        let result = manager.open_project("missing");
+        assert!(matches!(result, Err(ProjectError::ProjectNotFound(_))));
+    }
+
+    #[test]
+    fn update_changes_only_requested_fields() {
+        let mut manager = ProjectManager::in_memory();
+        let project = register(&mut manager, "Alpha");
+
+        let update = ProjectUpdate {
+            color: Some(Some("#AABBCC".to_string())),
+            set_custom: HashMap::from([("crm".to_string(), "42".to_string())]),
+            ..Default::default()
+        };
+        let updated = manager.update_project(&project.id, update).unwrap();
+        assert_eq!(updated.color.as_deref(), Some("#aabbcc"));
+        assert_eq!(updated.custom.get("crm").map(String::as_str), Some("42"));
+        assert!(!updated.is_favorite);
+
+        let stored = manager.get_project(&project.id).unwrap();
+        assert_eq!(stored.color, updated.color);
+        assert_eq!(stored.custom, updated.custom);
+    }
+
+    #[test]
+    fn update_with_invalid_color_leaves_project_unchanged() {
+        let mut manager = ProjectManager::in_memory();
+        let project = register(&mut manager, "Alpha");
+
+        let update = ProjectUpdate {
+            color: Some(Some("red".to_string())),
+            is_favorite: Some(true),
+            ..Default::default()
+        };
+        let result = manager.update_project(&project.id, update);
+        assert!(matches!(result, Err(ProjectError::InvalidColor(_))));
+
+        let stored = manager.get_project(&project.id).unwrap();
+        assert!(!stored.is_favorite);
+        assert_eq!(stored.color, None);
+    }
+
+    #[test]
+    fn unregister_removes_project_from_all_indexes() {
+        let mut manager = ProjectManager::in_memory();
+        let project = register(&mut manager, "Alpha");
+
+        let removed = manager.unregister_project(&project.id).unwrap();
+        assert_eq!(removed.id, project.id);
+        assert_eq!(manager.project_count(), 0);
+        assert!(manager.list_projects_on("local").is_empty());
+        assert!(matches!(
+            manager.unregister_project(&project.id),
+            Err(ProjectError::ProjectNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn in_memory_registry_has_no_snapshot() {
+        let mut manager = ProjectManager::in_memory();
+        register(&mut manager, "Alpha");
+
+        assert!(manager.index_path().is_none());
+        assert!(manager.index_snapshot().unwrap().is_none());
+    }
+
+    #[test]
+    fn saved_index_is_loaded_by_new_manager() {
+        let dir = tempfile::tempdir().unwrap();
+        let index_path = dir.path().join("nested").join("projects.json");
+
+        let mut manager = ProjectManager::with_path(&index_path).unwrap();
+        let project = register(&mut manager, "Alpha");
+        manager
+            .update_project(
+                &project.id,
+                ProjectUpdate {
+                    is_favorite: Some(true),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let snapshot = manager.index_snapshot().unwrap().expect("Реестр хранится в файле");
+        assert_eq!(snapshot.path(), index_path);
+        snapshot.write().unwrap();
+
+        let reloaded = ProjectManager::with_path(&index_path).unwrap();
+        let loaded = reloaded.get_project(&project.id).unwrap();
+        assert_eq!(loaded.name, "Alpha");
+        assert!(loaded.is_favorite);
+        assert_eq!(loaded.created_at(), project.created_at());
+        assert_eq!(reloaded.list_projects_on("local").len(), 1);
     }
 }
@@ -0,0 +1,200 @@
+//! Манифест переносимого архива проекта
+//!
+//! Архив собирает ApiGateway: `manifest.json` от движка плюс файлы папки
+//! проекта. Пути ассетов в манифесте относительны папки проекта, поэтому
+//! после распаковки в другое место (или на другое хранилище) их достаточно
+//! достроить от нового корня. Ассеты вне папки проекта попадают в архив
+//! под `assets/external/<id>/` и при импорте оказываются внутри проекта.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::file_gateway::FileGateway;
+use crate::project::assets::{self, AssetError, AssetRegistry};
+use crate::project::manager::ProjectMetadata;
+use crate::project::timeline::{self, Timeline, TimelineError};
+use crate::proto;
+
+/// Текущая версия схемы манифеста
+pub const MANIFEST_VERSION: u32 = 1;
+
+/// Куда внутри проекта кладутся файлы ассетов, лежавших вне его папки
+const EXTERNAL_ASSETS_DIR: &str = "assets/external";
+
+#[derive(Error, Debug)]
+pub enum ManifestError {
+    #[error("Ошибка сериализации: {0}")]
+    SerializationError(#[from] serde_json::Error),
+
+    #[error("Версия манифеста {0} не поддерживается (поддерживается до {MANIFEST_VERSION})")]
+    UnsupportedVersion(u32),
+
+    #[error("Некорректный манифест: {0}")]
+    Invalid(String),
+
+    #[error(transparent)]
+    Timeline(#[from] TimelineError),
+
+    #[error(transparent)]
+    Assets(#[from] AssetError),
+}
+
+/// Всё, что движок знает о проекте, кроме самих файлов
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectManifest {
+    pub version: u32,
+    pub name: String,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub is_favorite: bool,
+    #[serde(default)]
+    pub custom: BTreeMap<String, String>,
+    pub exported_at: DateTime<Utc>,
+    /// `None` - таймлайн ещё не сохранялся
+    #[serde(default)]
+    pub timeline: Option<Timeline>,
+    /// Реестр ассетов с путями относительно папки проекта
+    pub assets: AssetRegistry,
+}
+
+/// Файл ассета вне папки проекта, который нужно положить в архив
+pub struct ExternalFile {
+    pub asset_id: String,
+    /// Путь к файлу на FileGateway
+    pub source_path: String,
+    /// Путь относительно папки проекта, под которым файл хранится в архиве
+    pub archive_path: String,
+}
+
+/// Манифест и файлы, которые нужно добавить в архив помимо папки проекта
+pub struct ProjectExport {
+    pub manifest: ProjectManifest,
+    pub external_files: Vec<ExternalFile>,
+}
+
+impl ProjectManifest {
+    /// Разобрать манифест с проверкой версии схемы
+    pub fn from_json(data: &[u8]) -> Result<Self, ManifestError> {
+        #[derive(Deserialize)]
+        struct Header {
+            version: u32,
+        }
+
+        let header: Header = serde_json::from_slice(data)?;
+        if header.version == 0 || header.version > MANIFEST_VERSION {
+            return Err(ManifestError::UnsupportedVersion(header.version));
+        }
+
+        Ok(serde_json::from_slice(data)?)
+    }
+
+    pub fn to_json(&self) -> Result<Vec<u8>, ManifestError> {
+        Ok(serde_json::to_vec_pretty(self)?)
+    }
+}
+
+/// Путь `path` относительно `root` через `/`; `None`, если он вне `root`
+fn relative_path(root: &str, path: &str) -> Option<String> {
+    let root = root.trim_end_matches(['/', '\\']);
+    let rest = path.strip_prefix(root)?;
+    if !rest.starts_with(['/', '\\']) {
+        return None;
+    }
+
+    Some(rest.trim_start_matches(['/', '\\']).replace('\\', "/"))
+}
+
+/// Проверить, что путь из манифеста не выходит за папку проекта
+fn check_relative(path: &str) -> Result<(), ManifestError> {
+    let escapes = path.is_empty()
+        || path.starts_with(['/', '\\'])
+        || path.contains(':')
+        || path.split(['/', '\\']).any(|part| part == "..");
+
+    if escapes {
+        return Err(ManifestError::Invalid(format!(
+            "путь ассета `{}` выходит за папку проекта",
+            path
+        )));
+    }
+    Ok(())
+}
+
+/// Собрать манифест проекта для экспорта
+pub async fn export(
+    files: &FileGateway,
+    project: &ProjectMetadata,
+) -> Result<ProjectExport, ManifestError> {
+    let timeline = timeline::load(files, &project.path).await?;
+    let mut registry = assets::load(files, &project.path).await?;
+
+    let mut external_files = Vec::new();
+    for asset in &mut registry.assets {
+        asset.path = match relative_path(&project.path, &asset.path) {
+            Some(relative) => relative,
+            None => {
+                let filename = asset.path.rsplit(['/', '\\']).next().unwrap_or_default();
+                let archive_path = format!("{}/{}/{}", EXTERNAL_ASSETS_DIR, asset.id, filename);
+                external_files.push(ExternalFile {
+                    asset_id: asset.id.clone(),
+                    source_path: asset.path.clone(),
+                    archive_path: archive_path.clone(),
+                });
+                archive_path
+            }
+        };
+    }
+
+    Ok(ProjectExport {
+        manifest: ProjectManifest {
+            version: MANIFEST_VERSION,
+            name: project.name.clone(),
+            color: project.color.clone(),
+            is_favorite: project.is_favorite,
+            custom: project.custom.clone(),
+            exported_at: Utc::now(),
+            timeline,
+            assets: registry,
+        },
+        external_files,
+    })
+}
+
+/// Записать таймлайн и реестр ассетов из манифеста в распакованный проект
+///
+/// Файлы проекта уже должны лежать в `project_path`; пути ассетов
+/// достраиваются от него.
+pub async fn import(
+    files: &FileGateway,
+    project_path: &str,
+    manifest: &ProjectManifest,
+) -> Result<(), ManifestError> {
+    let root = project_path.trim_end_matches(['/', '\\']);
+
+    let mut registry = manifest.assets.clone();
+    for asset in &mut registry.assets {
+        check_relative(&asset.path)?;
+        asset.path = format!("{}/{}", root, asset.path);
+    }
+
+    assets::save(files, project_path, registry).await?;
+    if let Some(timeline) = &manifest.timeline {
+        timeline::save(files, project_path, timeline.clone()).await?;
+    }
+
+    Ok(())
+}
+
+impl From<&ExternalFile> for proto::ExternalAssetFile {
+    fn from(file: &ExternalFile) -> Self {
+        proto::ExternalAssetFile {
+            asset_id: file.asset_id.clone(),
+            source_path: file.source_path.clone(),
+            archive_path: file.archive_path.clone(),
+        }
+    }
+}
@@ -1,4 +1,9 @@
+pub mod assets;
 pub mod manager;
+pub mod manifest;
+pub mod orphans;
 pub mod service;
+pub mod timeline;
+pub mod validation;
 
 
@@ -0,0 +1,64 @@
+//! Поиск проектов, чьи директории удалены в обход приложения
+
+use tracing::warn;
+
+use crate::file_gateway::FileGateway;
+use crate::project::manager::ProjectMetadata;
+
+/// Результат проверки путей проектов
+pub struct OrphanReport {
+    /// Проекты, директорий которых больше нет
+    pub orphans: Vec<ProjectMetadata>,
+    /// Сколько проектов не удалось проверить (FileGateway недоступен и т.п.)
+    pub unchecked: u32,
+}
+
+/// Проверить пути проектов через FileGateway
+///
+/// Сиротой считается только проект, для которого FileGateway явно ответил,
+/// что пути нет; ошибки проверки учитываются в `unchecked`.
+pub async fn find_orphans(files: &FileGateway, projects: Vec<ProjectMetadata>) -> OrphanReport {
+    let mut report = OrphanReport {
+        orphans: Vec::new(),
+        unchecked: 0,
+    };
+
+    for project in projects {
+        match files.exists(&project.path).await {
+            Ok(true) => {}
+            Ok(false) => report.orphans.push(project),
+            Err(e) => {
+                warn!(
+                    "Не удалось проверить путь проекта {} ({}): {}",
+                    project.name, project.path, e
+                );
+                report.unchecked += 1;
+            }
+        }
+    }
+
+    report
+}
+
+/// Проверить пути при запуске и записать найденных сирот в лог
+pub async fn report_on_startup(files: FileGateway, projects: Vec<ProjectMetadata>) {
+    if projects.is_empty() {
+        return;
+    }
+
+    let report = find_orphans(&files, projects).await;
+
+    for orphan in &report.orphans {
+        warn!(
+            "Директория проекта {} ({}) не найдена: {}",
+            orphan.name, orphan.id, orphan.path
+        );
+    }
+
+    if !report.orphans.is_empty() {
+        warn!(
+            "Найдено проектов без директории: {}; удалить их из реестра можно через PruneOrphans",
+            report.orphans.len()
+        );
+    }
+}
@@ -1,30 +1,251 @@
-use std::sync::Mutex;
+use chrono::{DateTime, Utc};
+use std::fs;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::{Mutex, RwLock, RwLockReadGuard};
+use tokio::sync::broadcast;
+use tokio_stream::Stream;
 use tonic::{Request, Response, Status};
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
-use crate::project::manager::{ProjectError, ProjectManager, ProjectMetadata};
+use crate::config::{EngineConfig, ProjectDefaults};
+use crate::file_gateway::FileGateway;
+use crate::project::manager::{
+    validate_project_name, IndexSnapshot, ProjectError, ProjectManager, ProjectMetadata,
+    ProjectUpdate,
+};
+use crate::project::assets::{self, asset_kind, NewAsset};
+use crate::project::manifest::{self, ProjectManifest};
+use crate::project::orphans::find_orphans;
+use crate::project::timeline::{self, Timeline};
+use crate::project::validation::validate_project;
 use crate::proto::{
     project_service_server::ProjectService,
-    GetEngineInfoRequest, GetEngineInfoResponse,
+    ArchiveProjectRequest, ArchiveProjectResponse,
+    UnarchiveProjectRequest, UnarchiveProjectResponse,
+    FindOrphansRequest, FindOrphansResponse, PruneOrphansRequest, PruneOrphansResponse,
+    ProjectEvent, ProjectEventType, SubscribeEventsRequest,
+    LoadTimelineRequest, LoadTimelineResponse, SaveTimelineRequest, SaveTimelineResponse,
+    AddAssetRequest, AddAssetResponse, ListAssetsRequest, ListAssetsResponse,
+    RemoveAssetRequest, RemoveAssetResponse, AssetInfo,
+    RelinkAssetRequest, RelinkAssetResponse, NotifyFileMovedRequest, NotifyFileMovedResponse,
+    ValidateProjectRequest, ValidateProjectResponse, ClipIssue,
+    ExportProjectRequest, ExportProjectResponse, ExternalAssetFile,
+    ImportProjectRequest, ImportProjectResponse,
+    ValidateProjectNameRequest, ValidateProjectNameResponse,
+    CompactRegistryRequest, CompactRegistryResponse,
+    GetEngineInfoRequest, GetEngineInfoResponse, GetEngineStatsRequest, GetEngineStatsResponse,
     ListProjectsRequest, ListProjectsResponse,
     OpenProjectRequest, OpenProjectResponse,
     ProjectInfo, RegisterProjectRequest, RegisterProjectResponse,
     UnregisterProjectRequest, UnregisterProjectResponse,
+    UpdateProjectRequest, UpdateProjectResponse,
 };
 
 const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Сколько событий хранится для подписчиков, которые читают медленнее
+const EVENT_BUFFER: usize = 256;
+
 pub struct ProjectServiceImpl {
+    /// Держится только на время работы с памятью, без файлового ввода-вывода
     manager: Mutex<ProjectManager>,
+    /// Последнее сохранённое изменение реестра; лок упорядочивает записи индекса
+    saved_generation: tokio::sync::Mutex<u64>,
+    /// Файлы проектов на FileGateway (проверка путей, таймлайн)
+    files: FileGateway,
+    /// Изменения реестров ассетов (чтение-изменение-запись файла на FileGateway)
+    assets_lock: tokio::sync::Mutex<()>,
+    /// События проектов для подписчиков `SubscribeEvents`
+    events: broadcast::Sender<ProjectEvent>,
     engine_id: String,
+    /// Настройки из конфигурации, заменяются при перечитывании (SIGHUP)
+    settings: RwLock<Settings>,
+}
+
+/// Настройки сервиса из конфигурации
+struct Settings {
+    /// Форматы, сообщаются в `GetEngineInfo`
+    supported_formats: Vec<String>,
+    /// Настройки новых проектов
+    project_defaults: ProjectDefaults,
+}
+
+impl From<&EngineConfig> for Settings {
+    fn from(config: &EngineConfig) -> Self {
+        Settings {
+            supported_formats: config.supported_formats.clone(),
+            project_defaults: config.project_defaults.clone(),
+        }
+    }
 }
 
 impl ProjectServiceImpl {
-    pub fn new() -> Result<Self, ProjectError> {
-        Ok(Self {
-            manager: Mutex::new(ProjectManager::new()?),
+    pub fn new(mut manager: ProjectManager, files: FileGateway, config: &EngineConfig) -> Self {
+        manager.set_unique_names(config.unique_project_names);
+
+        Self {
+            manager: Mutex::new(manager),
+            saved_generation: tokio::sync::Mutex::new(0),
+            files,
+            assets_lock: tokio::sync::Mutex::new(()),
+            events: broadcast::channel(EVENT_BUFFER).0,
             engine_id: uuid::Uuid::new_v4().to_string(),
+            settings: RwLock::new(Settings::from(config)),
+        }
+    }
+
+    /// Применить перечитанную конфигурацию; действует для следующих запросов
+    pub fn apply_config(&self, config: &EngineConfig) {
+        *self.settings.write().unwrap_or_else(|e| e.into_inner()) = Settings::from(config);
+        self.manager
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .set_unique_names(config.unique_project_names);
+    }
+
+    fn settings(&self) -> RwLockReadGuard<'_, Settings> {
+        self.settings.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Разослать событие подписчикам (если их нет - событие отбрасывается)
+    fn publish(&self, event_type: ProjectEventType, project: &ProjectMetadata) {
+        let _ = self.events.send(ProjectEvent {
+            r#type: event_type as i32,
+            project: Some(ProjectInfo::from(project)),
+            timestamp: Some(to_timestamp(&Utc::now())),
+        });
+    }
+
+    /// Изменить реестр под блокировкой и сохранить индекс уже без неё
+    async fn update<T>(
+        &self,
+        change: impl FnOnce(&mut ProjectManager) -> Result<T, ProjectError>,
+    ) -> Result<Result<T, ProjectError>, Status> {
+        let (value, snapshot) = {
+            let mut manager = self.manager.lock().map_err(|e| {
+                error!("Ошибка блокировки менеджера: {}", e);
+                Status::internal("Внутренняя ошибка сервера")
+            })?;
+
+            let changed = change(&mut manager)
+                .and_then(|value| Ok((value, manager.index_snapshot()?)));
+            match changed {
+                Ok(changed) => changed,
+                Err(e) => return Ok(Err(e)),
+            }
+        };
+
+        Ok(self.save_index(snapshot).await.map(|()| value))
+    }
+
+    /// Переписать файл индекса, даже если реестр не менялся
+    ///
+    /// Возвращает размер файла до и после перезаписи.
+    async fn compact_index(&self, snapshot: IndexSnapshot) -> Result<(u64, u64), ProjectError> {
+        // Лок записей не даёт параллельному сохранению вклиниться между замерами
+        let mut saved_generation = self.saved_generation.lock().await;
+
+        // Если уже записан более новый снимок, он и так в каноническом виде
+        let generation = snapshot.generation;
+        let rewrite = generation >= *saved_generation;
+
+        let (size_before, size_after) = tokio::task::spawn_blocking(move || {
+            let file_size = |path: &Path| fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+
+            let size_before = file_size(snapshot.path());
+            if rewrite {
+                snapshot.write()?;
+            }
+            Ok::<_, ProjectError>((size_before, file_size(snapshot.path())))
         })
+        .await
+        .map_err(|e| ProjectError::IoError(std::io::Error::other(e)))??;
+
+        *saved_generation = (*saved_generation).max(generation);
+        Ok((size_before, size_after))
+    }
+
+    /// Записать индекс в пуле блокирующих задач
+    ///
+    /// Снимок, устаревший к моменту записи (более новый уже сохранён), пропускается.
+    async fn save_index(&self, snapshot: Option<IndexSnapshot>) -> Result<(), ProjectError> {
+        let Some(snapshot) = snapshot else {
+            return Ok(());
+        };
+
+        let mut saved_generation = self.saved_generation.lock().await;
+        if snapshot.generation <= *saved_generation {
+            return Ok(());
+        }
+
+        let generation = snapshot.generation;
+        tokio::task::spawn_blocking(move || snapshot.write())
+            .await
+            .map_err(|e| ProjectError::IoError(std::io::Error::other(e)))??;
+
+        *saved_generation = generation;
+        Ok(())
+    }
+
+    /// Записать таймлайн и ассеты из манифеста и зарегистрировать проект под новым ID
+    async fn import_manifest(
+        &self,
+        req: ImportProjectRequest,
+    ) -> Result<Result<ProjectMetadata, String>, Status> {
+        let manifest = match ProjectManifest::from_json(&req.manifest) {
+            Ok(manifest) => manifest,
+            Err(e) => return Ok(Err(e.to_string())),
+        };
+
+        let name = if req.name.is_empty() {
+            manifest.name.clone()
+        } else {
+            req.name
+        };
+        // Проверяем до записи файлов, чтобы не трогать папку с заведомо неверным названием
+        if let Err(e) = validate_project_name(&name) {
+            return Ok(Err(e.to_string()));
+        }
+
+        {
+            let _guard = self.assets_lock.lock().await;
+            if let Err(e) = manifest::import(&self.files, &req.path, &manifest).await {
+                return Ok(Err(e.to_string()));
+            }
+        }
+
+        let update = ProjectUpdate {
+            color: Some(manifest.color),
+            is_favorite: Some(manifest.is_favorite),
+            set_custom: manifest.custom.into_iter().collect(),
+            remove_custom: Vec::new(),
+        };
+
+        let result = self
+            .update(|manager| {
+                let metadata =
+                    manager.register_project(&name, &req.path, &req.file_gateway_id, None)?;
+                // Метаданные из манифеста не прошли проверку - проект не регистрируем
+                manager.update_project(&metadata.id, update).inspect_err(|_| {
+                    let _ = manager.unregister_project(&metadata.id);
+                })
+            })
+            .await?;
+
+        Ok(result.map_err(|e| e.to_string()))
+    }
+}
+
+/// Сколько секунд работает процесс
+fn uptime_seconds() -> u64 {
+    (Utc::now() - crate::started_at()).num_seconds().max(0) as u64
+}
+
+pub(crate) fn to_timestamp(time: &DateTime<Utc>) -> prost_types::Timestamp {
+    prost_types::Timestamp {
+        seconds: time.timestamp(),
+        nanos: time.timestamp_subsec_nanos() as i32,
     }
 }
 
@@ -35,8 +256,12 @@ impl From<&ProjectMetadata> for ProjectInfo {
             name: meta.name.clone(),
             path: meta.path.clone(),
             file_gateway_id: meta.file_gateway_id.clone(),
-            created_at: meta.created_at.timestamp(),
-            modified_at: meta.modified_at.timestamp(),
+            created_at: Some(to_timestamp(&meta.created_at())),
+            modified_at: Some(to_timestamp(&meta.modified_at)),
+            color: meta.color.clone().unwrap_or_default(),
+            is_favorite: meta.is_favorite,
+            archived: meta.archived,
+            custom: meta.custom.clone().into_iter().collect(),
         }
     }
 }
@@ -52,29 +277,125 @@ impl ProjectService for ProjectServiceImpl {
         Ok(Response::new(GetEngineInfoResponse {
             engine_id: self.engine_id.clone(),
             version: ENGINE_VERSION.to_string(),
-            supported_formats: vec![
-                "mp4".to_string(),
-                "mov".to_string(),
-                "avi".to_string(),
-                "mkv".to_string(),
-                "webm".to_string(),
-            ],
+            supported_formats: self.settings().supported_formats.clone(),
+            started_at: Some(to_timestamp(&crate::started_at())),
+            uptime_seconds: uptime_seconds(),
         }))
     }
 
+    async fn get_engine_stats(
+        &self,
+        request: Request<GetEngineStatsRequest>,
+    ) -> Result<Response<GetEngineStatsResponse>, Status> {
+        let req = request.into_inner();
+
+        let (project_count, index_path, projects) = {
+            let manager = self.manager.lock().map_err(|e| {
+                error!("Ошибка блокировки менеджера: {}", e);
+                Status::internal("Внутренняя ошибка сервера")
+            })?;
+
+            (
+                manager.project_count() as u64,
+                manager.index_path().map(|path| path.to_path_buf()),
+                req.check_paths.then(|| manager.list_projects()),
+            )
+        };
+
+        // Индекса может ещё не быть на диске, если реестр ни разу не менялся
+        let index_size_bytes = match index_path {
+            Some(path) => tokio::fs::metadata(&path).await.map(|m| m.len()).unwrap_or(0),
+            None => 0,
+        };
+
+        let mut stats = GetEngineStatsResponse {
+            project_count,
+            index_size_bytes,
+            ..Default::default()
+        };
+
+        if let Some(projects) = projects {
+            let report = find_orphans(&self.files, projects).await;
+            stats.paths_checked = true;
+            stats.missing_count = report.orphans.len() as u64;
+            stats.unchecked_count = report.unchecked as u64;
+            stats.available_count = project_count
+                .saturating_sub(stats.missing_count + stats.unchecked_count);
+        }
+
+        Ok(Response::new(stats))
+    }
+
+    async fn compact_registry(
+        &self,
+        _request: Request<CompactRegistryRequest>,
+    ) -> Result<Response<CompactRegistryResponse>, Status> {
+        info!("Перезапись реестра проектов");
+
+        let snapshot = self
+            .manager
+            .lock()
+            .map_err(|e| {
+                error!("Ошибка блокировки менеджера: {}", e);
+                Status::internal("Внутренняя ошибка сервера")
+            })?
+            .index_snapshot();
+
+        let result = match snapshot {
+            Ok(Some(snapshot)) => self.compact_index(snapshot).await,
+            Ok(None) => Err(ProjectError::InMemoryRegistry),
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok((size_before, size_after)) => {
+                info!("Реестр перезаписан: {} -> {} байт", size_before, size_after);
+                Ok(Response::new(CompactRegistryResponse {
+                    success: true,
+                    error_message: String::new(),
+                    size_before,
+                    size_after,
+                }))
+            }
+            Err(e) => {
+                error!("Ошибка перезаписи реестра: {}", e);
+                Ok(Response::new(CompactRegistryResponse {
+                    success: false,
+                    error_message: e.to_string(),
+                    ..Default::default()
+                }))
+            }
+        }
+    }
+
     async fn list_projects(
         &self,
-        _request: Request<ListProjectsRequest>,
+        request: Request<ListProjectsRequest>,
     ) -> Result<Response<ListProjectsResponse>, Status> {
+        let req = request.into_inner();
         info!("Запрос списка проектов");
 
-        let manager = self.manager.lock().map_err(|e| {
-            error!("Ошибка блокировки менеджера: {}", e);
-            Status::internal("Внутренняя ошибка сервера")
-        })?;
+        let mut projects = {
+            let manager = self.manager.lock().map_err(|e| {
+                error!("Ошибка блокировки менеджера: {}", e);
+                Status::internal("Внутренняя ошибка сервера")
+            })?;
+            match &req.file_gateway_id {
+                Some(file_gateway_id) => manager.list_projects_on(file_gateway_id),
+                None => manager.list_projects(),
+            }
+        };
+
+        if !req.include_archived {
+            projects.retain(|p| !p.archived);
+        }
+
+        if req.favorites_first {
+            // Сортировка стабильная: порядок внутри групп не меняется
+            projects.sort_by_key(|p| !p.is_favorite);
+        }
 
-        let projects: Vec<ProjectInfo> = manager
-            .list_projects()
+        let projects: Vec<ProjectInfo> = projects
             .iter()
             .map(ProjectInfo::from)
             .collect();
@@ -92,17 +413,22 @@ impl ProjectService for ProjectServiceImpl {
             req.name, req.path, req.file_gateway_id
         );
 
-        let mut manager = self.manager.lock().map_err(|e| {
-            error!("Ошибка блокировки менеджера: {}", e);
-            Status::internal("Внутренняя ошибка сервера")
-        })?;
+        let color = self.settings().project_defaults.color.clone();
+        let result = self
+            .update(|manager| {
+                manager.register_project(&req.name, &req.path, &req.file_gateway_id, color)
+            })
+            .await?;
 
-        match manager.register_project(&req.name, &req.path, &req.file_gateway_id) {
-            Ok(metadata) => Ok(Response::new(RegisterProjectResponse {
-                success: true,
-                error_message: String::new(),
-                project: Some(ProjectInfo::from(&metadata)),
-            })),
+        match result {
+            Ok(metadata) => {
+                self.publish(ProjectEventType::ProjectRegistered, &metadata);
+                Ok(Response::new(RegisterProjectResponse {
+                    success: true,
+                    error_message: String::new(),
+                    project: Some(ProjectInfo::from(&metadata)),
+                }))
+            }
             Err(e) => {
                 error!("Ошибка регистрации проекта: {}", e);
                 Ok(Response::new(RegisterProjectResponse {
@@ -114,6 +440,24 @@ impl ProjectService for ProjectServiceImpl {
         }
     }
 
+    async fn validate_project_name(
+        &self,
+        request: Request<ValidateProjectNameRequest>,
+    ) -> Result<Response<ValidateProjectNameResponse>, Status> {
+        let req = request.into_inner();
+
+        Ok(Response::new(match validate_project_name(&req.name) {
+            Ok(()) => ValidateProjectNameResponse {
+                valid: true,
+                error_message: String::new(),
+            },
+            Err(e) => ValidateProjectNameResponse {
+                valid: false,
+                error_message: e.to_string(),
+            },
+        }))
+    }
+
     async fn open_project(
         &self,
         request: Request<OpenProjectRequest>,
@@ -121,17 +465,17 @@ impl ProjectService for ProjectServiceImpl {
         let req = request.into_inner();
         info!("Открытие проекта: {}", req.project_id);
 
-        let mut manager = self.manager.lock().map_err(|e| {
-            error!("Ошибка блокировки менеджера: {}", e);
-            Status::internal("Внутренняя ошибка сервера")
-        })?;
+        let result = self.update(|manager| manager.open_project(&req.project_id)).await?;
 
-        match manager.open_project(&req.project_id) {
-            Ok(metadata) => Ok(Response::new(OpenProjectResponse {
-                success: true,
-                error_message: String::new(),
-                project: Some(ProjectInfo::from(&metadata)),
-            })),
+        match result {
+            Ok(metadata) => {
+                self.publish(ProjectEventType::ProjectOpened, &metadata);
+                Ok(Response::new(OpenProjectResponse {
+                    success: true,
+                    error_message: String::new(),
+                    project: Some(ProjectInfo::from(&metadata)),
+                }))
+            }
             Err(e) => {
                 error!("Ошибка открытия проекта: {}", e);
                 Ok(Response::new(OpenProjectResponse {
@@ -143,6 +487,107 @@ impl ProjectService for ProjectServiceImpl {
         }
     }
 
+    async fn update_project(
+        &self,
+        request: Request<UpdateProjectRequest>,
+    ) -> Result<Response<UpdateProjectResponse>, Status> {
+        let req = request.into_inner();
+        info!("Изменение проекта: {}", req.project_id);
+
+        // Пустая строка в запросе снимает метку
+        let update = ProjectUpdate {
+            color: req.color.map(|color| Some(color).filter(|c| !c.is_empty())),
+            is_favorite: req.is_favorite,
+            set_custom: req.set_custom,
+            remove_custom: req.remove_custom,
+        };
+
+        let result = self
+            .update(|manager| manager.update_project(&req.project_id, update))
+            .await?;
+
+        match result {
+            Ok(metadata) => {
+                self.publish(ProjectEventType::ProjectUpdated, &metadata);
+                Ok(Response::new(UpdateProjectResponse {
+                    success: true,
+                    error_message: String::new(),
+                    project: Some(ProjectInfo::from(&metadata)),
+                }))
+            }
+            Err(e) => {
+                error!("Ошибка изменения проекта: {}", e);
+                Ok(Response::new(UpdateProjectResponse {
+                    success: false,
+                    error_message: e.to_string(),
+                    project: None,
+                }))
+            }
+        }
+    }
+
+    async fn archive_project(
+        &self,
+        request: Request<ArchiveProjectRequest>,
+    ) -> Result<Response<ArchiveProjectResponse>, Status> {
+        let req = request.into_inner();
+        info!("Архивация проекта: {}", req.project_id);
+
+        let result = self
+            .update(|manager| manager.set_archived(&req.project_id, true))
+            .await?;
+
+        match result {
+            Ok(metadata) => {
+                self.publish(ProjectEventType::ProjectUpdated, &metadata);
+                Ok(Response::new(ArchiveProjectResponse {
+                    success: true,
+                    error_message: String::new(),
+                    project: Some(ProjectInfo::from(&metadata)),
+                }))
+            }
+            Err(e) => {
+                error!("Ошибка архивации проекта: {}", e);
+                Ok(Response::new(ArchiveProjectResponse {
+                    success: false,
+                    error_message: e.to_string(),
+                    project: None,
+                }))
+            }
+        }
+    }
+
+    async fn unarchive_project(
+        &self,
+        request: Request<UnarchiveProjectRequest>,
+    ) -> Result<Response<UnarchiveProjectResponse>, Status> {
+        let req = request.into_inner();
+        info!("Возврат проекта из архива: {}", req.project_id);
+
+        let result = self
+            .update(|manager| manager.set_archived(&req.project_id, false))
+            .await?;
+
+        match result {
+            Ok(metadata) => {
+                self.publish(ProjectEventType::ProjectUpdated, &metadata);
+                Ok(Response::new(UnarchiveProjectResponse {
+                    success: true,
+                    error_message: String::new(),
+                    project: Some(ProjectInfo::from(&metadata)),
+                }))
+            }
+            Err(e) => {
+                error!("Ошибка возврата проекта из архива: {}", e);
+                Ok(Response::new(UnarchiveProjectResponse {
+                    success: false,
+                    error_message: e.to_string(),
+                    project: None,
+                }))
+            }
+        }
+    }
+
     async fn unregister_project(
         &self,
         request: Request<UnregisterProjectRequest>,
@@ -150,23 +595,679 @@ impl ProjectService for ProjectServiceImpl {
         let req = request.into_inner();
         info!("Удаление проекта из реестра: {}", req.project_id);
 
-        let mut manager = self.manager.lock().map_err(|e| {
-            error!("Ошибка блокировки менеджера: {}", e);
-            Status::internal("Внутренняя ошибка сервера")
-        })?;
+        let result = self.update(|manager| manager.unregister_project(&req.project_id)).await?;
+
+        match result {
+            Ok(removed) => {
+                self.publish(ProjectEventType::ProjectUnregistered, &removed);
+                Ok(Response::new(UnregisterProjectResponse {
+                    success: true,
+                    error_message: String::new(),
+                }))
+            }
+            Err(e) => {
+                error!("Ошибка удаления проекта: {}", e);
+                Ok(Response::new(UnregisterProjectResponse {
+                    success: false,
+                    error_message: e.to_string(),
+                }))
+            }
+        }
+    }
+
+    async fn save_timeline(
+        &self,
+        request: Request<SaveTimelineRequest>,
+    ) -> Result<Response<SaveTimelineResponse>, Status> {
+        let req = request.into_inner();
+        info!("Сохранение таймлайна проекта: {}", req.project_id);
+
+        let project = self
+            .manager
+            .lock()
+            .map_err(|e| {
+                error!("Ошибка блокировки менеджера: {}", e);
+                Status::internal("Внутренняя ошибка сервера")
+            })?
+            .get_project(&req.project_id);
 
-        match manager.unregister_project(&req.project_id) {
-            Ok(()) => Ok(Response::new(UnregisterProjectResponse {
+        let result = match project {
+            Ok(project) => match Timeline::try_from(req.timeline.unwrap_or_default()) {
+                Ok(timeline) => timeline::save(&self.files, &project.path, timeline)
+                    .await
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            },
+            Err(e) => Err(e.to_string()),
+        };
+
+        match result {
+            Ok(()) => Ok(Response::new(SaveTimelineResponse {
                 success: true,
                 error_message: String::new(),
             })),
             Err(e) => {
-                error!("Ошибка удаления проекта: {}", e);
-                Ok(Response::new(UnregisterProjectResponse {
+                error!("Ошибка сохранения таймлайна: {}", e);
+                Ok(Response::new(SaveTimelineResponse {
+                    success: false,
+                    error_message: e,
+                }))
+            }
+        }
+    }
+
+    async fn load_timeline(
+        &self,
+        request: Request<LoadTimelineRequest>,
+    ) -> Result<Response<LoadTimelineResponse>, Status> {
+        let req = request.into_inner();
+        info!("Загрузка таймлайна проекта: {}", req.project_id);
+
+        let project = self
+            .manager
+            .lock()
+            .map_err(|e| {
+                error!("Ошибка блокировки менеджера: {}", e);
+                Status::internal("Внутренняя ошибка сервера")
+            })?
+            .get_project(&req.project_id);
+
+        let result = match project {
+            // Таймлайн, который ещё не сохранялся, начинается с дорожек из настроек
+            Ok(project) => timeline::load(&self.files, &project.path)
+                .await
+                .map(|timeline| {
+                    timeline.unwrap_or_else(|| {
+                        Timeline::from_template(&self.settings().project_defaults.tracks)
+                    })
+                })
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+
+        match result {
+            Ok(timeline) => Ok(Response::new(LoadTimelineResponse {
+                success: true,
+                error_message: String::new(),
+                timeline: Some(timeline.into()),
+            })),
+            Err(e) => {
+                error!("Ошибка загрузки таймлайна: {}", e);
+                Ok(Response::new(LoadTimelineResponse {
+                    success: false,
+                    error_message: e,
+                    timeline: None,
+                }))
+            }
+        }
+    }
+
+    async fn add_asset(
+        &self,
+        request: Request<AddAssetRequest>,
+    ) -> Result<Response<AddAssetResponse>, Status> {
+        let req = request.into_inner();
+        info!("Добавление ассета {} в проект {}", req.path, req.project_id);
+
+        let project = self
+            .manager
+            .lock()
+            .map_err(|e| {
+                error!("Ошибка блокировки менеджера: {}", e);
+                Status::internal("Внутренняя ошибка сервера")
+            })?
+            .get_project(&req.project_id);
+
+        let new_asset = NewAsset {
+            kind: asset_kind(req.kind()),
+            path: req.path,
+            duration_us: req.duration_us,
+            checksum: Some(req.checksum).filter(|c| !c.is_empty()),
+        };
+
+        let result = match project {
+            Ok(project) => {
+                let _guard = self.assets_lock.lock().await;
+                assets::add(&self.files, &project.path, new_asset)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+            Err(e) => Err(e.to_string()),
+        };
+
+        match result {
+            Ok(asset) => Ok(Response::new(AddAssetResponse {
+                success: true,
+                error_message: String::new(),
+                asset: Some(AssetInfo::from(&asset)),
+            })),
+            Err(e) => {
+                error!("Ошибка добавления ассета: {}", e);
+                Ok(Response::new(AddAssetResponse {
+                    success: false,
+                    error_message: e,
+                    asset: None,
+                }))
+            }
+        }
+    }
+
+    async fn list_assets(
+        &self,
+        request: Request<ListAssetsRequest>,
+    ) -> Result<Response<ListAssetsResponse>, Status> {
+        let req = request.into_inner();
+        info!("Запрос ассетов проекта: {}", req.project_id);
+
+        let project = self
+            .manager
+            .lock()
+            .map_err(|e| {
+                error!("Ошибка блокировки менеджера: {}", e);
+                Status::internal("Внутренняя ошибка сервера")
+            })?
+            .get_project(&req.project_id);
+
+        let result = match project {
+            Ok(project) => assets::load(&self.files, &project.path)
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+
+        match result {
+            Ok(registry) => Ok(Response::new(ListAssetsResponse {
+                success: true,
+                error_message: String::new(),
+                assets: registry.assets.iter().map(AssetInfo::from).collect(),
+            })),
+            Err(e) => {
+                error!("Ошибка чтения ассетов: {}", e);
+                Ok(Response::new(ListAssetsResponse {
+                    success: false,
+                    error_message: e,
+                    assets: Vec::new(),
+                }))
+            }
+        }
+    }
+
+    async fn remove_asset(
+        &self,
+        request: Request<RemoveAssetRequest>,
+    ) -> Result<Response<RemoveAssetResponse>, Status> {
+        let req = request.into_inner();
+        info!("Удаление ассета {} из проекта {}", req.asset_id, req.project_id);
+
+        let project = self
+            .manager
+            .lock()
+            .map_err(|e| {
+                error!("Ошибка блокировки менеджера: {}", e);
+                Status::internal("Внутренняя ошибка сервера")
+            })?
+            .get_project(&req.project_id);
+
+        let result = match project {
+            Ok(project) => {
+                let _guard = self.assets_lock.lock().await;
+                assets::remove(&self.files, &project.path, &req.asset_id)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+            Err(e) => Err(e.to_string()),
+        };
+
+        match result {
+            Ok(asset) => Ok(Response::new(RemoveAssetResponse {
+                success: true,
+                error_message: String::new(),
+                asset: Some(AssetInfo::from(&asset)),
+            })),
+            Err(e) => {
+                error!("Ошибка удаления ассета: {}", e);
+                Ok(Response::new(RemoveAssetResponse {
+                    success: false,
+                    error_message: e,
+                    asset: None,
+                }))
+            }
+        }
+    }
+
+    async fn relink_asset(
+        &self,
+        request: Request<RelinkAssetRequest>,
+    ) -> Result<Response<RelinkAssetResponse>, Status> {
+        let req = request.into_inner();
+        info!(
+            "Перепривязка ассета {} проекта {} к {}",
+            req.asset_id, req.project_id, req.new_path
+        );
+
+        let project = self
+            .manager
+            .lock()
+            .map_err(|e| {
+                error!("Ошибка блокировки менеджера: {}", e);
+                Status::internal("Внутренняя ошибка сервера")
+            })?
+            .get_project(&req.project_id);
+
+        let result = match project {
+            Ok(project) => {
+                let _guard = self.assets_lock.lock().await;
+                assets::relink(&self.files, &project.path, &req.asset_id, req.new_path)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+            Err(e) => Err(e.to_string()),
+        };
+
+        match result {
+            Ok(asset) => Ok(Response::new(RelinkAssetResponse {
+                success: true,
+                error_message: String::new(),
+                asset: Some(AssetInfo::from(&asset)),
+            })),
+            Err(e) => {
+                error!("Ошибка перепривязки ассета: {}", e);
+                Ok(Response::new(RelinkAssetResponse {
+                    success: false,
+                    error_message: e,
+                    asset: None,
+                }))
+            }
+        }
+    }
+
+    async fn notify_file_moved(
+        &self,
+        request: Request<NotifyFileMovedRequest>,
+    ) -> Result<Response<NotifyFileMovedResponse>, Status> {
+        let req = request.into_inner();
+        info!("Файл перенесён: {} -> {}", req.old_path, req.new_path);
+
+        // Ассет может лежать вне папки проекта, поэтому проверяются все реестры
+        let projects = self
+            .manager
+            .lock()
+            .map_err(|e| {
+                error!("Ошибка блокировки менеджера: {}", e);
+                Status::internal("Внутренняя ошибка сервера")
+            })?
+            .list_projects();
+
+        let _guard = self.assets_lock.lock().await;
+        let mut relinked = 0;
+        let mut unchecked = 0;
+
+        for project in &projects {
+            match assets::relink_moved(&self.files, &project.path, &req.old_path, &req.new_path)
+                .await
+            {
+                Ok(count) => relinked += count as u32,
+                Err(e) => {
+                    warn!(
+                        "Не удалось обновить ассеты проекта {} ({}): {}",
+                        project.name, project.id, e
+                    );
+                    unchecked += 1;
+                }
+            }
+        }
+
+        if relinked > 0 {
+            info!("Перепривязано ассетов: {}", relinked);
+        }
+
+        Ok(Response::new(NotifyFileMovedResponse {
+            success: true,
+            error_message: String::new(),
+            relinked,
+            unchecked,
+        }))
+    }
+
+    async fn validate_project(
+        &self,
+        request: Request<ValidateProjectRequest>,
+    ) -> Result<Response<ValidateProjectResponse>, Status> {
+        let req = request.into_inner();
+        info!("Проверка проекта: {}", req.project_id);
+
+        let project = self
+            .manager
+            .lock()
+            .map_err(|e| {
+                error!("Ошибка блокировки менеджера: {}", e);
+                Status::internal("Внутренняя ошибка сервера")
+            })?
+            .get_project(&req.project_id);
+
+        let result = match project {
+            Ok(project) => validate_project(&self.files, &project.path)
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+
+        match result {
+            Ok(report) => Ok(Response::new(ValidateProjectResponse {
+                success: true,
+                error_message: String::new(),
+                issues: report.issues.iter().map(ClipIssue::from).collect(),
+                unchecked_assets: report.unchecked_assets,
+            })),
+            Err(e) => {
+                error!("Ошибка проверки проекта: {}", e);
+                Ok(Response::new(ValidateProjectResponse {
+                    success: false,
+                    error_message: e,
+                    issues: Vec::new(),
+                    unchecked_assets: 0,
+                }))
+            }
+        }
+    }
+
+    async fn export_project(
+        &self,
+        request: Request<ExportProjectRequest>,
+    ) -> Result<Response<ExportProjectResponse>, Status> {
+        let req = request.into_inner();
+        info!("Экспорт проекта: {}", req.project_id);
+
+        let project = self
+            .manager
+            .lock()
+            .map_err(|e| {
+                error!("Ошибка блокировки менеджера: {}", e);
+                Status::internal("Внутренняя ошибка сервера")
+            })?
+            .get_project(&req.project_id);
+
+        let result = match project {
+            Ok(project) => manifest::export(&self.files, &project)
+                .await
+                .and_then(|export| {
+                    Ok((project.clone(), export.manifest.to_json()?, export.external_files))
+                })
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+
+        match result {
+            Ok((project, manifest, external_files)) => {
+                Ok(Response::new(ExportProjectResponse {
+                    success: true,
+                    error_message: String::new(),
+                    project: Some(ProjectInfo::from(&project)),
+                    manifest,
+                    external_files: external_files.iter().map(ExternalAssetFile::from).collect(),
+                }))
+            }
+            Err(e) => {
+                error!("Ошибка экспорта проекта: {}", e);
+                Ok(Response::new(ExportProjectResponse {
+                    success: false,
+                    error_message: e,
+                    project: None,
+                    manifest: Vec::new(),
+                    external_files: Vec::new(),
+                }))
+            }
+        }
+    }
+
+    async fn import_project(
+        &self,
+        request: Request<ImportProjectRequest>,
+    ) -> Result<Response<ImportProjectResponse>, Status> {
+        let req = request.into_inner();
+        info!("Импорт проекта в {} (gateway: {})", req.path, req.file_gateway_id);
+
+        match self.import_manifest(req).await? {
+            Ok(metadata) => {
+                self.publish(ProjectEventType::ProjectRegistered, &metadata);
+                Ok(Response::new(ImportProjectResponse {
+                    success: true,
+                    error_message: String::new(),
+                    project: Some(ProjectInfo::from(&metadata)),
+                }))
+            }
+            Err(e) => {
+                error!("Ошибка импорта проекта: {}", e);
+                Ok(Response::new(ImportProjectResponse {
+                    success: false,
+                    error_message: e,
+                    project: None,
+                }))
+            }
+        }
+    }
+
+    async fn find_orphans(
+        &self,
+        _request: Request<FindOrphansRequest>,
+    ) -> Result<Response<FindOrphansResponse>, Status> {
+        info!("Поиск проектов без директории");
+
+        // Копия списка: лок не держится, пока идут запросы к FileGateway
+        let projects = self
+            .manager
+            .lock()
+            .map_err(|e| {
+                error!("Ошибка блокировки менеджера: {}", e);
+                Status::internal("Внутренняя ошибка сервера")
+            })?
+            .list_projects();
+
+        let report = find_orphans(&self.files, projects).await;
+
+        Ok(Response::new(FindOrphansResponse {
+            orphans: report.orphans.iter().map(ProjectInfo::from).collect(),
+            unchecked: report.unchecked,
+        }))
+    }
+
+    async fn prune_orphans(
+        &self,
+        _request: Request<PruneOrphansRequest>,
+    ) -> Result<Response<PruneOrphansResponse>, Status> {
+        info!("Удаление из реестра проектов без директории");
+
+        // Сироты определяются заново; лок не держится, пока идут запросы к FileGateway
+        let projects = self
+            .manager
+            .lock()
+            .map_err(|e| {
+                error!("Ошибка блокировки менеджера: {}", e);
+                Status::internal("Внутренняя ошибка сервера")
+            })?
+            .list_projects();
+
+        let report = find_orphans(&self.files, projects).await;
+        let orphan_ids: Vec<String> = report.orphans.into_iter().map(|p| p.id).collect();
+
+        let result = self
+            .update(|manager| Ok(manager.remove_projects(&orphan_ids)))
+            .await?;
+
+        match result {
+            Ok(removed) => {
+                info!("Удалено из реестра проектов: {}", removed.len());
+                for project in &removed {
+                    self.publish(ProjectEventType::ProjectUnregistered, project);
+                }
+                Ok(Response::new(PruneOrphansResponse {
+                    success: true,
+                    error_message: String::new(),
+                    removed: removed.iter().map(ProjectInfo::from).collect(),
+                    unchecked: report.unchecked,
+                }))
+            }
+            Err(e) => {
+                error!("Ошибка удаления проектов без директории: {}", e);
+                Ok(Response::new(PruneOrphansResponse {
                     success: false,
                     error_message: e.to_string(),
+                    removed: Vec::new(),
+                    unchecked: report.unchecked,
                 }))
             }
         }
     }
+
+    type SubscribeEventsStream = Pin<Box<dyn Stream<Item = Result<ProjectEvent, Status>> + Send>>;
+
+    async fn subscribe_events(
+        &self,
+        _request: Request<SubscribeEventsRequest>,
+    ) -> Result<Response<Self::SubscribeEventsStream>, Status> {
+        info!("Новая подписка на события проектов");
+
+        let mut receiver = self.events.subscribe();
+
+        let stream = async_stream::try_stream! {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => yield event,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        // Пропущенные события не восстановить - клиент перечитает список
+                        warn!("Подписчик отстал, пропущено событий: {}", skipped);
+                        Err(Status::data_loss(format!("Пропущено событий: {}", skipped)))?;
+                    }
+                }
+            }
+        };
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::proto::{
+        ListProjectsRequest, OpenProjectRequest, ProjectInfo, RegisterProjectRequest,
+        UnregisterProjectRequest, UpdateProjectRequest,
+    };
+    use crate::testing::TestEngine;
+
+    /// FileGateway не нужен: регистрация и изменение проектов работают только с реестром
+    const UNUSED_FILE_GATEWAY: &str = "http://127.0.0.1:1";
+
+    async fn register(engine: &TestEngine, name: &str) -> ProjectInfo {
+        let response = engine
+            .client
+            .clone()
+            .register_project(RegisterProjectRequest {
+                name: name.to_string(),
+                path: format!("/projects/{}", name),
+                file_gateway_id: "local".to_string(),
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(response.success, "{}", response.error_message);
+        response.project.expect("Ответ без проекта")
+    }
+
+    async fn list(engine: &TestEngine) -> Vec<ProjectInfo> {
+        engine
+            .client
+            .clone()
+            .list_projects(ListProjectsRequest::default())
+            .await
+            .unwrap()
+            .into_inner()
+            .projects
+    }
+
+    #[tokio::test]
+    async fn registered_project_is_listed_and_opened() {
+        let engine = TestEngine::start(UNUSED_FILE_GATEWAY).await;
+        let project = register(&engine, "Alpha").await;
+
+        let projects = list(&engine).await;
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].id, project.id);
+
+        let opened = engine
+            .client
+            .clone()
+            .open_project(OpenProjectRequest {
+                project_id: project.id.clone(),
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(opened.success, "{}", opened.error_message);
+        assert_eq!(opened.project.unwrap().created_at, project.created_at);
+    }
+
+    #[tokio::test]
+    async fn duplicate_path_is_reported_in_response() {
+        let engine = TestEngine::start(UNUSED_FILE_GATEWAY).await;
+        register(&engine, "Alpha").await;
+
+        let response = engine
+            .client
+            .clone()
+            .register_project(RegisterProjectRequest {
+                name: "Beta".to_string(),
+                path: "/projects/Alpha".to_string(),
+                file_gateway_id: "local".to_string(),
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(!response.success);
+        assert!(!response.error_message.is_empty());
+        assert_eq!(list(&engine).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn update_is_visible_in_list() {
+        let engine = TestEngine::start(UNUSED_FILE_GATEWAY).await;
+        let project = register(&engine, "Alpha").await;
+
+        let response = engine
+            .client
+            .clone()
+            .update_project(UpdateProjectRequest {
+                project_id: project.id.clone(),
+                color: Some("#00FF00".to_string()),
+                is_favorite: Some(true),
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(response.success, "{}", response.error_message);
+
+        let projects = list(&engine).await;
+        assert_eq!(projects[0].color, "#00ff00");
+        assert!(projects[0].is_favorite);
+        assert_eq!(projects[0].created_at, project.created_at);
+    }
+
+    #[tokio::test]
+    async fn unregistered_project_disappears_from_list() {
+        let engine = TestEngine::start(UNUSED_FILE_GATEWAY).await;
+        let project = register(&engine, "Alpha").await;
+
+        let response = engine
+            .client
+            .clone()
+            .unregister_project(UnregisterProjectRequest {
+                project_id: project.id,
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(response.success, "{}", response.error_message);
+        assert!(list(&engine).await.is_empty());
+    }
 }
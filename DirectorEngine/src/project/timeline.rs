@@ -0,0 +1,278 @@
+//! Таймлайн проекта: дорожки и клипы монтажа
+//!
+//! Хранится в `timeline/timeline.json` внутри папки проекта на FileGateway.
+//! Формат - JSON с номером версии схемы; файлы более новой версии, чем
+//! поддерживает движок, не читаются, чтобы не потерять незнакомые поля.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::config::TrackTemplate;
+use crate::file_gateway::FileGateway;
+use crate::proto;
+
+/// Текущая версия схемы таймлайна
+pub const TIMELINE_VERSION: u32 = 1;
+
+/// Папка таймлайна внутри проекта (создаётся `InitProjectStructure`)
+const TIMELINE_DIR: &str = "timeline";
+const TIMELINE_FILE: &str = "timeline.json";
+
+#[derive(Error, Debug)]
+pub enum TimelineError {
+    #[error("Ошибка сериализации: {0}")]
+    SerializationError(#[from] serde_json::Error),
+
+    #[error("Версия таймлайна {0} не поддерживается (поддерживается до {TIMELINE_VERSION})")]
+    UnsupportedVersion(u32),
+
+    #[error("Некорректный таймлайн: {0}")]
+    Invalid(String),
+
+    #[error("Ошибка FileGateway: {0}")]
+    FileGateway(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrackKind {
+    Video,
+    Audio,
+}
+
+/// Фрагмент исходного ассета на дорожке
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Clip {
+    pub id: String,
+    /// ID ассета из реестра проекта
+    pub asset: String,
+    /// Начало фрагмента в ассете (мкс)
+    pub in_point_us: u64,
+    /// Конец фрагмента в ассете (мкс, не включительно)
+    pub out_point_us: u64,
+    /// Позиция клипа на таймлайне (мкс)
+    pub start_us: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Track {
+    pub id: String,
+    pub name: String,
+    pub kind: TrackKind,
+    #[serde(default)]
+    pub clips: Vec<Clip>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Timeline {
+    pub version: u32,
+    #[serde(default)]
+    pub tracks: Vec<Track>,
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self {
+            version: TIMELINE_VERSION,
+            tracks: Vec::new(),
+        }
+    }
+}
+
+impl Timeline {
+    /// Пустой таймлайн с дорожками по шаблону
+    pub fn from_template(tracks: &[TrackTemplate]) -> Self {
+        Self {
+            version: TIMELINE_VERSION,
+            tracks: tracks
+                .iter()
+                .map(|template| Track {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    name: template.name.clone(),
+                    kind: template.kind,
+                    clips: Vec::new(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Разобрать файл таймлайна с проверкой версии схемы
+    pub fn from_json(data: &[u8]) -> Result<Self, TimelineError> {
+        // Сначала читаем только версию: поля новой схемы могут не разобраться
+        #[derive(Deserialize)]
+        struct Header {
+            version: u32,
+        }
+
+        let header: Header = serde_json::from_slice(data)?;
+        if header.version == 0 || header.version > TIMELINE_VERSION {
+            return Err(TimelineError::UnsupportedVersion(header.version));
+        }
+
+        Ok(serde_json::from_slice(data)?)
+    }
+
+    pub fn to_json(&self) -> Result<Vec<u8>, TimelineError> {
+        Ok(serde_json::to_vec_pretty(self)?)
+    }
+
+    /// Проверить целостность: уникальные ID и корректные точки клипов
+    pub fn validate(&self) -> Result<(), TimelineError> {
+        let mut track_ids = HashSet::new();
+        let mut clip_ids = HashSet::new();
+
+        for track in &self.tracks {
+            if track.id.is_empty() {
+                return invalid("у дорожки пустой ID".to_string());
+            }
+            if !track_ids.insert(track.id.as_str()) {
+                return invalid(format!("ID дорожки `{}` повторяется", track.id));
+            }
+
+            for clip in &track.clips {
+                if clip.id.is_empty() {
+                    return invalid(format!("у клипа на дорожке `{}` пустой ID", track.id));
+                }
+                if !clip_ids.insert(clip.id.as_str()) {
+                    return invalid(format!("ID клипа `{}` повторяется", clip.id));
+                }
+                if clip.asset.is_empty() {
+                    return invalid(format!("у клипа `{}` не указан ассет", clip.id));
+                }
+                if clip.out_point_us <= clip.in_point_us {
+                    return invalid(format!(
+                        "у клипа `{}` конец фрагмента не позже начала",
+                        clip.id
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn invalid(message: String) -> Result<(), TimelineError> {
+    Err(TimelineError::Invalid(message))
+}
+
+/// Путь к папке таймлайна проекта на FileGateway
+fn timeline_dir(project_path: &str) -> String {
+    format!("{}/{}", project_path.trim_end_matches(['/', '\\']), TIMELINE_DIR)
+}
+
+/// Загрузить таймлайн проекта (`None`, если он ещё не сохранялся)
+pub async fn load(
+    files: &FileGateway,
+    project_path: &str,
+) -> Result<Option<Timeline>, TimelineError> {
+    let path = format!("{}/{}", timeline_dir(project_path), TIMELINE_FILE);
+
+    let data = files
+        .read_file(&path)
+        .await
+        .map_err(|e| TimelineError::FileGateway(e.message().to_string()))?;
+
+    data.map(|data| Timeline::from_json(&data)).transpose()
+}
+
+/// Сохранить таймлайн проекта в текущей версии схемы
+pub async fn save(
+    files: &FileGateway,
+    project_path: &str,
+    mut timeline: Timeline,
+) -> Result<(), TimelineError> {
+    timeline.validate()?;
+    timeline.version = TIMELINE_VERSION;
+
+    files
+        .write_file(&timeline_dir(project_path), TIMELINE_FILE, timeline.to_json()?)
+        .await
+        .map_err(|e| TimelineError::FileGateway(e.message().to_string()))
+}
+
+/// Тип дорожки из запроса (`None` - не указан)
+fn track_kind(kind: proto::TrackKind) -> Option<TrackKind> {
+    match kind {
+        proto::TrackKind::Unspecified => None,
+        proto::TrackKind::Video => Some(TrackKind::Video),
+        proto::TrackKind::Audio => Some(TrackKind::Audio),
+    }
+}
+
+impl From<TrackKind> for proto::TrackKind {
+    fn from(kind: TrackKind) -> Self {
+        match kind {
+            TrackKind::Video => proto::TrackKind::Video,
+            TrackKind::Audio => proto::TrackKind::Audio,
+        }
+    }
+}
+
+impl TryFrom<proto::Timeline> for Timeline {
+    type Error = TimelineError;
+
+    fn try_from(timeline: proto::Timeline) -> Result<Self, Self::Error> {
+        let tracks = timeline
+            .tracks
+            .into_iter()
+            .map(|track| {
+                let kind = track_kind(track.kind()).ok_or_else(|| {
+                    TimelineError::Invalid(format!("не указан тип дорожки `{}`", track.id))
+                })?;
+
+                Ok(Track {
+                    id: track.id,
+                    name: track.name,
+                    kind,
+                    clips: track
+                        .clips
+                        .into_iter()
+                        .map(|clip| Clip {
+                            id: clip.id,
+                            asset: clip.asset,
+                            in_point_us: clip.in_point_us,
+                            out_point_us: clip.out_point_us,
+                            start_us: clip.start_us,
+                        })
+                        .collect(),
+                })
+            })
+            .collect::<Result<_, TimelineError>>()?;
+
+        Ok(Timeline {
+            version: TIMELINE_VERSION,
+            tracks,
+        })
+    }
+}
+
+impl From<Timeline> for proto::Timeline {
+    fn from(timeline: Timeline) -> Self {
+        proto::Timeline {
+            version: timeline.version,
+            tracks: timeline
+                .tracks
+                .into_iter()
+                .map(|track| proto::TimelineTrack {
+                    id: track.id,
+                    name: track.name,
+                    kind: proto::TrackKind::from(track.kind) as i32,
+                    clips: track
+                        .clips
+                        .into_iter()
+                        .map(|clip| proto::TimelineClip {
+                            id: clip.id,
+                            asset: clip.asset,
+                            in_point_us: clip.in_point_us,
+                            out_point_us: clip.out_point_us,
+                            start_us: clip.start_us,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
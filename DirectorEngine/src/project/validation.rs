@@ -0,0 +1,132 @@
+//! Проверка целостности проекта: клипы таймлайна и ассеты, на которые они ссылаются
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+use tracing::warn;
+
+use crate::file_gateway::FileGateway;
+use crate::project::assets::{self, AssetError};
+use crate::project::timeline::{self, TimelineError};
+use crate::proto;
+
+#[derive(Error, Debug)]
+pub enum ValidationError {
+    #[error(transparent)]
+    Timeline(#[from] TimelineError),
+
+    #[error(transparent)]
+    Assets(#[from] AssetError),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipIssueKind {
+    /// Ассета с таким ID нет в реестре проекта
+    UnknownAsset,
+    /// Ассет зарегистрирован, но его файла нет на FileGateway
+    MissingFile,
+}
+
+/// Клип, ссылающийся на недоступный ассет
+pub struct ClipIssue {
+    pub track_id: String,
+    pub clip_id: String,
+    pub asset_id: String,
+    pub kind: ClipIssueKind,
+    /// Путь файла ассета (для `MissingFile`)
+    pub path: Option<String>,
+}
+
+/// Результат проверки проекта
+pub struct ValidationReport {
+    pub issues: Vec<ClipIssue>,
+    /// Сколько ассетов не удалось проверить (FileGateway недоступен и т.п.)
+    pub unchecked_assets: u32,
+}
+
+/// Найти клипы, ссылающиеся на незарегистрированные или пропавшие ассеты
+///
+/// Файл каждого ассета проверяется один раз, сколько бы клипов на него ни ссылалось.
+pub async fn validate_project(
+    files: &FileGateway,
+    project_path: &str,
+) -> Result<ValidationReport, ValidationError> {
+    let timeline = timeline::load(files, project_path).await?.unwrap_or_default();
+    let registry = assets::load(files, project_path).await?;
+
+    let paths: HashMap<&str, &str> = registry
+        .assets
+        .iter()
+        .map(|asset| (asset.id.as_str(), asset.path.as_str()))
+        .collect();
+
+    // Результат проверки файла по ID ассета: `None` - проверить не удалось
+    let mut exists: HashMap<&str, Option<bool>> = HashMap::new();
+    let mut report = ValidationReport {
+        issues: Vec::new(),
+        unchecked_assets: 0,
+    };
+
+    for track in &timeline.tracks {
+        for clip in &track.clips {
+            let Some(&path) = paths.get(clip.asset.as_str()) else {
+                report.issues.push(ClipIssue {
+                    track_id: track.id.clone(),
+                    clip_id: clip.id.clone(),
+                    asset_id: clip.asset.clone(),
+                    kind: ClipIssueKind::UnknownAsset,
+                    path: None,
+                });
+                continue;
+            };
+
+            let found = match exists.get(clip.asset.as_str()) {
+                Some(found) => *found,
+                None => {
+                    let found = match files.exists(path).await {
+                        Ok(found) => Some(found),
+                        Err(e) => {
+                            warn!(
+                                "Не удалось проверить файл ассета {} ({}): {}",
+                                clip.asset, path, e
+                            );
+                            report.unchecked_assets += 1;
+                            None
+                        }
+                    };
+                    exists.insert(clip.asset.as_str(), found);
+                    found
+                }
+            };
+
+            if found == Some(false) {
+                report.issues.push(ClipIssue {
+                    track_id: track.id.clone(),
+                    clip_id: clip.id.clone(),
+                    asset_id: clip.asset.clone(),
+                    kind: ClipIssueKind::MissingFile,
+                    path: Some(path.to_string()),
+                });
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+impl From<&ClipIssue> for proto::ClipIssue {
+    fn from(issue: &ClipIssue) -> Self {
+        let kind = match issue.kind {
+            ClipIssueKind::UnknownAsset => proto::ClipIssueKind::UnknownAsset,
+            ClipIssueKind::MissingFile => proto::ClipIssueKind::MissingFile,
+        };
+
+        proto::ClipIssue {
+            track_id: issue.track_id.clone(),
+            clip_id: issue.clip_id.clone(),
+            asset_id: issue.asset_id.clone(),
+            kind: kind as i32,
+            path: issue.path.clone().unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Перечитывание конфигурации движка по `SIGHUP`
+//!
+//! По сигналу файл конфигурации (`--config` или файл по умолчанию) читается
+//! заново и применяется к следующим запросам без разрыва подключений:
+//! форматы (с повторным определением по ffmpeg), настройки новых проектов,
+//! уникальность названий. Изменение настроек из `RESTART_ONLY` пишется в
+//! лог и действует только после перезапуска. Фильтр логов меняется
+//! сигналами `SIGUSR1`/`SIGUSR2` (см. `logging`).
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::config::EngineConfig;
+use crate::project::service::ProjectServiceImpl;
+
+/// Настройки, которые применяются только при перезапуске
+///
+/// - `data_dir` - реестр проектов уже загружен из прежней директории
+const RESTART_ONLY: &[&str] = &["data_dir"];
+
+/// Перечитывает конфигурацию и применяет её к сервису
+pub struct Reloader {
+    path: Option<PathBuf>,
+    /// Действующая конфигурация
+    current: EngineConfig,
+    service: Arc<ProjectServiceImpl>,
+}
+
+impl Reloader {
+    pub fn new(
+        path: Option<PathBuf>,
+        current: EngineConfig,
+        service: Arc<ProjectServiceImpl>,
+    ) -> Self {
+        Self {
+            path,
+            current,
+            service,
+        }
+    }
+
+    /// Перечитать файл и применить изменения, не требующие перезапуска
+    async fn reload(&mut self) -> Result<(), String> {
+        let path = self
+            .path
+            .clone()
+            .ok_or_else(|| "файл конфигурации не задан".to_string())?;
+        let config = EngineConfig::load(&path)
+            .map_err(|e| format!("не удалось загрузить {}: {}", path.display(), e))?;
+        let mut config = config.prepare().await.map_err(|e| e.to_string())?;
+
+        let (restart, live): (Vec<String>, Vec<String>) = changed_fields(&self.current, &config)
+            .into_iter()
+            .partition(|field| RESTART_ONLY.contains(&field.as_str()));
+
+        if !restart.is_empty() {
+            warn!(
+                "Изменения требуют перезапуска и пока не применены: {}",
+                restart.join(", ")
+            );
+        }
+        if live.is_empty() {
+            info!("Конфигурация перечитана, применять нечего");
+            return Ok(());
+        }
+
+        config.data_dir = self.current.data_dir.clone();
+        self.service.apply_config(&config);
+        info!("Поддерживаемые форматы: {:?}", config.supported_formats);
+
+        self.current = config;
+        info!("Конфигурация применена, изменено: {}", live.join(", "));
+        Ok(())
+    }
+}
+
+/// Верхнеуровневые поля, значения которых различаются
+fn changed_fields<T: Serialize>(old: &T, new: &T) -> Vec<String> {
+    let (Ok(serde_json::Value::Object(old)), Ok(serde_json::Value::Object(new))) =
+        (serde_json::to_value(old), serde_json::to_value(new))
+    else {
+        return Vec::new();
+    };
+
+    let mut changed: Vec<String> = old
+        .keys()
+        .chain(new.keys())
+        .filter(|key| old.get(*key) != new.get(*key))
+        .cloned()
+        .collect();
+    changed.sort();
+    changed.dedup();
+    changed
+}
+
+/// Запустить обработчик `SIGHUP`
+#[cfg(unix)]
+pub fn spawn_reload_handler(mut reloader: Reloader) {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    tokio::spawn(async move {
+        let mut hangup = match signal(SignalKind::hangup()) {
+            Ok(hangup) => hangup,
+            Err(e) => {
+                warn!("Не удалось установить обработчик SIGHUP: {}", e);
+                return;
+            }
+        };
+
+        while hangup.recv().await.is_some() {
+            info!("Получен SIGHUP, перечитывание конфигурации");
+            if let Err(e) = reloader.reload().await {
+                warn!("Конфигурация не перечитана: {}", e);
+            }
+        }
+    });
+}
+
+/// На платформах без POSIX-сигналов конфигурация меняется только перезапуском
+#[cfg(not(unix))]
+pub fn spawn_reload_handler(_reloader: Reloader) {}
@@ -0,0 +1,79 @@
+//! Запуск DirectorEngine в процессе теста
+//!
+//! Движок слушает свободный порт на loopback, реестр проектов хранится
+//! только в памяти (`ProjectManager::in_memory`) и не трогает директорию
+//! данных разработчика. Сервер останавливается вместе с `TestEngine`.
+
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+
+use director_common::bind;
+use director_common::health::HealthService;
+use tokio::task::JoinHandle;
+use tonic::transport::{Channel, Server};
+
+use crate::config::EngineConfig;
+use crate::file_gateway::FileGateway;
+use crate::project::manager::ProjectManager;
+use crate::project::service::ProjectServiceImpl;
+use crate::proto::project_service_client::ProjectServiceClient;
+use crate::proto::project_service_server::{self, ProjectServiceServer};
+
+/// DirectorEngine, запущенный тестом
+pub struct TestEngine {
+    /// Адрес для клиентов (`http://127.0.0.1:<порт>`)
+    pub address: String,
+    pub client: ProjectServiceClient<Channel>,
+    /// Сервис, которому клиент отправляет запросы
+    pub service: Arc<ProjectServiceImpl>,
+    server: JoinHandle<()>,
+}
+
+impl TestEngine {
+    /// Движок с конфигурацией по умолчанию
+    ///
+    /// * `file_gateway_address` - FileGateway для проверки путей проектов;
+    ///   подключение ленивое, поэтому тестам без файлов подойдёт любой адрес
+    pub async fn start(file_gateway_address: &str) -> Self {
+        Self::with_config(file_gateway_address, &EngineConfig::default()).await
+    }
+
+    pub async fn with_config(file_gateway_address: &str, config: &EngineConfig) -> Self {
+        let files = FileGateway::new(file_gateway_address)
+            .expect("Некорректный адрес FileGateway");
+        let service = Arc::new(ProjectServiceImpl::new(ProjectManager::in_memory(), files, config));
+
+        let (incoming, local_addr) = bind::listen(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)))
+            .await
+            .expect("Не удалось открыть сокет DirectorEngine");
+
+        let server = tokio::spawn({
+            let service = service.clone();
+            async move {
+                let _ = Server::builder()
+                    .add_service(ProjectServiceServer::from_arc(service))
+                    .add_service(HealthService::server(project_service_server::SERVICE_NAME))
+                    .serve_with_incoming(incoming)
+                    .await;
+            }
+        });
+
+        let address = format!("http://{}", local_addr);
+        let client = ProjectServiceClient::connect(address.clone())
+            .await
+            .expect("Не удалось подключиться к DirectorEngine");
+
+        Self {
+            address,
+            client,
+            service,
+            server,
+        }
+    }
+}
+
+impl Drop for TestEngine {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
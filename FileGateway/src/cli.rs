@@ -0,0 +1,26 @@
+//! Аргументы командной строки
+//!
+//! Приоритет: флаги командной строки > переменные окружения > значения по умолчанию.
+
+use clap::Parser;
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+#[command(name = "file-gateway", version, about = "Сервис управления файлами и хранилищем")]
+pub struct Args {
+    /// Адрес для входящих подключений (например, `0.0.0.0` или `[::]:50052`)
+    #[arg(long, env = "FILE_GATEWAY_BIND")]
+    pub bind: Option<String>,
+
+    /// Порт (имеет приоритет над портом из `--bind`)
+    #[arg(long, env = "FILE_GATEWAY_PORT")]
+    pub port: Option<u16>,
+
+    /// Путь к JSON-файлу конфигурации хранилища
+    #[arg(long, env = "STORAGE_CONFIG")]
+    pub config: Option<PathBuf>,
+
+    /// Корневая директория локального хранилища (вместо домашней)
+    #[arg(long, env = "STORAGE_ROOT")]
+    pub storage_root: Option<PathBuf>,
+}
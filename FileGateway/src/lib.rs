@@ -0,0 +1,27 @@
+//! FileGateway - доступ к файлам и хранилищам по gRPC
+//!
+//! Библиотека нужна исполняемому файлу и тестам других сервисов, которые
+//! запускают FileGateway в своём процессе (`testing`).
+
+pub mod reload;
+pub mod service;
+pub mod storage;
+
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Utc};
+
+pub mod proto {
+    tonic::include_proto!("file_gateway");
+}
+
+/// Время запуска процесса
+static STARTED_AT: OnceLock<DateTime<Utc>> = OnceLock::new();
+
+/// Время запуска процесса (задаётся при первом вызове в начале `main`)
+pub fn started_at() -> DateTime<Utc> {
+    *STARTED_AT.get_or_init(Utc::now)
+}
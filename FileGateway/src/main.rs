@@ -1,37 +1,123 @@
-mod service;
-pub mod storage;
+mod cli;
 
-use tonic::transport::Server;
-use tracing::info;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use std::sync::Arc;
 
-use service::FileGatewayImpl;
+use clap::Parser;
+use director_common::health::HealthService;
+use director_common::{bind, logging};
+use tonic::transport::Server;
+use tracing::{error, info};
 
-pub mod proto {
-    tonic::include_proto!("file_gateway");
-}
+use file_gateway::proto::file_gateway_server::{self, FileGatewayServer};
+use file_gateway::service::FileGatewayImpl;
+use file_gateway::storage::{
+    self, EncryptionKey, ReloadableStorageProvider, StorageConfig, StorageError, StorageProvider,
+};
+use file_gateway::{reload, started_at};
 
-use proto::file_gateway_server::FileGatewayServer;
+const DEFAULT_PORT: u16 = 50052;
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    started_at();
+
     // Инициализация логирования
-    tracing_subscriber::registry()
-        .with(tracing_subscriber::fmt::layer())
-        .with(tracing_subscriber::EnvFilter::from_default_env())
-        .init();
+    let log_handle = logging::init(env!("CARGO_PKG_NAME"));
+    logging::spawn_signal_handler(log_handle);
+
+    let args = cli::Args::parse();
+    let addr = bind::resolve(args.bind.as_deref(), args.port, DEFAULT_PORT)?;
+
+    // Конфигурация: --config / STORAGE_CONFIG, затем файл по умолчанию,
+    // иначе локальный провайдер с настройками по умолчанию
+    let config_path = args
+        .config
+        .clone()
+        .or_else(|| StorageConfig::default_path().filter(|p| p.exists()));
+
+    let mut config = match &config_path {
+        Some(path) => {
+            info!("Загрузка конфигурации хранилища: {}", path.display());
+            StorageConfig::load(path).map_err(|e| {
+                format!("Не удалось загрузить конфигурацию {}: {}", path.display(), e)
+            })?
+        }
+        None => {
+            info!("Файл конфигурации не найден, используется локальное хранилище");
+            StorageConfig::local()
+        }
+    };
+
+    if let Some(root) = &args.storage_root {
+        config.root_path = Some(root.to_string_lossy().to_string());
+    }
+
+    info!("Используется провайдер: {}", config.storage_type);
+    info!(
+        "Настройки хранилища: show_hidden={}, root_path={:?}, default_projects_path={:?}, sniff_mime={}",
+        config.show_hidden, config.root_path, config.default_projects_path, config.sniff_mime
+    );
+
+    if !config.root_paths.is_empty() {
+        info!(
+            "Настроенные корневые пути ({:?}): {:?}",
+            config.root_paths_mode, config.root_paths
+        );
+    }
+
+    if let Some(quota) = config.quota_bytes {
+        info!("Квота хранилища: {} байт, подсчёт занятого объёма...", quota);
+    }
+
+    let init_error = |e: StorageError| {
+        error!("Не удалось инициализировать хранилище {}: {}", config.storage_type, e);
+        format!("Не удалось инициализировать хранилище {}: {}", config.storage_type, e)
+    };
+    // Ключ читается один раз: при перечитывании конфигурации используется он же
+    let encryption_key =
+        config.encryption.as_ref().map(EncryptionKey::load).transpose().map_err(init_error)?;
+    let provider = storage::create_provider_with_key(&config, encryption_key.as_ref())
+        .map_err(init_error)?;
+
+    // Другие хранилища - только источники и назначения `Transfer`
+    let mut transfer_storages: Vec<Arc<dyn StorageProvider>> = Vec::new();
+    for storage_config in &config.transfer_storages {
+        let id = storage_config.id.as_deref().ok_or_else(|| {
+            format!("У хранилища {} в transfer_storages не задан id", storage_config.storage_type)
+        })?;
+        if id == provider.id() || transfer_storages.iter().any(|p| p.id() == id) {
+            return Err(format!("ID хранилища {} в transfer_storages повторяется", id).into());
+        }
+
+        let transfer_storage = storage::create_provider(storage_config).map_err(|e| {
+            error!("Не удалось инициализировать хранилище {}: {}", id, e);
+            format!("Не удалось инициализировать хранилище {}: {}", id, e)
+        })?;
+        info!("Хранилище для переноса: {} ({})", id, storage_config.storage_type);
+        transfer_storages.push(transfer_storage);
+    }
 
-    let addr = "[::1]:50052".parse()?;
-    
-    // Создаём сервис с локальным провайдером (по умолчанию)
-    let file_gateway = FileGatewayImpl::new()?;
+    // Провайдер заменяется по SIGHUP без разрыва подключений
+    let provider = Arc::new(ReloadableStorageProvider::new(provider));
+    reload::spawn_reload_handler(reload::Reloader::new(
+        config_path,
+        args.storage_root.clone(),
+        config,
+        encryption_key,
+        provider.clone(),
+    ));
+    let file_gateway = transfer_storages
+        .into_iter()
+        .fold(FileGatewayImpl::with_provider(provider), FileGatewayImpl::with_transfer_storage);
 
-    info!("FileGateway gRPC сервер запущен на {}", addr);
-    info!("Используется провайдер: LocalStorageProvider");
+    let (incoming, local_addr) = bind::listen(addr).await?;
+    info!("FileGateway gRPC сервер запущен на {}", local_addr);
 
     Server::builder()
+        .trace_fn(logging::rpc_span)
         .add_service(FileGatewayServer::new(file_gateway))
-        .serve(addr)
+        .add_service(HealthService::server(file_gateway_server::SERVICE_NAME))
+        .serve_with_incoming(incoming)
         .await?;
 
     Ok(())
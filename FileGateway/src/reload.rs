@@ -0,0 +1,235 @@
+//! Перечитывание конфигурации хранилища по `SIGHUP`
+//!
+//! По сигналу файл конфигурации (`--config` или файл по умолчанию) читается
+//! заново, провайдер собирается по новой конфигурации и заменяет текущий.
+//! Подключения не разрываются: начатые операции завершаются со старым
+//! провайдером. Так применяются все настройки, кроме перечисленных в
+//! `RESTART_ONLY`: их изменение пишется в лог, а до перезапуска действуют
+//! прежние значения. `--storage-root` из командной строки по-прежнему
+//! имеет приоритет над файлом. Фильтр логов меняется сигналами
+//! `SIGUSR1`/`SIGUSR2` (см. `logging`).
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::storage::{
+    create_provider_with_key, EncryptionKey, ReloadableStorageProvider, StorageConfig,
+    StorageProvider, StorageType,
+};
+
+/// Настройки, которые применяются только при перезапуске
+///
+/// - `storage_type`, `id` - для клиентов и реестра проектов это другое хранилище
+/// - `encryption` - записанные файлы зашифрованы прежним ключом; новый
+///   провайдер получает ключ, прочитанный при запуске
+/// - `quota_bytes` - занятый объём учитывается с момента запуска; новый
+///   провайдер получает квоту прежнего (`Quota::shared`)
+/// - `transfer_storages` - провайдеры других хранилищ создаются при запуске
+const RESTART_ONLY: &[&str] = &[
+    "storage_type",
+    "id",
+    "encryption",
+    "quota_bytes",
+    "transfer_storages",
+];
+
+/// Перечитывает конфигурацию и заменяет провайдер
+pub struct Reloader {
+    path: Option<PathBuf>,
+    /// `--storage-root`, заменяющий `root_path` из файла
+    storage_root: Option<PathBuf>,
+    /// Действующая конфигурация
+    current: StorageConfig,
+    /// Ключ шифрования, прочитанный при запуске
+    encryption_key: Option<EncryptionKey>,
+    provider: Arc<ReloadableStorageProvider>,
+}
+
+impl Reloader {
+    pub fn new(
+        path: Option<PathBuf>,
+        storage_root: Option<PathBuf>,
+        mut current: StorageConfig,
+        encryption_key: Option<EncryptionKey>,
+        provider: Arc<ReloadableStorageProvider>,
+    ) -> Self {
+        // ID, сгенерированный при запуске, сохраняется при замене провайдера
+        current.id = Some(provider.id().to_string());
+
+        Self {
+            path,
+            storage_root,
+            current,
+            encryption_key,
+            provider,
+        }
+    }
+
+    /// Перечитать файл и применить изменения, не требующие перезапуска
+    async fn reload(&mut self) -> Result<(), String> {
+        let path = self
+            .path
+            .clone()
+            .ok_or_else(|| "файл конфигурации не задан".to_string())?;
+        let mut config = StorageConfig::load(&path)
+            .map_err(|e| format!("не удалось загрузить {}: {}", path.display(), e))?;
+
+        if let Some(root) = &self.storage_root {
+            config.root_path = Some(root.to_string_lossy().to_string());
+        }
+        if config.id.is_none() {
+            config.id = self.current.id.clone();
+        }
+
+        let (restart, live): (Vec<String>, Vec<String>) = changed_fields(&self.current, &config)
+            .into_iter()
+            .partition(|field| RESTART_ONLY.contains(&field.as_str()));
+
+        if !restart.is_empty() {
+            warn!(
+                "Изменения требуют перезапуска и пока не применены: {}",
+                restart.join(", ")
+            );
+        }
+        if live.is_empty() {
+            info!("Конфигурация перечитана, применять нечего");
+            return Ok(());
+        }
+
+        // Новый провайдер в памяти начал бы с пустого хранилища
+        if self.current.storage_type.as_str() == StorageType::MEMORY {
+            warn!("Хранилище в памяти не пересоздаётся, изменения применятся после перезапуска");
+            return Ok(());
+        }
+
+        config.storage_type = self.current.storage_type.clone();
+        config.id = self.current.id.clone();
+        config.encryption = self.current.encryption.clone();
+        config.quota_bytes = self.current.quota_bytes;
+        config.transfer_storages = self.current.transfer_storages.clone();
+
+        // Сборка провайдера может читать диск (проверка корня, новая квота)
+        let new_config = config.clone();
+        let key = self.encryption_key.clone();
+        let provider =
+            tokio::task::spawn_blocking(move || create_provider_with_key(&new_config, key.as_ref()))
+            .await
+            .map_err(|e| e.to_string())?
+            .map_err(|e| e.to_string())?;
+        self.provider.replace(provider).map_err(|e| e.to_string())?;
+
+        self.current = config;
+        info!("Конфигурация применена, изменено: {}", live.join(", "));
+        Ok(())
+    }
+}
+
+/// Верхнеуровневые поля, значения которых различаются
+fn changed_fields<T: Serialize>(old: &T, new: &T) -> Vec<String> {
+    let (Ok(serde_json::Value::Object(old)), Ok(serde_json::Value::Object(new))) =
+        (serde_json::to_value(old), serde_json::to_value(new))
+    else {
+        return Vec::new();
+    };
+
+    let mut changed: Vec<String> = old
+        .keys()
+        .chain(new.keys())
+        .filter(|key| old.get(*key) != new.get(*key))
+        .cloned()
+        .collect();
+    changed.sort();
+    changed.dedup();
+    changed
+}
+
+/// Запустить обработчик `SIGHUP`
+#[cfg(unix)]
+pub fn spawn_reload_handler(mut reloader: Reloader) {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    tokio::spawn(async move {
+        let mut hangup = match signal(SignalKind::hangup()) {
+            Ok(hangup) => hangup,
+            Err(e) => {
+                warn!("Не удалось установить обработчик SIGHUP: {}", e);
+                return;
+            }
+        };
+
+        while hangup.recv().await.is_some() {
+            info!("Получен SIGHUP, перечитывание конфигурации");
+            if let Err(e) = reloader.reload().await {
+                warn!("Конфигурация не перечитана: {}", e);
+            }
+        }
+    });
+}
+
+/// На платформах без POSIX-сигналов конфигурация меняется только перезапуском
+#[cfg(not(unix))]
+pub fn spawn_reload_handler(_reloader: Reloader) {}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+
+    use super::*;
+    use crate::storage::{create_provider_with_key, EncryptionConfig, KeySource};
+
+    #[tokio::test]
+    async fn reload_keeps_encryption_key_and_quota() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("storage");
+        std::fs::create_dir(&root).unwrap();
+        let key_path = dir.path().join("storage.key");
+        std::fs::write(&key_path, base64::engine::general_purpose::STANDARD.encode([7u8; 32]))
+            .unwrap();
+        let config_path = dir.path().join("storage.json");
+
+        let mut config = StorageConfig {
+            root_path: Some(root.to_string_lossy().to_string()),
+            encryption: Some(EncryptionConfig {
+                algorithm: Default::default(),
+                key: KeySource::File(key_path.clone()),
+            }),
+            quota_bytes: Some(1024 * 1024),
+            ..StorageConfig::local()
+        };
+        config.save(&config_path).unwrap();
+
+        let key = EncryptionKey::load(config.encryption.as_ref().unwrap()).unwrap();
+        let first = create_provider_with_key(&config, Some(&key)).unwrap();
+        let provider = Arc::new(ReloadableStorageProvider::new(first.clone()));
+        let mut reloader = Reloader::new(
+            Some(config_path.clone()),
+            None,
+            config.clone(),
+            Some(key),
+            provider.clone(),
+        );
+
+        let file = root.join("clip.bin").to_string_lossy().to_string();
+        first.upload_bytes(&file, vec![1u8; 1000].into(), false).await.unwrap();
+
+        // Ключ больше не прочитать: новый провайдер должен получить прежний
+        std::fs::remove_file(&key_path).unwrap();
+        config.show_hidden = true;
+        config.save(&config_path).unwrap();
+        reloader.reload().await.unwrap();
+
+        let second = provider.current();
+        assert!(!Arc::ptr_eq(&first, &second));
+        assert_eq!(second.download_bytes(&file).await.unwrap().as_ref(), [1u8; 1000]);
+
+        // Запись через прежний провайдер учитывается в квоте нового
+        let used = second.get_info().await.unwrap().quota_used_bytes.unwrap();
+        let other = root.join("other.bin").to_string_lossy().to_string();
+        first.upload_bytes(&other, vec![2u8; 500].into(), false).await.unwrap();
+        let used_after = second.get_info().await.unwrap().quota_used_bytes.unwrap();
+        assert!(used_after > used, "{} -> {}", used, used_after);
+    }
+}
@@ -1,36 +1,221 @@
 //! Реализация gRPC сервиса FileGateway
 
+use std::collections::HashMap;
+use std::path::Path;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
-use tokio::io::AsyncReadExt;
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::broadcast;
 use tokio_stream::{Stream, StreamExt};
-use tonic::{Request, Response, Status, Streaming};
-use tracing::{error, info};
+use tokio_util::sync::CancellationToken;
+use director_common::telemetry;
+use tonic::metadata::{MetadataMap, MetadataValue};
+use tonic::{Code, Request, Response, Status, Streaming};
+use tracing::{error, info, warn};
 
 use crate::proto::*;
-use crate::storage::{StorageProvider, StorageConfig, create_provider};
+use crate::storage::{
+    self, DeletePreview, DeleteProgress, MeteredStorageProvider, StorageProvider, StorageError,
+    TransferControl,
+};
+
+/// Как часто отправляется прогресс долгих операций
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Сколько файлов `DeleteTree` удаляет одновременно по умолчанию и наибольшее
+const DELETE_CONCURRENCY: usize = 16;
+const MAX_DELETE_CONCURRENCY: usize = 256;
+
+/// Сколько путей перечисляет пробный запуск удаления
+const DELETE_PREVIEW_LIMIT: usize = 10_000;
+
+/// Сколько событий хранилища может отстать подписчик до разрыва стрима
+const EVENT_BUFFER: usize = 256;
+
+/// Через сколько записанных байт загрузка сбрасывает поток записи
+const UPLOAD_FLUSH_INTERVAL: u64 = 8 * 1024 * 1024;
+
+/// Срок действия ссылки на скачивание, если в запросе не задан
+const DOWNLOAD_URL_EXPIRY: Duration = Duration::from_secs(60 * 60);
 
 pub struct FileGatewayImpl {
     provider: Arc<dyn StorageProvider>,
+    /// Тот же провайдер, что и `provider`, для чтения метрик
+    metered: Arc<MeteredStorageProvider>,
+    /// Другие хранилища по ID - только для `Transfer`
+    transfer_storages: HashMap<String, Arc<dyn StorageProvider>>,
+    /// События операций для подписчиков `SubscribeStorageEvents`
+    events: broadcast::Sender<StorageEvent>,
 }
 
 impl FileGatewayImpl {
-    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
-        // Загружаем конфигурацию или используем дефолтную
-        let config = StorageConfig::local();
-        let provider = create_provider(&config)?;
-        
-        Ok(Self { provider })
+    /// Провайдер по ID хранилища (пустой ID - провайдер по умолчанию)
+    fn provider_for(&self, storage_id: &str) -> Result<&Arc<dyn StorageProvider>, StorageError> {
+        if storage_id.is_empty() || storage_id == self.provider.id() {
+            return Ok(&self.provider);
+        }
+        self.transfer_storages
+            .get(storage_id)
+            .ok_or_else(|| StorageError::NotFound(format!("хранилище {}", storage_id)))
+    }
+
+    /// Создать сервис поверх готового провайдера
+    ///
+    /// Позволяет подставить любую реализацию `StorageProvider`
+    /// (например, заглушку) без чтения конфигурации.
+    pub fn with_provider(provider: Arc<dyn StorageProvider>) -> Self {
+        let metered = Arc::new(MeteredStorageProvider::new(provider));
+        Self {
+            provider: metered.clone(),
+            metered,
+            transfer_storages: HashMap::new(),
+            events: broadcast::channel(EVENT_BUFFER).0,
+        }
+    }
+
+    /// Подключить другое хранилище для `Transfer` (доступно по `id()` провайдера)
+    pub fn with_transfer_storage(mut self, provider: Arc<dyn StorageProvider>) -> Self {
+        self.transfer_storages.insert(provider.id().to_string(), provider);
+        self
+    }
+
+    /// Разослать событие подписчикам (если их нет - событие отбрасывается)
+    ///
+    /// Отправка в broadcast не ждёт подписчиков: медленный подписчик
+    /// отстаёт и теряет стрим, а не тормозит операции.
+    fn publish(&self, event: StorageEvent) {
+        let _ = self.events.send(event);
+    }
+
+    /// Открыть следующий файл пакетной загрузки (ошибка - результат для ответа)
+    async fn start_batch_file(
+        &self,
+        metadata: &UploadFileMetadata,
+    ) -> Result<BatchFile, UploadFileResponse> {
+        let destination = storage::join_file_path(&metadata.destination_path, &metadata.filename)
+            .map_err(|e| upload_failed(metadata.filename.clone(), e))?;
+
+        let expected = metadata.expected_modified_at.as_ref();
+        check_unmodified(self.provider.as_ref(), &destination, expected)
+            .await
+            .map_err(|e| upload_failed(destination.clone(), e))?;
+
+        let write_stream = self.provider
+            .get_write_stream(
+                &destination,
+                metadata.overwrite || expected.is_some(),
+                metadata.mode,
+            )
+            .await
+            .map_err(|e| upload_failed(destination.clone(), e))?;
+
+        Ok(BatchFile {
+            write_stream,
+            partial: PartialUpload::new(self.provider.clone(), destination.clone()),
+            path: destination,
+            total_size: metadata.total_size,
+            bytes_written: 0,
+            unflushed: 0,
+        })
+    }
+
+    /// Закрыть файл пакетной загрузки; если закрыть не удалось, guard удаляет файл
+    async fn finish_batch_file(&self, mut file: BatchFile, caller: &Caller) -> UploadFileResponse {
+        if let Err(e) = check_upload_size(file.total_size, file.bytes_written) {
+            warn!("Файл пакета {} не загружен: {}", file.path, e);
+            return upload_failed(file.path.clone(), e);
+        }
+        if let Err(e) = file.write_stream.shutdown().await {
+            warn!("Не удалось дописать {}: {}", file.path, e);
+            return upload_failed(file.path.clone(), e);
+        }
+        file.partial.complete();
+
+        info!("Файл загружен: {}, {} байт", file.path, file.bytes_written);
+        self.publish(StorageEvent {
+            size: file.bytes_written,
+            ..caller.event(StorageEventType::FileUploaded, file.path.clone())
+        });
+
+        UploadFileResponse {
+            success: true,
+            error_message: String::new(),
+            file_path: file.path,
+            bytes_written: file.bytes_written,
+        }
+    }
+}
+
+/// Контекст вызова, который попадает в события хранилища
+struct Caller {
+    rpc: &'static str,
+    trace_id: String,
+    client_address: String,
+}
+
+impl Caller {
+    fn new<T>(rpc: &'static str, request: &Request<T>) -> Self {
+        Self {
+            rpc,
+            trace_id: telemetry::trace_id(request.metadata()),
+            client_address: request
+                .remote_addr()
+                .map(|addr| addr.to_string())
+                .unwrap_or_default(),
+        }
     }
 
-    pub fn with_config(config: StorageConfig) -> Result<Self, Box<dyn std::error::Error>> {
-        let provider = create_provider(&config)?;
-        Ok(Self { provider })
+    /// Событие операции, выполненной этим вызовом
+    fn event(&self, event_type: StorageEventType, path: String) -> StorageEvent {
+        StorageEvent {
+            r#type: event_type as i32,
+            path,
+            rpc: self.rpc.to_string(),
+            trace_id: self.trace_id.clone(),
+            client_address: self.client_address.clone(),
+            timestamp: Some(to_timestamp(Utc::now())),
+            ..Default::default()
+        }
     }
 }
 
 // Конвертация типов
+fn to_timestamp(time: DateTime<Utc>) -> prost_types::Timestamp {
+    prost_types::Timestamp {
+        seconds: time.timestamp(),
+        nanos: time.timestamp_subsec_nanos() as i32,
+    }
+}
+
+/// Проверить, что файл не изменился с `expected` (условная перезапись)
+///
+/// Проверка выполняется до открытия потока записи; запись, начатая другим
+/// клиентом между проверкой и открытием, не обнаруживается.
+async fn check_unmodified(
+    provider: &dyn StorageProvider,
+    path: &str,
+    expected: Option<&prost_types::Timestamp>,
+) -> Result<(), StorageError> {
+    let Some(expected) = expected else {
+        return Ok(());
+    };
+
+    let modified_at = match provider.get_entry_info(path).await {
+        Ok(entry) => entry.modified_at.map(to_timestamp),
+        Err(StorageError::NotFound(_)) => None,
+        Err(e) => return Err(e),
+    };
+    if modified_at.as_ref() != Some(expected) {
+        return Err(StorageError::Modified(path.to_string()));
+    }
+    Ok(())
+}
+
 impl From<crate::storage::StorageEntry> for DirectoryEntry {
     fn from(entry: crate::storage::StorageEntry) -> Self {
         DirectoryEntry {
@@ -38,9 +223,290 @@ impl From<crate::storage::StorageEntry> for DirectoryEntry {
             path: entry.path,
             is_directory: entry.is_directory,
             size: entry.size,
-            created_at: entry.created_at,
-            modified_at: entry.modified_at,
+            created_at: entry.created_at.map(to_timestamp),
+            modified_at: entry.modified_at.map(to_timestamp),
             mime_type: entry.mime_type,
+            metadata_error: entry.metadata_error.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<crate::storage::PathError> for PathError {
+    fn from(error: crate::storage::PathError) -> Self {
+        PathError {
+            path: error.path,
+            error: error.error,
+        }
+    }
+}
+
+impl From<crate::storage::StorageCapabilities> for StorageCapabilities {
+    fn from(caps: crate::storage::StorageCapabilities) -> Self {
+        StorageCapabilities {
+            streaming: caps.streaming,
+            rename: caps.rename,
+            watch: caps.watch,
+            thumbnails: caps.thumbnails,
+            disk_space: caps.disk_space,
+            atomic_writes: caps.atomic_writes,
+        }
+    }
+}
+
+impl From<crate::storage::ImageMetadata> for ImageMetadata {
+    fn from(meta: crate::storage::ImageMetadata) -> Self {
+        ImageMetadata {
+            has_exif: meta.has_exif,
+            orientation: meta.orientation.unwrap_or_default(),
+            datetime_original: meta.datetime_original.unwrap_or_default(),
+            width: meta.width.unwrap_or_default(),
+            height: meta.height.unwrap_or_default(),
+            camera_make: meta.camera_make.unwrap_or_default(),
+            camera_model: meta.camera_model.unwrap_or_default(),
+            lens_model: meta.lens_model.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<crate::storage::DuplicateGroup> for DuplicateGroup {
+    fn from(group: crate::storage::DuplicateGroup) -> Self {
+        DuplicateGroup {
+            hash: group.hash,
+            size: group.size,
+            paths: group.paths,
+        }
+    }
+}
+
+impl From<crate::storage::OperationMetrics> for OperationMetrics {
+    fn from(metrics: crate::storage::OperationMetrics) -> Self {
+        OperationMetrics {
+            operation: metrics.operation.to_string(),
+            calls: metrics.calls,
+            errors: metrics.errors,
+            total_time_us: metrics.total_duration.as_micros() as u64,
+            max_time_us: metrics.max_duration.as_micros() as u64,
+        }
+    }
+}
+
+impl From<crate::storage::TreeNode> for TreeNode {
+    fn from(node: crate::storage::TreeNode) -> Self {
+        TreeNode {
+            entry: Some(DirectoryEntry::from(node.entry)),
+            children: node.children.into_iter().map(TreeNode::from).collect(),
+            file_count: node.file_count,
+            dir_count: node.dir_count,
+            truncated: node.truncated,
+        }
+    }
+}
+
+/// Ключ метаданных ответа со стабильным идентификатором ошибки
+///
+/// Текст ошибки предназначен для людей и может меняться, поэтому клиенты
+/// ветвятся по коду статуса и этому идентификатору.
+const ERROR_REASON_KEY: &str = "error-reason";
+
+/// Status с идентификатором ошибки в метаданных и в начале сообщения
+fn coded_status(code: Code, reason: &'static str, message: impl std::fmt::Display) -> Status {
+    let mut metadata = MetadataMap::new();
+    metadata.insert(ERROR_REASON_KEY, MetadataValue::from_static(reason));
+    Status::with_metadata(code, format!("{}: {}", reason, message), metadata)
+}
+
+/// Ошибка хранилища для RPC, которые возвращают ошибки статусом
+fn storage_status(e: StorageError) -> Status {
+    use std::io::ErrorKind;
+
+    let code = match &e {
+        StorageError::NotFound(_) => Code::NotFound,
+        StorageError::PermissionDenied(_) => Code::PermissionDenied,
+        StorageError::NotSupported => Code::Unimplemented,
+        StorageError::QuotaExceeded(_) => Code::ResourceExhausted,
+        StorageError::AlreadyExists(_) => Code::AlreadyExists,
+        StorageError::InvalidName(_)
+        | StorageError::NotADirectory(_)
+        | StorageError::NotAFile(_) => Code::InvalidArgument,
+        StorageError::Modified(_) => Code::Aborted,
+        StorageError::Io(e) => match e.kind() {
+            ErrorKind::NotFound => Code::NotFound,
+            ErrorKind::PermissionDenied => Code::PermissionDenied,
+            ErrorKind::StorageFull => Code::ResourceExhausted,
+            _ => Code::Internal,
+        },
+        StorageError::Config(_) | StorageError::InvalidData(_) => Code::Internal,
+    };
+    coded_status(code, e.reason(), e)
+}
+
+/// Ответ на пробный запуск удаления
+fn preview_response(preview: Result<DeletePreview, StorageError>) -> DeleteResponse {
+    match preview {
+        Ok(preview) => DeleteResponse {
+            success: true,
+            error_message: String::new(),
+            paths: preview.paths,
+            paths_truncated: preview.truncated,
+            total_size: preview.total_size,
+            errors: preview.errors.into_iter().map(PathError::from).collect(),
+        },
+        Err(e) => {
+            error!("Ошибка пробного удаления: {}", e);
+            DeleteResponse {
+                success: false,
+                error_message: e.to_string(),
+                ..Default::default()
+            }
+        }
+    }
+}
+
+/// Ошибка ввода-вывода в статус: нехватка места - `resource_exhausted`
+fn io_status(e: std::io::Error) -> Status {
+    match e.kind() {
+        std::io::ErrorKind::StorageFull => {
+            coded_status(Code::ResourceExhausted, "STORAGE_FULL", e)
+        }
+        _ => coded_status(Code::Internal, "IO_ERROR", e),
+    }
+}
+
+/// Частично записанный файл загрузки
+///
+/// Если загрузка не дошла до конца (ошибка, отмена клиентом или обрыв
+/// соединения, при котором tonic просто отбрасывает обработчик), файл
+/// удаляется при уничтожении guard'а. Провайдер с атомарной записью сам
+/// удаляет временный файл, а под конечным именем может лежать прежний
+/// файл, поэтому для него guard ничего не удаляет.
+struct PartialUpload {
+    provider: Arc<dyn StorageProvider>,
+    path: String,
+    completed: bool,
+}
+
+impl PartialUpload {
+    fn new(provider: Arc<dyn StorageProvider>, path: String) -> Self {
+        let completed = provider.capabilities().atomic_writes;
+        Self {
+            provider,
+            path,
+            completed,
+        }
+    }
+
+    /// Загрузка завершена, файл оставляем
+    fn complete(mut self) {
+        self.completed = true;
+    }
+}
+
+impl Drop for PartialUpload {
+    fn drop(&mut self) {
+        if self.completed {
+            return;
+        }
+
+        let provider = self.provider.clone();
+        let path = std::mem::take(&mut self.path);
+        tokio::spawn(async move {
+            match provider.delete_file(&path).await {
+                Ok(()) => info!("Удалён частично загруженный файл: {}", path),
+                Err(e) => warn!("Не удалось удалить частично загруженный файл {}: {}", path, e),
+            }
+        });
+    }
+}
+
+/// Файл пакетной загрузки, в который сейчас пишутся чанки
+///
+/// Поля уничтожаются в порядке объявления: поток записи закрывается
+/// до того, как guard удалит недописанный файл.
+struct BatchFile {
+    write_stream: Pin<Box<dyn AsyncWrite + Send>>,
+    partial: PartialUpload,
+    path: String,
+    /// Размер из метаданных (`0` - не объявлен)
+    total_size: u64,
+    bytes_written: u64,
+    unflushed: u64,
+}
+
+impl BatchFile {
+    /// Записать чанк, периодически сбрасывая поток (как в `upload_file`)
+    async fn write(&mut self, chunk: &[u8]) -> std::io::Result<()> {
+        self.write_stream.write_all(chunk).await?;
+        self.bytes_written += chunk.len() as u64;
+
+        self.unflushed += chunk.len() as u64;
+        if self.unflushed >= UPLOAD_FLUSH_INTERVAL {
+            self.write_stream.flush().await?;
+            self.unflushed = 0;
+        }
+        Ok(())
+    }
+}
+
+/// Проверить, что получено столько байт, сколько объявлено в метаданных
+///
+/// Клиент, отключившийся посреди загрузки, может выглядеть для обработчика
+/// как обычный конец стрима - без проверки недописанный файл заменил бы
+/// прежний. Размер `0` не проверяется: клиент мог его не знать.
+fn check_upload_size(total_size: u64, bytes_written: u64) -> Result<(), StorageError> {
+    if total_size != 0 && bytes_written != total_size {
+        return Err(StorageError::InvalidData(format!(
+            "получено {} байт из {}",
+            bytes_written, total_size
+        )));
+    }
+    Ok(())
+}
+
+/// Результат файла, который не удалось загрузить
+fn upload_failed(file_path: String, error: impl std::fmt::Display) -> UploadFileResponse {
+    UploadFileResponse {
+        success: false,
+        error_message: error.to_string(),
+        file_path,
+        bytes_written: 0,
+    }
+}
+
+/// Незавершённое скачивание
+///
+/// Файл читается только тогда, когда tonic запрашивает у стрима следующее
+/// сообщение. Если клиент отключился, tonic сбрасывает стрим вместе с
+/// потоком чтения провайдера (и прерывает начатое чтение), так что лишнего
+/// ввода-вывода нет. Guard сообщает в лог, на каком байте это произошло.
+struct PartialDownload {
+    path: String,
+    bytes_sent: u64,
+    completed: bool,
+}
+
+impl PartialDownload {
+    fn new(path: String) -> Self {
+        Self {
+            path,
+            bytes_sent: 0,
+            completed: false,
+        }
+    }
+
+    fn sent(&mut self, bytes: usize) {
+        self.bytes_sent += bytes as u64;
+    }
+
+    /// Файл прочитан до конца
+    fn complete(&mut self) {
+        self.completed = true;
+    }
+}
+
+impl Drop for PartialDownload {
+    fn drop(&mut self) {
+        if !self.completed {
+            info!("Скачивание {} прервано после {} байт", self.path, self.bytes_sent);
         }
     }
 }
@@ -57,7 +523,7 @@ impl file_gateway_server::FileGateway for FileGatewayImpl {
 
         let info = self.provider.get_info().await.map_err(|e| {
             error!("Ошибка получения информации: {}", e);
-            Status::internal(e.to_string())
+            storage_status(e)
         })?;
 
         Ok(Response::new(GetStorageInfoResponse {
@@ -69,9 +535,87 @@ impl file_gateway_server::FileGateway for FileGatewayImpl {
             root_paths: info.root_paths,
             total_space: info.total_space,
             free_space: info.free_space,
+            capabilities: Some(self.provider.capabilities().into()),
+            storage_type: info.storage_type,
+            quota_bytes: info.quota_bytes.unwrap_or_default(),
+            quota_used_bytes: info.quota_used_bytes.unwrap_or_default(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            started_at: Some(to_timestamp(crate::started_at())),
+            uptime_seconds: (Utc::now() - crate::started_at()).num_seconds().max(0) as u64,
+        }))
+    }
+
+    async fn get_disk_space(
+        &self,
+        request: Request<GetDiskSpaceRequest>,
+    ) -> Result<Response<GetDiskSpaceResponse>, Status> {
+        let req = request.into_inner();
+
+        match self.provider.get_disk_space(&req.path).await {
+            Ok(space) => Ok(Response::new(GetDiskSpaceResponse {
+                success: true,
+                error_message: String::new(),
+                total_space: space.total_space,
+                free_space: space.free_space,
+            })),
+            Err(e) => {
+                error!("Ошибка получения свободного места для {}: {}", req.path, e);
+                Ok(Response::new(GetDiskSpaceResponse {
+                    success: false,
+                    error_message: e.to_string(),
+                    ..Default::default()
+                }))
+            }
+        }
+    }
+
+    async fn get_storage_metrics(
+        &self,
+        _request: Request<GetStorageMetricsRequest>,
+    ) -> Result<Response<GetStorageMetricsResponse>, Status> {
+        let metrics = self.metered.snapshot();
+
+        Ok(Response::new(GetStorageMetricsResponse {
+            storage_id: metrics.provider_id,
+            bytes_read: metrics.bytes_read,
+            bytes_written: metrics.bytes_written,
+            read_time_us: metrics.read_duration.as_micros() as u64,
+            write_time_us: metrics.write_duration.as_micros() as u64,
+            operations: metrics.operations.into_iter().map(OperationMetrics::from).collect(),
         }))
     }
 
+    type SubscribeStorageEventsStream =
+        Pin<Box<dyn Stream<Item = Result<StorageEvent, Status>> + Send>>;
+
+    async fn subscribe_storage_events(
+        &self,
+        _request: Request<SubscribeStorageEventsRequest>,
+    ) -> Result<Response<Self::SubscribeStorageEventsStream>, Status> {
+        info!("Новая подписка на события хранилища");
+
+        let mut receiver = self.events.subscribe();
+
+        let stream = async_stream::try_stream! {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => yield event,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("Подписчик на события хранилища отстал, пропущено: {}", skipped);
+                        Err(coded_status(
+                            Code::DataLoss,
+                            "EVENTS_SKIPPED",
+                            format!("Пропущено событий: {}", skipped),
+                        ))?;
+                    }
+                }
+            }
+        };
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+
     // === Навигация ===
 
     async fn browse_directory(
@@ -89,12 +633,30 @@ impl file_gateway_server::FileGateway for FileGatewayImpl {
                     .map(DirectoryEntry::from)
                     .collect();
 
+                // Место на томе просматриваемой директории: внешний диск может
+                // быть смонтирован внутри корня. Без этих сведений список
+                // всё равно отдаётся, поля остаются нулевыми.
+                let (total_space, free_space) =
+                    match self.provider.get_disk_space(&listing.current_path).await {
+                        Ok(space) => (space.total_space, space.free_space),
+                        Err(StorageError::NotSupported) => (0, 0),
+                        Err(e) => {
+                            warn!(
+                                "Не удалось узнать свободное место для {}: {}",
+                                listing.current_path, e
+                            );
+                            (0, 0)
+                        }
+                    };
+
                 Ok(Response::new(BrowseDirectoryResponse {
                     success: true,
                     error_message: String::new(),
                     current_path: listing.current_path,
                     parent_path: listing.parent_path,
                     entries,
+                    total_space,
+                    free_space,
                 }))
             }
             Err(e) => {
@@ -108,19 +670,51 @@ impl file_gateway_server::FileGateway for FileGatewayImpl {
         }
     }
 
+    async fn get_tree(
+        &self,
+        request: Request<GetTreeRequest>,
+    ) -> Result<Response<GetTreeResponse>, Status> {
+        let req = request.into_inner();
+        info!("Дерево директории: {}, глубина: {}", req.path, req.max_depth);
+
+        match storage::get_tree(self.provider.as_ref(), &req.path, req.max_depth).await {
+            Ok((root, errors)) => Ok(Response::new(GetTreeResponse {
+                success: true,
+                error_message: String::new(),
+                root: Some(root.into()),
+                errors: errors.into_iter().map(PathError::from).collect(),
+            })),
+            Err(e) => {
+                error!("Ошибка построения дерева: {}", e);
+                Ok(Response::new(GetTreeResponse {
+                    success: false,
+                    error_message: e.to_string(),
+                    ..Default::default()
+                }))
+            }
+        }
+    }
+
     async fn create_directory(
         &self,
         request: Request<CreateDirectoryRequest>,
     ) -> Result<Response<CreateDirectoryResponse>, Status> {
+        let caller = Caller::new("CreateDirectory", &request);
         let req = request.into_inner();
         info!("Создание директории: {}", req.path);
 
-        match self.provider.create_directory(&req.path, req.create_parents).await {
-            Ok(created_path) => Ok(Response::new(CreateDirectoryResponse {
-                success: true,
-                error_message: String::new(),
-                created_path,
-            })),
+        match self.provider.create_directory(&req.path, req.create_parents, req.mode).await {
+            Ok(created_path) => {
+                self.publish(StorageEvent {
+                    is_directory: true,
+                    ..caller.event(StorageEventType::DirectoryCreated, created_path.clone())
+                });
+                Ok(Response::new(CreateDirectoryResponse {
+                    success: true,
+                    error_message: String::new(),
+                    created_path,
+                }))
+            }
             Err(e) => {
                 error!("Ошибка создания директории: {}", e);
                 Ok(Response::new(CreateDirectoryResponse {
@@ -132,96 +726,649 @@ impl file_gateway_server::FileGateway for FileGatewayImpl {
         }
     }
 
+    async fn create_file(
+        &self,
+        request: Request<CreateFileRequest>,
+    ) -> Result<Response<CreateFileResponse>, Status> {
+        let caller = Caller::new("CreateFile", &request);
+        let req = request.into_inner();
+        info!("Создание файла: {}, перезапись: {}", req.path, req.overwrite);
+
+        match self.provider.create_file(&req.path, req.overwrite).await {
+            Ok(created_path) => {
+                self.publish(caller.event(StorageEventType::FileCreated, created_path.clone()));
+                Ok(Response::new(CreateFileResponse {
+                    success: true,
+                    error_message: String::new(),
+                    created_path,
+                }))
+            }
+            Err(e) => {
+                error!("Ошибка создания файла: {}", e);
+                Ok(Response::new(CreateFileResponse {
+                    success: false,
+                    error_message: e.to_string(),
+                    created_path: String::new(),
+                }))
+            }
+        }
+    }
+
     async fn delete(
         &self,
         request: Request<DeleteRequest>,
     ) -> Result<Response<DeleteResponse>, Status> {
+        let caller = Caller::new("Delete", &request);
         let req = request.into_inner();
+
+        if req.dry_run {
+            info!("Пробное удаление: {}, рекурсивно: {}", req.path, req.recursive);
+            let preview = self
+                .provider
+                .preview_delete(&req.path, req.recursive, DELETE_PREVIEW_LIMIT)
+                .await;
+            return Ok(Response::new(preview_response(preview)));
+        }
+
         info!("Удаление: {}, рекурсивно: {}", req.path, req.recursive);
 
         // Проверяем, файл это или директория
         let entry_info = self.provider.get_entry_info(&req.path).await;
 
         let result = match entry_info {
-            Ok(entry) if entry.is_directory => {
-                self.provider.delete_directory(&req.path, req.recursive).await
-            }
+            Ok(entry) if entry.is_directory => self
+                .provider
+                .delete_directory(&req.path, req.recursive)
+                .await
+                .map(|()| true),
             Ok(_) => {
-                self.provider.delete_file(&req.path).await
+                self.provider.delete_file(&req.path).await.map(|()| false)
             }
             Err(e) => Err(e),
         };
 
         match result {
-            Ok(()) => Ok(Response::new(DeleteResponse {
-                success: true,
-                error_message: String::new(),
-            })),
+            Ok(is_directory) => {
+                self.publish(StorageEvent {
+                    is_directory,
+                    ..caller.event(StorageEventType::Deleted, req.path)
+                });
+                Ok(Response::new(DeleteResponse {
+                    success: true,
+                    ..Default::default()
+                }))
+            }
             Err(e) => {
                 error!("Ошибка удаления: {}", e);
                 Ok(Response::new(DeleteResponse {
                     success: false,
                     error_message: e.to_string(),
+                    ..Default::default()
                 }))
             }
         }
     }
 
-    // === Загрузка файлов ===
+    type DeleteTreeStream = Pin<Box<dyn Stream<Item = Result<DeleteTreeEvent, Status>> + Send>>;
 
-    async fn upload_file(
+    async fn delete_tree(
         &self,
-        request: Request<Streaming<UploadFileRequest>>,
-    ) -> Result<Response<UploadFileResponse>, Status> {
-        let mut stream = request.into_inner();
-
-        // Первое сообщение - метаданные
-        let first_message = stream
-            .next()
-            .await
-            .ok_or_else(|| Status::invalid_argument("Пустой стрим"))??;
-
-        let metadata = match first_message.data {
-            Some(upload_file_request::Data::Metadata(m)) => m,
-            _ => return Err(Status::invalid_argument("Первое сообщение должно содержать метаданные")),
+        request: Request<DeleteTreeRequest>,
+    ) -> Result<Response<Self::DeleteTreeStream>, Status> {
+        let caller = Caller::new("DeleteTree", &request);
+        let req = request.into_inner();
+        let concurrency = match req.concurrency as usize {
+            0 => DELETE_CONCURRENCY,
+            n => n.min(MAX_DELETE_CONCURRENCY),
         };
 
-        info!(
-            "Загрузка файла: {} в {}, размер: {} байт",
-            metadata.filename, metadata.destination_path, metadata.total_size
-        );
+        if req.dry_run {
+            info!("Пробное удаление дерева: {}", req.path);
+            let preview = self
+                .provider
+                .preview_delete(&req.path, true, DELETE_PREVIEW_LIMIT)
+                .await;
+            let result = DeleteTreeEvent {
+                event: Some(delete_tree_event::Event::Result(preview_response(preview))),
+            };
+            return Ok(Response::new(Box::pin(tokio_stream::once(Ok(result)))));
+        }
 
-        let destination = format!("{}/{}", metadata.destination_path, metadata.filename);
+        info!("Удаление дерева: {}, параллельно: {}", req.path, concurrency);
 
-        // Получаем поток для записи
-        let mut write_stream = self.provider
-            .get_write_stream(&destination, metadata.overwrite)
-            .await
-            .map_err(|e| Status::internal(e.to_string()))?;
+        let provider = self.provider.clone();
+        let events = self.events.clone();
+        let progress = Arc::new(DeleteProgress::default());
+        let counters = progress.clone();
 
-        let mut bytes_written: u64 = 0;
+        let output_stream = async_stream::try_stream! {
+            // Удаление идёт в самом стриме, а не в отдельной задаче: когда клиент
+            // отключается, tonic сбрасывает стрим и удаление прерывается
+            let delete = provider.delete_tree(&req.path, concurrency, counters);
+            tokio::pin!(delete);
 
-        // Записываем чанки
-        while let Some(message) = stream.next().await {
-            let message = message?;
-            if let Some(upload_file_request::Data::Chunk(chunk)) = message.data {
-                use tokio::io::AsyncWriteExt;
-                write_stream.write_all(&chunk).await.map_err(|e| Status::internal(e.to_string()))?;
-                bytes_written += chunk.len() as u64;
-            }
-        }
+            let mut ticker = tokio::time::interval(PROGRESS_INTERVAL);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
-        use tokio::io::AsyncWriteExt;
-        write_stream.flush().await.map_err(|e| Status::internal(e.to_string()))?;
+            let result = loop {
+                tokio::select! {
+                    result = &mut delete => break result,
+                    _ = ticker.tick() => {
+                        let (files_deleted, directories_deleted, bytes_deleted) =
+                            progress.snapshot();
+                        yield DeleteTreeEvent {
+                            event: Some(delete_tree_event::Event::Progress(DeleteTreeProgress {
+                                files_deleted,
+                                directories_deleted,
+                                bytes_deleted,
+                            })),
+                        };
+                    }
+                }
+            };
 
-        info!("Файл загружен: {}, {} байт", destination, bytes_written);
+            let errors: Vec<PathError> =
+                progress.errors().into_iter().map(PathError::from).collect();
+            let response = match result {
+                Ok(()) if !errors.is_empty() => {
+                    warn!("Дерево {} удалено не полностью, ошибок: {}", req.path, errors.len());
+                    DeleteResponse {
+                        success: false,
+                        error_message: format!("Не удалось удалить путей: {}", errors.len()),
+                        errors,
+                        ..Default::default()
+                    }
+                }
+                Ok(()) => {
+                    let (files, directories, bytes) = progress.snapshot();
+                    info!(
+                        "Дерево удалено: {}, файлов: {}, директорий: {}, {} байт",
+                        req.path, files, directories, bytes
+                    );
+                    let _ = events.send(StorageEvent {
+                        is_directory: true,
+                        ..caller.event(StorageEventType::Deleted, req.path.clone())
+                    });
+                    DeleteResponse {
+                        success: true,
+                        ..Default::default()
+                    }
+                }
+                Err(e) => {
+                    error!("Ошибка удаления дерева {}: {}", req.path, e);
+                    DeleteResponse {
+                        success: false,
+                        error_message: e.to_string(),
+                        errors,
+                        ..Default::default()
+                    }
+                }
+            };
 
-        Ok(Response::new(UploadFileResponse {
-            success: true,
-            error_message: String::new(),
-            file_path: destination,
-            bytes_written,
-        }))
+            yield DeleteTreeEvent {
+                event: Some(delete_tree_event::Event::Result(response)),
+            };
+        };
+
+        Ok(Response::new(Box::pin(output_stream)))
+    }
+
+    async fn transfer(
+        &self,
+        request: Request<TransferRequest>,
+    ) -> Result<Response<TransferResponse>, Status> {
+        let caller = Caller::new("Transfer", &request);
+        let req = request.into_inner();
+        info!(
+            "Перенос: {}:{} -> {}:{}, удалить источник: {}",
+            req.source_storage_id,
+            req.source_path,
+            req.destination_storage_id,
+            req.destination_path,
+            req.delete_source
+        );
+
+        let result = async {
+            let source = self.provider_for(&req.source_storage_id)?;
+            let destination = self.provider_for(&req.destination_storage_id)?;
+
+            // Запись в тот же файл обнулила бы его до чтения
+            if Arc::ptr_eq(source, destination)
+                && storage::same_path(&req.source_path, &req.destination_path)?
+            {
+                return Err(StorageError::AlreadyExists(req.destination_path.clone()));
+            }
+
+            storage::transfer(
+                source.as_ref(),
+                &req.source_path,
+                destination.as_ref(),
+                &req.destination_path,
+                req.overwrite,
+                req.delete_source,
+            )
+            .await
+        }
+        .await;
+
+        match result {
+            Ok(result) => {
+                self.publish(StorageEvent {
+                    source_path: req.source_path,
+                    size: result.bytes_transferred,
+                    source_deleted: result.source_deleted,
+                    ..caller.event(StorageEventType::Transferred, req.destination_path)
+                });
+                Ok(Response::new(TransferResponse {
+                    success: true,
+                    error_message: String::new(),
+                    bytes_transferred: result.bytes_transferred,
+                    source_deleted: result.source_deleted,
+                }))
+            }
+            Err(e) => {
+                error!("Ошибка переноса: {}", e);
+                Ok(Response::new(TransferResponse {
+                    success: false,
+                    error_message: e.to_string(),
+                    bytes_transferred: 0,
+                    source_deleted: false,
+                }))
+            }
+        }
+    }
+
+    async fn rename(
+        &self,
+        request: Request<RenameRequest>,
+    ) -> Result<Response<RenameResponse>, Status> {
+        let caller = Caller::new("Rename", &request);
+        let req = request.into_inner();
+        info!("Переименование: {} -> {}", req.path, req.new_name);
+
+        // Новое имя в той же директории
+        let result = async {
+            let entry = self.provider.get_entry_info(&req.path).await?;
+            let parent = Path::new(&req.path)
+                .parent()
+                .ok_or_else(|| StorageError::InvalidName(req.path.clone()))?;
+            let new_path = storage::join_file_path(&parent.to_string_lossy(), &req.new_name)?;
+
+            self.provider.rename(&req.path, &new_path, false).await?;
+            Ok::<_, StorageError>((new_path, entry.is_directory))
+        }
+        .await;
+
+        match result {
+            Ok((new_path, is_directory)) => {
+                self.publish(StorageEvent {
+                    source_path: req.path,
+                    is_directory,
+                    ..caller.event(StorageEventType::Renamed, new_path.clone())
+                });
+                Ok(Response::new(RenameResponse {
+                    success: true,
+                    error_message: String::new(),
+                    new_path,
+                    is_directory,
+                }))
+            }
+            Err(e) => {
+                error!("Ошибка переименования: {}", e);
+                Ok(Response::new(RenameResponse {
+                    success: false,
+                    error_message: e.to_string(),
+                    ..Default::default()
+                }))
+            }
+        }
+    }
+
+    type TransferWithProgressStream =
+        Pin<Box<dyn Stream<Item = Result<TransferEvent, Status>> + Send>>;
+
+    async fn transfer_with_progress(
+        &self,
+        request: Request<TransferRequest>,
+    ) -> Result<Response<Self::TransferWithProgressStream>, Status> {
+        let caller = Caller::new("TransferWithProgress", &request);
+        let req = request.into_inner();
+        info!(
+            "Перенос с прогрессом: {}:{} -> {}:{}, удалить источник: {}",
+            req.source_storage_id,
+            req.source_path,
+            req.destination_storage_id,
+            req.destination_path,
+            req.delete_source
+        );
+
+        let source = self.provider_for(&req.source_storage_id).map_err(storage_status)?.clone();
+        let destination = self
+            .provider_for(&req.destination_storage_id)
+            .map_err(storage_status)?
+            .clone();
+
+        let progress = Arc::new(AtomicU64::new(0));
+        let total_bytes = source
+            .get_entry_info(&req.source_path)
+            .await
+            .map(|entry| entry.size)
+            .unwrap_or(0);
+
+        let event = StorageEvent {
+            source_path: req.source_path.clone(),
+            ..caller.event(StorageEventType::Transferred, req.destination_path.clone())
+        };
+        let events = self.events.clone();
+
+        // Перенос идёт в отдельной задаче, а стрим по таймеру читает его счётчик.
+        // Стрим уничтожается, когда клиент отменяет вызов или отключается, -
+        // тогда guard отменяет и перенос
+        let control = TransferControl {
+            progress: progress.clone(),
+            cancel: CancellationToken::new(),
+        };
+        let cancel_on_drop = control.cancel.clone().drop_guard();
+        let current_file = req.source_path.clone();
+        let mut task = tokio::spawn(async move {
+            // Запись в тот же файл обнулила бы его до чтения
+            if Arc::ptr_eq(&source, &destination)
+                && storage::same_path(&req.source_path, &req.destination_path)?
+            {
+                return Err(StorageError::AlreadyExists(req.destination_path));
+            }
+
+            storage::transfer_with_progress(
+                source.as_ref(),
+                &req.source_path,
+                destination.as_ref(),
+                &req.destination_path,
+                req.overwrite,
+                req.delete_source,
+                control,
+            )
+            .await
+        });
+
+        let output_stream = async_stream::try_stream! {
+            let _cancel_on_drop = cancel_on_drop;
+
+            let mut ticker = tokio::time::interval(PROGRESS_INTERVAL);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+            let result = loop {
+                tokio::select! {
+                    result = &mut task => break result,
+                    _ = ticker.tick() => {
+                        yield TransferEvent {
+                            event: Some(transfer_event::Event::Progress(TransferProgress {
+                                bytes_done: progress.load(Ordering::Relaxed),
+                                total_bytes,
+                                current_file: current_file.clone(),
+                            })),
+                        };
+                    }
+                }
+            };
+
+            let response = match result {
+                Ok(Ok(result)) => {
+                    let _ = events.send(StorageEvent {
+                        size: result.bytes_transferred,
+                        source_deleted: result.source_deleted,
+                        ..event
+                    });
+                    TransferResponse {
+                        success: true,
+                        error_message: String::new(),
+                        bytes_transferred: result.bytes_transferred,
+                        source_deleted: result.source_deleted,
+                    }
+                }
+                Ok(Err(e)) => {
+                    error!("Ошибка переноса: {}", e);
+                    TransferResponse {
+                        success: false,
+                        error_message: e.to_string(),
+                        bytes_transferred: 0,
+                        source_deleted: false,
+                    }
+                }
+                Err(e) => Err(coded_status(
+                    Code::Internal,
+                    "TRANSFER_ABORTED",
+                    format!("Перенос прерван: {}", e),
+                ))?,
+            };
+
+            yield TransferEvent {
+                event: Some(transfer_event::Event::Result(response)),
+            };
+        };
+
+        Ok(Response::new(Box::pin(output_stream)))
+    }
+
+    // === Загрузка файлов ===
+
+    async fn upload_file(
+        &self,
+        request: Request<Streaming<UploadFileRequest>>,
+    ) -> Result<Response<UploadFileResponse>, Status> {
+        let caller = Caller::new("UploadFile", &request);
+        let mut stream = request.into_inner();
+
+        // Первое сообщение - метаданные. Стрим без сообщений - ошибка клиента,
+        // а метаданные без чанков - загрузка пустого файла
+        let first_message = stream
+            .next()
+            .await
+            .ok_or_else(|| {
+                coded_status(
+                    Code::InvalidArgument,
+                    "EMPTY_UPLOAD_STREAM",
+                    "Пустой стрим загрузки: нет метаданных",
+                )
+            })??;
+
+        let metadata = match first_message.data {
+            Some(upload_file_request::Data::Metadata(m)) => m,
+            _ => {
+                return Err(coded_status(
+                    Code::InvalidArgument,
+                    "METADATA_EXPECTED",
+                    "Первое сообщение должно содержать метаданные",
+                ))
+            }
+        };
+
+        info!(
+            "Загрузка файла: {} в {}, размер: {} байт",
+            metadata.filename, metadata.destination_path, metadata.total_size
+        );
+
+        let destination = storage::join_file_path(&metadata.destination_path, &metadata.filename)
+            .map_err(|e| {
+                warn!("Загрузка отклонена: {}", e);
+                storage_status(e)
+            })?;
+
+        // Условная перезапись: файл, изменённый другим клиентом, - ABORTED
+        // до чтения данных, как и занятое имя
+        let expected = metadata.expected_modified_at.as_ref();
+        check_unmodified(self.provider.as_ref(), &destination, expected)
+            .await
+            .map_err(|e| {
+                warn!("Загрузка отклонена: {}", e);
+                storage_status(e)
+            })?;
+
+        // Получаем поток для записи. Занятое имя без overwrite - ALREADY_EXISTS
+        // до чтения данных: клиент может спросить о перезаписи и повторить
+        let write_stream = self.provider
+            .get_write_stream(
+                &destination,
+                metadata.overwrite || expected.is_some(),
+                metadata.mode,
+            )
+            .await
+            .map_err(storage_status)?;
+
+        // Guard объявлен раньше потока: переменные уничтожаются в обратном порядке,
+        // поэтому поток закрывается до удаления файла
+        let partial = PartialUpload::new(self.provider.clone(), destination.clone());
+        let mut write_stream = write_stream;
+
+        let mut bytes_written: u64 = 0;
+        let mut unflushed: u64 = 0;
+
+        // Записываем чанки. Следующее сообщение читается только после записи
+        // предыдущего, поэтому быстрый клиент упирается в окно HTTP/2 и ждёт
+        // хранилище. В памяти на загрузку остаются окно соединения, одно
+        // сообщение (не больше лимита tonic на размер сообщения) и буфер потока
+        // записи; его периодически сбрасываем, чтобы провайдер не копил данные
+        // до конца загрузки.
+        while let Some(message) = stream.next().await {
+            match message?.data {
+                Some(upload_file_request::Data::Chunk(chunk)) => {
+                    write_stream.write_all(&chunk).await.map_err(io_status)?;
+                    bytes_written += chunk.len() as u64;
+
+                    unflushed += chunk.len() as u64;
+                    if unflushed >= UPLOAD_FLUSH_INTERVAL {
+                        write_stream.flush().await.map_err(io_status)?;
+                        unflushed = 0;
+                    }
+                }
+                Some(upload_file_request::Data::Cancel(cancel)) => {
+                    info!(
+                        "Загрузка {} отменена клиентом после {} байт: {}",
+                        destination, bytes_written, cancel.reason
+                    );
+                    return Err(coded_status(
+                        Code::Cancelled,
+                        "UPLOAD_CANCELLED",
+                        "Загрузка отменена клиентом",
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        check_upload_size(metadata.total_size, bytes_written).map_err(|e| {
+            warn!("Загрузка {} не завершена: {}", destination, e);
+            coded_status(Code::InvalidArgument, e.reason(), e)
+        })?;
+
+        // shutdown, а не flush: провайдер может дописывать данные при закрытии
+        // (например, последний зашифрованный блок). Без чанков получается
+        // пустой файл: он создан вместе с потоком записи
+        write_stream.shutdown().await.map_err(io_status)?;
+        partial.complete();
+
+        info!("Файл загружен: {}, {} байт", destination, bytes_written);
+        self.publish(StorageEvent {
+            size: bytes_written,
+            ..caller.event(StorageEventType::FileUploaded, destination.clone())
+        });
+
+        Ok(Response::new(UploadFileResponse {
+            success: true,
+            error_message: String::new(),
+            file_path: destination,
+            bytes_written,
+        }))
+    }
+
+    async fn upload_batch(
+        &self,
+        request: Request<Streaming<UploadFileRequest>>,
+    ) -> Result<Response<UploadBatchResponse>, Status> {
+        let caller = Caller::new("UploadBatch", &request);
+        let mut stream = request.into_inner();
+
+        let mut files = Vec::new();
+        let mut current: Option<BatchFile> = None;
+        let mut started = false;
+
+        // Файлы пишутся по очереди. Ошибка одного файла попадает в его результат,
+        // а его оставшиеся чанки пропускаются до следующих метаданных
+        while let Some(message) = stream.next().await {
+            match message?.data {
+                Some(upload_file_request::Data::Metadata(metadata)) => {
+                    started = true;
+                    if let Some(file) = current.take() {
+                        files.push(self.finish_batch_file(file, &caller).await);
+                    }
+
+                    match self.start_batch_file(&metadata).await {
+                        Ok(file) => current = Some(file),
+                        Err(result) => {
+                            warn!(
+                                "Файл пакета отклонён: {}: {}",
+                                result.file_path, result.error_message
+                            );
+                            files.push(result);
+                        }
+                    }
+                }
+                Some(upload_file_request::Data::Chunk(chunk)) => {
+                    if !started {
+                        return Err(coded_status(
+                            Code::InvalidArgument,
+                            "METADATA_EXPECTED",
+                            "Первое сообщение должно содержать метаданные",
+                        ));
+                    }
+
+                    let Some(file) = current.as_mut() else {
+                        continue;
+                    };
+                    if let Err(e) = file.write(&chunk).await {
+                        warn!("Ошибка записи {}: {}", file.path, e);
+                        files.push(upload_failed(file.path.clone(), e));
+                        // Guard удаляет недописанный файл
+                        current = None;
+                    }
+                }
+                Some(upload_file_request::Data::Cancel(cancel)) => {
+                    info!(
+                        "Пакетная загрузка отменена клиентом после {} файлов: {}",
+                        files.len(), cancel.reason
+                    );
+                    return Err(coded_status(
+                        Code::Cancelled,
+                        "UPLOAD_CANCELLED",
+                        "Загрузка отменена клиентом",
+                    ));
+                }
+                None => {}
+            }
+        }
+
+        if !started {
+            return Err(coded_status(
+                Code::InvalidArgument,
+                "EMPTY_UPLOAD_STREAM",
+                "Пустой стрим загрузки: нет метаданных",
+            ));
+        }
+        if let Some(file) = current.take() {
+            files.push(self.finish_batch_file(file, &caller).await);
+        }
+
+        let failed = files.iter().filter(|f| !f.success).count();
+        info!("Пакетная загрузка: файлов {}, с ошибкой {}", files.len(), failed);
+
+        Ok(Response::new(UploadBatchResponse {
+            success: failed == 0,
+            error_message: if failed == 0 {
+                String::new()
+            } else {
+                format!("Не загружено файлов: {} из {}", failed, files.len())
+            },
+            files,
+        }))
     }
 
     // === Скачивание файлов ===
@@ -233,27 +1380,46 @@ impl file_gateway_server::FileGateway for FileGatewayImpl {
         request: Request<DownloadFileRequest>,
     ) -> Result<Response<Self::DownloadFileStream>, Status> {
         let req = request.into_inner();
-        info!("Скачивание файла: {}", req.path);
+        info!("Скачивание файла: {} (с {} байта)", req.path, req.offset);
 
         // Получаем информацию о файле
         let entry = self.provider
             .get_entry_info(&req.path)
             .await
-            .map_err(|e| Status::not_found(e.to_string()))?;
+            .map_err(storage_status)?;
 
         if entry.is_directory {
-            return Err(Status::invalid_argument("Путь является директорией"));
+            return Err(coded_status(
+                Code::InvalidArgument,
+                "NOT_A_FILE",
+                "Путь является директорией",
+            ));
         }
 
         let filename = entry.name.clone();
         let mime_type = entry.mime_type.clone();
         let total_size = entry.size;
 
-        // Получаем поток чтения
+        if req.offset > total_size {
+            return Err(coded_status(
+                Code::OutOfRange,
+                "OFFSET_OUT_OF_RANGE",
+                format!("Смещение {} за концом файла ({} байт)", req.offset, total_size),
+            ));
+        }
+        let length = (total_size - req.offset).min(req.length.unwrap_or(u64::MAX));
+
+        // Получаем поток чтения нужного диапазона
         let mut read_stream = self.provider
-            .get_read_stream(&req.path)
+            .get_read_stream_at(&req.path, req.offset)
             .await
-            .map_err(|e| Status::internal(e.to_string()))?;
+            .map_err(storage_status)?
+            .take(length);
+
+        // Контрольная сумма считается по отправленным данным, без второго чтения файла
+        let mut hasher = req.checksum.then(Sha256::new);
+
+        let mut download = PartialDownload::new(req.path);
 
         let stream = async_stream::try_stream! {
             // Отправляем метаданные
@@ -262,6 +1428,12 @@ impl file_gateway_server::FileGateway for FileGatewayImpl {
                     filename,
                     total_size,
                     mime_type,
+                    checksum_follows: hasher.is_some(),
+                    offset: req.offset,
+                    length,
+                    // Любой провайдер читает с произвольного смещения
+                    // (`get_read_stream_at`)
+                    accepts_ranges: true,
                 })),
             };
 
@@ -272,35 +1444,178 @@ impl file_gateway_server::FileGateway for FileGatewayImpl {
                 if n == 0 {
                     break;
                 }
+                if let Some(hasher) = hasher.as_mut() {
+                    hasher.update(&buffer[..n]);
+                }
                 yield DownloadFileResponse {
                     data: Some(download_file_response::Data::Chunk(buffer[..n].to_vec())),
                 };
+                download.sent(n);
+            }
+
+            // Поток чтения больше не нужен: освобождаем файл (или соединение
+            // удалённого провайдера), не дожидаясь, пока клиент заберёт остаток
+            drop(read_stream);
+            download.complete();
+
+            if let Some(hasher) = hasher {
+                let checksum = hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect();
+                yield DownloadFileResponse {
+                    data: Some(download_file_response::Data::Checksum(checksum)),
+                };
             }
         };
 
         Ok(Response::new(Box::pin(stream)))
     }
 
+    async fn get_download_url(
+        &self,
+        request: Request<GetDownloadUrlRequest>,
+    ) -> Result<Response<GetDownloadUrlResponse>, Status> {
+        let req = request.into_inner();
+        let expires_in = match req.expires_seconds {
+            0 => DOWNLOAD_URL_EXPIRY,
+            seconds => Duration::from_secs(seconds),
+        };
+
+        match self.provider.get_download_url(&req.path, expires_in).await {
+            Ok(url) => {
+                info!("Выдана ссылка на скачивание {} на {:?}", req.path, expires_in);
+                let expires_at = chrono::TimeDelta::from_std(expires_in)
+                    .ok()
+                    .and_then(|delta| Utc::now().checked_add_signed(delta));
+                Ok(Response::new(GetDownloadUrlResponse {
+                    success: true,
+                    error_message: String::new(),
+                    url,
+                    expires_at: expires_at.map(to_timestamp),
+                    not_supported: false,
+                }))
+            }
+            Err(e) => {
+                let not_supported = matches!(e, StorageError::NotSupported);
+                if !not_supported {
+                    error!("Ошибка получения ссылки на скачивание {}: {}", req.path, e);
+                }
+                Ok(Response::new(GetDownloadUrlResponse {
+                    success: false,
+                    error_message: e.to_string(),
+                    not_supported,
+                    ..Default::default()
+                }))
+            }
+        }
+    }
+
     async fn get_file_info(
         &self,
         request: Request<GetFileInfoRequest>,
     ) -> Result<Response<GetFileInfoResponse>, Status> {
         let req = request.into_inner();
 
-        match self.provider.get_entry_info(&req.path).await {
+        // Без флага ссылка раскрывается, как и раньше
+        let entry = if req.follow_symlinks.unwrap_or(true) {
+            self.provider.get_entry_info(&req.path).await
+        } else {
+            self.provider.get_link_info(&req.path).await
+        };
+
+        match entry {
             Ok(entry) => Ok(Response::new(GetFileInfoResponse {
                 success: true,
                 error_message: String::new(),
+                // Провайдер, который не проверяет права, не обещает запись
+                is_writable: entry.is_writable.unwrap_or(false),
+                is_symlink: entry.symlink_target.is_some(),
+                symlink_target: entry.symlink_target.clone().unwrap_or_default(),
                 file_info: Some(DirectoryEntry::from(entry)),
+                not_found: false,
             })),
             Err(e) => Ok(Response::new(GetFileInfoResponse {
                 success: false,
                 error_message: e.to_string(),
-                file_info: None,
+                not_found: matches!(e, StorageError::NotFound(_)),
+                ..Default::default()
             })),
         }
     }
 
+    async fn get_directory_stats(
+        &self,
+        request: Request<GetDirectoryStatsRequest>,
+    ) -> Result<Response<GetDirectoryStatsResponse>, Status> {
+        let req = request.into_inner();
+        info!("Статистика директории: {}", req.path);
+
+        match self.provider.get_directory_stats(&req.path).await {
+            Ok(stats) => Ok(Response::new(GetDirectoryStatsResponse {
+                success: true,
+                error_message: String::new(),
+                total_size: stats.total_size,
+                file_count: stats.file_count,
+                dir_count: stats.dir_count,
+                errors: stats.errors.into_iter().map(PathError::from).collect(),
+            })),
+            Err(e) => {
+                error!("Ошибка подсчёта статистики {}: {}", req.path, e);
+                Ok(Response::new(GetDirectoryStatsResponse {
+                    success: false,
+                    error_message: e.to_string(),
+                    ..Default::default()
+                }))
+            }
+        }
+    }
+
+    async fn get_image_metadata(
+        &self,
+        request: Request<GetImageMetadataRequest>,
+    ) -> Result<Response<GetImageMetadataResponse>, Status> {
+        let req = request.into_inner();
+
+        match self.provider.get_image_metadata(&req.path).await {
+            Ok(metadata) => Ok(Response::new(GetImageMetadataResponse {
+                success: true,
+                error_message: String::new(),
+                metadata: Some(metadata.into()),
+            })),
+            Err(e) => {
+                error!("Ошибка чтения EXIF {}: {}", req.path, e);
+                Ok(Response::new(GetImageMetadataResponse {
+                    success: false,
+                    error_message: e.to_string(),
+                    metadata: None,
+                }))
+            }
+        }
+    }
+
+    type FindDuplicatesStream = Pin<Box<dyn Stream<Item = Result<DuplicateGroup, Status>> + Send>>;
+
+    async fn find_duplicates(
+        &self,
+        request: Request<FindDuplicatesRequest>,
+    ) -> Result<Response<Self::FindDuplicatesStream>, Status> {
+        let req = request.into_inner();
+        info!("Поиск дубликатов: {}", req.root_path);
+
+        let groups = self
+            .provider
+            .find_duplicates(&req.root_path)
+            .await
+            .map_err(storage_status)?;
+
+        let stream = async_stream::try_stream! {
+            let mut groups = groups;
+            while let Some(group) = groups.next().await {
+                yield DuplicateGroup::from(group.map_err(storage_status)?);
+            }
+        };
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+
     // === Проекты ===
 
     async fn init_project_structure(
@@ -308,9 +1623,16 @@ impl file_gateway_server::FileGateway for FileGatewayImpl {
         request: Request<InitProjectStructureRequest>,
     ) -> Result<Response<InitProjectStructureResponse>, Status> {
         let req = request.into_inner();
-        info!("Инициализация проекта: {} в {}", req.project_name, req.base_path);
+        info!(
+            "Инициализация проекта: {} в {} (слияние: {})",
+            req.project_name, req.base_path, req.merge
+        );
 
-        match self.provider.init_project_structure(&req.base_path, &req.project_name).await {
+        match self
+            .provider
+            .init_project_structure(&req.base_path, &req.project_name, req.merge)
+            .await
+        {
             Ok(structure) => Ok(Response::new(InitProjectStructureResponse {
                 success: true,
                 error_message: String::new(),
@@ -333,3 +1655,723 @@ impl file_gateway_server::FileGateway for FileGatewayImpl {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto::file_gateway_server::FileGateway;
+    use crate::storage::{MemoryStorageProvider, S3StorageProvider, StorageConfig};
+    use crate::testing::TestFileGateway;
+
+    async fn memory_gateway() -> TestFileGateway {
+        let provider = MemoryStorageProvider::new(&StorageConfig::memory());
+        TestFileGateway::with_provider(Arc::new(provider)).await
+    }
+
+    /// Данные на несколько чанков загрузки и скачивания
+    fn sample_data() -> Vec<u8> {
+        (0..200_000u32).map(|i| (i % 251) as u8).collect()
+    }
+
+    #[tokio::test]
+    async fn upload_then_download_round_trips() {
+        let gateway = memory_gateway().await;
+        let data = sample_data();
+
+        let uploaded = gateway.upload("/", "clip.mp4", &data, false).await.unwrap();
+        assert!(uploaded.success);
+        assert_eq!(uploaded.file_path, "/clip.mp4");
+        assert_eq!(uploaded.bytes_written, data.len() as u64);
+
+        assert_eq!(gateway.download("/clip.mp4").await.unwrap(), data);
+    }
+
+    #[tokio::test]
+    async fn upload_to_local_storage_writes_file() {
+        let gateway = TestFileGateway::start().await;
+        let data = sample_data();
+
+        let uploaded = gateway.upload(gateway.root(), "clip.mp4", &data, false).await.unwrap();
+        assert_eq!(uploaded.file_path, gateway.path("clip.mp4"));
+        assert_eq!(std::fs::read(gateway.path("clip.mp4")).unwrap(), data);
+    }
+
+    #[tokio::test]
+    async fn zero_byte_upload_creates_empty_file() {
+        let gateway = TestFileGateway::start().await;
+
+        let uploaded = gateway.upload(gateway.root(), "empty.txt", b"", false).await.unwrap();
+        assert!(uploaded.success);
+        assert_eq!(uploaded.bytes_written, 0);
+        assert_eq!(std::fs::metadata(gateway.path("empty.txt")).unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn empty_upload_stream_is_rejected() {
+        let gateway = TestFileGateway::start().await;
+
+        let status = gateway
+            .client
+            .clone()
+            .upload_file(tokio_stream::iter(Vec::<UploadFileRequest>::new()))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(status.metadata().get(ERROR_REASON_KEY).unwrap(), "EMPTY_UPLOAD_STREAM");
+        assert_eq!(std::fs::read_dir(gateway.root()).unwrap().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn upload_without_overwrite_keeps_existing_file() {
+        let gateway = memory_gateway().await;
+        gateway.upload("/", "clip.mp4", b"first", false).await.unwrap();
+
+        let status = gateway.upload("/", "clip.mp4", b"second", false).await.unwrap_err();
+        assert_eq!(status.code(), Code::AlreadyExists);
+        assert_eq!(gateway.download("/clip.mp4").await.unwrap(), b"first");
+
+        gateway.upload("/", "clip.mp4", b"second", true).await.unwrap();
+        assert_eq!(gateway.download("/clip.mp4").await.unwrap(), b"second");
+    }
+
+    #[test]
+    fn storage_errors_map_to_grpc_codes() {
+        use std::io::{Error, ErrorKind};
+
+        let cases = [
+            (StorageError::NotFound("a".into()), Code::NotFound),
+            (StorageError::PermissionDenied("a".into()), Code::PermissionDenied),
+            (StorageError::NotSupported, Code::Unimplemented),
+            (StorageError::InvalidName("a/b".into()), Code::InvalidArgument),
+            (StorageError::NotAFile("a".into()), Code::InvalidArgument),
+            (StorageError::AlreadyExists("a".into()), Code::AlreadyExists),
+            (StorageError::QuotaExceeded(1), Code::ResourceExhausted),
+            (StorageError::Io(Error::from(ErrorKind::NotFound)), Code::NotFound),
+            (StorageError::Io(Error::from(ErrorKind::PermissionDenied)), Code::PermissionDenied),
+            (StorageError::Io(Error::other("disk")), Code::Internal),
+        ];
+        for (error, code) in cases {
+            let reason = error.reason();
+            let status = storage_status(error);
+            assert_eq!(status.code(), code, "{}", reason);
+            assert_eq!(status.metadata().get(ERROR_REASON_KEY).unwrap(), reason);
+        }
+    }
+
+    /// Имена, которые при соединении с директорией вывели бы путь за её пределы
+    const MALICIOUS_NAMES: &[&str] = &[
+        "../evil.sh",
+        "../../evil.sh",
+        "..",
+        ".",
+        "",
+        "nested/evil.sh",
+        "..\\evil.sh",
+        "/evil.sh",
+        "evil\0.sh",
+    ];
+
+    #[tokio::test]
+    async fn upload_with_malicious_filename_writes_nothing() {
+        let gateway = TestFileGateway::start().await;
+        let uploads = gateway.path("uploads");
+        std::fs::create_dir(&uploads).unwrap();
+
+        for name in MALICIOUS_NAMES {
+            let status = gateway.upload(&uploads, name, b"#!/bin/sh", true).await.unwrap_err();
+            assert_eq!(status.code(), Code::InvalidArgument, "имя {:?}", name);
+        }
+
+        assert_eq!(std::fs::read_dir(&uploads).unwrap().count(), 0);
+        let root_entries: Vec<_> = std::fs::read_dir(gateway.root())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(root_entries, ["uploads"]);
+        let outside = Path::new(gateway.root()).parent().unwrap().join("evil.sh");
+        assert!(!outside.exists());
+    }
+
+    #[tokio::test]
+    async fn init_project_with_malicious_name_creates_nothing() {
+        let gateway = TestFileGateway::start().await;
+
+        for name in MALICIOUS_NAMES {
+            let response = gateway
+                .client
+                .clone()
+                .init_project_structure(InitProjectStructureRequest {
+                    base_path: gateway.root().to_string(),
+                    project_name: name.to_string(),
+                    merge: false,
+                })
+                .await;
+            let failed = match response {
+                Ok(response) => !response.into_inner().success,
+                Err(_) => true,
+            };
+            assert!(failed, "имя {:?}", name);
+        }
+
+        assert_eq!(std::fs::read_dir(gateway.root()).unwrap().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn writes_outside_root_are_refused() {
+        let gateway = TestFileGateway::start().await;
+        let outside = tempfile::tempdir().unwrap();
+        let outside_path = outside.path().to_string_lossy().to_string();
+        let outside_name = outside.path().file_name().unwrap().to_string_lossy().to_string();
+        let escaping = gateway.path(&format!("../{}", outside_name));
+
+        for path in [format!("{}/escaped", outside_path), format!("{}/escaped", escaping)] {
+            let response = gateway
+                .client
+                .clone()
+                .create_directory(CreateDirectoryRequest {
+                    path: path.clone(),
+                    ..Default::default()
+                })
+                .await
+                .unwrap()
+                .into_inner();
+            assert!(!response.success, "путь {}", path);
+        }
+
+        let status = gateway
+            .upload(&outside_path, "evil.sh", b"#!/bin/sh", true)
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::PermissionDenied);
+        assert_eq!(std::fs::read_dir(outside.path()).unwrap().count(), 0);
+
+        for base_path in [outside_path.clone(), escaping, String::new()] {
+            let response = gateway
+                .client
+                .clone()
+                .init_project_structure(InitProjectStructureRequest {
+                    base_path: base_path.clone(),
+                    project_name: "escaped".to_string(),
+                    merge: false,
+                })
+                .await;
+            let failed = match response {
+                Ok(response) => !response.into_inner().success,
+                Err(_) => true,
+            };
+            assert!(failed, "базовый путь {:?}", base_path);
+        }
+        assert_eq!(std::fs::read_dir(outside.path()).unwrap().count(), 0);
+        assert!(!Path::new("escaped").exists());
+
+        // Внутри корня всё по-прежнему можно
+        gateway.upload(gateway.root(), "ok.txt", b"ok", false).await.unwrap();
+    }
+
+    /// Дождаться, пока в директории станет `count` записей
+    async fn wait_for_entries(dir: &str, count: usize) -> Vec<String> {
+        for _ in 0..100 {
+            let entries: Vec<String> = std::fs::read_dir(dir)
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+                .collect();
+            if entries.len() == count {
+                return entries;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        panic!("В {} не дождались {} записей", dir, count);
+    }
+
+    #[tokio::test]
+    async fn aborted_upload_leaves_previous_file_and_no_partial() {
+        let gateway = TestFileGateway::start().await;
+        std::fs::write(gateway.path("clip.mp4"), b"old").unwrap();
+
+        let metadata = UploadFileMetadata {
+            destination_path: gateway.root().to_string(),
+            filename: "clip.mp4".to_string(),
+            total_size: 1024 * 1024,
+            overwrite: true,
+            ..Default::default()
+        };
+        let messages = [
+            upload_file_request::Data::Metadata(metadata),
+            upload_file_request::Data::Chunk(vec![1u8; 64 * 1024]),
+        ]
+        .map(|data| UploadFileRequest { data: Some(data) });
+        // Клиент отправил начало файла и завис
+        let requests = tokio_stream::iter(messages).chain(tokio_stream::pending());
+
+        let mut client = gateway.client.clone();
+        let upload = tokio::spawn(async move { client.upload_file(requests).await });
+
+        // Загрузка началась - рядом появился временный файл
+        wait_for_entries(gateway.root(), 2).await;
+        upload.abort();
+
+        let entries = wait_for_entries(gateway.root(), 1).await;
+        assert_eq!(entries, ["clip.mp4"]);
+        assert_eq!(std::fs::read(gateway.path("clip.mp4")).unwrap(), b"old");
+    }
+
+    #[tokio::test]
+    async fn upload_shorter_than_total_size_is_rejected() {
+        let gateway = memory_gateway().await;
+        gateway.upload("/", "clip.mp4", b"old", false).await.unwrap();
+
+        let metadata = UploadFileMetadata {
+            destination_path: "/".to_string(),
+            filename: "clip.mp4".to_string(),
+            total_size: 10,
+            overwrite: true,
+            ..Default::default()
+        };
+        let messages = [
+            upload_file_request::Data::Metadata(metadata),
+            upload_file_request::Data::Chunk(b"new".to_vec()),
+        ]
+        .map(|data| UploadFileRequest { data: Some(data) });
+
+        let status = gateway
+            .client
+            .clone()
+            .upload_file(tokio_stream::iter(messages))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(gateway.download("/clip.mp4").await.unwrap(), b"old");
+    }
+
+    #[tokio::test]
+    async fn dropped_download_stops_reading_provider() {
+        let gateway = memory_gateway().await;
+        let data = vec![3u8; 16 * 1024 * 1024];
+        gateway.upload("/", "clip.mp4", &data, false).await.unwrap();
+
+        let mut stream = gateway
+            .client
+            .clone()
+            .download_file(DownloadFileRequest {
+                path: "/clip.mp4".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        // Метаданные и первый чанк
+        stream.next().await.unwrap().unwrap();
+        stream.next().await.unwrap().unwrap();
+        drop(stream);
+
+        let bytes_read = || async {
+            gateway
+                .client
+                .clone()
+                .get_storage_metrics(GetStorageMetricsRequest {})
+                .await
+                .unwrap()
+                .into_inner()
+                .bytes_read
+        };
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let after_drop = bytes_read().await;
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        assert!(after_drop < data.len() as u64 / 2, "прочитано {} байт", after_drop);
+        assert_eq!(bytes_read().await, after_drop);
+    }
+
+    #[tokio::test]
+    async fn download_range_returns_requested_bytes() {
+        let gateway = memory_gateway().await;
+        let data = sample_data();
+        gateway.upload("/", "clip.mp4", &data, false).await.unwrap();
+
+        let mut stream = gateway
+            .client
+            .clone()
+            .download_file(DownloadFileRequest {
+                path: "/clip.mp4".to_string(),
+                offset: 70_000,
+                length: Some(100_000),
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .into_inner();
+
+        let mut received = Vec::new();
+        while let Some(message) = stream.next().await {
+            match message.unwrap().data {
+                Some(download_file_response::Data::Metadata(metadata)) => {
+                    assert_eq!(metadata.total_size, data.len() as u64);
+                    assert_eq!(metadata.offset, 70_000);
+                    assert_eq!(metadata.length, 100_000);
+                }
+                Some(download_file_response::Data::Chunk(chunk)) => received.extend(chunk),
+                _ => {}
+            }
+        }
+        assert_eq!(received, &data[70_000..170_000]);
+    }
+
+    #[tokio::test]
+    async fn download_missing_file_is_not_found() {
+        let gateway = memory_gateway().await;
+
+        let status = gateway.download("/missing.mp4").await.unwrap_err();
+        assert_eq!(status.code(), Code::NotFound);
+        assert_eq!(status.metadata().get(ERROR_REASON_KEY).unwrap(), "NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn download_directory_is_invalid_argument() {
+        let gateway = TestFileGateway::start().await;
+        std::fs::create_dir(gateway.path("media")).unwrap();
+
+        let status = gateway.download(&gateway.path("media")).await.unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn browse_directory_lists_uploaded_files() {
+        let gateway = memory_gateway().await;
+        let mut client = gateway.client.clone();
+        client
+            .create_directory(CreateDirectoryRequest {
+                path: "/media".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        gateway.upload("/media", "b.mp4", b"bb", false).await.unwrap();
+        gateway.upload("/media", "a.mp4", b"a", false).await.unwrap();
+        client
+            .create_directory(CreateDirectoryRequest {
+                path: "/media/clips".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let listing = client
+            .browse_directory(BrowseDirectoryRequest {
+                path: "/media".to_string(),
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(listing.success, "{}", listing.error_message);
+        assert_eq!(listing.current_path, "/media");
+        assert_eq!(listing.parent_path, "/");
+
+        // Директории сверху, затем файлы по имени
+        let entries: Vec<(&str, bool, u64)> = listing
+            .entries
+            .iter()
+            .map(|entry| (entry.name.as_str(), entry.is_directory, entry.size))
+            .collect();
+        assert_eq!(entries, [("clips", true, 0), ("a.mp4", false, 1), ("b.mp4", false, 2)]);
+    }
+
+    #[tokio::test]
+    async fn browse_missing_directory_reports_error() {
+        let gateway = memory_gateway().await;
+
+        let listing = gateway
+            .client
+            .clone()
+            .browse_directory(BrowseDirectoryRequest {
+                path: "/missing".to_string(),
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(!listing.success);
+        assert!(listing.entries.is_empty());
+    }
+
+    fn memory_storage(id: &str) -> Arc<dyn StorageProvider> {
+        let config = StorageConfig {
+            id: Some(id.to_string()),
+            ..StorageConfig::memory()
+        };
+        Arc::new(MemoryStorageProvider::new(&config))
+    }
+
+    fn transfer_request(source: (&str, &str), destination: (&str, &str)) -> TransferRequest {
+        TransferRequest {
+            source_storage_id: source.0.to_string(),
+            source_path: source.1.to_string(),
+            destination_storage_id: destination.0.to_string(),
+            destination_path: destination.1.to_string(),
+            delete_source: true,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn transfer_moves_file_to_another_storage() {
+        let main = memory_storage("main");
+        let archive = memory_storage("archive");
+        main.upload_bytes("/clip.mp4", sample_data().into(), false).await.unwrap();
+        let gateway = FileGatewayImpl::with_provider(main.clone())
+            .with_transfer_storage(archive.clone());
+
+        let request = transfer_request(("", "/clip.mp4"), ("archive", "/clip.mp4"));
+        let response = gateway.transfer(Request::new(request)).await.unwrap().into_inner();
+        assert!(response.success, "{}", response.error_message);
+        assert_eq!(response.bytes_transferred, sample_data().len() as u64);
+        assert!(response.source_deleted);
+
+        assert_eq!(archive.download_bytes("/clip.mp4").await.unwrap(), sample_data());
+        assert!(!main.exists("/clip.mp4").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn transfer_to_unknown_storage_keeps_source() {
+        let main = memory_storage("main");
+        main.upload_bytes("/clip.mp4", sample_data().into(), false).await.unwrap();
+        let gateway = FileGatewayImpl::with_provider(main.clone());
+
+        let request = transfer_request(("main", "/clip.mp4"), ("archive", "/clip.mp4"));
+        let response = gateway.transfer(Request::new(request)).await.unwrap().into_inner();
+        assert!(!response.success);
+        assert!(main.exists("/clip.mp4").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn transfer_onto_same_file_is_rejected() {
+        let main = memory_storage("main");
+        main.upload_bytes("/media/clip.mp4", sample_data().into(), false).await.unwrap();
+        let gateway = FileGatewayImpl::with_provider(main.clone());
+
+        let request = transfer_request(("", "/media/clip.mp4"), ("main", "/media//./clip.mp4"));
+        let response = gateway.transfer(Request::new(request)).await.unwrap().into_inner();
+        assert!(!response.success);
+        assert_eq!(main.download_bytes("/media/clip.mp4").await.unwrap(), sample_data());
+    }
+
+    /// FIFO, в который поток пишет данные, пока читатель не закроет его
+    ///
+    /// Возвращает поток записи: он завершается с `true`, если запись
+    /// оборвалась (`BrokenPipe`) в пределах `timeout`.
+    #[cfg(unix)]
+    fn endless_fifo(path: &Path, timeout: Duration) -> std::thread::JoinHandle<bool> {
+        use std::ffi::CString;
+        use std::io::Write;
+        use std::os::unix::ffi::OsStrExt;
+
+        let c_path = CString::new(path.as_os_str().as_bytes()).unwrap();
+        assert_eq!(unsafe { libc::mkfifo(c_path.as_ptr(), 0o600) }, 0);
+
+        let path = path.to_path_buf();
+        std::thread::spawn(move || {
+            // Открытие блокируется, пока FIFO не откроют на чтение
+            let mut fifo = std::fs::OpenOptions::new().write(true).open(&path).unwrap();
+            let started = std::time::Instant::now();
+            while started.elapsed() < timeout {
+                if let Err(e) = fifo.write_all(&[0u8; 4096]) {
+                    return e.kind() == std::io::ErrorKind::BrokenPipe;
+                }
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            false
+        })
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn cancelled_transfer_stops_reading_source() {
+        let gateway = TestFileGateway::start().await;
+        let source = gateway.path("stream.fifo");
+        let writer = endless_fifo(Path::new(&source), Duration::from_secs(10));
+
+        let mut stream = gateway
+            .client
+            .clone()
+            .transfer_with_progress(TransferRequest {
+                source_path: source,
+                destination_path: gateway.path("copy.bin"),
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .into_inner();
+
+        // Ждём, пока перенос начнёт читать, и отменяем вызов
+        loop {
+            let event = stream.next().await.unwrap().unwrap().event;
+            match event {
+                Some(transfer_event::Event::Progress(p)) if p.bytes_done > 0 => break,
+                Some(transfer_event::Event::Result(r)) => panic!("Перенос завершился: {:?}", r),
+                _ => {}
+            }
+        }
+        drop(stream);
+
+        let stopped = tokio::task::spawn_blocking(move || writer.join().unwrap()).await.unwrap();
+        assert!(stopped, "Перенос продолжил читать источник после отмены");
+        assert!(!Path::new(&gateway.path("copy.bin")).exists());
+    }
+
+    /// Результат шага: успех и вид ошибки
+    ///
+    /// Вид ошибки - текст до двоеточия или код gRPC: путь в тексте у
+    /// провайдеров разный.
+    type Outcome = (&'static str, bool, String);
+
+    async fn create_directory_outcome(
+        gateway: &TestFileGateway,
+        step: &'static str,
+        relative: &str,
+        create_parents: bool,
+    ) -> Outcome {
+        let response = gateway
+            .client
+            .clone()
+            .create_directory(CreateDirectoryRequest {
+                path: gateway.path(relative),
+                create_parents,
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        (step, response.success, error_kind(&response.error_message))
+    }
+
+    async fn delete_outcome(
+        gateway: &TestFileGateway,
+        step: &'static str,
+        relative: &str,
+        recursive: bool,
+    ) -> Outcome {
+        let response = gateway
+            .client
+            .clone()
+            .delete(DeleteRequest {
+                path: gateway.path(relative),
+                recursive,
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        (step, response.success, error_kind(&response.error_message))
+    }
+
+    async fn upload_outcome(
+        gateway: &TestFileGateway,
+        step: &'static str,
+        data: &[u8],
+        overwrite: bool,
+    ) -> Outcome {
+        match gateway.upload(&gateway.path("media"), "a.mp4", data, overwrite).await {
+            Ok(response) => (step, response.success, error_kind(&response.error_message)),
+            Err(status) => (step, false, format!("{:?}", status.code())),
+        }
+    }
+
+    fn error_kind(message: &str) -> String {
+        message.split(':').next().unwrap_or_default().to_string()
+    }
+
+    /// Создание, перезапись и удаление в хранилище `gateway`
+    async fn mutation_outcomes(gateway: &TestFileGateway) -> Vec<Outcome> {
+        let content = |step, data: &'static [u8]| async move {
+            let path = gateway.path("media/a.mp4");
+            (step, gateway.download(&path).await.unwrap() == data, String::new())
+        };
+
+        vec![
+            create_directory_outcome(gateway, "создание", "media", false).await,
+            create_directory_outcome(gateway, "повторное создание", "media", false).await,
+            create_directory_outcome(gateway, "без родителей", "media/clips/day1", false).await,
+            create_directory_outcome(gateway, "с родителями", "media/clips/day1", true).await,
+            upload_outcome(gateway, "загрузка", b"one", false).await,
+            upload_outcome(gateway, "загрузка без перезаписи", b"two", false).await,
+            content("прежнее содержимое", b"one").await,
+            upload_outcome(gateway, "загрузка с перезаписью", b"two", true).await,
+            content("новое содержимое", b"two").await,
+            delete_outcome(gateway, "непустая без recursive", "media", false).await,
+            delete_outcome(gateway, "файл", "media/a.mp4", false).await,
+            delete_outcome(gateway, "отсутствующий файл", "media/a.mp4", false).await,
+            delete_outcome(gateway, "пустая без recursive", "media/clips/day1", false).await,
+            delete_outcome(gateway, "recursive", "media", true).await,
+            delete_outcome(gateway, "удалённая", "media", true).await,
+        ]
+    }
+
+    #[tokio::test]
+    async fn memory_storage_mutations_match_local_storage() {
+        let memory = mutation_outcomes(&memory_gateway().await).await;
+        let local = mutation_outcomes(&TestFileGateway::start().await).await;
+
+        // Ошибки файловой системы локальный провайдер передаёт как есть
+        // (`Io` с её видом), хранилище в памяти - так же
+        let ok = |step| (step, true, String::new());
+        let failed = |step, kind: &str| (step, false, kind.to_string());
+        let expected = vec![
+            ok("создание"),
+            failed("повторное создание", "Ошибка ввода-вывода"),
+            failed("без родителей", "Ошибка ввода-вывода"),
+            ok("с родителями"),
+            ok("загрузка"),
+            failed("загрузка без перезаписи", "AlreadyExists"),
+            ok("прежнее содержимое"),
+            ok("загрузка с перезаписью"),
+            ok("новое содержимое"),
+            failed("непустая без recursive", "Ошибка ввода-вывода"),
+            ok("файл"),
+            failed("отсутствующий файл", "Путь не существует"),
+            ok("пустая без recursive"),
+            ok("recursive"),
+            failed("удалённая", "Путь не существует"),
+        ];
+        assert_eq!(local, expected);
+        assert_eq!(memory, expected);
+    }
+
+    /// Хранилище MinIO для проверки загрузки в S3
+    ///
+    /// Запуск: `docker run -p 9000:9000 minio/minio server /data`, создать
+    /// бакет (например, `mc mb`), задать `DIRECTOR_TEST_S3_ENDPOINT`,
+    /// `DIRECTOR_TEST_S3_ACCESS_KEY`, `DIRECTOR_TEST_S3_SECRET_KEY`,
+    /// `DIRECTOR_TEST_S3_BUCKET` и выполнить `cargo test -- --ignored`.
+    fn minio_config() -> StorageConfig {
+        let var = |name: &str| {
+            std::env::var(name).unwrap_or_else(|_| panic!("Не задана переменная {}", name))
+        };
+        StorageConfig::minio(
+            &var("DIRECTOR_TEST_S3_ENDPOINT"),
+            &var("DIRECTOR_TEST_S3_ACCESS_KEY"),
+            &var("DIRECTOR_TEST_S3_SECRET_KEY"),
+            &var("DIRECTOR_TEST_S3_BUCKET"),
+        )
+    }
+
+    #[tokio::test]
+    #[ignore = "нужен MinIO, см. minio_config"]
+    async fn s3_upload_reports_bytes_written() {
+        let provider = Arc::new(S3StorageProvider::new(&minio_config()).unwrap());
+        let gateway = TestFileGateway::with_provider(provider.clone()).await;
+        let dir = format!("/director-test-{}", uuid::Uuid::new_v4());
+        provider.create_directory(&dir, true, None).await.unwrap();
+
+        // 20 МиБ - три части по 8 МиБ, 1 МиБ - одна часть через PutObject
+        for (name, size) in [("large.bin", 20 * 1024 * 1024), ("small.bin", 1024 * 1024)] {
+            let data: Vec<u8> = (0..size).map(|i| (i % 251) as u8).collect();
+            let response = gateway.upload(&dir, name, &data, false).await.unwrap();
+            assert!(response.success, "{}", response.error_message);
+            assert_eq!(response.bytes_written, size as u64);
+
+            let path = format!("{}/{}", dir, name);
+            assert_eq!(provider.get_entry_info(&path).await.unwrap().size, size as u64);
+            assert!(gateway.download(&path).await.unwrap() == data, "{} повреждён", name);
+        }
+
+        provider.delete_directory(&dir, true).await.unwrap();
+    }
+}
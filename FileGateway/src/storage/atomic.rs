@@ -0,0 +1,173 @@
+//! Атомарная запись файла через временный `.part` файл
+//!
+//! Данные пишутся во временный файл, который переименовывается в конечный
+//! только при закрытии потока (`shutdown`). Читатели не видят недописанный
+//! файл, а прерванная перезапись не портит прежнее содержимое. Переименование
+//! атомарно только в пределах одной файловой системы, поэтому временная
+//! директория должна быть на той же файловой системе, что и конечный файл.
+
+use std::future::Future;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+
+use tokio::fs;
+use tokio::io::AsyncWrite;
+use tracing::warn;
+use uuid::Uuid;
+
+use super::mode::set_mode;
+use super::quota::Quota;
+
+/// Расширение временных файлов загрузки
+pub const PART_EXTENSION: &str = "part";
+
+/// Переименование временного файла в конечный; результат - размер прежнего файла
+type Commit = Pin<Box<dyn Future<Output = io::Result<u64>> + Send>>;
+
+/// Поток записи в `.part` файл с переименованием в конечный при закрытии
+///
+/// Если поток уничтожен до успешного `shutdown`, временный файл удаляется.
+pub struct AtomicWriter {
+    /// `None` после закрытия: на Windows открытый файл нельзя переименовать
+    file: Option<fs::File>,
+    temp_path: PathBuf,
+    destination: PathBuf,
+    commit: Option<Commit>,
+    committed: bool,
+    /// Квота конечного файла: при замене освобождается прежний размер,
+    /// при отмене - записанные байты
+    quota: Option<Arc<Quota>>,
+    written: u64,
+}
+
+impl AtomicWriter {
+    /// Создать скрытый временный файл для `destination` в `temp_dir`
+    ///
+    /// Права `mode` ставятся временному файлу и сохраняются при переименовании.
+    pub async fn create(
+        temp_dir: &Path,
+        destination: PathBuf,
+        quota: Option<Arc<Quota>>,
+        mode: Option<u32>,
+    ) -> io::Result<Self> {
+        let name = destination
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let temp_path = temp_dir.join(format!(".{}.{}.{}", name, Uuid::new_v4(), PART_EXTENSION));
+        let file = fs::File::create(&temp_path).await?;
+
+        let writer = Self {
+            file: Some(file),
+            temp_path,
+            destination,
+            commit: None,
+            committed: false,
+            quota,
+            written: 0,
+        };
+        // При ошибке writer уничтожается и удаляет временный файл
+        set_mode(&writer.temp_path, mode).await?;
+        Ok(writer)
+    }
+
+    fn file(&mut self) -> io::Result<Pin<&mut fs::File>> {
+        self.file
+            .as_mut()
+            .map(Pin::new)
+            .ok_or_else(|| io::Error::other("поток записи уже закрыт"))
+    }
+}
+
+impl AsyncWrite for AtomicWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let written = ready!(this.file()?.poll_write(cx, buf))?;
+        this.written += written as u64;
+        Poll::Ready(Ok(written))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.get_mut().file.as_mut() {
+            Some(file) => Pin::new(file).poll_flush(cx),
+            None => Poll::Ready(Ok(())),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if this.committed {
+            return Poll::Ready(Ok(()));
+        }
+
+        if this.commit.is_none() {
+            ready!(this.file()?.poll_shutdown(cx))?;
+            this.file = None;
+
+            let temp_path = this.temp_path.clone();
+            let destination = this.destination.clone();
+            this.commit = Some(Box::pin(async move {
+                let previous_size = fs::metadata(&destination).await.map_or(0, |m| m.len());
+                fs::rename(&temp_path, &destination).await?;
+                Ok(previous_size)
+            }));
+        }
+
+        let result = ready!(this.commit.as_mut().expect("commit задан выше").as_mut().poll(cx));
+        this.commit = None;
+        let previous_size = result?;
+
+        this.committed = true;
+        if let Some(quota) = &this.quota {
+            // Прежний файл заменён - его размер больше не занят
+            quota.release(previous_size);
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Drop for AtomicWriter {
+    fn drop(&mut self) {
+        if self.committed {
+            return;
+        }
+
+        self.file = None;
+        if let Err(e) = std::fs::remove_file(&self.temp_path) {
+            if e.kind() != io::ErrorKind::NotFound {
+                warn!(
+                    "Не удалось удалить временный файл {}: {}",
+                    self.temp_path.display(),
+                    e
+                );
+            }
+        }
+        if let Some(quota) = &self.quota {
+            quota.release(self.written);
+        }
+    }
+}
+
+/// Лежат ли две существующие директории на одной файловой системе
+pub async fn same_filesystem(a: &Path, b: &Path) -> io::Result<bool> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        Ok(fs::metadata(a).await?.dev() == fs::metadata(b).await?.dev())
+    }
+
+    #[cfg(not(unix))]
+    {
+        // Без номера устройства сравниваем диски (префикс пути)
+        let a = fs::canonicalize(a).await?;
+        let b = fs::canonicalize(b).await?;
+        Ok(a.components().next() == b.components().next())
+    }
+}
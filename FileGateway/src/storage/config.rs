@@ -1,15 +1,192 @@
 //! Конфигурация хранилища
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
 use std::path::PathBuf;
 
-/// Тип хранилища
+/// Тип хранилища - имя, под которым зарегистрирован провайдер
+///
+/// Встроенные типы - `local`, `s3` и `memory` (в памяти, для тестов);
+/// другие добавляются через `register_provider`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct StorageType(String);
+
+impl StorageType {
+    pub const LOCAL: &'static str = "local";
+    pub const S3: &'static str = "s3";
+    pub const MEMORY: &'static str = "memory";
+
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+
+    pub fn local() -> Self {
+        Self::new(Self::LOCAL)
+    }
+
+    pub fn s3() -> Self {
+        Self::new(Self::S3)
+    }
+
+    pub fn memory() -> Self {
+        Self::new(Self::MEMORY)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for StorageType {
+    fn default() -> Self {
+        Self::local()
+    }
+}
+
+impl fmt::Display for StorageType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Как настроенные корневые пути сочетаются с найденными автоматически
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
-pub enum StorageType {
+pub enum RootPathsMode {
+    /// Добавить к найденным дискам и точкам монтирования
+    #[default]
+    Augment,
+    /// Использовать только настроенные пути
+    Replace,
+}
+
+/// Алгоритм шифрования содержимого
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum EncryptionAlgorithm {
     #[default]
-    Local,
-    S3,
+    #[serde(rename = "aes-256-gcm")]
+    Aes256Gcm,
+}
+
+/// Источник ключа шифрования (32 байта в base64)
+///
+/// В JSON: `{"env": "DIRECTOR_STORAGE_KEY"}` или `{"file": "/etc/director/storage.key"}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeySource {
+    /// Переменная окружения
+    Env(String),
+    /// Файл с ключом
+    File(PathBuf),
+}
+
+/// Настройки шифрования содержимого файлов
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncryptionConfig {
+    /// Алгоритм
+    #[serde(default)]
+    pub algorithm: EncryptionAlgorithm,
+
+    /// Откуда взять ключ
+    pub key: KeySource,
+}
+
+/// Повтор операций чтения при временных ошибках хранилища
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetryConfig {
+    /// Сколько раз повторить операцию после первой неудачи
+    pub attempts: u32,
+    /// Пауза перед первым повтором (мс); каждая следующая вдвое дольше
+    pub initial_backoff_ms: u64,
+    /// Наибольшая пауза между повторами (мс)
+    pub max_backoff_ms: u64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            attempts: 3,
+            initial_backoff_ms: 100,
+            max_backoff_ms: 2000,
+        }
+    }
+}
+
+/// Названия стандартных папок проекта
+///
+/// Позволяют студии использовать свои (например, локализованные) названия
+/// вместо `assets`, `timeline` и т.д. `video`, `audio` и `images` создаются
+/// внутри `assets`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProjectFolders {
+    pub assets: String,
+    pub video: String,
+    pub audio: String,
+    pub images: String,
+    pub timeline: String,
+    pub exports: String,
+}
+
+impl Default for ProjectFolders {
+    fn default() -> Self {
+        Self {
+            assets: "assets".to_string(),
+            video: "video".to_string(),
+            audio: "audio".to_string(),
+            images: "images".to_string(),
+            timeline: "timeline".to_string(),
+            exports: "exports".to_string(),
+        }
+    }
+}
+
+impl ProjectFolders {
+    /// Проверить, что названия допустимы и соседние папки не совпадают
+    ///
+    /// Совпадение проверяется без учёта регистра: на части файловых систем
+    /// `Assets` и `assets` - одна папка.
+    pub fn validate(&self) -> Result<(), String> {
+        let top = [
+            ("assets", &self.assets),
+            ("timeline", &self.timeline),
+            ("exports", &self.exports),
+        ];
+        let media = [
+            ("video", &self.video),
+            ("audio", &self.audio),
+            ("images", &self.images),
+        ];
+
+        for (slot, name) in top.iter().chain(media.iter()) {
+            let invalid = name.trim().is_empty()
+                || name.contains(['/', '\\', ':', '\0'])
+                || name.as_str() == "."
+                || name.as_str() == "..";
+            if invalid {
+                return Err(format!("Недопустимое название папки `{}`: \"{}\"", slot, name));
+            }
+        }
+
+        for group in [&top[..], &media[..]] {
+            for (i, (slot, name)) in group.iter().enumerate() {
+                let duplicate = group[i + 1..]
+                    .iter()
+                    .find(|(_, other)| other.to_lowercase() == name.to_lowercase());
+                if let Some((other_slot, _)) = duplicate {
+                    return Err(format!(
+                        "Папки `{}` и `{}` названы одинаково: \"{}\"",
+                        slot, other_slot, name
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// Конфигурация хранилища
@@ -31,9 +208,79 @@ pub struct StorageConfig {
     #[serde(default)]
     pub show_hidden: bool,
 
-    // === Настройки для S3 (будущее) ===
+    /// Корневая директория (используется вместо домашней для пустого пути)
+    ///
+    /// Если задана, изменения разрешены только внутри неё и `root_paths`;
+    /// читать по-прежнему можно любые доступные пути.
+    #[serde(default)]
+    pub root_path: Option<String>,
+
+    /// Дополнительные корневые пути для файлового браузера
+    /// (например, нестандартные точки монтирования медиа-хранилищ)
+    #[serde(default)]
+    pub root_paths: Vec<String>,
+
+    /// Добавлять `root_paths` к найденным автоматически или заменять их
+    #[serde(default)]
+    pub root_paths_mode: RootPathsMode,
+
+    /// Уточнять MIME тип по сигнатуре файла, а не только по расширению
+    ///
+    /// Добавляет чтение заголовка каждого файла при листинге директории.
+    #[serde(default)]
+    pub sniff_mime: bool,
+
+    /// Максимальный размер файла (байт), для которого читается сигнатура
+    ///
+    /// По умолчанию 4 МиБ.
+    #[serde(default)]
+    pub sniff_mime_max_size: Option<u64>,
+
+    /// MIME типы для расширений, которые не распознаются или распознаются неверно
+    ///
+    /// Например, `{"dtimeline": "application/vnd.director.timeline+json"}`.
+    /// Расширение указывается без учёта регистра, точка в начале необязательна.
+    #[serde(default)]
+    pub extension_mime_overrides: HashMap<String, String>,
+
+    /// Сколько записей директории опрашивается (stat) одновременно при листинге
+    ///
+    /// На сетевых файловых системах с большой задержкой параллельный опрос
+    /// заметно ускоряет листинг больших папок. По умолчанию 32.
+    #[serde(default)]
+    pub list_concurrency: Option<usize>,
+
+    /// Квота на объём данных (байты) в корневой директории
+    /// (или в директории проектов, если корень не задан)
+    #[serde(default)]
+    pub quota_bytes: Option<u64>,
+
+    /// Названия стандартных папок, создаваемых в новом проекте
+    #[serde(default)]
+    pub project_folders: ProjectFolders,
+
+    /// Директория временных `.part` файлов загрузки (по умолчанию - директория назначения)
+    ///
+    /// Должна быть на той же файловой системе, что и файлы назначения: иначе
+    /// переименование не атомарно, и загрузка идёт через директорию назначения.
+    #[serde(default)]
+    pub upload_temp_dir: Option<String>,
+
+    /// Повторять операции чтения при временных сетевых ошибках
+    /// (полезно для сетевых файловых систем и удалённых хранилищ)
+    #[serde(default)]
+    pub retry: Option<RetryConfig>,
+
+    // === Шифрование ===
+
+    /// Шифровать содержимое файлов (имена остаются открытыми)
+    #[serde(default)]
+    pub encryption: Option<EncryptionConfig>,
+
+    // === Настройки для S3 ===
     
-    /// Endpoint S3 (например, http://localhost:9000 для MinIO)
+    /// Endpoint S3 (например, http://localhost:9000 для MinIO или
+    /// https://s3.eu-central-1.amazonaws.com)
     pub s3_endpoint: Option<String>,
     
     /// Регион
@@ -47,25 +294,63 @@ pub struct StorageConfig {
     
     /// Имя бакета по умолчанию
     pub s3_bucket: Option<String>,
+
+    // === Настройки сторонних провайдеров ===
+
+    /// Произвольные настройки провайдера, зарегистрированного через
+    /// `register_provider`; встроенные провайдеры их не читают
+    #[serde(default)]
+    pub provider_options: HashMap<String, serde_json::Value>,
+
+    // === Другие хранилища ===
+
+    /// Хранилища, доступные для `Transfer` по их `id` (например, S3-архив
+    /// рядом с локальным диском); остальные операции работают только
+    /// с основным хранилищем. `id` у каждого обязателен.
+    #[serde(default)]
+    pub transfer_storages: Vec<StorageConfig>,
 }
 
 impl Default for StorageConfig {
     fn default() -> Self {
         Self {
-            storage_type: StorageType::Local,
+            storage_type: StorageType::local(),
             id: None,
             default_projects_path: None,
             show_hidden: false,
+            root_path: None,
+            root_paths: Vec::new(),
+            root_paths_mode: RootPathsMode::Augment,
+            sniff_mime: false,
+            sniff_mime_max_size: None,
+            extension_mime_overrides: HashMap::new(),
+            list_concurrency: None,
+            quota_bytes: None,
+            project_folders: ProjectFolders::default(),
+            upload_temp_dir: None,
+            retry: None,
+            encryption: None,
             s3_endpoint: None,
             s3_region: None,
             s3_access_key: None,
             s3_secret_key: None,
             s3_bucket: None,
+            provider_options: HashMap::new(),
+            transfer_storages: Vec::new(),
         }
     }
 }
 
+/// Имя файла конфигурации по умолчанию
+pub const DEFAULT_CONFIG_FILE: &str = "storage.json";
+
 impl StorageConfig {
+    /// Путь к конфигурации по умолчанию (`<config_dir>/FileGateway/storage.json`)
+    pub fn default_path() -> Option<PathBuf> {
+        directories::ProjectDirs::from("com", "director", "FileGateway")
+            .map(|dirs| dirs.config_dir().join(DEFAULT_CONFIG_FILE))
+    }
+
     /// Загрузить конфигурацию из файла
     pub fn load(path: &PathBuf) -> Result<Self, Box<dyn std::error::Error>> {
         let content = std::fs::read_to_string(path)?;
@@ -83,7 +368,15 @@ impl StorageConfig {
     /// Создать конфигурацию для локального хранилища
     pub fn local() -> Self {
         Self {
-            storage_type: StorageType::Local,
+            storage_type: StorageType::local(),
+            ..Default::default()
+        }
+    }
+
+    /// Создать конфигурацию для хранилища в памяти
+    pub fn memory() -> Self {
+        Self {
+            storage_type: StorageType::memory(),
             ..Default::default()
         }
     }
@@ -91,7 +384,7 @@ impl StorageConfig {
     /// Создать конфигурацию для MinIO
     pub fn minio(endpoint: &str, access_key: &str, secret_key: &str, bucket: &str) -> Self {
         Self {
-            storage_type: StorageType::S3,
+            storage_type: StorageType::s3(),
             s3_endpoint: Some(endpoint.to_string()),
             s3_region: Some("us-east-1".to_string()),
             s3_access_key: Some(access_key.to_string()),
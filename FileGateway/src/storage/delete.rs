@@ -0,0 +1,156 @@
+//! Рекурсивное удаление больших директорий
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tokio::fs;
+use tokio::task::JoinSet;
+
+use super::walk::record_error;
+use super::PathError;
+
+/// Счётчики удаления, которые опрашиваются, пока оно идёт
+#[derive(Debug, Default)]
+pub struct DeleteProgress {
+    pub files_deleted: AtomicU64,
+    pub directories_deleted: AtomicU64,
+    pub bytes_deleted: AtomicU64,
+    /// Пути, которые не удалось удалить
+    errors: Mutex<Vec<PathError>>,
+}
+
+impl DeleteProgress {
+    /// Значения счётчиков: (файлы, директории, байты)
+    pub fn snapshot(&self) -> (u64, u64, u64) {
+        (
+            self.files_deleted.load(Ordering::Relaxed),
+            self.directories_deleted.load(Ordering::Relaxed),
+            self.bytes_deleted.load(Ordering::Relaxed),
+        )
+    }
+
+    /// Ошибки удаления отдельных путей
+    pub fn errors(&self) -> Vec<PathError> {
+        self.errors.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn record_error(&self, path: &Path, error: std::io::Error) {
+        let mut errors = self.errors.lock().unwrap_or_else(|e| e.into_inner());
+        record_error(&mut errors, path, error);
+    }
+}
+
+/// Удалить дерево директорий, удаляя до `concurrency` файлов одновременно
+///
+/// Символические ссылки не раскрываются: удаляется сама ссылка, поэтому
+/// удаление не выходит за пределы дерева. Если `root` - ссылка, удаляется
+/// только она. Сброс future прерывает удаление (уже удалённое не вернуть).
+///
+/// Путь, который не удалось прочитать или удалить, попадает в ошибки
+/// `progress`, а удаление продолжается; директории с оставшимися элементами
+/// остаются на месте. Ошибкой завершается только недоступный корень.
+pub async fn delete_tree(
+    root: &Path,
+    concurrency: usize,
+    progress: Arc<DeleteProgress>,
+) -> std::io::Result<()> {
+    if fs::symlink_metadata(root).await?.is_symlink() {
+        return remove_link(root).await;
+    }
+
+    // Директории в порядке обхода в ширину: в обратном порядке вложенные
+    // идут раньше родительских
+    let mut directories = vec![root.to_path_buf()];
+    let mut tasks = JoinSet::new();
+    let mut next = 0;
+
+    while next < directories.len() {
+        let directory = directories[next].clone();
+        next += 1;
+
+        let mut entries = match fs::read_dir(&directory).await {
+            Ok(entries) => entries,
+            Err(e) if next == 1 => return Err(e),
+            Err(e) => {
+                progress.record_error(&directory, e);
+                continue;
+            }
+        };
+
+        loop {
+            let entry = match entries.next_entry().await {
+                Ok(Some(entry)) => entry,
+                Ok(None) => break,
+                Err(e) => {
+                    progress.record_error(&directory, e);
+                    break;
+                }
+            };
+
+            // Тип записи без перехода по ссылке
+            match entry.file_type().await {
+                Ok(file_type) if file_type.is_dir() => {
+                    directories.push(entry.path());
+                    continue;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    progress.record_error(&entry.path(), e);
+                    continue;
+                }
+            }
+
+            if tasks.len() >= concurrency.max(1) {
+                if let Some(result) = tasks.join_next().await {
+                    result.map_err(std::io::Error::other)?;
+                }
+            }
+            tasks.spawn(remove_entry(entry.path(), progress.clone()));
+        }
+    }
+
+    while let Some(result) = tasks.join_next().await {
+        result.map_err(std::io::Error::other)?;
+    }
+
+    for directory in directories.iter().rev() {
+        match fs::remove_dir(directory).await {
+            Ok(()) => {
+                progress.directories_deleted.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(e) => progress.record_error(directory, e),
+        }
+    }
+
+    Ok(())
+}
+
+/// Удалить файл или ссылку, учтя его размер (ошибка попадает в `progress`)
+async fn remove_entry(path: PathBuf, progress: Arc<DeleteProgress>) {
+    let removed = async {
+        let metadata = fs::symlink_metadata(&path).await?;
+        if metadata.is_symlink() {
+            remove_link(&path).await?;
+        } else {
+            fs::remove_file(&path).await?;
+            progress.bytes_deleted.fetch_add(metadata.len(), Ordering::Relaxed);
+        }
+        Ok::<_, std::io::Error>(())
+    };
+
+    match removed.await {
+        Ok(()) => {
+            progress.files_deleted.fetch_add(1, Ordering::Relaxed);
+        }
+        Err(e) => progress.record_error(&path, e),
+    }
+}
+
+/// Удалить символическую ссылку (на Windows ссылка на директорию удаляется как директория)
+async fn remove_link(path: &Path) -> std::io::Result<()> {
+    match fs::remove_file(path).await {
+        Err(e) if cfg!(windows) => fs::remove_dir(path).await.map_err(|_| e),
+        result => result,
+    }
+}
@@ -0,0 +1,575 @@
+//! Провайдер-обёртка с шифрованием содержимого файлов
+//!
+//! Шифрует данные при записи и расшифровывает при чтении, делегируя
+//! хранение любому другому провайдеру. Имена файлов и структура директорий
+//! остаются открытыми.
+//!
+//! Формат зашифрованного файла:
+//! - заголовок: `DENC`, версия формата (1 байт), префикс nonce (7 байт);
+//! - блоки по 64 КБ открытого текста, каждый с тегом AES-GCM (16 байт).
+//!
+//! Nonce блока - префикс файла, номер блока и флаг последнего блока
+//! (схема STREAM), поэтому блоки нельзя переставить, а обрезанный файл
+//! не расшифруется. Файл читается и пишется потоково, по одному блоку.
+
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+use std::time::Duration;
+
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use async_trait::async_trait;
+use base64::Engine;
+use bytes::Bytes;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite};
+use tokio_stream::Stream;
+use tokio_util::io::StreamReader;
+
+use super::{
+    config::{EncryptionAlgorithm, EncryptionConfig, KeySource},
+    provider::StorageProvider,
+    DeleteProgress,
+    types::*,
+    StorageError,
+};
+
+/// Сигнатура зашифрованного файла
+const MAGIC: &[u8; 4] = b"DENC";
+
+/// Версия формата
+const FORMAT_VERSION: u8 = 1;
+
+/// Длина случайного префикса nonce
+const NONCE_PREFIX_LEN: usize = 7;
+
+/// Длина заголовка файла
+const HEADER_LEN: usize = MAGIC.len() + 1 + NONCE_PREFIX_LEN;
+
+/// Размер блока открытого текста
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Длина тега аутентификации AES-GCM
+const TAG_LEN: usize = 16;
+
+/// Размер зашифрованного блока
+const FRAME_LEN: usize = CHUNK_SIZE + TAG_LEN;
+
+/// Длина ключа AES-256
+const KEY_LEN: usize = 32;
+
+/// Поток расшифрованных блоков
+type PlaintextStream = Pin<Box<dyn Stream<Item = io::Result<Bytes>> + Send>>;
+
+/// Прочитанный ключ шифрования для выбранного алгоритма
+#[derive(Clone)]
+pub struct EncryptionKey {
+    cipher: Aes256Gcm,
+}
+
+impl EncryptionKey {
+    /// Прочитать ключ из источника, указанного в настройках
+    pub fn load(config: &EncryptionConfig) -> Result<Self, StorageError> {
+        let key = load_key(&config.key)?;
+
+        let cipher = match config.algorithm {
+            EncryptionAlgorithm::Aes256Gcm => Aes256Gcm::new_from_slice(&key)
+                .map_err(|e| StorageError::Config(format!("Некорректный ключ: {}", e)))?,
+        };
+
+        Ok(Self { cipher })
+    }
+}
+
+/// Провайдер, шифрующий содержимое файлов поверх другого провайдера
+pub struct EncryptedStorageProvider {
+    inner: Arc<dyn StorageProvider>,
+    cipher: Aes256Gcm,
+}
+
+impl EncryptedStorageProvider {
+    pub fn new(
+        inner: Arc<dyn StorageProvider>,
+        config: &EncryptionConfig,
+    ) -> Result<Self, StorageError> {
+        Ok(Self::with_key(inner, EncryptionKey::load(config)?))
+    }
+
+    /// Провайдер с уже прочитанным ключом
+    pub fn with_key(inner: Arc<dyn StorageProvider>, key: EncryptionKey) -> Self {
+        Self {
+            inner,
+            cipher: key.cipher,
+        }
+    }
+
+    /// Зашифровать файл целиком (для небольших файлов)
+    fn encrypt_all(&self, data: &[u8]) -> Result<Vec<u8>, StorageError> {
+        let mut chunks = ChunkCipher::generate(self.cipher.clone());
+        let mut output = chunks.header();
+
+        // Пустой файл - один пустой последний блок
+        let count = data.len().div_ceil(CHUNK_SIZE).max(1);
+        for index in 0..count {
+            let start = index * CHUNK_SIZE;
+            let end = (start + CHUNK_SIZE).min(data.len());
+            output.extend(chunks.seal(&data[start..end], index + 1 == count)?);
+        }
+
+        Ok(output)
+    }
+
+    /// Расшифровать файл целиком (для небольших файлов)
+    fn decrypt_all(&self, data: &[u8]) -> Result<Vec<u8>, StorageError> {
+        let mut chunks = ChunkCipher::from_header(self.cipher.clone(), data)?;
+        let body = &data[HEADER_LEN..];
+
+        let count = body.len().div_ceil(FRAME_LEN).max(1);
+        let mut output = Vec::with_capacity(body.len());
+        for index in 0..count {
+            let start = index * FRAME_LEN;
+            let end = (start + FRAME_LEN).min(body.len());
+            output.extend(chunks.open(&body[start..end], index + 1 == count)?);
+        }
+
+        Ok(output)
+    }
+
+    /// Размер открытого текста в элементе (провайдер сообщает размер шифротекста)
+    fn decrypted_entry(entry: StorageEntry) -> StorageEntry {
+        if entry.is_directory || entry.metadata_error.is_some() {
+            return entry;
+        }
+
+        StorageEntry {
+            size: plaintext_size(entry.size),
+            ..entry
+        }
+    }
+}
+
+/// Прочитать ключ (32 байта в base64)
+fn load_key(source: &KeySource) -> Result<Vec<u8>, StorageError> {
+    let encoded = match source {
+        KeySource::Env(name) => std::env::var(name).map_err(|_| {
+            StorageError::Config(format!("Переменная окружения с ключом не задана: {}", name))
+        })?,
+        KeySource::File(path) => std::fs::read_to_string(path).map_err(|e| {
+            StorageError::Config(format!("Не удалось прочитать ключ {}: {}", path.display(), e))
+        })?,
+    };
+
+    let key = base64::engine::general_purpose::STANDARD
+        .decode(encoded.trim())
+        .map_err(|e| StorageError::Config(format!("Ключ должен быть в base64: {}", e)))?;
+
+    if key.len() != KEY_LEN {
+        return Err(StorageError::Config(format!(
+            "Ключ должен быть длиной {} байт, получено {}",
+            KEY_LEN,
+            key.len()
+        )));
+    }
+
+    Ok(key)
+}
+
+/// Размер открытого текста по размеру зашифрованного файла
+fn plaintext_size(encrypted_size: u64) -> u64 {
+    let body = encrypted_size.saturating_sub(HEADER_LEN as u64);
+    let frames = body.div_ceil(FRAME_LEN as u64);
+    body.saturating_sub(frames * TAG_LEN as u64)
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+/// Шифрование последовательности блоков одного файла
+struct ChunkCipher {
+    cipher: Aes256Gcm,
+    prefix: [u8; NONCE_PREFIX_LEN],
+    counter: u32,
+}
+
+impl ChunkCipher {
+    /// Новый файл со случайным префиксом nonce
+    fn generate(cipher: Aes256Gcm) -> Self {
+        Self {
+            cipher,
+            prefix: rand::random(),
+            counter: 0,
+        }
+    }
+
+    /// Существующий файл - префикс берётся из заголовка
+    fn from_header(cipher: Aes256Gcm, header: &[u8]) -> io::Result<Self> {
+        if header.len() < HEADER_LEN || &header[..MAGIC.len()] != MAGIC {
+            return Err(invalid_data("Файл не зашифрован или повреждён"));
+        }
+
+        if header[MAGIC.len()] != FORMAT_VERSION {
+            return Err(invalid_data("Неподдерживаемая версия формата шифрования"));
+        }
+
+        let mut prefix = [0u8; NONCE_PREFIX_LEN];
+        prefix.copy_from_slice(&header[MAGIC.len() + 1..HEADER_LEN]);
+
+        Ok(Self {
+            cipher,
+            prefix,
+            counter: 0,
+        })
+    }
+
+    fn header(&self) -> Vec<u8> {
+        let mut header = Vec::with_capacity(HEADER_LEN);
+        header.extend_from_slice(MAGIC);
+        header.push(FORMAT_VERSION);
+        header.extend_from_slice(&self.prefix);
+        header
+    }
+
+    /// Nonce очередного блока: префикс, номер блока, флаг последнего блока
+    fn next_nonce(&mut self, last: bool) -> io::Result<[u8; 12]> {
+        let mut nonce = [0u8; 12];
+        nonce[..NONCE_PREFIX_LEN].copy_from_slice(&self.prefix);
+        nonce[NONCE_PREFIX_LEN..11].copy_from_slice(&self.counter.to_be_bytes());
+        nonce[11] = last as u8;
+
+        self.counter = self
+            .counter
+            .checked_add(1)
+            .ok_or_else(|| invalid_data("Превышено число блоков в файле"))?;
+
+        Ok(nonce)
+    }
+
+    fn seal(&mut self, chunk: &[u8], last: bool) -> io::Result<Vec<u8>> {
+        let nonce = self.next_nonce(last)?;
+        self.cipher
+            .encrypt(Nonce::from_slice(&nonce), chunk)
+            .map_err(|_| io::Error::other("Ошибка шифрования"))
+    }
+
+    fn open(&mut self, frame: &[u8], last: bool) -> io::Result<Vec<u8>> {
+        let nonce = self.next_nonce(last)?;
+        self.cipher
+            .decrypt(Nonce::from_slice(&nonce), frame)
+            .map_err(|_| invalid_data("Не удалось расшифровать: неверный ключ или данные повреждены"))
+    }
+}
+
+/// Прочитать, пока буфер не заполнится или поток не закончится
+async fn read_full<R: AsyncRead + Unpin + ?Sized>(
+    reader: &mut R,
+    buf: &mut [u8],
+) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        let n = reader.read(&mut buf[filled..]).await?;
+        if n == 0 {
+            break;
+        }
+        filled += n;
+    }
+    Ok(filled)
+}
+
+/// Поток записи, шифрующий данные блоками
+///
+/// Последний блок записывается в `shutdown`, поэтому запись обязательно
+/// завершать вызовом `shutdown`, а не только `flush`.
+struct EncryptingWriter {
+    inner: Pin<Box<dyn AsyncWrite + Send>>,
+    chunks: ChunkCipher,
+    /// Накопленный открытый текст текущего блока
+    plain: Vec<u8>,
+    /// Зашифрованные данные, ещё не переданные во внутренний поток
+    pending: Vec<u8>,
+    pending_pos: usize,
+    finished: bool,
+}
+
+impl EncryptingWriter {
+    fn new(inner: Pin<Box<dyn AsyncWrite + Send>>, cipher: Aes256Gcm) -> Self {
+        let chunks = ChunkCipher::generate(cipher);
+        let pending = chunks.header();
+
+        Self {
+            inner,
+            chunks,
+            plain: Vec::with_capacity(CHUNK_SIZE),
+            pending,
+            pending_pos: 0,
+            finished: false,
+        }
+    }
+
+    fn seal_chunk(&mut self, last: bool) -> io::Result<()> {
+        let frame = self.chunks.seal(&self.plain, last)?;
+        self.plain.clear();
+        self.pending = frame;
+        self.pending_pos = 0;
+        Ok(())
+    }
+
+    fn poll_write_pending(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while self.pending_pos < self.pending.len() {
+            let n = ready!(self
+                .inner
+                .as_mut()
+                .poll_write(cx, &self.pending[self.pending_pos..]))?;
+            if n == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            self.pending_pos += n;
+        }
+
+        self.pending.clear();
+        self.pending_pos = 0;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncWrite for EncryptingWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        if this.finished {
+            return Poll::Ready(Err(io::Error::other("Запись уже завершена")));
+        }
+
+        ready!(this.poll_write_pending(cx))?;
+
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        // Полный блок шифруем, только когда есть продолжение:
+        // иначе он может оказаться последним
+        if this.plain.len() == CHUNK_SIZE {
+            this.seal_chunk(false)?;
+            ready!(this.poll_write_pending(cx))?;
+        }
+
+        let n = buf.len().min(CHUNK_SIZE - this.plain.len());
+        this.plain.extend_from_slice(&buf[..n]);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_write_pending(cx))?;
+        this.inner.as_mut().poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        if !this.finished {
+            ready!(this.poll_write_pending(cx))?;
+            this.seal_chunk(true)?;
+            this.finished = true;
+        }
+
+        ready!(this.poll_write_pending(cx))?;
+        this.inner.as_mut().poll_shutdown(cx)
+    }
+}
+
+#[async_trait]
+impl StorageProvider for EncryptedStorageProvider {
+    fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    async fn get_info(&self) -> Result<StorageInfo, StorageError> {
+        self.inner.get_info().await
+    }
+
+    async fn get_disk_space(&self, path: &str) -> Result<DiskSpace, StorageError> {
+        self.inner.get_disk_space(path).await
+    }
+
+    fn capabilities(&self) -> StorageCapabilities {
+        self.inner.capabilities()
+    }
+
+    async fn list_directory(&self, path: &str) -> Result<DirectoryListing, StorageError> {
+        let listing = self.inner.list_directory(path).await?;
+
+        Ok(DirectoryListing {
+            entries: listing.entries.into_iter().map(Self::decrypted_entry).collect(),
+            ..listing
+        })
+    }
+
+    async fn exists(&self, path: &str) -> Result<bool, StorageError> {
+        self.inner.exists(path).await
+    }
+
+    async fn get_entry_info(&self, path: &str) -> Result<StorageEntry, StorageError> {
+        let entry = self.inner.get_entry_info(path).await?;
+        Ok(Self::decrypted_entry(entry))
+    }
+
+    async fn get_link_info(&self, path: &str) -> Result<StorageEntry, StorageError> {
+        let entry = self.inner.get_link_info(path).await?;
+        Ok(Self::decrypted_entry(entry))
+    }
+
+    async fn create_directory(
+        &self,
+        path: &str,
+        recursive: bool,
+        mode: Option<u32>,
+    ) -> Result<String, StorageError> {
+        self.inner.create_directory(path, recursive, mode).await
+    }
+
+    /// Объём считается по зашифрованным файлам - столько они занимают в хранилище
+    async fn get_directory_stats(&self, path: &str) -> Result<DirectoryStats, StorageError> {
+        self.inner.get_directory_stats(path).await
+    }
+
+    async fn delete_directory(&self, path: &str, recursive: bool) -> Result<(), StorageError> {
+        self.inner.delete_directory(path, recursive).await
+    }
+
+    async fn preview_delete(
+        &self,
+        path: &str,
+        recursive: bool,
+        limit: usize,
+    ) -> Result<DeletePreview, StorageError> {
+        self.inner.preview_delete(path, recursive, limit).await
+    }
+
+    async fn delete_tree(
+        &self,
+        path: &str,
+        concurrency: usize,
+        progress: Arc<DeleteProgress>,
+    ) -> Result<(), StorageError> {
+        self.inner.delete_tree(path, concurrency, progress).await
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+        self.inner.delete_file(path).await
+    }
+
+    async fn upload_bytes(
+        &self,
+        destination: &str,
+        data: Bytes,
+        overwrite: bool,
+    ) -> Result<UploadResult, StorageError> {
+        let size = data.len() as u64;
+        let encrypted = self.encrypt_all(&data)?;
+        let result = self
+            .inner
+            .upload_bytes(destination, Bytes::from(encrypted), overwrite)
+            .await?;
+
+        Ok(UploadResult {
+            size,
+            checksum: None,
+            ..result
+        })
+    }
+
+    async fn download_bytes(&self, path: &str) -> Result<Bytes, StorageError> {
+        let encrypted = self.inner.download_bytes(path).await?;
+        Ok(Bytes::from(self.decrypt_all(&encrypted)?))
+    }
+
+    async fn get_read_stream(
+        &self,
+        path: &str,
+    ) -> Result<Pin<Box<dyn AsyncRead + Send>>, StorageError> {
+        let mut inner = self.inner.get_read_stream(path).await?;
+        let cipher = self.cipher.clone();
+
+        let stream: PlaintextStream = Box::pin(async_stream::try_stream! {
+            let mut header = [0u8; HEADER_LEN];
+            let read = read_full(&mut inner, &mut header).await?;
+            let mut chunks = ChunkCipher::from_header(cipher, &header[..read])?;
+
+            // Читаем на байт больше блока: по нему видно, последний ли это блок
+            let mut frame = vec![0u8; FRAME_LEN + 1];
+            let mut filled = 0;
+            loop {
+                filled += read_full(&mut inner, &mut frame[filled..]).await?;
+
+                if filled <= FRAME_LEN {
+                    let plain = chunks.open(&frame[..filled], true)?;
+                    if !plain.is_empty() {
+                        yield Bytes::from(plain);
+                    }
+                    break;
+                }
+
+                let plain = chunks.open(&frame[..FRAME_LEN], false)?;
+                yield Bytes::from(plain);
+
+                frame[0] = frame[FRAME_LEN];
+                filled = 1;
+            }
+        });
+
+        Ok(Box::pin(StreamReader::new(stream)))
+    }
+
+    /// Ссылка вела бы к зашифрованному содержимому: скачивание - только через шлюз
+    async fn get_download_url(
+        &self,
+        _path: &str,
+        _expires_in: Duration,
+    ) -> Result<String, StorageError> {
+        Err(StorageError::NotSupported)
+    }
+
+    async fn get_write_stream(
+        &self,
+        path: &str,
+        overwrite: bool,
+        mode: Option<u32>,
+    ) -> Result<Pin<Box<dyn AsyncWrite + Send>>, StorageError> {
+        let inner = self.inner.get_write_stream(path, overwrite, mode).await?;
+        Ok(Box::pin(EncryptingWriter::new(inner, self.cipher.clone())))
+    }
+
+    async fn init_project_structure(
+        &self,
+        base_path: &str,
+        project_name: &str,
+        merge: bool,
+    ) -> Result<ProjectStructure, StorageError> {
+        self.inner.init_project_structure(base_path, project_name, merge).await
+    }
+
+    async fn copy(
+        &self,
+        source: &str,
+        destination: &str,
+        overwrite: bool,
+    ) -> Result<(), StorageError> {
+        // Шифротекст переносится как есть - расшифровывать не нужно
+        self.inner.copy(source, destination, overwrite).await
+    }
+
+    async fn rename(
+        &self,
+        source: &str,
+        destination: &str,
+        overwrite: bool,
+    ) -> Result<(), StorageError> {
+        self.inner.rename(source, destination, overwrite).await
+    }
+}
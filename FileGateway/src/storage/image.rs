@@ -0,0 +1,52 @@
+//! Чтение EXIF-метаданных изображений
+
+use std::io::{BufRead, Seek};
+
+use exif::{Exif, In, Tag, Value};
+
+use super::{ImageMetadata, StorageError};
+
+/// Прочитать EXIF из изображения (JPEG, TIFF, HEIF, PNG, WebP)
+///
+/// Отсутствие EXIF не считается ошибкой - возвращается `has_exif == false`.
+pub fn read_image_metadata<R: BufRead + Seek>(
+    reader: &mut R,
+) -> Result<ImageMetadata, StorageError> {
+    let exif = match exif::Reader::new().read_from_container(reader) {
+        Ok(exif) => exif,
+        Err(exif::Error::NotFound(_)) => return Ok(ImageMetadata::default()),
+        Err(exif::Error::Io(e)) => return Err(StorageError::Io(e)),
+        Err(e) => return Err(StorageError::InvalidData(e.to_string())),
+    };
+
+    Ok(ImageMetadata {
+        has_exif: true,
+        orientation: uint_field(&exif, Tag::Orientation),
+        datetime_original: ascii_field(&exif, Tag::DateTimeOriginal),
+        width: uint_field(&exif, Tag::PixelXDimension)
+            .or_else(|| uint_field(&exif, Tag::ImageWidth)),
+        height: uint_field(&exif, Tag::PixelYDimension)
+            .or_else(|| uint_field(&exif, Tag::ImageLength)),
+        camera_make: ascii_field(&exif, Tag::Make),
+        camera_model: ascii_field(&exif, Tag::Model),
+        lens_model: ascii_field(&exif, Tag::LensModel),
+    })
+}
+
+fn uint_field(exif: &Exif, tag: Tag) -> Option<u32> {
+    exif.get_field(tag, In::PRIMARY)?.value.get_uint(0)
+}
+
+/// Строковое поле без завершающих нулей и пробелов (камеры дополняют ими значения)
+fn ascii_field(exif: &Exif, tag: Tag) -> Option<String> {
+    match &exif.get_field(tag, In::PRIMARY)?.value {
+        Value::Ascii(values) => {
+            let raw = values.first()?;
+            let text = String::from_utf8_lossy(raw)
+                .trim_end_matches(['\0', ' '])
+                .to_string();
+            (!text.is_empty()).then_some(text)
+        }
+        _ => None,
+    }
+}
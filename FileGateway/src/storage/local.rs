@@ -2,35 +2,129 @@
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::fs;
-use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, AsyncWrite};
+use tokio::sync::Mutex;
+use tokio::task::{JoinHandle, JoinSet};
+use tracing::warn;
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
 use super::{
-    config::StorageConfig,
-    provider::StorageProvider,
+    atomic::{same_filesystem, AtomicWriter},
+    config::{ProjectFolders, RootPathsMode, StorageConfig},
+    delete::{delete_tree, DeleteProgress},
+    image::read_image_metadata,
+    mode::{check_mode, set_mode},
+    path::{check_file_name, display_path, normalize_path, target_path, to_fs_path},
+    provider::{DuplicateStream, StorageProvider},
+    quota::{directory_size, Quota, QuotaWriter},
+    walk::{delete_preview, directory_stats, CancelOnDrop},
     types::*,
     StorageError,
 };
 
+/// Сколько живёт закэшированный список корневых путей
+const ROOT_PATHS_TTL: Duration = Duration::from_secs(30);
+
+/// Максимальное время ожидания опроса точек монтирования
+///
+/// Недоступный сетевой ресурс может подвесить `read_dir`/`stat` надолго -
+/// в этом случае отдаём предыдущий список, а опрос завершится в фоне.
+/// Пока он не завершился, новый не запускается.
+const ROOT_PATHS_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Сколько байт заголовка читается для определения типа по содержимому
+const SNIFF_HEADER_LEN: usize = 8192;
+
+/// Порог размера файла для определения типа по содержимому (по умолчанию)
+///
+/// Большие файлы (видео, архивы) почти всегда распознаются по расширению,
+/// а открывать их при каждом листинге дорого на сетевых дисках.
+const SNIFF_MAX_SIZE_DEFAULT: u64 = 4 * 1024 * 1024;
+
+/// Сколько записей директории опрашивается одновременно (по умолчанию)
+const LIST_CONCURRENCY_DEFAULT: usize = 32;
+
+/// Кэш автоматически найденных корневых путей
+#[derive(Default)]
+struct RootPathsCache {
+    /// Результат последнего завершённого опроса и его время
+    paths: Option<(Instant, Vec<String>)>,
+    /// Опрос, не уложившийся в `ROOT_PATHS_TIMEOUT` и ещё выполняющийся
+    detection: Option<JoinHandle<Vec<String>>>,
+}
+
+impl RootPathsCache {
+    /// Последний известный список, даже если он устарел
+    fn last(&self) -> Vec<String> {
+        self.paths.as_ref().map(|(_, paths)| paths.clone()).unwrap_or_default()
+    }
+}
+
 /// Провайдер для локальной файловой системы
 pub struct LocalStorageProvider {
     id: String,
     show_hidden: bool,
+    root_path: Option<PathBuf>,
+    /// Где разрешены изменения (`root_path` и `root_paths`); пусто - везде
+    write_roots: Vec<PathBuf>,
     default_projects_path: PathBuf,
+    root_paths_cache: Mutex<RootPathsCache>,
+    /// Настроенные корневые пути (только существующие на момент запуска)
+    custom_root_paths: Vec<String>,
+    /// Не опрашивать диски, а отдавать только `custom_root_paths`
+    replace_root_paths: bool,
+    /// Порог размера для определения MIME по содержимому; `None` - выключено
+    sniff_mime_max_size: Option<u64>,
+    /// Переопределения MIME: расширение в нижнем регистре без точки -> тип
+    mime_overrides: HashMap<String, String>,
+    /// Сколько записей директории опрашивается одновременно
+    list_concurrency: usize,
+    quota: Option<Arc<Quota>>,
+    /// Названия стандартных папок проекта
+    project_folders: ProjectFolders,
+    /// Директория временных файлов загрузки; `None` - директория назначения
+    upload_temp_dir: Option<PathBuf>,
+    /// Предупреждение о временной директории на другой файловой системе уже выведено
+    upload_temp_dir_warned: AtomicBool,
 }
 
 impl LocalStorageProvider {
     pub fn new(config: &StorageConfig) -> Result<Self, StorageError> {
         let id = config.id.clone().unwrap_or_else(|| Uuid::new_v4().to_string());
+
+        let root_path = config.root_path.as_ref().map(PathBuf::from);
+        if let Some(root) = &root_path {
+            if !root.is_dir() {
+                return Err(StorageError::Config(format!(
+                    "Корневая директория не существует: {}",
+                    root.display()
+                )));
+            }
+            // Существующий, но нечитаемый корень иначе проявится только на первом запросе
+            if let Err(e) = std::fs::read_dir(root) {
+                return Err(StorageError::Config(format!(
+                    "Корневая директория недоступна: {}: {}",
+                    root.display(),
+                    e
+                )));
+            }
+        }
         
         let default_projects_path = config
             .default_projects_path
             .as_ref()
             .map(PathBuf::from)
+            .or_else(|| root_path.clone())
             .or_else(|| {
                 directories::UserDirs::new()
                     .and_then(|d| d.video_dir().map(|p| p.to_path_buf()))
@@ -41,23 +135,226 @@ impl LocalStorageProvider {
                     .unwrap_or_else(|| PathBuf::from("/tmp"))
             });
 
+        if config.default_projects_path.is_some() {
+            if default_projects_path.exists() && !default_projects_path.is_dir() {
+                return Err(StorageError::Config(format!(
+                    "Путь для проектов не является директорией: {}",
+                    default_projects_path.display()
+                )));
+            }
+            if !default_projects_path.exists() {
+                warn!(
+                    "Директория для проектов не существует и будет создана с первым проектом: {}",
+                    default_projects_path.display()
+                );
+            }
+        }
+
+        let custom_root_paths: Vec<String> = config
+            .root_paths
+            .iter()
+            .filter(|path| {
+                let exists = Path::new(path).is_dir();
+                if !exists {
+                    warn!("Настроенный корневой путь не существует и будет пропущен: {}", path);
+                }
+                exists
+            })
+            .cloned()
+            .collect();
+
+        let replace_root_paths = config.root_paths_mode == RootPathsMode::Replace;
+        if replace_root_paths && custom_root_paths.is_empty() {
+            warn!(
+                "Нет ни одного доступного корневого пути из root_paths, используется автоопределение"
+            );
+        }
+
+        config.project_folders.validate().map_err(StorageError::Config)?;
+
+        let upload_temp_dir = config.upload_temp_dir.as_ref().map(PathBuf::from);
+        if let Some(temp_dir) = &upload_temp_dir {
+            if !temp_dir.is_dir() {
+                return Err(StorageError::Config(format!(
+                    "Директория временных файлов загрузки не существует: {}",
+                    temp_dir.display()
+                )));
+            }
+        }
+
+        let write_roots: Vec<PathBuf> = match &root_path {
+            Some(root) => std::iter::once(root.clone())
+                .chain(custom_root_paths.iter().map(PathBuf::from))
+                .map(|path| path.components().collect())
+                .collect(),
+            None => Vec::new(),
+        };
+        let projects_path = default_projects_path.to_string_lossy();
+        if target_path(&projects_path, &write_roots).is_err() {
+            return Err(StorageError::Config(format!(
+                "Путь для проектов вне корневой директории: {}",
+                projects_path
+            )));
+        }
+
+        // Квота считается по корневой директории, а без неё - по директории проектов
+        let quota = config.quota_bytes.map(|limit| {
+            let quota_root = root_path.clone().unwrap_or_else(|| default_projects_path.clone());
+            Quota::shared(limit, to_fs_path(&quota_root))
+        });
+
         Ok(Self {
             id,
             show_hidden: config.show_hidden,
+            root_path,
+            write_roots,
             default_projects_path,
+            root_paths_cache: Mutex::new(RootPathsCache::default()),
+            replace_root_paths: replace_root_paths && !custom_root_paths.is_empty(),
+            custom_root_paths,
+            sniff_mime_max_size: config
+                .sniff_mime
+                .then(|| config.sniff_mime_max_size.unwrap_or(SNIFF_MAX_SIZE_DEFAULT)),
+            mime_overrides: config
+                .extension_mime_overrides
+                .iter()
+                .map(|(ext, mime)| (ext.trim_start_matches('.').to_lowercase(), mime.clone()))
+                .collect(),
+            list_concurrency: config.list_concurrency.unwrap_or(LIST_CONCURRENCY_DEFAULT).max(1),
+            quota,
+            project_folders: config.project_folders.clone(),
+            upload_temp_dir,
+            upload_temp_dir_warned: AtomicBool::new(false),
         })
     }
 
+    /// Путь для изменяющей операции в пределах `write_roots`
+    fn target(&self, path: &str) -> Result<PathBuf, StorageError> {
+        target_path(path, &self.write_roots)
+    }
+
+    /// Директория для временного файла загрузки в `directory`
+    ///
+    /// Настроенная временная директория используется, только если она на той же
+    /// файловой системе: иначе финальное переименование было бы копированием.
+    async fn upload_temp_dir_for(&self, directory: &Path) -> PathBuf {
+        let Some(temp_dir) = &self.upload_temp_dir else {
+            return directory.to_path_buf();
+        };
+
+        match same_filesystem(temp_dir, directory).await {
+            Ok(true) => return temp_dir.clone(),
+            Ok(false) => {
+                if !self.upload_temp_dir_warned.swap(true, Ordering::Relaxed) {
+                    warn!(
+                        "Директория временных файлов {} на другой файловой системе, чем {}; \
+                         такие загрузки пишутся в директорию назначения",
+                        temp_dir.display(),
+                        directory.display()
+                    );
+                }
+            }
+            Err(e) => warn!(
+                "Не удалось проверить директорию временных файлов {}: {}",
+                temp_dir.display(),
+                e
+            ),
+        }
+        directory.to_path_buf()
+    }
+
+    /// Квота, если путь в ней учитывается
+    fn quota_for(&self, path: &Path) -> Option<&Arc<Quota>> {
+        self.quota.as_ref().filter(|quota| quota.covers(path))
+    }
+
     fn get_home_directory(&self) -> PathBuf {
+        if let Some(root) = &self.root_path {
+            return root.clone();
+        }
+
         directories::UserDirs::new()
             .map(|d| d.home_dir().to_path_buf())
             .unwrap_or_else(|| PathBuf::from("/"))
     }
 
-    fn get_root_paths(&self) -> Vec<String> {
+    /// Корневые пути: найденные автоматически и/или настроенные
+    async fn get_root_paths(&self) -> Vec<String> {
+        if self.replace_root_paths {
+            return self.custom_root_paths.clone();
+        }
+
+        let mut paths = self.detected_root_paths().await;
+        for path in &self.custom_root_paths {
+            if !paths.contains(path) {
+                paths.push(path.clone());
+            }
+        }
+        paths
+    }
+
+    /// Автоматически найденные корневые пути с кэшированием
+    ///
+    /// Опрос выполняется в пуле блокирующих задач, чтобы медленные точки
+    /// монтирования не останавливали async-рантайм. Одновременно выполняется
+    /// не больше одного опроса: зависший поток пула не размножается по TTL.
+    async fn detected_root_paths(&self) -> Vec<String> {
+        // Лок держится на время ожидания опроса, чтобы параллельные запросы
+        // не запускали его повторно
+        let mut cache = self.root_paths_cache.lock().await;
+
+        if let Some((updated_at, paths)) = cache.paths.as_ref() {
+            if updated_at.elapsed() < ROOT_PATHS_TTL {
+                return paths.clone();
+            }
+        }
+
+        let mut detection = match cache.detection.take() {
+            // Опрос уже не уложился в таймаут: не ждём его снова, пока он не завершится
+            Some(detection) if !detection.is_finished() => {
+                cache.detection = Some(detection);
+                return cache.last();
+            }
+            Some(detection) => detection,
+            None => {
+                let configured: Vec<PathBuf> =
+                    std::iter::once(self.default_projects_path.clone())
+                        .chain(self.root_path.clone())
+                        .collect();
+                tokio::task::spawn_blocking(move || Self::detect_root_paths(&configured))
+            }
+        };
+
+        let paths = match tokio::time::timeout(ROOT_PATHS_TIMEOUT, &mut detection).await {
+            Ok(Ok(paths)) => paths,
+            Ok(Err(e)) => {
+                warn!("Ошибка опроса корневых путей: {}", e);
+                cache.last()
+            }
+            Err(_) => {
+                warn!(
+                    "Опрос корневых путей занял больше {:?}, используется предыдущий список",
+                    ROOT_PATHS_TIMEOUT
+                );
+                // Время опроса не обновляется: список остаётся устаревшим
+                cache.detection = Some(detection);
+                return cache.last();
+            }
+        };
+
+        // Неудачный опрос тоже кэшируется, чтобы не запускать его на каждый запрос
+        cache.paths = Some((Instant::now(), paths.clone()));
+        paths
+    }
+
+    /// Опросить диски и точки монтирования (блокирующая операция)
+    ///
+    /// * `configured` - настроенные пути; на Windows их сетевые корни тоже считаются корневыми
+    #[cfg_attr(not(target_os = "windows"), allow(unused_variables))]
+    fn detect_root_paths(configured: &[PathBuf]) -> Vec<String> {
         #[cfg(target_os = "windows")]
         {
-            (b'A'..=b'Z')
+            let mut paths: Vec<String> = (b'A'..=b'Z')
                 .filter_map(|c| {
                     let drive = format!("{}:\\", c as char);
                     if std::path::Path::new(&drive).exists() {
@@ -66,7 +363,19 @@ impl LocalStorageProvider {
                         None
                     }
                 })
-                .collect()
+                .collect();
+
+            // Сетевые ресурсы без буквы диска: добавляем корни настроенных
+            // путей, если они расположены на \\server\share
+            for path in configured {
+                if let Some(root) = super::path::unc_root(path) {
+                    if !paths.contains(&root) {
+                        paths.push(root);
+                    }
+                }
+            }
+
+            paths
         }
 
         #[cfg(not(target_os = "windows"))]
@@ -91,38 +400,12 @@ impl LocalStorageProvider {
         }
     }
 
-    fn get_disk_space(&self) -> (u64, u64) {
-        #[cfg(target_os = "linux")]
-        {
-            use std::ffi::CString;
-            use std::mem::MaybeUninit;
-            
-            let c_path = CString::new("/").unwrap();
-            
-            unsafe {
-                let mut stat: MaybeUninit<libc::statvfs> = MaybeUninit::uninit();
-                if libc::statvfs(c_path.as_ptr(), stat.as_mut_ptr()) == 0 {
-                    let stat = stat.assume_init();
-                    let total = stat.f_blocks as u64 * stat.f_frsize as u64;
-                    let free = stat.f_bavail as u64 * stat.f_frsize as u64;
-                    (total, free)
-                } else {
-                    (0, 0)
-                }
-            }
-        }
-
-        #[cfg(not(target_os = "linux"))]
-        {
-            (0, 0)
-        }
-    }
-
+    /// Путь для чтения (пустой путь - домашняя директория)
     fn resolve_path(&self, path: &str) -> PathBuf {
         if path.is_empty() {
-            self.get_home_directory()
+            to_fs_path(&self.get_home_directory())
         } else {
-            PathBuf::from(path)
+            normalize_path(path)
         }
     }
 
@@ -132,76 +415,374 @@ impl LocalStorageProvider {
         path: PathBuf,
         metadata: std::fs::Metadata,
     ) -> StorageEntry {
+        // На Linux std читает время создания через statx и возвращает ошибку,
+        // если файловая система его не хранит. Некоторые ФС (FUSE, сетевые)
+        // вместо этого отдают нулевое время - его тоже считаем неизвестным.
         let created_at = metadata
             .created()
             .ok()
-            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-            .map(|d| d.as_secs() as i64)
-            .unwrap_or(0);
-
-        let modified_at = metadata
-            .modified()
-            .ok()
-            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-            .map(|d| d.as_secs() as i64)
-            .unwrap_or(0);
+            .filter(|created| *created != std::time::UNIX_EPOCH)
+            .map(DateTime::<Utc>::from);
+        let modified_at = metadata.modified().ok().map(DateTime::<Utc>::from);
 
         let mime_type = if metadata.is_file() {
-            mime_guess::from_path(&path)
-                .first_or_octet_stream()
-                .to_string()
+            self.guess_mime_type(&path)
         } else {
             "inode/directory".to_string()
         };
 
         StorageEntry {
             name,
-            path: path.to_string_lossy().to_string(),
+            path: display_path(&path),
             is_directory: metadata.is_dir(),
             size: if metadata.is_file() { metadata.len() } else { 0 },
             created_at,
             modified_at,
             mime_type,
+            metadata_error: None,
+            is_writable: None,
+            symlink_target: None,
             metadata: HashMap::new(),
         }
     }
+
+    /// Переопределённый в конфигурации MIME тип для расширения файла
+    fn mime_override(&self, path: &Path) -> Option<&String> {
+        let extension = path.extension()?.to_string_lossy().to_lowercase();
+        self.mime_overrides.get(&extension)
+    }
+
+    /// MIME тип по расширению: сначала переопределения, затем `mime_guess`
+    fn guess_mime_type(&self, path: &Path) -> String {
+        match self.mime_override(path) {
+            Some(mime) => mime.clone(),
+            None => mime_guess::from_path(path).first_or_octet_stream().to_string(),
+        }
+    }
+
+    /// Уточнить MIME тип по сигнатуре файла
+    ///
+    /// Работает, только если включено в конфигурации. Если сигнатура
+    /// не распознана или файл не читается, остаётся тип по расширению.
+    async fn sniff_mime_type(&self, entry: &mut StorageEntry, path: &Path) {
+        let Some(max_size) = self.sniff_mime_max_size else {
+            return;
+        };
+
+        if entry.is_directory || entry.metadata_error.is_some() || entry.size > max_size {
+            return;
+        }
+
+        // Явно заданный в конфигурации тип важнее сигнатуры
+        if self.mime_override(path).is_some() {
+            return;
+        }
+
+        let mut header = vec![0u8; SNIFF_HEADER_LEN];
+        let read = match fs::File::open(path).await {
+            Ok(mut file) => read_header(&mut file, &mut header).await,
+            Err(e) => Err(e),
+        };
+
+        match read {
+            Ok(len) => {
+                if let Some(kind) = infer::get(&header[..len]) {
+                    entry.mime_type = kind.mime_type().to_string();
+                }
+            }
+            Err(e) => {
+                warn!("Не удалось прочитать заголовок {}: {}", path.display(), e);
+            }
+        }
+    }
+
+    /// Элемент, для которого не удалось получить метаданные
+    ///
+    /// Такие элементы не скрываются из листинга, чтобы пользователь
+    /// не принял недоступный файл за удалённый.
+    fn entry_without_metadata(
+        &self,
+        name: String,
+        path: PathBuf,
+        is_directory: bool,
+        error: String,
+    ) -> StorageEntry {
+        let mime_type = if is_directory {
+            "inode/directory".to_string()
+        } else {
+            self.guess_mime_type(&path)
+        };
+
+        StorageEntry {
+            name,
+            path: display_path(&path),
+            is_directory,
+            size: 0,
+            created_at: None,
+            modified_at: None,
+            mime_type,
+            metadata_error: Some(error),
+            is_writable: None,
+            symlink_target: None,
+            metadata: HashMap::new(),
+        }
+    }
+}
+
+/// Все файлы под директорией с размерами (блокирующая операция)
+///
+/// Символические ссылки не раскрываются, недоступные элементы пропускаются.
+fn collect_files(dir: &Path, show_hidden: bool, files: &mut Vec<(PathBuf, u64)>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.filter_map(Result::ok) {
+        if !show_hidden && entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+
+        if metadata.is_dir() {
+            collect_files(&entry.path(), show_hidden, files);
+        } else if metadata.is_file() {
+            files.push((entry.path(), metadata.len()));
+        }
+    }
+}
+
+/// SHA-256 содержимого файла (hex), файл читается потоково
+async fn hash_file(path: &Path) -> std::io::Result<String> {
+    let mut file = fs::File::open(path).await?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 64 * 1024];
+
+    loop {
+        let n = file.read(&mut buffer).await?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buffer[..n]);
+    }
+
+    Ok(hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+/// Размер существующего файла (0, если файла нет)
+async fn file_size(path: &Path) -> u64 {
+    match fs::metadata(path).await {
+        Ok(metadata) if metadata.is_file() => metadata.len(),
+        _ => 0,
+    }
+}
+
+/// Пробный запуск удаления одного пути (файла, ссылки или пустой директории)
+fn single_path_preview(path: &Path, limit: usize, size: u64) -> DeletePreview {
+    DeletePreview {
+        paths: if limit > 0 { vec![display_path(path)] } else { Vec::new() },
+        truncated: limit == 0,
+        total_size: size,
+        errors: Vec::new(),
+    }
+}
+
+/// Прочитать начало файла (read может вернуть меньше запрошенного)
+async fn read_header(file: &mut fs::File, buf: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        let n = file.read(&mut buf[filled..]).await?;
+        if n == 0 {
+            break;
+        }
+        filled += n;
+    }
+    Ok(filled)
+}
+
+/// Может ли процесс изменять путь
+///
+/// Для директории - создавать в ней файлы (нужны права на запись и вход).
+/// На Unix учитываются владелец, группы и read-only монтирование;
+/// на остальных платформах - только атрибут "только чтение".
+fn is_writable(path: &Path, is_directory: bool) -> bool {
+    #[cfg(unix)]
+    {
+        use std::ffi::CString;
+        use std::os::unix::ffi::OsStrExt;
+
+        let Ok(c_path) = CString::new(path.as_os_str().as_bytes()) else {
+            return false;
+        };
+
+        let mode = if is_directory {
+            libc::W_OK | libc::X_OK
+        } else {
+            libc::W_OK
+        };
+
+        unsafe { libc::access(c_path.as_ptr(), mode) == 0 }
+    }
+
+    #[cfg(not(unix))]
+    {
+        // Атрибут "только чтение" у директорий Windows не запрещает создавать файлы
+        is_directory
+            || std::fs::metadata(path)
+                .map(|metadata| !metadata.permissions().readonly())
+                .unwrap_or(false)
+    }
+}
+
+/// Общий и доступный размер тома, на котором расположен путь
+///
+/// Путь должен существовать. Возвращает `(0, 0)`, если размер определить
+/// не удалось.
+// Типы полей statvfs различаются между платформами, поэтому приведения нужны
+#[allow(clippy::unnecessary_cast)]
+#[cfg_attr(not(any(unix, windows)), allow(unused_variables))]
+fn disk_space(path: &Path) -> (u64, u64) {
+    // statvfs есть и на Linux, и на macOS
+    #[cfg(unix)]
+    {
+        use std::ffi::CString;
+        use std::mem::MaybeUninit;
+        use std::os::unix::ffi::OsStrExt;
+
+        let Ok(c_path) = CString::new(path.as_os_str().as_bytes()) else {
+            return (0, 0);
+        };
+
+        unsafe {
+            let mut stat: MaybeUninit<libc::statvfs> = MaybeUninit::uninit();
+            if libc::statvfs(c_path.as_ptr(), stat.as_mut_ptr()) == 0 {
+                let stat = stat.assume_init();
+                let total = stat.f_blocks as u64 * stat.f_frsize as u64;
+                let free = stat.f_bavail as u64 * stat.f_frsize as u64;
+                (total, free)
+            } else {
+                (0, 0)
+            }
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::ffi::OsStrExt;
+        use windows_sys::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+        // Функция принимает только директорию
+        let directory = if path.is_dir() { path } else { path.parent().unwrap_or(path) };
+        let wide: Vec<u16> = directory
+            .as_os_str()
+            .encode_wide()
+            .chain(std::iter::once(0))
+            .collect();
+
+        let mut available = 0u64;
+        let mut total = 0u64;
+        // Доступное место - с учётом дисковых квот пользователя, как f_bavail
+        let ok = unsafe {
+            GetDiskFreeSpaceExW(wide.as_ptr(), &mut available, &mut total, std::ptr::null_mut())
+        };
+        if ok != 0 {
+            (total, available)
+        } else {
+            (0, 0)
+        }
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        (0, 0)
+    }
+}
+
+/// Ближайший существующий путь среди `path` и его предков
+fn existing_ancestor(path: &Path) -> Option<&Path> {
+    path.ancestors().find(|p| p.exists())
 }
 
 #[async_trait]
 impl StorageProvider for LocalStorageProvider {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
     async fn get_info(&self) -> Result<StorageInfo, StorageError> {
         let hostname = hostname::get()
             .map(|h| h.to_string_lossy().to_string())
             .unwrap_or_else(|_| "unknown".to_string());
 
-        let (total_space, free_space) = self.get_disk_space();
+        // Том, где создаются проекты; корень - только если путь недоступен
+        let (total_space, free_space) = match existing_ancestor(&self.default_projects_path) {
+            Some(existing) => disk_space(existing),
+            None => disk_space(Path::new("/")),
+        };
 
         Ok(StorageInfo {
             id: self.id.clone(),
             storage_type: "local".to_string(),
             hostname,
             os: std::env::consts::OS.to_string(),
-            home_directory: self.get_home_directory().to_string_lossy().to_string(),
-            default_projects_path: self.default_projects_path.to_string_lossy().to_string(),
-            root_paths: self.get_root_paths(),
+            home_directory: display_path(&self.get_home_directory()),
+            default_projects_path: display_path(&self.default_projects_path),
+            root_paths: self.get_root_paths().await,
             total_space,
             free_space,
+            quota_bytes: self.quota.as_ref().map(|quota| quota.limit()),
+            quota_used_bytes: self.quota.as_ref().map(|quota| quota.used()),
         })
     }
 
+    async fn get_disk_space(&self, path: &str) -> Result<DiskSpace, StorageError> {
+        let requested = self.resolve_path(path);
+
+        // Ближайший существующий предок - на его томе будет создан путь
+        let existing = existing_ancestor(&requested)
+            .ok_or_else(|| StorageError::NotFound(path.to_string()))?;
+
+        let (total_space, mut free_space) = disk_space(existing);
+        if total_space == 0 {
+            return Err(StorageError::NotSupported);
+        }
+
+        if let Some(quota) = self.quota_for(&requested) {
+            free_space = free_space.min(quota.limit().saturating_sub(quota.used()));
+        }
+
+        Ok(DiskSpace {
+            total_space,
+            free_space,
+        })
+    }
+
+    fn capabilities(&self) -> StorageCapabilities {
+        StorageCapabilities {
+            streaming: true,
+            rename: true,
+            watch: false,
+            thumbnails: false,
+            disk_space: true,
+            atomic_writes: true,
+        }
+    }
+
     async fn list_directory(&self, path: &str) -> Result<DirectoryListing, StorageError> {
         let dir_path = self.resolve_path(path);
 
         if !dir_path.exists() {
-            return Err(StorageError::NotFound(dir_path.to_string_lossy().to_string()));
+            return Err(StorageError::NotFound(display_path(&dir_path)));
         }
 
         if !dir_path.is_dir() {
-            return Err(StorageError::NotADirectory(dir_path.to_string_lossy().to_string()));
+            return Err(StorageError::NotADirectory(display_path(&dir_path)));
         }
 
-        let mut entries = Vec::new();
         let mut read_dir = fs::read_dir(&dir_path).await?;
+        let mut names = Vec::new();
 
         while let Some(entry) = read_dir.next_entry().await? {
             let name = entry.file_name().to_string_lossy().to_string();
@@ -210,28 +791,74 @@ impl StorageProvider for LocalStorageProvider {
             if !self.show_hidden && name.starts_with('.') {
                 continue;
             }
+            names.push((name, entry));
+        }
 
-            if let Ok(metadata) = entry.metadata().await {
-                entries.push(self.entry_from_metadata(name, entry.path(), metadata));
+        // Метаданные запрашиваются параллельно (не больше `list_concurrency`
+        // одновременно) и затем раскладываются в порядке чтения директории
+        let mut stats = Vec::with_capacity(names.len());
+        let mut tasks = JoinSet::new();
+        for (index, (name, entry)) in names.into_iter().enumerate() {
+            if tasks.len() >= self.list_concurrency {
+                if let Some(result) = tasks.join_next().await {
+                    stats.push(result.map_err(std::io::Error::other)?);
+                }
+            }
+            tasks.spawn(async move {
+                let metadata = entry.metadata().await;
+                let is_directory = match &metadata {
+                    Ok(metadata) => metadata.is_dir(),
+                    Err(_) => entry.file_type().await.map(|t| t.is_dir()).unwrap_or(false),
+                };
+                (index, name, entry.path(), metadata, is_directory)
+            });
+        }
+        while let Some(result) = tasks.join_next().await {
+            stats.push(result.map_err(std::io::Error::other)?);
+        }
+        stats.sort_by_key(|(index, ..)| *index);
+
+        let mut entries = Vec::with_capacity(stats.len());
+        for (_, name, path, metadata, is_directory) in stats {
+            match metadata {
+                Ok(metadata) => {
+                    let mut storage_entry = self.entry_from_metadata(name, path.clone(), metadata);
+                    self.sniff_mime_type(&mut storage_entry, &path).await;
+                    entries.push(storage_entry);
+                }
+                Err(e) => {
+                    warn!("Не удалось получить метаданные {}: {}", path.display(), e);
+                    entries.push(self.entry_without_metadata(
+                        name,
+                        path,
+                        is_directory,
+                        e.to_string(),
+                    ));
+                }
             }
         }
 
-        // Сортировка: директории сверху, потом по имени
+        // Сортировка: директории сверху, потом по имени (имена, различающиеся
+        // только регистром, - в одном и том же порядке при каждом листинге)
         entries.sort_by(|a, b| {
             match (a.is_directory, b.is_directory) {
                 (true, false) => std::cmp::Ordering::Less,
                 (false, true) => std::cmp::Ordering::Greater,
-                _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+                _ => a
+                    .name
+                    .to_lowercase()
+                    .cmp(&b.name.to_lowercase())
+                    .then_with(|| a.name.cmp(&b.name)),
             }
         });
 
         let parent_path = dir_path
             .parent()
-            .map(|p| p.to_string_lossy().to_string())
+            .map(display_path)
             .unwrap_or_default();
 
         Ok(DirectoryListing {
-            current_path: dir_path.to_string_lossy().to_string(),
+            current_path: display_path(&dir_path),
             parent_path,
             entries,
         })
@@ -255,23 +882,82 @@ impl StorageProvider for LocalStorageProvider {
             .map(|n| n.to_string_lossy().to_string())
             .unwrap_or_default();
 
-        Ok(self.entry_from_metadata(name, file_path, metadata.into()))
+        let mut entry = self.entry_from_metadata(name, file_path.clone(), metadata);
+        entry.is_writable = Some(is_writable(&file_path, entry.is_directory));
+        self.sniff_mime_type(&mut entry, &file_path).await;
+        Ok(entry)
     }
 
-    async fn create_directory(&self, path: &str, recursive: bool) -> Result<String, StorageError> {
-        let dir_path = PathBuf::from(path);
+    async fn get_link_info(&self, path: &str) -> Result<StorageEntry, StorageError> {
+        let file_path = self.resolve_path(path);
+
+        let metadata = match fs::symlink_metadata(&file_path).await {
+            Ok(metadata) => metadata,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(StorageError::NotFound(path.to_string()));
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        if !metadata.is_symlink() {
+            return self.get_entry_info(path).await;
+        }
+
+        let target = fs::read_link(&file_path).await?;
+        let name = file_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let mut entry = self.entry_from_metadata(name, file_path, metadata);
+        entry.mime_type = "inode/symlink".to_string();
+        entry.symlink_target = Some(display_path(&target));
+        Ok(entry)
+    }
+
+    async fn create_directory(
+        &self,
+        path: &str,
+        recursive: bool,
+        mode: Option<u32>,
+    ) -> Result<String, StorageError> {
+        let dir_path = self.target(path)?;
+        check_mode(mode)?;
 
         if recursive {
+            // Права уже существующей директории не меняются, как у `mkdir -p -m`
+            let existed = dir_path.is_dir();
             fs::create_dir_all(&dir_path).await?;
+            if !existed {
+                set_mode(&dir_path, mode).await?;
+            }
         } else {
             fs::create_dir(&dir_path).await?;
+            set_mode(&dir_path, mode).await?;
         }
 
-        Ok(dir_path.to_string_lossy().to_string())
+        Ok(display_path(&dir_path))
+    }
+
+    async fn get_directory_stats(&self, path: &str) -> Result<DirectoryStats, StorageError> {
+        let dir_path = self.resolve_path(path);
+
+        if !dir_path.is_dir() {
+            return Err(StorageError::NotADirectory(path.to_string()));
+        }
+
+        // Если запрос отменён (клиент отключился), обход останавливается
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let _cancel_guard = CancelOnDrop(cancelled.clone());
+
+        tokio::task::spawn_blocking(move || directory_stats(&dir_path, &cancelled))
+            .await
+            .map_err(|e| StorageError::Io(std::io::Error::other(e)))?
+            .ok_or_else(|| StorageError::Io(std::io::ErrorKind::Interrupted.into()))
     }
 
     async fn delete_directory(&self, path: &str, recursive: bool) -> Result<(), StorageError> {
-        let dir_path = PathBuf::from(path);
+        let dir_path = self.target(path)?;
 
         if !dir_path.exists() {
             return Err(StorageError::NotFound(path.to_string()));
@@ -281,17 +967,99 @@ impl StorageProvider for LocalStorageProvider {
             return Err(StorageError::NotADirectory(path.to_string()));
         }
 
+        let freed = match self.quota_for(&dir_path) {
+            Some(_) if recursive => {
+                let path = dir_path.clone();
+                tokio::task::spawn_blocking(move || directory_size(&path))
+                    .await
+                    .unwrap_or(0)
+            }
+            _ => 0,
+        };
+
         if recursive {
             fs::remove_dir_all(&dir_path).await?;
         } else {
             fs::remove_dir(&dir_path).await?;
         }
 
+        if let Some(quota) = self.quota_for(&dir_path) {
+            quota.release(freed);
+        }
+
         Ok(())
     }
 
+    async fn preview_delete(
+        &self,
+        path: &str,
+        recursive: bool,
+        limit: usize,
+    ) -> Result<DeletePreview, StorageError> {
+        let target = self.target(path)?;
+
+        let metadata = fs::symlink_metadata(&target)
+            .await
+            .map_err(|_| StorageError::NotFound(path.to_string()))?;
+
+        // Файл или ссылка удаляются сами по себе
+        if !metadata.is_dir() {
+            return Ok(single_path_preview(
+                &target,
+                limit,
+                if metadata.is_file() { metadata.len() } else { 0 },
+            ));
+        }
+
+        if !recursive {
+            // Удаление без `recursive` не тронет непустую директорию
+            if fs::read_dir(&target).await?.next_entry().await?.is_some() {
+                return Err(StorageError::Io(std::io::Error::new(
+                    std::io::ErrorKind::DirectoryNotEmpty,
+                    format!("директория не пуста: {}", path),
+                )));
+            }
+            return Ok(single_path_preview(&target, limit, 0));
+        }
+
+        // Если запрос отменён (клиент отключился), обход останавливается
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let _cancel_guard = CancelOnDrop(cancelled.clone());
+
+        tokio::task::spawn_blocking(move || delete_preview(&target, limit, &cancelled))
+            .await
+            .map_err(|e| StorageError::Io(std::io::Error::other(e)))?
+            .ok_or_else(|| StorageError::Io(std::io::ErrorKind::Interrupted.into()))
+    }
+
+    async fn delete_tree(
+        &self,
+        path: &str,
+        concurrency: usize,
+        progress: Arc<DeleteProgress>,
+    ) -> Result<(), StorageError> {
+        let dir_path = self.target(path)?;
+
+        if !dir_path.exists() {
+            return Err(StorageError::NotFound(path.to_string()));
+        }
+
+        if !dir_path.is_dir() {
+            return Err(StorageError::NotADirectory(path.to_string()));
+        }
+
+        let result = delete_tree(&dir_path, concurrency, progress.clone()).await;
+
+        // Удалённое до ошибки или отмены не вернуть - освобождаем то, что удалено
+        if let Some(quota) = self.quota_for(&dir_path) {
+            quota.release(progress.bytes_deleted.load(Ordering::Relaxed));
+        }
+
+        Ok(result?)
+    }
+
     async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
-        let file_path = PathBuf::from(path);
+        let file_path = self.target(path)?;
 
         if !file_path.exists() {
             return Err(StorageError::NotFound(path.to_string()));
@@ -301,17 +1069,60 @@ impl StorageProvider for LocalStorageProvider {
             return Err(StorageError::NotAFile(path.to_string()));
         }
 
+        let size = file_size(&file_path).await;
         fs::remove_file(&file_path).await?;
+
+        if let Some(quota) = self.quota_for(&file_path) {
+            quota.release(size);
+        }
+
         Ok(())
     }
 
+    async fn create_file(&self, path: &str, overwrite: bool) -> Result<String, StorageError> {
+        let file_path = self.target(path)?;
+
+        if file_path.is_dir() {
+            return Err(StorageError::NotAFile(path.to_string()));
+        }
+
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+
+        let previous_size = file_size(&file_path).await;
+
+        // create_new проверяет существование атомарно, без гонки с exists()
+        let mut options = fs::OpenOptions::new();
+        options.write(true);
+        if overwrite {
+            options.create(true).truncate(true);
+        } else {
+            options.create_new(true);
+        }
+
+        match options.open(&file_path).await {
+            Ok(_) => {
+                // При перезаписи файл обнулён
+                if let Some(quota) = self.quota_for(&file_path) {
+                    quota.release(previous_size);
+                }
+                Ok(display_path(&file_path))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                Err(StorageError::AlreadyExists(path.to_string()))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
     async fn upload_bytes(
         &self,
         destination: &str,
         data: Bytes,
         overwrite: bool,
     ) -> Result<UploadResult, StorageError> {
-        let file_path = PathBuf::from(destination);
+        let file_path = self.target(destination)?;
 
         if file_path.exists() && !overwrite {
             return Err(StorageError::AlreadyExists(destination.to_string()));
@@ -323,17 +1134,34 @@ impl StorageProvider for LocalStorageProvider {
         }
 
         let size = data.len() as u64;
-        fs::write(&file_path, &data).await?;
+
+        // Резервируем только прирост относительно перезаписываемого файла
+        let previous_size = file_size(&file_path).await;
+        let quota = self.quota_for(&file_path);
+        if let Some(quota) = quota {
+            quota.reserve(size.saturating_sub(previous_size))?;
+        }
+
+        if let Err(e) = fs::write(&file_path, &data).await {
+            if let Some(quota) = quota {
+                quota.release(size.saturating_sub(previous_size));
+            }
+            return Err(e.into());
+        }
+
+        if let Some(quota) = quota {
+            quota.release(previous_size.saturating_sub(size));
+        }
 
         Ok(UploadResult {
-            path: file_path.to_string_lossy().to_string(),
+            path: display_path(&file_path),
             size,
             checksum: None,
         })
     }
 
     async fn download_bytes(&self, path: &str) -> Result<Bytes, StorageError> {
-        let file_path = PathBuf::from(path);
+        let file_path = self.resolve_path(path);
 
         if !file_path.exists() {
             return Err(StorageError::NotFound(path.to_string()));
@@ -347,11 +1175,81 @@ impl StorageProvider for LocalStorageProvider {
         Ok(Bytes::from(data))
     }
 
+    async fn get_image_metadata(&self, path: &str) -> Result<ImageMetadata, StorageError> {
+        let file_path = self.resolve_path(path);
+
+        if !file_path.exists() {
+            return Err(StorageError::NotFound(path.to_string()));
+        }
+
+        if file_path.is_dir() {
+            return Err(StorageError::NotAFile(path.to_string()));
+        }
+
+        // Разбор EXIF синхронный, читается только заголовок файла
+        tokio::task::spawn_blocking(move || {
+            let file = std::fs::File::open(&file_path)?;
+            read_image_metadata(&mut std::io::BufReader::new(file))
+        })
+        .await
+        .map_err(|e| StorageError::Io(std::io::Error::other(e)))?
+    }
+
+    async fn find_duplicates(&self, root: &str) -> Result<DuplicateStream, StorageError> {
+        let root_path = self.resolve_path(root);
+
+        if !root_path.is_dir() {
+            return Err(StorageError::NotADirectory(root.to_string()));
+        }
+
+        let show_hidden = self.show_hidden;
+        let stream = async_stream::try_stream! {
+            let files = tokio::task::spawn_blocking(move || {
+                let mut files = Vec::new();
+                collect_files(&root_path, show_hidden, &mut files);
+                files
+            })
+            .await
+            .map_err(|e| StorageError::Io(std::io::Error::other(e)))?;
+
+            // Хэшируем только файлы, размер которых совпадает хотя бы с одним другим.
+            // Пустые файлы не считаются дубликатами.
+            let mut by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+            for (path, size) in files {
+                if size > 0 {
+                    by_size.entry(size).or_default().push(path);
+                }
+            }
+
+            for (size, paths) in by_size {
+                if paths.len() < 2 {
+                    continue;
+                }
+
+                let mut by_hash: HashMap<String, Vec<String>> = HashMap::new();
+                for path in paths {
+                    match hash_file(&path).await {
+                        Ok(hash) => by_hash.entry(hash).or_default().push(display_path(&path)),
+                        Err(e) => warn!("Не удалось прочитать {}: {}", path.display(), e),
+                    }
+                }
+
+                for (hash, paths) in by_hash {
+                    if paths.len() > 1 {
+                        yield DuplicateGroup { hash, size, paths };
+                    }
+                }
+            }
+        };
+
+        Ok(Box::pin(stream))
+    }
+
     async fn get_read_stream(
         &self,
         path: &str,
     ) -> Result<Pin<Box<dyn AsyncRead + Send>>, StorageError> {
-        let file_path = PathBuf::from(path);
+        let file_path = self.resolve_path(path);
 
         if !file_path.exists() {
             return Err(StorageError::NotFound(path.to_string()));
@@ -361,47 +1259,84 @@ impl StorageProvider for LocalStorageProvider {
         Ok(Box::pin(file))
     }
 
+    async fn get_read_stream_at(
+        &self,
+        path: &str,
+        offset: u64,
+    ) -> Result<Pin<Box<dyn AsyncRead + Send>>, StorageError> {
+        let file_path = self.resolve_path(path);
+
+        if !file_path.exists() {
+            return Err(StorageError::NotFound(path.to_string()));
+        }
+
+        let mut file = fs::File::open(&file_path).await?;
+        file.seek(SeekFrom::Start(offset)).await?;
+        Ok(Box::pin(file))
+    }
+
     async fn get_write_stream(
         &self,
         path: &str,
         overwrite: bool,
+        mode: Option<u32>,
     ) -> Result<Pin<Box<dyn AsyncWrite + Send>>, StorageError> {
-        let file_path = PathBuf::from(path);
+        let file_path = self.target(path)?;
+        check_mode(mode)?;
 
         if file_path.exists() && !overwrite {
             return Err(StorageError::AlreadyExists(path.to_string()));
         }
 
         // Создаём родительские директории
-        if let Some(parent) = file_path.parent() {
-            fs::create_dir_all(parent).await?;
+        let directory = file_path.parent().map(Path::to_path_buf).unwrap_or_default();
+        fs::create_dir_all(&directory).await?;
+
+        // Данные пишутся во временный файл и заменяют прежний файл только при
+        // закрытии потока, поэтому прерванная загрузка его не портит
+        let temp_dir = self.upload_temp_dir_for(&directory).await;
+        let quota = self.quota_for(&file_path).cloned();
+        let writer = AtomicWriter::create(&temp_dir, file_path, quota.clone(), mode).await?;
+
+        match quota {
+            Some(quota) => Ok(Box::pin(QuotaWriter::new(Box::pin(writer), quota))),
+            None => Ok(Box::pin(writer)),
         }
-
-        let file = fs::File::create(&file_path).await?;
-        Ok(Box::pin(file))
     }
 
     async fn init_project_structure(
         &self,
         base_path: &str,
         project_name: &str,
+        merge: bool,
     ) -> Result<ProjectStructure, StorageError> {
-        let project_path = PathBuf::from(base_path).join(project_name);
+        check_file_name(project_name)?;
+        let project_path = to_fs_path(&self.target(base_path)?.join(project_name));
 
         if project_path.exists() {
-            return Err(StorageError::AlreadyExists(
-                project_path.to_string_lossy().to_string(),
-            ));
+            if !merge {
+                return Err(StorageError::AlreadyExists(
+                    display_path(&project_path),
+                ));
+            }
+            if !project_path.is_dir() {
+                return Err(StorageError::NotADirectory(display_path(&project_path)));
+            }
+        }
+
+        if let Some(quota) = self.quota_for(&project_path) {
+            quota.ensure_available()?;
         }
 
-        let assets_path = project_path.join("assets");
-        let video_path = assets_path.join("video");
-        let audio_path = assets_path.join("audio");
-        let images_path = assets_path.join("images");
-        let timeline_path = project_path.join("timeline");
-        let exports_path = project_path.join("exports");
+        let folders = &self.project_folders;
+        let assets_path = project_path.join(&folders.assets);
+        let video_path = assets_path.join(&folders.video);
+        let audio_path = assets_path.join(&folders.audio);
+        let images_path = assets_path.join(&folders.images);
+        let timeline_path = project_path.join(&folders.timeline);
+        let exports_path = project_path.join(&folders.exports);
 
-        // Создаём все директории
+        // Создаём все директории (существующие при слиянии остаются как есть)
         for dir in [
             &project_path,
             &assets_path,
@@ -415,14 +1350,35 @@ impl StorageProvider for LocalStorageProvider {
         }
 
         Ok(ProjectStructure {
-            project_path: project_path.to_string_lossy().to_string(),
-            assets_path: assets_path.to_string_lossy().to_string(),
-            video_path: video_path.to_string_lossy().to_string(),
-            audio_path: audio_path.to_string_lossy().to_string(),
-            images_path: images_path.to_string_lossy().to_string(),
-            timeline_path: timeline_path.to_string_lossy().to_string(),
-            exports_path: exports_path.to_string_lossy().to_string(),
+            project_path: display_path(&project_path),
+            assets_path: display_path(&assets_path),
+            video_path: display_path(&video_path),
+            audio_path: display_path(&audio_path),
+            images_path: display_path(&images_path),
+            timeline_path: display_path(&timeline_path),
+            exports_path: display_path(&exports_path),
         })
     }
-}
 
+    /// Переименование средствами ФС: директория переносится целиком,
+    /// без копирования (в пределах одного тома)
+    async fn rename(
+        &self,
+        source: &str,
+        destination: &str,
+        overwrite: bool,
+    ) -> Result<(), StorageError> {
+        let source_path = self.target(source)?;
+        let destination_path = self.target(destination)?;
+
+        if fs::symlink_metadata(&source_path).await.is_err() {
+            return Err(StorageError::NotFound(source.to_string()));
+        }
+        if !overwrite && fs::symlink_metadata(&destination_path).await.is_ok() {
+            return Err(StorageError::AlreadyExists(destination.to_string()));
+        }
+
+        fs::rename(&source_path, &destination_path).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,708 @@
+//! Хранилище в памяти процесса
+//!
+//! Файлы и директории живут в памяти и пропадают при остановке. Нужно для
+//! тестов сервиса без диска: результат не зависит от файловой системы и
+//! от других тестов. Пути - в стиле unix (`/a/b.mp4`), корень `/` есть
+//! всегда. Ошибки те же, что у `LocalStorageProvider` в тех же ситуациях.
+//! Права unix (`mode`) проверяются, но не хранятся.
+
+use std::collections::{BTreeSet, HashMap};
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::task::{Context, Poll};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use tokio::io::{AsyncRead, AsyncWrite};
+use uuid::Uuid;
+
+use super::mode::check_mode;
+use super::{
+    check_file_name, DeletePreview, DeleteProgress, DirectoryListing, DirectoryStats,
+    ProjectFolders, ProjectStructure, StorageCapabilities, StorageConfig, StorageEntry,
+    StorageError, StorageInfo, StorageProvider, UploadResult,
+};
+
+/// Корень хранилища
+const ROOT: &str = "/";
+
+struct MemoryFile {
+    data: Bytes,
+    created_at: DateTime<Utc>,
+    modified_at: DateTime<Utc>,
+}
+
+/// Содержимое хранилища
+#[derive(Default)]
+struct Tree {
+    /// Файлы по пути
+    files: HashMap<String, MemoryFile>,
+    /// Директории, кроме корня
+    directories: BTreeSet<String>,
+}
+
+impl Tree {
+    fn is_dir(&self, path: &str) -> bool {
+        path == ROOT || self.directories.contains(path)
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        self.is_dir(path) || self.files.contains_key(path)
+    }
+
+    /// Пути непосредственного содержимого директории
+    fn children(&self, dir: &str) -> Vec<String> {
+        self.directories
+            .iter()
+            .chain(self.files.keys())
+            .filter(|path| parent(path) == Some(dir))
+            .cloned()
+            .collect()
+    }
+
+    /// Пути всего содержимого директории на всех уровнях
+    fn descendants(&self, dir: &str) -> Vec<String> {
+        let prefix = if dir == ROOT { ROOT.to_string() } else { format!("{}/", dir) };
+        let mut paths: Vec<String> = self
+            .directories
+            .iter()
+            .chain(self.files.keys())
+            .filter(|path| path.starts_with(&prefix))
+            .cloned()
+            .collect();
+        paths.sort();
+        paths
+    }
+
+    /// Создать директорию вместе с недостающими родительскими (`create_dir_all`)
+    fn create_dir_all(&mut self, path: &str) -> Result<(), StorageError> {
+        if self.is_dir(path) {
+            return Ok(());
+        }
+        if self.files.contains_key(path) {
+            return Err(io_error(io::ErrorKind::AlreadyExists, "файл с таким именем", path));
+        }
+        if let Some(parent) = parent(path) {
+            self.create_dir_all(parent)?;
+        }
+        self.directories.insert(path.to_string());
+        Ok(())
+    }
+
+    /// Записать файл; время создания перезаписываемого файла сохраняется
+    fn write_file(&mut self, path: &str, data: Bytes) {
+        let now = Utc::now();
+        let created_at = self.files.get(path).map_or(now, |file| file.created_at);
+        self.files.insert(
+            path.to_string(),
+            MemoryFile {
+                data,
+                created_at,
+                modified_at: now,
+            },
+        );
+    }
+}
+
+pub struct MemoryStorageProvider {
+    id: String,
+    tree: Arc<RwLock<Tree>>,
+    /// Показывать файлы и директории, начинающиеся с точки
+    show_hidden: bool,
+    default_projects_path: String,
+    /// MIME типы по расширению (без точки, в нижнем регистре)
+    mime_overrides: HashMap<String, String>,
+    /// Названия стандартных папок проекта
+    project_folders: ProjectFolders,
+}
+
+impl MemoryStorageProvider {
+    pub fn new(config: &StorageConfig) -> Self {
+        Self {
+            id: config.id.clone().unwrap_or_else(|| Uuid::new_v4().to_string()),
+            tree: Arc::default(),
+            show_hidden: config.show_hidden,
+            default_projects_path: config
+                .default_projects_path
+                .as_deref()
+                .map_or_else(|| ROOT.to_string(), normalize),
+            mime_overrides: config
+                .extension_mime_overrides
+                .iter()
+                .map(|(ext, mime)| (ext.trim_start_matches('.').to_lowercase(), mime.clone()))
+                .collect(),
+            project_folders: config.project_folders.clone(),
+        }
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, Tree> {
+        self.tree.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Tree> {
+        self.tree.write().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// MIME тип по расширению: сначала переопределения, затем `mime_guess`
+    fn guess_mime_type(&self, path: &str) -> String {
+        let extension = std::path::Path::new(path)
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        match extension.and_then(|extension| self.mime_overrides.get(&extension)) {
+            Some(mime) => mime.clone(),
+            None => mime_guess::from_path(path).first_or_octet_stream().to_string(),
+        }
+    }
+
+    /// Сведения об элементе; `None`, если его нет
+    fn entry(&self, tree: &Tree, path: &str) -> Option<StorageEntry> {
+        let name = path.rsplit('/').next().unwrap_or_default().to_string();
+        let mut entry = StorageEntry {
+            name,
+            path: path.to_string(),
+            is_directory: true,
+            size: 0,
+            created_at: None,
+            modified_at: None,
+            mime_type: "inode/directory".to_string(),
+            metadata_error: None,
+            is_writable: None,
+            symlink_target: None,
+            metadata: HashMap::new(),
+        };
+
+        if let Some(file) = tree.files.get(path) {
+            entry.is_directory = false;
+            entry.size = file.data.len() as u64;
+            entry.created_at = Some(file.created_at);
+            entry.modified_at = Some(file.modified_at);
+            entry.mime_type = self.guess_mime_type(path);
+        } else if !tree.is_dir(path) {
+            return None;
+        }
+        Some(entry)
+    }
+
+    /// Содержимое файла
+    fn file_data(&self, path: &str) -> Result<Bytes, StorageError> {
+        let resolved = normalize(path);
+        let tree = self.read();
+        match tree.files.get(&resolved) {
+            Some(file) => Ok(file.data.clone()),
+            None if tree.is_dir(&resolved) => Err(StorageError::NotAFile(path.to_string())),
+            None => Err(StorageError::NotFound(path.to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl StorageProvider for MemoryStorageProvider {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    async fn get_info(&self) -> Result<StorageInfo, StorageError> {
+        Ok(StorageInfo {
+            id: self.id.clone(),
+            storage_type: "memory".to_string(),
+            hostname: hostname::get()
+                .map(|h| h.to_string_lossy().to_string())
+                .unwrap_or_else(|_| "unknown".to_string()),
+            os: std::env::consts::OS.to_string(),
+            home_directory: ROOT.to_string(),
+            default_projects_path: self.default_projects_path.clone(),
+            root_paths: vec![ROOT.to_string()],
+            total_space: 0,
+            free_space: 0,
+            quota_bytes: None,
+            quota_used_bytes: None,
+        })
+    }
+
+    fn capabilities(&self) -> StorageCapabilities {
+        StorageCapabilities {
+            streaming: true,
+            rename: true,
+            atomic_writes: true,
+            ..Default::default()
+        }
+    }
+
+    async fn list_directory(&self, path: &str) -> Result<DirectoryListing, StorageError> {
+        let dir = normalize(path);
+        let tree = self.read();
+        if !tree.exists(&dir) {
+            return Err(StorageError::NotFound(path.to_string()));
+        }
+        if !tree.is_dir(&dir) {
+            return Err(StorageError::NotADirectory(path.to_string()));
+        }
+
+        let mut entries: Vec<StorageEntry> = tree
+            .children(&dir)
+            .iter()
+            .filter_map(|child| self.entry(&tree, child))
+            .filter(|entry| self.show_hidden || !entry.name.starts_with('.'))
+            .collect();
+
+        // Сортировка как у локального хранилища: директории сверху, потом по имени
+        entries.sort_by(|a, b| {
+            b.is_directory.cmp(&a.is_directory).then_with(|| {
+                a.name
+                    .to_lowercase()
+                    .cmp(&b.name.to_lowercase())
+                    .then_with(|| a.name.cmp(&b.name))
+            })
+        });
+
+        Ok(DirectoryListing {
+            parent_path: parent(&dir).unwrap_or_default().to_string(),
+            current_path: dir,
+            entries,
+        })
+    }
+
+    async fn exists(&self, path: &str) -> Result<bool, StorageError> {
+        Ok(self.read().exists(&normalize(path)))
+    }
+
+    async fn get_entry_info(&self, path: &str) -> Result<StorageEntry, StorageError> {
+        self.entry(&self.read(), &normalize(path))
+            .ok_or_else(|| StorageError::NotFound(path.to_string()))
+    }
+
+    async fn create_directory(
+        &self,
+        path: &str,
+        recursive: bool,
+        mode: Option<u32>,
+    ) -> Result<String, StorageError> {
+        let dir = target(path)?;
+        check_mode(mode)?;
+
+        let mut tree = self.write();
+        if recursive {
+            tree.create_dir_all(&dir)?;
+        } else {
+            if tree.exists(&dir) {
+                return Err(io_error(io::ErrorKind::AlreadyExists, "путь уже существует", &dir));
+            }
+            let parent = parent(&dir).unwrap_or(ROOT);
+            if !tree.is_dir(parent) {
+                return Err(io_error(io::ErrorKind::NotFound, "нет родительской директории", &dir));
+            }
+            tree.directories.insert(dir.clone());
+        }
+
+        Ok(dir)
+    }
+
+    async fn get_directory_stats(&self, path: &str) -> Result<DirectoryStats, StorageError> {
+        let dir = normalize(path);
+        let tree = self.read();
+        if !tree.is_dir(&dir) {
+            return Err(StorageError::NotADirectory(path.to_string()));
+        }
+
+        let mut stats = DirectoryStats::default();
+        for descendant in tree.descendants(&dir) {
+            match tree.files.get(&descendant) {
+                Some(file) => {
+                    stats.file_count += 1;
+                    stats.total_size += file.data.len() as u64;
+                }
+                None => stats.dir_count += 1,
+            }
+        }
+        Ok(stats)
+    }
+
+    async fn delete_directory(&self, path: &str, recursive: bool) -> Result<(), StorageError> {
+        let dir = target(path)?;
+        let mut tree = self.write();
+        if !tree.exists(&dir) {
+            return Err(StorageError::NotFound(path.to_string()));
+        }
+        if !tree.is_dir(&dir) {
+            return Err(StorageError::NotADirectory(path.to_string()));
+        }
+        if dir == ROOT {
+            return Err(StorageError::PermissionDenied(path.to_string()));
+        }
+
+        let descendants = tree.descendants(&dir);
+        if !recursive && !descendants.is_empty() {
+            return Err(io_error(io::ErrorKind::DirectoryNotEmpty, "директория не пуста", path));
+        }
+        for descendant in descendants {
+            tree.files.remove(&descendant);
+            tree.directories.remove(&descendant);
+        }
+        tree.directories.remove(&dir);
+        Ok(())
+    }
+
+    async fn preview_delete(
+        &self,
+        path: &str,
+        recursive: bool,
+        limit: usize,
+    ) -> Result<DeletePreview, StorageError> {
+        let target = target(path)?;
+        let tree = self.read();
+        if !tree.exists(&target) {
+            return Err(StorageError::NotFound(path.to_string()));
+        }
+
+        let mut paths = vec![target.clone()];
+        if tree.is_dir(&target) {
+            let descendants = tree.descendants(&target);
+            if !recursive && !descendants.is_empty() {
+                return Err(io_error(io::ErrorKind::DirectoryNotEmpty, "директория не пуста", path));
+            }
+            paths.extend(descendants);
+        }
+
+        let total_size = paths
+            .iter()
+            .filter_map(|path| tree.files.get(path))
+            .map(|file| file.data.len() as u64)
+            .sum();
+        let truncated = paths.len() > limit;
+        paths.truncate(limit);
+
+        Ok(DeletePreview {
+            paths,
+            truncated,
+            total_size,
+            errors: Vec::new(),
+        })
+    }
+
+    /// Удаление целиком под блокировкой: параллельность в памяти не нужна
+    async fn delete_tree(
+        &self,
+        path: &str,
+        _concurrency: usize,
+        progress: Arc<DeleteProgress>,
+    ) -> Result<(), StorageError> {
+        let dir = target(path)?;
+        let mut tree = self.write();
+        if !tree.exists(&dir) {
+            return Err(StorageError::NotFound(path.to_string()));
+        }
+        if !tree.is_dir(&dir) {
+            return Err(StorageError::NotADirectory(path.to_string()));
+        }
+        if dir == ROOT {
+            return Err(StorageError::PermissionDenied(path.to_string()));
+        }
+
+        for descendant in tree.descendants(&dir) {
+            match tree.files.remove(&descendant) {
+                Some(file) => {
+                    progress.files_deleted.fetch_add(1, Ordering::Relaxed);
+                    progress
+                        .bytes_deleted
+                        .fetch_add(file.data.len() as u64, Ordering::Relaxed);
+                }
+                None => {
+                    tree.directories.remove(&descendant);
+                    progress.directories_deleted.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+        tree.directories.remove(&dir);
+        progress.directories_deleted.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+        let file = target(path)?;
+        let mut tree = self.write();
+        if !tree.exists(&file) {
+            return Err(StorageError::NotFound(path.to_string()));
+        }
+        if tree.is_dir(&file) {
+            return Err(StorageError::NotAFile(path.to_string()));
+        }
+        tree.files.remove(&file);
+        Ok(())
+    }
+
+    async fn upload_bytes(
+        &self,
+        destination: &str,
+        data: Bytes,
+        overwrite: bool,
+    ) -> Result<UploadResult, StorageError> {
+        let file = target(destination)?;
+        let mut tree = self.write();
+        if tree.exists(&file) && !overwrite {
+            return Err(StorageError::AlreadyExists(destination.to_string()));
+        }
+        if tree.is_dir(&file) {
+            return Err(StorageError::NotAFile(destination.to_string()));
+        }
+        if let Some(parent) = parent(&file) {
+            tree.create_dir_all(parent)?;
+        }
+
+        let size = data.len() as u64;
+        tree.write_file(&file, data);
+        Ok(UploadResult {
+            path: file,
+            size,
+            checksum: None,
+        })
+    }
+
+    async fn download_bytes(&self, path: &str) -> Result<Bytes, StorageError> {
+        self.file_data(path)
+    }
+
+    async fn get_read_stream(
+        &self,
+        path: &str,
+    ) -> Result<Pin<Box<dyn AsyncRead + Send>>, StorageError> {
+        Ok(Box::pin(io::Cursor::new(self.file_data(path)?)))
+    }
+
+    async fn get_read_stream_at(
+        &self,
+        path: &str,
+        offset: u64,
+    ) -> Result<Pin<Box<dyn AsyncRead + Send>>, StorageError> {
+        let data = self.file_data(path)?;
+        let offset = usize::try_from(offset).unwrap_or(usize::MAX).min(data.len());
+        Ok(Box::pin(io::Cursor::new(data.slice(offset..))))
+    }
+
+    async fn get_write_stream(
+        &self,
+        path: &str,
+        overwrite: bool,
+        mode: Option<u32>,
+    ) -> Result<Pin<Box<dyn AsyncWrite + Send>>, StorageError> {
+        let file = target(path)?;
+        check_mode(mode)?;
+
+        let mut tree = self.write();
+        if tree.exists(&file) && !overwrite {
+            return Err(StorageError::AlreadyExists(path.to_string()));
+        }
+        if tree.is_dir(&file) {
+            return Err(StorageError::NotAFile(path.to_string()));
+        }
+        if let Some(parent) = parent(&file) {
+            tree.create_dir_all(parent)?;
+        }
+
+        Ok(Box::pin(MemoryWriter {
+            tree: self.tree.clone(),
+            path: file,
+            buffer: Vec::new(),
+            committed: false,
+        }))
+    }
+
+    async fn init_project_structure(
+        &self,
+        base_path: &str,
+        project_name: &str,
+        merge: bool,
+    ) -> Result<ProjectStructure, StorageError> {
+        check_file_name(project_name)?;
+        let project = join(&normalize(base_path), project_name);
+
+        let mut tree = self.write();
+        if tree.exists(&project) {
+            if !merge {
+                return Err(StorageError::AlreadyExists(project));
+            }
+            if !tree.is_dir(&project) {
+                return Err(StorageError::NotADirectory(project));
+            }
+        }
+
+        let folders = &self.project_folders;
+        let assets = join(&project, &folders.assets);
+        let video = join(&assets, &folders.video);
+        let audio = join(&assets, &folders.audio);
+        let images = join(&assets, &folders.images);
+        let timeline = join(&project, &folders.timeline);
+        let exports = join(&project, &folders.exports);
+
+        for dir in [&video, &audio, &images, &timeline, &exports] {
+            tree.create_dir_all(dir)?;
+        }
+
+        Ok(ProjectStructure {
+            project_path: project,
+            assets_path: assets,
+            video_path: video,
+            audio_path: audio,
+            images_path: images,
+            timeline_path: timeline,
+            exports_path: exports,
+        })
+    }
+
+    /// Перемещение файла или директории целиком, как `fs::rename`
+    async fn rename(
+        &self,
+        source: &str,
+        destination: &str,
+        overwrite: bool,
+    ) -> Result<(), StorageError> {
+        let from = target(source)?;
+        let to = target(destination)?;
+
+        let mut tree = self.write();
+        if !tree.exists(&from) {
+            return Err(StorageError::NotFound(source.to_string()));
+        }
+        if !overwrite && tree.exists(&to) {
+            return Err(StorageError::AlreadyExists(destination.to_string()));
+        }
+        if from == to {
+            return Ok(());
+        }
+        if !tree.is_dir(parent(&to).unwrap_or(ROOT)) {
+            return Err(io_error(io::ErrorKind::NotFound, "нет родительской директории", &to));
+        }
+
+        if let Some(file) = tree.files.remove(&from) {
+            if tree.is_dir(&to) {
+                tree.files.insert(from, file);
+                return Err(io_error(io::ErrorKind::IsADirectory, "путь - директория", &to));
+            }
+            tree.files.insert(to, file);
+            return Ok(());
+        }
+
+        // Директория: нельзя внутрь себя, заменить можно только пустую директорию
+        if from == ROOT || to.starts_with(&format!("{}/", from)) {
+            return Err(StorageError::InvalidName(destination.to_string()));
+        }
+        if tree.files.contains_key(&to) {
+            return Err(io_error(io::ErrorKind::NotADirectory, "путь - файл", &to));
+        }
+        if !tree.descendants(&to).is_empty() {
+            return Err(io_error(io::ErrorKind::DirectoryNotEmpty, "директория не пуста", &to));
+        }
+
+        let descendants = tree.descendants(&from);
+        tree.directories.remove(&from);
+        tree.directories.insert(to.clone());
+        for old in descendants {
+            let new = format!("{}{}", to, &old[from.len()..]);
+            match tree.files.remove(&old) {
+                Some(file) => {
+                    tree.files.insert(new, file);
+                }
+                None => {
+                    tree.directories.remove(&old);
+                    tree.directories.insert(new);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Поток записи: файл появляется (или заменяется) при закрытии потока
+struct MemoryWriter {
+    tree: Arc<RwLock<Tree>>,
+    path: String,
+    buffer: Vec<u8>,
+    committed: bool,
+}
+
+impl AsyncWrite for MemoryWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        if this.committed {
+            return Poll::Ready(Err(io::Error::other("Запись уже завершена")));
+        }
+        this.buffer.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if !this.committed {
+            let data = Bytes::from(std::mem::take(&mut this.buffer));
+            let mut tree = this.tree.write().unwrap_or_else(|e| e.into_inner());
+            // Родительскую директорию могли удалить, пока шла запись
+            if let Some(parent) = parent(&this.path) {
+                if !tree.is_dir(parent) {
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("нет родительской директории: {}", this.path),
+                    )));
+                }
+            }
+            tree.write_file(&this.path, data);
+            this.committed = true;
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Путь клиента в виде `/a/b`: разделители `\` и `/`, без `.` и повторных
+/// разделителей, `..` убирает предыдущий компонент. Пустой путь - корень.
+fn normalize(path: &str) -> String {
+    let mut components: Vec<&str> = Vec::new();
+    for component in path.split(['/', '\\']) {
+        match component {
+            "" | "." => {}
+            ".." => {
+                components.pop();
+            }
+            _ => components.push(component),
+        }
+    }
+    format!("/{}", components.join("/"))
+}
+
+/// Путь для изменяющей операции: пустой путь не означает корень
+fn target(path: &str) -> Result<String, StorageError> {
+    if path.trim().is_empty() {
+        return Err(StorageError::InvalidName(path.to_string()));
+    }
+    Ok(normalize(path))
+}
+
+/// Родительская директория; у корня - `None`
+fn parent(path: &str) -> Option<&str> {
+    match path.rsplit_once('/') {
+        _ if path == ROOT => None,
+        Some(("", _)) => Some(ROOT),
+        Some((parent, _)) => Some(parent),
+        None => None,
+    }
+}
+
+fn join(dir: &str, name: &str) -> String {
+    if dir == ROOT {
+        format!("/{}", name)
+    } else {
+        format!("{}/{}", dir, name)
+    }
+}
+
+fn io_error(kind: io::ErrorKind, message: &str, path: &str) -> StorageError {
+    StorageError::Io(io::Error::new(kind, format!("{}: {}", message, path)))
+}
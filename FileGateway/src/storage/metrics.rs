@@ -0,0 +1,461 @@
+//! Метрики операций хранилища
+//!
+//! `MeteredStorageProvider` оборачивает провайдер и считает вызовы, ошибки
+//! и длительность каждой операции, а также объём и время чтения/записи
+//! данных. Время чтения и записи потоков учитывается только внутри
+//! провайдера, поэтому по нему можно отличить медленный диск от медленной
+//! сети: сервис ждёт клиента вне этих вызовов.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use super::{
+    provider::{DuplicateStream, StorageProvider},
+    DeleteProgress,
+    types::*,
+    StorageError,
+};
+
+/// Счётчики одной операции
+#[derive(Debug, Clone, Copy, Default)]
+struct OperationCounters {
+    calls: u64,
+    errors: u64,
+    total: Duration,
+    max: Duration,
+}
+
+/// Метрики одной операции на момент снимка
+#[derive(Debug, Clone)]
+pub struct OperationMetrics {
+    pub operation: &'static str,
+    pub calls: u64,
+    pub errors: u64,
+    pub total_duration: Duration,
+    pub max_duration: Duration,
+}
+
+/// Снимок метрик провайдера
+#[derive(Debug, Clone)]
+pub struct StorageMetricsSnapshot {
+    pub provider_id: String,
+    pub bytes_read: u64,
+    pub bytes_written: u64,
+    /// Время, проведённое в чтении данных из хранилища
+    pub read_duration: Duration,
+    /// Время, проведённое в записи данных в хранилище
+    pub write_duration: Duration,
+    pub operations: Vec<OperationMetrics>,
+}
+
+/// Накопленные метрики провайдера
+#[derive(Default)]
+pub struct StorageMetrics {
+    operations: Mutex<BTreeMap<&'static str, OperationCounters>>,
+    bytes_read: AtomicU64,
+    bytes_written: AtomicU64,
+    read_nanos: AtomicU64,
+    write_nanos: AtomicU64,
+}
+
+impl StorageMetrics {
+    fn record(&self, operation: &'static str, elapsed: Duration, success: bool) {
+        let mut operations = self.operations.lock().unwrap_or_else(|e| e.into_inner());
+        let counters = operations.entry(operation).or_default();
+        counters.calls += 1;
+        if !success {
+            counters.errors += 1;
+        }
+        counters.total += elapsed;
+        counters.max = counters.max.max(elapsed);
+    }
+
+    fn add_read(&self, bytes: u64, elapsed: Duration) {
+        self.bytes_read.fetch_add(bytes, Ordering::Relaxed);
+        self.read_nanos.fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    fn add_written(&self, bytes: u64, elapsed: Duration) {
+        self.bytes_written.fetch_add(bytes, Ordering::Relaxed);
+        self.write_nanos.fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self, provider_id: &str) -> StorageMetricsSnapshot {
+        let operations = self.operations.lock().unwrap_or_else(|e| e.into_inner());
+
+        StorageMetricsSnapshot {
+            provider_id: provider_id.to_string(),
+            bytes_read: self.bytes_read.load(Ordering::Relaxed),
+            bytes_written: self.bytes_written.load(Ordering::Relaxed),
+            read_duration: Duration::from_nanos(self.read_nanos.load(Ordering::Relaxed)),
+            write_duration: Duration::from_nanos(self.write_nanos.load(Ordering::Relaxed)),
+            operations: operations
+                .iter()
+                .map(|(operation, counters)| OperationMetrics {
+                    operation,
+                    calls: counters.calls,
+                    errors: counters.errors,
+                    total_duration: counters.total,
+                    max_duration: counters.max,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Провайдер, собирающий метрики операций вложенного провайдера
+pub struct MeteredStorageProvider {
+    inner: Arc<dyn StorageProvider>,
+    metrics: Arc<StorageMetrics>,
+}
+
+impl MeteredStorageProvider {
+    pub fn new(inner: Arc<dyn StorageProvider>) -> Self {
+        Self {
+            inner,
+            metrics: Arc::new(StorageMetrics::default()),
+        }
+    }
+
+    /// Текущие значения метрик
+    pub fn snapshot(&self) -> StorageMetricsSnapshot {
+        self.metrics.snapshot(self.inner.id())
+    }
+
+    async fn timed<T>(
+        &self,
+        operation: &'static str,
+        call: impl Future<Output = Result<T, StorageError>>,
+    ) -> Result<T, StorageError> {
+        let started = Instant::now();
+        let result = call.await;
+        self.metrics.record(operation, started.elapsed(), result.is_ok());
+        result
+    }
+}
+
+#[async_trait]
+impl StorageProvider for MeteredStorageProvider {
+    fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    async fn get_info(&self) -> Result<StorageInfo, StorageError> {
+        self.timed("get_info", self.inner.get_info()).await
+    }
+
+    async fn get_disk_space(&self, path: &str) -> Result<DiskSpace, StorageError> {
+        self.timed("get_disk_space", self.inner.get_disk_space(path))
+            .await
+    }
+
+    fn capabilities(&self) -> StorageCapabilities {
+        self.inner.capabilities()
+    }
+
+    async fn list_directory(&self, path: &str) -> Result<DirectoryListing, StorageError> {
+        self.timed("list_directory", self.inner.list_directory(path)).await
+    }
+
+    async fn exists(&self, path: &str) -> Result<bool, StorageError> {
+        self.timed("exists", self.inner.exists(path)).await
+    }
+
+    async fn get_entry_info(&self, path: &str) -> Result<StorageEntry, StorageError> {
+        self.timed("get_entry_info", self.inner.get_entry_info(path)).await
+    }
+
+    async fn get_link_info(&self, path: &str) -> Result<StorageEntry, StorageError> {
+        self.timed("get_link_info", self.inner.get_link_info(path)).await
+    }
+
+    async fn create_directory(
+        &self,
+        path: &str,
+        recursive: bool,
+        mode: Option<u32>,
+    ) -> Result<String, StorageError> {
+        self.timed("create_directory", self.inner.create_directory(path, recursive, mode))
+            .await
+    }
+
+    async fn get_directory_stats(&self, path: &str) -> Result<DirectoryStats, StorageError> {
+        self.timed("get_directory_stats", self.inner.get_directory_stats(path))
+            .await
+    }
+
+    async fn delete_directory(&self, path: &str, recursive: bool) -> Result<(), StorageError> {
+        self.timed("delete_directory", self.inner.delete_directory(path, recursive))
+            .await
+    }
+
+    async fn preview_delete(
+        &self,
+        path: &str,
+        recursive: bool,
+        limit: usize,
+    ) -> Result<DeletePreview, StorageError> {
+        self.timed("preview_delete", self.inner.preview_delete(path, recursive, limit))
+            .await
+    }
+
+    async fn delete_tree(
+        &self,
+        path: &str,
+        concurrency: usize,
+        progress: Arc<DeleteProgress>,
+    ) -> Result<(), StorageError> {
+        self.timed("delete_tree", self.inner.delete_tree(path, concurrency, progress))
+            .await
+    }
+
+    async fn create_file(&self, path: &str, overwrite: bool) -> Result<String, StorageError> {
+        self.timed("create_file", self.inner.create_file(path, overwrite))
+            .await
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+        self.timed("delete_file", self.inner.delete_file(path)).await
+    }
+
+    async fn upload_bytes(
+        &self,
+        destination: &str,
+        data: Bytes,
+        overwrite: bool,
+    ) -> Result<UploadResult, StorageError> {
+        let len = data.len() as u64;
+        let started = Instant::now();
+        let result = self
+            .timed("upload_bytes", self.inner.upload_bytes(destination, data, overwrite))
+            .await;
+        if result.is_ok() {
+            self.metrics.add_written(len, started.elapsed());
+        }
+        result
+    }
+
+    async fn download_bytes(&self, path: &str) -> Result<Bytes, StorageError> {
+        let started = Instant::now();
+        let result = self
+            .timed("download_bytes", self.inner.download_bytes(path))
+            .await;
+        if let Ok(data) = &result {
+            self.metrics.add_read(data.len() as u64, started.elapsed());
+        }
+        result
+    }
+
+    async fn get_image_metadata(&self, path: &str) -> Result<ImageMetadata, StorageError> {
+        self.timed("get_image_metadata", self.inner.get_image_metadata(path))
+            .await
+    }
+
+    async fn get_read_stream(
+        &self,
+        path: &str,
+    ) -> Result<Pin<Box<dyn AsyncRead + Send>>, StorageError> {
+        let stream = self
+            .timed("get_read_stream", self.inner.get_read_stream(path))
+            .await?;
+        Ok(Box::pin(MeteredReader {
+            inner: stream,
+            metrics: self.metrics.clone(),
+            in_flight: InFlight::default(),
+        }))
+    }
+
+    async fn get_read_stream_at(
+        &self,
+        path: &str,
+        offset: u64,
+    ) -> Result<Pin<Box<dyn AsyncRead + Send>>, StorageError> {
+        let stream = self
+            .timed("get_read_stream_at", self.inner.get_read_stream_at(path, offset))
+            .await?;
+        Ok(Box::pin(MeteredReader {
+            inner: stream,
+            metrics: self.metrics.clone(),
+            in_flight: InFlight::default(),
+        }))
+    }
+
+    async fn get_download_url(
+        &self,
+        path: &str,
+        expires_in: Duration,
+    ) -> Result<String, StorageError> {
+        self.timed("get_download_url", self.inner.get_download_url(path, expires_in))
+            .await
+    }
+
+    async fn get_write_stream(
+        &self,
+        path: &str,
+        overwrite: bool,
+        mode: Option<u32>,
+    ) -> Result<Pin<Box<dyn AsyncWrite + Send>>, StorageError> {
+        let stream = self
+            .timed("get_write_stream", self.inner.get_write_stream(path, overwrite, mode))
+            .await?;
+        Ok(Box::pin(MeteredWriter {
+            inner: stream,
+            metrics: self.metrics.clone(),
+            in_flight: InFlight::default(),
+        }))
+    }
+
+    async fn init_project_structure(
+        &self,
+        base_path: &str,
+        project_name: &str,
+        merge: bool,
+    ) -> Result<ProjectStructure, StorageError> {
+        self.timed(
+            "init_project_structure",
+            self.inner.init_project_structure(base_path, project_name, merge),
+        )
+        .await
+    }
+
+    async fn find_duplicates(&self, root: &str) -> Result<DuplicateStream, StorageError> {
+        self.timed("find_duplicates", self.inner.find_duplicates(root))
+            .await
+    }
+
+    async fn copy(
+        &self,
+        source: &str,
+        destination: &str,
+        overwrite: bool,
+    ) -> Result<(), StorageError> {
+        self.timed("copy", self.inner.copy(source, destination, overwrite))
+            .await
+    }
+
+    async fn rename(
+        &self,
+        source: &str,
+        destination: &str,
+        overwrite: bool,
+    ) -> Result<(), StorageError> {
+        self.timed("rename", self.inner.rename(source, destination, overwrite))
+            .await
+    }
+}
+
+/// Время ожидания операции ввода-вывода с первого опроса до готовности
+///
+/// Файловые операции tokio сначала возвращают `Pending`, а результат
+/// приходит в одном из следующих опросов, поэтому измерять только
+/// последний опрос недостаточно.
+#[derive(Default)]
+struct InFlight {
+    since: Option<Instant>,
+}
+
+impl InFlight {
+    /// Отметить начало опроса (время запоминается только для первого)
+    fn begin(&mut self) {
+        self.since.get_or_insert_with(Instant::now);
+    }
+
+    /// Длительность операции, если после опроса она завершилась
+    fn end<T>(&mut self, result: &Poll<T>) -> Option<Duration> {
+        match result {
+            Poll::Pending => None,
+            Poll::Ready(_) => self.since.take().map(|since| since.elapsed()),
+        }
+    }
+}
+
+/// Поток чтения, учитывающий прочитанные байты и время чтения
+struct MeteredReader {
+    inner: Pin<Box<dyn AsyncRead + Send>>,
+    metrics: Arc<StorageMetrics>,
+    in_flight: InFlight,
+}
+
+impl AsyncRead for MeteredReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        this.in_flight.begin();
+        let before = buf.filled().len();
+
+        let result = this.inner.as_mut().poll_read(cx, buf);
+
+        if let Some(elapsed) = this.in_flight.end(&result) {
+            let read = (buf.filled().len() - before) as u64;
+            this.metrics.add_read(read, elapsed);
+        }
+
+        result
+    }
+}
+
+/// Поток записи, учитывающий записанные байты и время записи
+struct MeteredWriter {
+    inner: Pin<Box<dyn AsyncWrite + Send>>,
+    metrics: Arc<StorageMetrics>,
+    in_flight: InFlight,
+}
+
+impl AsyncWrite for MeteredWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        this.in_flight.begin();
+        let result = this.inner.as_mut().poll_write(cx, buf);
+
+        if let Some(elapsed) = this.in_flight.end(&result) {
+            let written = match &result {
+                Poll::Ready(Ok(written)) => *written as u64,
+                _ => 0,
+            };
+            this.metrics.add_written(written, elapsed);
+        }
+
+        result
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        this.in_flight.begin();
+        let result = this.inner.as_mut().poll_flush(cx);
+
+        if let Some(elapsed) = this.in_flight.end(&result) {
+            this.metrics.add_written(0, elapsed);
+        }
+
+        result
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        this.in_flight.begin();
+        let result = this.inner.as_mut().poll_shutdown(cx);
+
+        if let Some(elapsed) = this.in_flight.end(&result) {
+            this.metrics.add_written(0, elapsed);
+        }
+
+        result
+    }
+}
@@ -2,16 +2,54 @@
 //!
 //! Поддерживаемые провайдеры:
 //! - `LocalStorageProvider` - локальная файловая система
-//! - `S3StorageProvider` - S3-совместимые хранилища (MinIO, AWS S3, etc.) [будущее]
+//! - `S3StorageProvider` - S3-совместимые хранилища (MinIO, AWS S3, etc.)
+//! - `MemoryStorageProvider` - в памяти процесса, для тестов
+//! - сторонние, зарегистрированные через `register_provider`
+//!
+//! `EncryptedStorageProvider` оборачивает любой из них и шифрует содержимое файлов,
+//! `RetryingStorageProvider` - повторяет чтение при временных ошибках,
+//! `MeteredStorageProvider` - собирает метрики операций,
+//! `ReloadableStorageProvider` - позволяет заменить провайдер без перезапуска.
 
 mod provider;
 mod local;
+mod atomic;
 mod config;
+mod delete;
+mod encrypted;
+mod image;
+mod memory;
+mod metrics;
+mod mode;
+mod path;
+mod quota;
+mod registry;
+mod reloadable;
+mod retry;
+mod s3;
+mod transfer;
+mod tree;
+mod walk;
 mod types;
 
-pub use provider::StorageProvider;
+pub use provider::{DuplicateStream, StorageProvider};
 pub use local::LocalStorageProvider;
-pub use config::{StorageConfig, StorageType};
+pub use memory::MemoryStorageProvider;
+pub use s3::S3StorageProvider;
+pub use reloadable::ReloadableStorageProvider;
+pub use retry::RetryingStorageProvider;
+pub use encrypted::{EncryptedStorageProvider, EncryptionKey};
+pub use config::{
+    EncryptionAlgorithm, EncryptionConfig, KeySource, ProjectFolders, RetryConfig, RootPathsMode,
+    StorageConfig, StorageType,
+};
+pub use delete::DeleteProgress;
+pub use image::read_image_metadata;
+pub use path::{check_file_name, join_file_path, same_path};
+pub use metrics::{MeteredStorageProvider, OperationMetrics, StorageMetricsSnapshot};
+pub use registry::{register_provider, registered_providers, ProviderFactory};
+pub use transfer::{transfer, transfer_with_progress, TransferControl, TransferResult};
+pub use tree::get_tree;
 pub use types::*;
 
 use std::sync::Arc;
@@ -40,21 +78,71 @@ pub enum StorageError {
     #[error("Ошибка конфигурации: {0}")]
     Config(String),
 
+    #[error("Недопустимое имя: {0}")]
+    InvalidName(String),
+
+    #[error("Некорректные данные: {0}")]
+    InvalidData(String),
+
     #[error("Провайдер не поддерживает эту операцию")]
     NotSupported,
+
+    #[error("Превышена квота хранилища ({0} байт)")]
+    QuotaExceeded(u64),
+
+    #[error("Файл изменён другим клиентом: {0}")]
+    Modified(String),
 }
 
-/// Создать провайдер хранилища из конфигурации
-pub fn create_provider(config: &StorageConfig) -> Result<Arc<dyn StorageProvider>, StorageError> {
-    match config.storage_type {
-        StorageType::Local => {
-            let provider = LocalStorageProvider::new(config)?;
-            Ok(Arc::new(provider))
-        }
-        StorageType::S3 => {
-            // TODO: Реализовать S3 провайдер
-            Err(StorageError::NotSupported)
+impl StorageError {
+    /// Стабильный идентификатор ошибки для клиентов (текст может меняться)
+    pub fn reason(&self) -> &'static str {
+        match self {
+            StorageError::NotFound(_) => "NOT_FOUND",
+            StorageError::NotADirectory(_) => "NOT_A_DIRECTORY",
+            StorageError::NotAFile(_) => "NOT_A_FILE",
+            StorageError::AlreadyExists(_) => "ALREADY_EXISTS",
+            StorageError::PermissionDenied(_) => "PERMISSION_DENIED",
+            StorageError::Io(e) if e.kind() == std::io::ErrorKind::StorageFull => "STORAGE_FULL",
+            StorageError::Io(_) => "IO_ERROR",
+            StorageError::Config(_) => "CONFIG_ERROR",
+            StorageError::InvalidName(_) => "INVALID_NAME",
+            StorageError::InvalidData(_) => "INVALID_DATA",
+            StorageError::NotSupported => "NOT_SUPPORTED",
+            StorageError::QuotaExceeded(_) => "QUOTA_EXCEEDED",
+            StorageError::Modified(_) => "FILE_MODIFIED",
         }
     }
 }
 
+/// Создать провайдер хранилища из конфигурации
+pub fn create_provider(config: &StorageConfig) -> Result<Arc<dyn StorageProvider>, StorageError> {
+    create_provider_with_key(config, None)
+}
+
+/// Создать провайдер с уже прочитанным ключом шифрования
+///
+/// При перечитывании конфигурации ключ не читается заново: файлы зашифрованы
+/// ключом, прочитанным при запуске, даже если источник с тех пор изменился.
+/// Без `key` ключ читается из `config.encryption`.
+pub fn create_provider_with_key(
+    config: &StorageConfig,
+    key: Option<&EncryptionKey>,
+) -> Result<Arc<dyn StorageProvider>, StorageError> {
+    let provider = registry::create_registered(config)?;
+
+    // Повторы - ближе всего к хранилищу: шифрование получает уже
+    // открытый поток и не повторяет расшифровку
+    let provider: Arc<dyn StorageProvider> = match &config.retry {
+        Some(retry) => Arc::new(RetryingStorageProvider::new(provider, retry.clone())),
+        None => provider,
+    };
+
+    let provider = match (&config.encryption, key) {
+        (Some(_), Some(key)) => EncryptedStorageProvider::with_key(provider, key.clone()),
+        (Some(encryption), None) => EncryptedStorageProvider::new(provider, encryption)?,
+        (None, _) => return Ok(provider),
+    };
+    Ok(Arc::new(provider))
+}
+
@@ -0,0 +1,44 @@
+//! Права доступа unix для создаваемых файлов и директорий
+//!
+//! Без заданных прав действует umask процесса. Заданные права ставятся
+//! после создания через `set_permissions` и umask не учитывают: так можно
+//! создать, например, групповую директорию `2775` для совместной работы.
+//! На Windows права unix не применяются и игнорируются.
+
+use std::io;
+use std::path::Path;
+
+use super::StorageError;
+
+/// Наибольшее значение прав: setuid/setgid/sticky и rwx для всех
+const MAX_MODE: u32 = 0o7777;
+
+/// Проверить права до создания, чтобы не оставлять созданное при ошибке
+pub fn check_mode(mode: Option<u32>) -> Result<(), StorageError> {
+    match mode {
+        Some(mode) if mode > MAX_MODE => Err(StorageError::InvalidData(format!(
+            "недопустимые права доступа {:o}",
+            mode
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Установить права созданного файла или директории (`None` - оставить по umask)
+pub async fn set_mode(path: &Path, mode: Option<u32>) -> io::Result<()> {
+    let Some(mode) = mode else {
+        return Ok(());
+    };
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        tokio::fs::set_permissions(path, std::fs::Permissions::from_mode(mode)).await
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = (path, mode);
+        Ok(())
+    }
+}
@@ -0,0 +1,282 @@
+//! Преобразования путей локальной файловой системы
+//!
+//! На Windows пути длиннее `MAX_PATH` (260 символов), в том числе длинные
+//! UNC-пути (`\\server\share\...`), работают только с префиксом `\\?\`
+//! (`\\?\UNC\` для сетевых ресурсов). Префикс добавляется для файловых
+//! операций и убирается, когда путь возвращается клиенту.
+//!
+//! На остальных платформах функции путь не меняют.
+//!
+//! Пути от клиентов локальный провайдер получает только через
+//! `normalize_path` (чтение) и `target_path` (изменение).
+
+use std::path::{Path, PathBuf};
+
+use super::StorageError;
+
+/// Путь для файловых операций
+#[cfg(windows)]
+pub fn to_fs_path(path: &Path) -> PathBuf {
+    use std::path::{Component, Prefix};
+
+    const MAX_PATH: usize = 260;
+
+    if path.as_os_str().len() < MAX_PATH {
+        return path.to_path_buf();
+    }
+
+    let mut components = path.components();
+    let prefix = match components.next() {
+        Some(Component::Prefix(prefix)) => prefix,
+        // Относительный путь - префикс к нему не применим
+        _ => return path.to_path_buf(),
+    };
+
+    let base = match prefix.kind() {
+        Prefix::Disk(letter) => format!(r"\\?\{}:\", letter as char),
+        Prefix::UNC(server, share) => format!(
+            r"\\?\UNC\{}\{}\",
+            server.to_string_lossy(),
+            share.to_string_lossy()
+        ),
+        // Уже расширенный путь (`\\?\...`) или путь к устройству
+        _ => return path.to_path_buf(),
+    };
+
+    // Система не нормализует пути с `\\?\`, поэтому `.` и `..` убираем сами
+    let mut normalized = PathBuf::from(base);
+    for component in components {
+        match component {
+            Component::Normal(part) => normalized.push(part),
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            Component::CurDir | Component::RootDir | Component::Prefix(_) => {}
+        }
+    }
+
+    normalized
+}
+
+/// Путь для файловых операций
+#[cfg(not(windows))]
+pub fn to_fs_path(path: &Path) -> PathBuf {
+    path.to_path_buf()
+}
+
+/// Путь клиента для файловых операций
+///
+/// Повторные и завершающие разделители и компоненты `.` убираются, `..`
+/// остаётся файловой системе (лексически его не разрешить из-за ссылок).
+/// Пустой путь остаётся пустым.
+pub fn normalize_path(path: &str) -> PathBuf {
+    let path: PathBuf = Path::new(path).components().collect();
+    to_fs_path(&path)
+}
+
+/// Путь для изменяющей операции
+///
+/// Для чтения пустой путь означает домашнюю директорию, но удалить или
+/// перезаписать её по пустой строке не должно быть возможно.
+///
+/// Если `roots` не пуст, путь должен лежать внутри одного из них. Проверка
+/// лексическая, поэтому `..` в этом случае запрещён: с ним путь, начинающийся
+/// с корня, мог бы из него выйти. Ссылки внутри корней не проверяются.
+pub fn target_path(path: &str, roots: &[PathBuf]) -> Result<PathBuf, StorageError> {
+    use std::path::Component;
+
+    if path.trim().is_empty() {
+        return Err(StorageError::InvalidName(path.to_string()));
+    }
+
+    let lexical: PathBuf = Path::new(path).components().collect();
+    if !roots.is_empty() {
+        let contained = !lexical.components().any(|c| c == Component::ParentDir)
+            && roots.iter().any(|root| lexical.starts_with(root));
+        if !contained {
+            return Err(StorageError::PermissionDenied(path.to_string()));
+        }
+    }
+    Ok(to_fs_path(&lexical))
+}
+
+/// Указывают ли два пути клиента на один файл
+///
+/// Сравниваются пути после `target_path`, поэтому `a//b` и `a/./b/`
+/// совпадают; ссылки и `..` не разрешаются.
+pub fn same_path(a: &str, b: &str) -> Result<bool, StorageError> {
+    Ok(target_path(a, &[])? == target_path(b, &[])?)
+}
+
+/// Путь в виде, пригодном для показа клиенту (без префикса `\\?\`)
+pub fn display_path(path: &Path) -> String {
+    let raw = path.to_string_lossy();
+
+    #[cfg(windows)]
+    {
+        if let Some(rest) = raw.strip_prefix(r"\\?\UNC\") {
+            return format!(r"\\{}", rest);
+        }
+        if let Some(rest) = raw.strip_prefix(r"\\?\") {
+            return rest.to_string();
+        }
+    }
+
+    raw.to_string()
+}
+
+/// Корень сетевого ресурса (`\\server\share\`), если путь расположен на нём
+#[cfg(windows)]
+pub fn unc_root(path: &Path) -> Option<String> {
+    use std::path::{Component, Prefix};
+
+    match path.components().next() {
+        Some(Component::Prefix(prefix)) => match prefix.kind() {
+            Prefix::UNC(server, share) | Prefix::VerbatimUNC(server, share) => Some(format!(
+                r"\\{}\{}\",
+                server.to_string_lossy(),
+                share.to_string_lossy()
+            )),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Проверить, что `name` - одно имя файла или папки
+///
+/// Имя с разделителями, `.` или `..` при соединении с директорией выводит
+/// путь за её пределы. `\` отклоняется на всех платформах: имя, пришедшее
+/// от клиента на Windows, на другой платформе стало бы путём.
+pub fn check_file_name(name: &str) -> Result<(), StorageError> {
+    use std::path::Component;
+
+    let mut components = Path::new(name).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(_)), None) if !name.contains(['/', '\\', '\0']) => Ok(()),
+        _ => Err(StorageError::InvalidName(name.to_string())),
+    }
+}
+
+/// Путь к файлу `filename` в директории `directory`
+///
+/// Разделители приводятся к принятым на платформе, повторные и завершающие
+/// разделители директории убираются.
+pub fn join_file_path(directory: &str, filename: &str) -> Result<String, StorageError> {
+    check_file_name(filename)?;
+
+    let path: PathBuf = Path::new(directory).components().collect();
+    Ok(display_path(&path.join(filename)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_path_drops_empty_segments_and_dots() {
+        assert_eq!(normalize_path("/media//clips/./day1/"), PathBuf::from("/media/clips/day1"));
+        assert_eq!(normalize_path("media/clips"), PathBuf::from("media/clips"));
+        assert_eq!(normalize_path(""), PathBuf::new());
+        // `..` остаётся файловой системе
+        assert_eq!(normalize_path("/media/../etc"), PathBuf::from("/media/../etc"));
+    }
+
+    #[test]
+    fn target_path_rejects_empty_path() {
+        for path in ["", "   "] {
+            assert!(matches!(target_path(path, &[]), Err(StorageError::InvalidName(_))));
+        }
+        assert_eq!(target_path("/media//a.mp4", &[]).unwrap(), PathBuf::from("/media/a.mp4"));
+    }
+
+    #[test]
+    fn target_path_stays_within_roots() {
+        let roots = [PathBuf::from("/srv/media"), PathBuf::from("/mnt/nas")];
+
+        for path in ["/srv/media", "/srv/media/", "/srv/media//clips/a.mp4", "/mnt/nas/./b.mp4"] {
+            assert!(target_path(path, &roots).is_ok(), "путь {}", path);
+        }
+        for path in [
+            "/etc/passwd",
+            "/srv/media-other/a.mp4",
+            "/srv",
+            "/srv/media/../../etc/passwd",
+            "/srv/media/clips/..",
+            "srv/media/a.mp4",
+        ] {
+            assert!(
+                matches!(target_path(path, &roots), Err(StorageError::PermissionDenied(_))),
+                "путь {}",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn same_path_ignores_empty_segments() {
+        assert!(same_path("/media//a.mp4", "/media/./a.mp4").unwrap());
+        assert!(!same_path("/media/a.mp4", "/media/b.mp4").unwrap());
+        assert!(same_path("", "/media").is_err());
+    }
+
+    #[test]
+    fn check_file_name_accepts_single_name() {
+        for name in ["a.mp4", "..hidden", "...", "имя файла.txt"] {
+            assert!(check_file_name(name).is_ok(), "имя {:?}", name);
+        }
+        for name in ["", ".", "..", "a/b", "a\\b", "/etc", "../a", "a\0b", "clips/"] {
+            assert!(
+                matches!(check_file_name(name), Err(StorageError::InvalidName(_))),
+                "имя {:?}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn join_file_path_checks_name() {
+        assert_eq!(
+            join_file_path("/media//clips/", "a.mp4").unwrap(),
+            display_path(Path::new("/media/clips/a.mp4"))
+        );
+        assert!(join_file_path("/media", "../a.mp4").is_err());
+        assert!(join_file_path("/media", "/etc/passwd").is_err());
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn long_paths_get_verbatim_prefix() {
+        let name = "a".repeat(300);
+
+        let disk = format!(r"C:\media\..\clips\{}", name);
+        let fs_path = to_fs_path(Path::new(&disk));
+        assert_eq!(fs_path, PathBuf::from(format!(r"\\?\C:\clips\{}", name)));
+        assert_eq!(display_path(&fs_path), format!(r"C:\clips\{}", name));
+
+        let unc = format!(r"\\server\share\{}", name);
+        let fs_path = to_fs_path(Path::new(&unc));
+        assert_eq!(fs_path, PathBuf::from(format!(r"\\?\UNC\server\share\{}", name)));
+        assert_eq!(display_path(&fs_path), unc);
+
+        // Короткие и уже расширенные пути не меняются
+        assert_eq!(to_fs_path(Path::new(r"C:\media")), PathBuf::from(r"C:\media"));
+        let verbatim = format!(r"\\?\C:\{}", name);
+        assert_eq!(to_fs_path(Path::new(&verbatim)), PathBuf::from(&verbatim));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn unc_root_of_network_paths() {
+        assert_eq!(
+            unc_root(Path::new(r"\\server\share\clips")).as_deref(),
+            Some(r"\\server\share\")
+        );
+        assert_eq!(
+            unc_root(Path::new(r"\\?\UNC\server\share\clips")).as_deref(),
+            Some(r"\\server\share\")
+        );
+        assert_eq!(unc_root(Path::new(r"C:\clips")), None);
+        assert_eq!(unc_root(Path::new(r"\\?\C:\clips")), None);
+    }
+}
@@ -2,10 +2,20 @@
 
 use async_trait::async_trait;
 use bytes::Bytes;
-use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite};
 use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_stream::Stream;
 
-use super::{StorageError, StorageInfo, StorageEntry, DirectoryListing, UploadResult, ProjectStructure};
+use super::{
+    StorageError, StorageInfo, StorageEntry, DirectoryListing, UploadResult, ProjectStructure,
+    StorageCapabilities, ImageMetadata, DuplicateGroup, DirectoryStats, DiskSpace, DeleteProgress,
+    DeletePreview,
+};
+
+/// Поток найденных групп дубликатов
+pub type DuplicateStream = Pin<Box<dyn Stream<Item = Result<DuplicateGroup, StorageError>> + Send>>;
 
 /// Абстракция провайдера хранилища
 /// 
@@ -26,9 +36,24 @@ use super::{StorageError, StorageInfo, StorageEntry, DirectoryListing, UploadRes
 pub trait StorageProvider: Send + Sync {
     // === Информация ===
 
+    /// Уникальный идентификатор хранилища (совпадает с `StorageInfo::id`)
+    fn id(&self) -> &str;
+
     /// Получить информацию о хранилище
     async fn get_info(&self) -> Result<StorageInfo, StorageError>;
 
+    /// Место на томе, где расположен путь
+    ///
+    /// Путь может ещё не существовать (например, будущая директория проекта).
+    async fn get_disk_space(&self, _path: &str) -> Result<DiskSpace, StorageError> {
+        Err(StorageError::NotSupported)
+    }
+
+    /// Возможности провайдера (по умолчанию - ничего сверх базовых операций)
+    fn capabilities(&self) -> StorageCapabilities {
+        StorageCapabilities::default()
+    }
+
     // === Навигация ===
 
     /// Получить содержимое директории/бакета
@@ -42,13 +67,33 @@ pub trait StorageProvider: Send + Sync {
     /// Получить информацию о файле/директории
     async fn get_entry_info(&self, path: &str) -> Result<StorageEntry, StorageError>;
 
+    /// Информация о самом элементе, без перехода по символической ссылке
+    ///
+    /// Для ссылки заполняется `symlink_target`. По умолчанию - то же, что
+    /// `get_entry_info` (провайдер без ссылок).
+    async fn get_link_info(&self, path: &str) -> Result<StorageEntry, StorageError> {
+        self.get_entry_info(path).await
+    }
+
     // === Операции с директориями ===
 
     /// Создать директорию
     /// 
     /// * `path` - путь к директории
     /// * `recursive` - создавать родительские директории
-    async fn create_directory(&self, path: &str, recursive: bool) -> Result<String, StorageError>;
+    /// * `mode` - права unix созданной директории (родительские создаются по
+    ///   umask); `None` - по umask
+    async fn create_directory(
+        &self,
+        path: &str,
+        recursive: bool,
+        mode: Option<u32>,
+    ) -> Result<String, StorageError>;
+
+    /// Подсчитать файлы, поддиректории и объём директории рекурсивно
+    async fn get_directory_stats(&self, _path: &str) -> Result<DirectoryStats, StorageError> {
+        Err(StorageError::NotSupported)
+    }
 
     /// Удалить директорию
     /// 
@@ -56,8 +101,45 @@ pub trait StorageProvider: Send + Sync {
     /// * `recursive` - удалять содержимое
     async fn delete_directory(&self, path: &str, recursive: bool) -> Result<(), StorageError>;
 
+    /// Что удалило бы удаление пути, ничего не удаляя
+    ///
+    /// * `recursive` - для директории: вместе с содержимым (иначе она должна быть пустой)
+    /// * `limit` - сколько путей перечислить (объём считается по всем)
+    async fn preview_delete(
+        &self,
+        _path: &str,
+        _recursive: bool,
+        _limit: usize,
+    ) -> Result<DeletePreview, StorageError> {
+        Err(StorageError::NotSupported)
+    }
+
+    /// Удалить директорию со всем содержимым, удаляя файлы параллельно
+    ///
+    /// * `concurrency` - сколько файлов удаляется одновременно
+    /// * `progress` - счётчики, которые можно опрашивать во время удаления
+    ///
+    /// Сброс future прерывает удаление.
+    async fn delete_tree(
+        &self,
+        _path: &str,
+        _concurrency: usize,
+        _progress: Arc<DeleteProgress>,
+    ) -> Result<(), StorageError> {
+        Err(StorageError::NotSupported)
+    }
+
     // === Операции с файлами ===
 
+    /// Создать пустой файл
+    ///
+    /// * `path` - путь к файлу
+    /// * `overwrite` - обнулить файл, если он уже существует
+    async fn create_file(&self, path: &str, overwrite: bool) -> Result<String, StorageError> {
+        let result = self.upload_bytes(path, Bytes::new(), overwrite).await?;
+        Ok(result.path)
+    }
+
     /// Удалить файл
     async fn delete_file(&self, path: &str) -> Result<(), StorageError>;
 
@@ -76,17 +158,55 @@ pub trait StorageProvider: Send + Sync {
     /// Скачать файл (для небольших файлов)
     async fn download_bytes(&self, path: &str) -> Result<Bytes, StorageError>;
 
+    /// Прочитать EXIF-метаданные изображения
+    ///
+    /// По умолчанию файл скачивается целиком; провайдеры с произвольным
+    /// доступом к файлу могут читать только нужные части.
+    async fn get_image_metadata(&self, path: &str) -> Result<ImageMetadata, StorageError> {
+        let data = self.download_bytes(path).await?;
+        super::read_image_metadata(&mut std::io::Cursor::new(data))
+    }
+
     /// Получить поток для чтения файла (для больших файлов)
     async fn get_read_stream(
         &self,
         path: &str,
     ) -> Result<Pin<Box<dyn AsyncRead + Send>>, StorageError>;
 
+    /// Получить поток для чтения файла с позиции `offset`
+    ///
+    /// По умолчанию данные до `offset` читаются и отбрасываются; провайдеры,
+    /// которые умеют начинать чтение с середины файла, переопределяют метод.
+    async fn get_read_stream_at(
+        &self,
+        path: &str,
+        offset: u64,
+    ) -> Result<Pin<Box<dyn AsyncRead + Send>>, StorageError> {
+        let mut stream = self.get_read_stream(path).await?;
+        tokio::io::copy(&mut (&mut stream).take(offset), &mut tokio::io::sink()).await?;
+        Ok(stream)
+    }
+
+    /// Ссылка на скачивание файла напрямую из хранилища, минуя шлюз
+    ///
+    /// Ссылка действует `expires_in`. По умолчанию - `NotSupported`: так
+    /// клиент узнаёт, что файл нужно скачивать через `download_file`.
+    async fn get_download_url(
+        &self,
+        _path: &str,
+        _expires_in: Duration,
+    ) -> Result<String, StorageError> {
+        Err(StorageError::NotSupported)
+    }
+
     /// Получить поток для записи файла (для больших файлов)
+    ///
+    /// `mode` - права unix записанного файла; `None` - по umask
     async fn get_write_stream(
         &self,
         path: &str,
         overwrite: bool,
+        mode: Option<u32>,
     ) -> Result<Pin<Box<dyn AsyncWrite + Send>>, StorageError>;
 
     // === Проекты ===
@@ -95,24 +215,51 @@ pub trait StorageProvider: Send + Sync {
     /// 
     /// * `base_path` - базовая директория
     /// * `project_name` - название проекта
+    /// * `merge` - директория проекта может уже существовать: создаются только
+    ///   недостающие папки, существующее содержимое не удаляется и не меняется
     async fn init_project_structure(
         &self,
         base_path: &str,
         project_name: &str,
+        merge: bool,
     ) -> Result<ProjectStructure, StorageError>;
 
     // === Утилиты ===
 
+    /// Найти файлы с одинаковым содержимым
+    ///
+    /// Группы (две и более копии) отдаются по мере нахождения.
+    async fn find_duplicates(&self, _root: &str) -> Result<DuplicateStream, StorageError> {
+        Err(StorageError::NotSupported)
+    }
+
     /// Копировать файл
-    async fn copy(&self, source: &str, destination: &str) -> Result<(), StorageError> {
+    ///
+    /// * `overwrite` - заменить файл назначения, если он существует
+    async fn copy(
+        &self,
+        source: &str,
+        destination: &str,
+        overwrite: bool,
+    ) -> Result<(), StorageError> {
         let data = self.download_bytes(source).await?;
-        self.upload_bytes(destination, data, false).await?;
+        self.upload_bytes(destination, data, overwrite).await?;
         Ok(())
     }
 
     /// Переместить файл
-    async fn rename(&self, source: &str, destination: &str) -> Result<(), StorageError> {
-        self.copy(source, destination).await?;
+    ///
+    /// По умолчанию файл копируется и удаляется; провайдеры, умеющие
+    /// переименовывать на месте (в том числе директории), переопределяют метод.
+    ///
+    /// * `overwrite` - заменить файл назначения, если он существует
+    async fn rename(
+        &self,
+        source: &str,
+        destination: &str,
+        overwrite: bool,
+    ) -> Result<(), StorageError> {
+        self.copy(source, destination, overwrite).await?;
         self.delete_file(source).await?;
         Ok(())
     }
@@ -0,0 +1,184 @@
+//! Квота на объём данных провайдера
+//!
+//! Занятый объём подсчитывается при запуске обходом корня квоты и дальше
+//! поддерживается инкрементально при записи и удалении через FileGateway.
+//! Изменения в обход сервиса (например, ручное копирование файлов)
+//! учитываются только после перезапуска.
+
+use std::io;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, Weak};
+use std::task::{Context, Poll};
+
+use tokio::io::AsyncWrite;
+
+use super::walk::directory_stats;
+use super::StorageError;
+
+/// Квоты действующих провайдеров по корню
+static QUOTAS: LazyLock<Mutex<HashMap<PathBuf, Weak<Quota>>>> = LazyLock::new(Default::default);
+
+/// Ограничение объёма данных в директории
+pub struct Quota {
+    limit: u64,
+    used: AtomicU64,
+    root: PathBuf,
+}
+
+impl Quota {
+    /// Создать квоту и подсчитать текущее использование (блокирующая операция)
+    pub fn new(limit: u64, root: PathBuf) -> Self {
+        let used = directory_size(&root);
+
+        Self {
+            limit,
+            used: AtomicU64::new(used),
+            root,
+        }
+    }
+
+    /// Квота на `root`, общая для всех провайдеров с этим корнем и лимитом
+    ///
+    /// Провайдер, пересозданный при перечитывании конфигурации, получает квоту
+    /// прежнего: объём не подсчитывается заново, а записи, начатые через
+    /// прежний провайдер, учитываются в том же счётчике. Новая квота
+    /// подсчитывается, только если действующей нет (блокирующая операция).
+    pub fn shared(limit: u64, root: PathBuf) -> Arc<Self> {
+        let mut quotas = QUOTAS.lock().unwrap_or_else(|e| e.into_inner());
+        quotas.retain(|_, quota| quota.strong_count() > 0);
+
+        if let Some(quota) = quotas.get(&root).and_then(Weak::upgrade) {
+            if quota.limit == limit {
+                return quota;
+            }
+        }
+
+        let quota = Arc::new(Self::new(limit, root.clone()));
+        quotas.insert(root, Arc::downgrade(&quota));
+        quota
+    }
+
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    pub fn used(&self) -> u64 {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    /// Учитывается ли путь квотой
+    pub fn covers(&self, path: &Path) -> bool {
+        path.starts_with(&self.root)
+    }
+
+    /// Зарезервировать место перед записью
+    pub fn reserve(&self, bytes: u64) -> Result<(), StorageError> {
+        self.used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                used.checked_add(bytes).filter(|total| *total <= self.limit)
+            })
+            .map(|_| ())
+            .map_err(|_| StorageError::QuotaExceeded(self.limit))
+    }
+
+    /// Освободить место (удаление, перезапись, неудавшаяся запись)
+    pub fn release(&self, bytes: u64) {
+        let _ = self
+            .used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                Some(used.saturating_sub(bytes))
+            });
+    }
+
+    /// Проверить, что квота ещё не исчерпана
+    pub fn ensure_available(&self) -> Result<(), StorageError> {
+        if self.used() >= self.limit {
+            return Err(StorageError::QuotaExceeded(self.limit));
+        }
+        Ok(())
+    }
+}
+
+/// Суммарный размер файлов в директории (блокирующая операция)
+pub fn directory_size(path: &Path) -> u64 {
+    directory_stats(path, &AtomicBool::new(false))
+        .map(|stats| stats.total_size)
+        .unwrap_or(0)
+}
+
+/// Поток записи, учитывающий записанные байты в квоте
+///
+/// При превышении квоты запись завершается ошибкой `StorageFull`.
+pub struct QuotaWriter {
+    inner: Pin<Box<dyn AsyncWrite + Send>>,
+    quota: Arc<Quota>,
+}
+
+impl QuotaWriter {
+    pub fn new(inner: Pin<Box<dyn AsyncWrite + Send>>, quota: Arc<Quota>) -> Self {
+        Self { inner, quota }
+    }
+}
+
+impl AsyncWrite for QuotaWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let requested = buf.len() as u64;
+
+        if let Err(e) = this.quota.reserve(requested) {
+            return Poll::Ready(Err(io::Error::new(io::ErrorKind::StorageFull, e.to_string())));
+        }
+
+        let result = this.inner.as_mut().poll_write(cx, buf);
+
+        // Резервируем весь буфер, лишнее возвращаем по факту записи
+        match &result {
+            Poll::Ready(Ok(written)) => this.quota.release(requested - *written as u64),
+            _ => this.quota.release(requested),
+        }
+
+        result
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().inner.as_mut().poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().inner.as_mut().poll_shutdown(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shared_quota_is_reused_while_in_use() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.bin"), [0u8; 5]).unwrap();
+
+        let quota = Quota::shared(100, dir.path().to_path_buf());
+        quota.reserve(10).unwrap();
+        // Файл в обход квоты не пересчитывается, пока квота используется
+        std::fs::write(dir.path().join("b.bin"), [0u8; 7]).unwrap();
+
+        let same = Quota::shared(100, dir.path().to_path_buf());
+        assert!(Arc::ptr_eq(&quota, &same));
+        assert_eq!(same.used(), 15);
+
+        let other_limit = Quota::shared(200, dir.path().to_path_buf());
+        assert!(!Arc::ptr_eq(&quota, &other_limit));
+        assert_eq!(other_limit.used(), 12);
+
+        drop((quota, same, other_limit));
+        assert_eq!(Quota::shared(100, dir.path().to_path_buf()).used(), 12);
+    }
+}
@@ -0,0 +1,108 @@
+//! Реестр провайдеров хранилища
+//!
+//! `create_provider` выбирает провайдер по имени типа из конфигурации
+//! (`storage_type`). Встроенные `local`, `s3` и `memory` зарегистрированы всегда;
+//! сторонний провайдер (IPFS, MAM студии и т.п.) регистрируется до создания
+//! хранилища через `register_provider` и читает свои настройки из
+//! `provider_options`. Обёртки (повторы, шифрование, метрики) применяются
+//! к любому провайдеру одинаково.
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, RwLock};
+
+use super::{
+    config::{StorageConfig, StorageType},
+    local::LocalStorageProvider,
+    memory::MemoryStorageProvider,
+    provider::StorageProvider,
+    s3::S3StorageProvider,
+    StorageError,
+};
+
+/// Фабрика провайдера: создаёт провайдер по конфигурации
+pub type ProviderFactory =
+    Arc<dyn Fn(&StorageConfig) -> Result<Arc<dyn StorageProvider>, StorageError> + Send + Sync>;
+
+static REGISTRY: LazyLock<RwLock<HashMap<String, ProviderFactory>>> = LazyLock::new(|| {
+    let mut factories: HashMap<String, ProviderFactory> = HashMap::new();
+    factories.insert(StorageType::LOCAL.to_string(), Arc::new(create_local));
+    factories.insert(StorageType::S3.to_string(), Arc::new(create_s3));
+    factories.insert(StorageType::MEMORY.to_string(), Arc::new(create_memory));
+    RwLock::new(factories)
+});
+
+/// Зарегистрировать провайдер под именем типа; возвращает заменённую фабрику
+///
+/// Встроенный тип тоже можно заменить, например, своей реализацией S3.
+pub fn register_provider(
+    storage_type: impl Into<String>,
+    factory: ProviderFactory,
+) -> Option<ProviderFactory> {
+    REGISTRY
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(storage_type.into(), factory)
+}
+
+/// Имена зарегистрированных типов хранилища (по алфавиту)
+pub fn registered_providers() -> Vec<String> {
+    let mut names: Vec<String> = REGISTRY
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .keys()
+        .cloned()
+        .collect();
+    names.sort();
+    names
+}
+
+/// Создать провайдер зарегистрированной фабрикой типа из конфигурации
+pub(super) fn create_registered(
+    config: &StorageConfig,
+) -> Result<Arc<dyn StorageProvider>, StorageError> {
+    // Фабрика вызывается без блокировки: она может сама обращаться к реестру
+    let factory = REGISTRY
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(config.storage_type.as_str())
+        .cloned();
+
+    match factory {
+        Some(factory) => factory(config),
+        None => Err(StorageError::Config(format!(
+            "неизвестный тип хранилища \"{}\", доступны: {}",
+            config.storage_type,
+            registered_providers().join(", ")
+        ))),
+    }
+}
+
+fn create_local(config: &StorageConfig) -> Result<Arc<dyn StorageProvider>, StorageError> {
+    Ok(Arc::new(LocalStorageProvider::new(config)?))
+}
+
+fn create_memory(config: &StorageConfig) -> Result<Arc<dyn StorageProvider>, StorageError> {
+    Ok(Arc::new(MemoryStorageProvider::new(config)))
+}
+
+fn create_s3(config: &StorageConfig) -> Result<Arc<dyn StorageProvider>, StorageError> {
+    // Сначала сообщаем обо всех незаполненных полях сразу
+    let missing: Vec<&str> = [
+        ("s3_endpoint", &config.s3_endpoint),
+        ("s3_bucket", &config.s3_bucket),
+        ("s3_access_key", &config.s3_access_key),
+        ("s3_secret_key", &config.s3_secret_key),
+    ]
+    .into_iter()
+    .filter(|(_, value)| value.as_deref().is_none_or(str::is_empty))
+    .map(|(name, _)| name)
+    .collect();
+    if !missing.is_empty() {
+        return Err(StorageError::Config(format!(
+            "для хранилища S3 не заданы: {}",
+            missing.join(", ")
+        )));
+    }
+
+    Ok(Arc::new(S3StorageProvider::new(config)?))
+}
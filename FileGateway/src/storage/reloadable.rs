@@ -0,0 +1,214 @@
+//! Провайдер, заменяемый без перезапуска
+//!
+//! `ReloadableStorageProvider` передаёт вызовы текущему провайдеру, который
+//! можно заменить новым, собранным по перечитанной конфигурации (SIGHUP).
+//! Начатые операции и открытые потоки продолжают работать со старым
+//! провайдером до завершения, новые вызовы идут в новый. ID хранилища
+//! при замене не меняется.
+
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use tokio::io::{AsyncRead, AsyncWrite};
+
+use super::{
+    provider::{DuplicateStream, StorageProvider},
+    DeleteProgress,
+    types::*,
+    StorageError,
+};
+
+/// Провайдер, передающий вызовы заменяемому вложенному провайдеру
+pub struct ReloadableStorageProvider {
+    id: String,
+    current: RwLock<Arc<dyn StorageProvider>>,
+}
+
+impl ReloadableStorageProvider {
+    pub fn new(inner: Arc<dyn StorageProvider>) -> Self {
+        Self {
+            id: inner.id().to_string(),
+            current: RwLock::new(inner),
+        }
+    }
+
+    /// Текущий провайдер
+    pub fn current(&self) -> Arc<dyn StorageProvider> {
+        self.current.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Заменить провайдер; провайдер с другим ID отклоняется
+    pub fn replace(&self, provider: Arc<dyn StorageProvider>) -> Result<(), StorageError> {
+        if provider.id() != self.id {
+            return Err(StorageError::Config(format!(
+                "ID хранилища изменён ({} -> {}), нужен перезапуск",
+                self.id,
+                provider.id()
+            )));
+        }
+
+        *self.current.write().unwrap_or_else(|e| e.into_inner()) = provider;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl StorageProvider for ReloadableStorageProvider {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    async fn get_info(&self) -> Result<StorageInfo, StorageError> {
+        self.current().get_info().await
+    }
+
+    async fn get_disk_space(&self, path: &str) -> Result<DiskSpace, StorageError> {
+        self.current().get_disk_space(path).await
+    }
+
+    fn capabilities(&self) -> StorageCapabilities {
+        self.current().capabilities()
+    }
+
+    async fn list_directory(&self, path: &str) -> Result<DirectoryListing, StorageError> {
+        self.current().list_directory(path).await
+    }
+
+    async fn exists(&self, path: &str) -> Result<bool, StorageError> {
+        self.current().exists(path).await
+    }
+
+    async fn get_entry_info(&self, path: &str) -> Result<StorageEntry, StorageError> {
+        self.current().get_entry_info(path).await
+    }
+
+    async fn get_link_info(&self, path: &str) -> Result<StorageEntry, StorageError> {
+        self.current().get_link_info(path).await
+    }
+
+    async fn create_directory(
+        &self,
+        path: &str,
+        recursive: bool,
+        mode: Option<u32>,
+    ) -> Result<String, StorageError> {
+        self.current().create_directory(path, recursive, mode).await
+    }
+
+    async fn get_directory_stats(&self, path: &str) -> Result<DirectoryStats, StorageError> {
+        self.current().get_directory_stats(path).await
+    }
+
+    async fn delete_directory(&self, path: &str, recursive: bool) -> Result<(), StorageError> {
+        self.current().delete_directory(path, recursive).await
+    }
+
+    async fn preview_delete(
+        &self,
+        path: &str,
+        recursive: bool,
+        limit: usize,
+    ) -> Result<DeletePreview, StorageError> {
+        self.current().preview_delete(path, recursive, limit).await
+    }
+
+    async fn delete_tree(
+        &self,
+        path: &str,
+        concurrency: usize,
+        progress: Arc<DeleteProgress>,
+    ) -> Result<(), StorageError> {
+        self.current().delete_tree(path, concurrency, progress).await
+    }
+
+    async fn create_file(&self, path: &str, overwrite: bool) -> Result<String, StorageError> {
+        self.current().create_file(path, overwrite).await
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+        self.current().delete_file(path).await
+    }
+
+    async fn upload_bytes(
+        &self,
+        destination: &str,
+        data: Bytes,
+        overwrite: bool,
+    ) -> Result<UploadResult, StorageError> {
+        self.current().upload_bytes(destination, data, overwrite).await
+    }
+
+    async fn download_bytes(&self, path: &str) -> Result<Bytes, StorageError> {
+        self.current().download_bytes(path).await
+    }
+
+    async fn get_image_metadata(&self, path: &str) -> Result<ImageMetadata, StorageError> {
+        self.current().get_image_metadata(path).await
+    }
+
+    async fn get_read_stream(
+        &self,
+        path: &str,
+    ) -> Result<Pin<Box<dyn AsyncRead + Send>>, StorageError> {
+        self.current().get_read_stream(path).await
+    }
+
+    async fn get_read_stream_at(
+        &self,
+        path: &str,
+        offset: u64,
+    ) -> Result<Pin<Box<dyn AsyncRead + Send>>, StorageError> {
+        self.current().get_read_stream_at(path, offset).await
+    }
+
+    async fn get_download_url(
+        &self,
+        path: &str,
+        expires_in: Duration,
+    ) -> Result<String, StorageError> {
+        self.current().get_download_url(path, expires_in).await
+    }
+
+    async fn get_write_stream(
+        &self,
+        path: &str,
+        overwrite: bool,
+        mode: Option<u32>,
+    ) -> Result<Pin<Box<dyn AsyncWrite + Send>>, StorageError> {
+        self.current().get_write_stream(path, overwrite, mode).await
+    }
+
+    async fn init_project_structure(
+        &self,
+        base_path: &str,
+        project_name: &str,
+        merge: bool,
+    ) -> Result<ProjectStructure, StorageError> {
+        self.current().init_project_structure(base_path, project_name, merge).await
+    }
+
+    async fn find_duplicates(&self, root: &str) -> Result<DuplicateStream, StorageError> {
+        self.current().find_duplicates(root).await
+    }
+
+    async fn copy(
+        &self,
+        source: &str,
+        destination: &str,
+        overwrite: bool,
+    ) -> Result<(), StorageError> {
+        self.current().copy(source, destination, overwrite).await
+    }
+
+    async fn rename(
+        &self,
+        source: &str,
+        destination: &str,
+        overwrite: bool,
+    ) -> Result<(), StorageError> {
+        self.current().rename(source, destination, overwrite).await
+    }
+}
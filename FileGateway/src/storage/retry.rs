@@ -0,0 +1,255 @@
+//! Повтор операций хранилища при временных ошибках
+//!
+//! `RetryingStorageProvider` оборачивает провайдер и повторяет с растущей
+//! паузой только идемпотентные операции: чтение сведений, листинг и открытие
+//! потоков чтения. Изменяющие операции и запись выполняются один раз:
+//! после обрыва нельзя узнать, успела ли операция выполниться, и повтор
+//! мог бы, например, удалить только что созданный другим клиентом файл.
+//! Уже открытый поток чтения при обрыве не переоткрывается.
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tracing::warn;
+
+use super::{
+    config::RetryConfig,
+    provider::{DuplicateStream, StorageProvider},
+    DeleteProgress,
+    types::*,
+    StorageError,
+};
+
+/// Провайдер, повторяющий идемпотентные операции вложенного провайдера
+pub struct RetryingStorageProvider {
+    inner: Arc<dyn StorageProvider>,
+    config: RetryConfig,
+}
+
+impl RetryingStorageProvider {
+    pub fn new(inner: Arc<dyn StorageProvider>, config: RetryConfig) -> Self {
+        Self { inner, config }
+    }
+
+    /// Выполнить операцию, повторяя её при временных ошибках
+    async fn retry<T, F, Fut>(
+        &self,
+        operation: &'static str,
+        mut call: F,
+    ) -> Result<T, StorageError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, StorageError>>,
+    {
+        let mut backoff = Duration::from_millis(self.config.initial_backoff_ms);
+        let max_backoff = Duration::from_millis(self.config.max_backoff_ms);
+        let mut attempt = 0;
+
+        loop {
+            match call().await {
+                Err(e) if attempt < self.config.attempts && is_transient(&e) => {
+                    attempt += 1;
+                    warn!(
+                        "Временная ошибка {} ({}), повтор {} из {} через {} мс",
+                        operation,
+                        e,
+                        attempt,
+                        self.config.attempts,
+                        backoff.as_millis()
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(max_backoff);
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Может ли ошибка пройти при повторе (обрыв или таймаут сети)
+fn is_transient(error: &StorageError) -> bool {
+    let StorageError::Io(e) = error else {
+        return false;
+    };
+
+    matches!(
+        e.kind(),
+        io::ErrorKind::TimedOut
+            | io::ErrorKind::Interrupted
+            | io::ErrorKind::WouldBlock
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::ConnectionRefused
+            | io::ErrorKind::NotConnected
+            | io::ErrorKind::BrokenPipe
+    )
+}
+
+#[async_trait]
+impl StorageProvider for RetryingStorageProvider {
+    fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    async fn get_info(&self) -> Result<StorageInfo, StorageError> {
+        self.retry("get_info", || self.inner.get_info()).await
+    }
+
+    async fn get_disk_space(&self, path: &str) -> Result<DiskSpace, StorageError> {
+        self.retry("get_disk_space", || self.inner.get_disk_space(path)).await
+    }
+
+    fn capabilities(&self) -> StorageCapabilities {
+        self.inner.capabilities()
+    }
+
+    async fn list_directory(&self, path: &str) -> Result<DirectoryListing, StorageError> {
+        self.retry("list_directory", || self.inner.list_directory(path)).await
+    }
+
+    async fn exists(&self, path: &str) -> Result<bool, StorageError> {
+        self.retry("exists", || self.inner.exists(path)).await
+    }
+
+    async fn get_entry_info(&self, path: &str) -> Result<StorageEntry, StorageError> {
+        self.retry("get_entry_info", || self.inner.get_entry_info(path)).await
+    }
+
+    async fn get_link_info(&self, path: &str) -> Result<StorageEntry, StorageError> {
+        self.retry("get_link_info", || self.inner.get_link_info(path)).await
+    }
+
+    async fn create_directory(
+        &self,
+        path: &str,
+        recursive: bool,
+        mode: Option<u32>,
+    ) -> Result<String, StorageError> {
+        self.inner.create_directory(path, recursive, mode).await
+    }
+
+    async fn get_directory_stats(&self, path: &str) -> Result<DirectoryStats, StorageError> {
+        self.retry("get_directory_stats", || self.inner.get_directory_stats(path))
+            .await
+    }
+
+    async fn delete_directory(&self, path: &str, recursive: bool) -> Result<(), StorageError> {
+        self.inner.delete_directory(path, recursive).await
+    }
+
+    async fn preview_delete(
+        &self,
+        path: &str,
+        recursive: bool,
+        limit: usize,
+    ) -> Result<DeletePreview, StorageError> {
+        self.retry("preview_delete", || self.inner.preview_delete(path, recursive, limit))
+            .await
+    }
+
+    async fn delete_tree(
+        &self,
+        path: &str,
+        concurrency: usize,
+        progress: Arc<DeleteProgress>,
+    ) -> Result<(), StorageError> {
+        self.inner.delete_tree(path, concurrency, progress).await
+    }
+
+    async fn create_file(&self, path: &str, overwrite: bool) -> Result<String, StorageError> {
+        self.inner.create_file(path, overwrite).await
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+        self.inner.delete_file(path).await
+    }
+
+    async fn upload_bytes(
+        &self,
+        destination: &str,
+        data: Bytes,
+        overwrite: bool,
+    ) -> Result<UploadResult, StorageError> {
+        self.inner.upload_bytes(destination, data, overwrite).await
+    }
+
+    async fn download_bytes(&self, path: &str) -> Result<Bytes, StorageError> {
+        self.retry("download_bytes", || self.inner.download_bytes(path)).await
+    }
+
+    async fn get_image_metadata(&self, path: &str) -> Result<ImageMetadata, StorageError> {
+        self.retry("get_image_metadata", || self.inner.get_image_metadata(path))
+            .await
+    }
+
+    async fn get_read_stream(
+        &self,
+        path: &str,
+    ) -> Result<Pin<Box<dyn AsyncRead + Send>>, StorageError> {
+        self.retry("get_read_stream", || self.inner.get_read_stream(path)).await
+    }
+
+    async fn get_read_stream_at(
+        &self,
+        path: &str,
+        offset: u64,
+    ) -> Result<Pin<Box<dyn AsyncRead + Send>>, StorageError> {
+        self.retry("get_read_stream_at", || self.inner.get_read_stream_at(path, offset))
+            .await
+    }
+
+    async fn get_download_url(
+        &self,
+        path: &str,
+        expires_in: Duration,
+    ) -> Result<String, StorageError> {
+        self.retry("get_download_url", || self.inner.get_download_url(path, expires_in))
+            .await
+    }
+
+    async fn get_write_stream(
+        &self,
+        path: &str,
+        overwrite: bool,
+        mode: Option<u32>,
+    ) -> Result<Pin<Box<dyn AsyncWrite + Send>>, StorageError> {
+        self.inner.get_write_stream(path, overwrite, mode).await
+    }
+
+    async fn init_project_structure(
+        &self,
+        base_path: &str,
+        project_name: &str,
+        merge: bool,
+    ) -> Result<ProjectStructure, StorageError> {
+        self.inner.init_project_structure(base_path, project_name, merge).await
+    }
+
+    async fn find_duplicates(&self, root: &str) -> Result<DuplicateStream, StorageError> {
+        self.retry("find_duplicates", || self.inner.find_duplicates(root)).await
+    }
+
+    async fn copy(
+        &self,
+        source: &str,
+        destination: &str,
+        overwrite: bool,
+    ) -> Result<(), StorageError> {
+        self.inner.copy(source, destination, overwrite).await
+    }
+
+    async fn rename(
+        &self,
+        source: &str,
+        destination: &str,
+        overwrite: bool,
+    ) -> Result<(), StorageError> {
+        self.inner.rename(source, destination, overwrite).await
+    }
+}
@@ -0,0 +1,547 @@
+//! HTTP-клиент S3: запросы к объектам одного бакета
+//!
+//! Адресация path-style (`<endpoint>/<бакет>/<ключ>`), как у MinIO и других
+//! S3-совместимых серверов; у AWS S3 она тоже поддерживается. Запросы
+//! подписываются SigV4 (см. `sign`). Endpoint - `http://` или `https://`;
+//! сертификат сервера проверяется по системным корневым сертификатам.
+
+use std::io;
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use http::header::{CONTENT_LENGTH, ETAG, LAST_MODIFIED};
+use http::{Method, Request, Response, StatusCode, Uri};
+use http_body_util::{BodyExt, Full};
+use hyper::body::Incoming;
+use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::client::legacy::Client;
+use hyper_util::rt::TokioExecutor;
+use rustls::{ClientConfig, RootCertStore};
+
+use super::sign::{amz_date, encode_query, sha256_hex, uri_encode, Signer, EMPTY_PAYLOAD_HASH};
+use super::xml;
+use crate::storage::StorageError;
+
+/// Сколько ключей запрашивается за одну страницу листинга (максимум S3)
+const LIST_PAGE_SIZE: &str = "1000";
+
+/// Сведения об объекте из `HEAD`
+pub struct ObjectHead {
+    pub size: u64,
+    pub modified_at: Option<DateTime<Utc>>,
+}
+
+/// Объект из листинга
+pub struct ObjectSummary {
+    pub key: String,
+    pub size: u64,
+    pub modified_at: Option<DateTime<Utc>>,
+}
+
+/// Результат листинга по префиксу
+#[derive(Default)]
+pub struct ObjectList {
+    pub objects: Vec<ObjectSummary>,
+    /// Общие префиксы до `/` (поддиректории), только при листинге одного уровня
+    pub prefixes: Vec<String>,
+}
+
+pub struct S3Client {
+    http: Client<HttpsConnector<HttpConnector>, Full<Bytes>>,
+    /// `http(s)://host:port` без завершающего `/`
+    endpoint: String,
+    /// Значение заголовка `Host` (участвует в подписи)
+    host: String,
+    bucket: String,
+    signer: Signer,
+}
+
+impl S3Client {
+    pub fn new(endpoint: &str, bucket: String, signer: Signer) -> Result<Self, StorageError> {
+        let uri: Uri = endpoint
+            .parse()
+            .map_err(|e| StorageError::Config(format!("некорректный s3_endpoint: {}", e)))?;
+
+        let scheme = match uri.scheme_str() {
+            Some(scheme @ ("http" | "https")) => scheme,
+            _ => {
+                return Err(StorageError::Config(format!(
+                    "s3_endpoint должен начинаться с http:// или https://: {}",
+                    endpoint
+                )))
+            }
+        };
+        if !matches!(uri.path(), "" | "/") {
+            return Err(StorageError::Config(format!(
+                "s3_endpoint не должен содержать путь: {}",
+                endpoint
+            )));
+        }
+        let host = uri
+            .authority()
+            .map(|authority| authority.to_string())
+            .ok_or_else(|| StorageError::Config(format!("в s3_endpoint нет хоста: {}", endpoint)))?;
+
+        Ok(Self {
+            http: Client::builder(TokioExecutor::new()).build(connector(scheme == "https")?),
+            endpoint: format!("{}://{}", scheme, host),
+            host,
+            bucket,
+            signer,
+        })
+    }
+
+    pub fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Путь хранилища для ключа: `/<бакет>/<ключ>`
+    pub fn path(&self, key: &str) -> String {
+        let key = key.trim_end_matches('/');
+        if key.is_empty() {
+            format!("/{}", self.bucket)
+        } else {
+            format!("/{}/{}", self.bucket, key)
+        }
+    }
+
+    /// Сведения об объекте; `None`, если объекта нет
+    pub async fn head_object(&self, key: &str) -> Result<Option<ObjectHead>, StorageError> {
+        let response = self.send(Method::HEAD, key, &[], &[], Bytes::new()).await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let response = self.check(response, key).await?;
+
+        let headers = response.headers();
+        let size = headers
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(0);
+        let modified_at = headers
+            .get(LAST_MODIFIED)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| DateTime::parse_from_rfc2822(value).ok())
+            .map(|time| time.with_timezone(&Utc));
+
+        Ok(Some(ObjectHead { size, modified_at }))
+    }
+
+    /// Тело объекта начиная с `offset`; `None`, если `offset` за концом объекта
+    pub async fn get_object(
+        &self,
+        key: &str,
+        offset: u64,
+    ) -> Result<Option<Incoming>, StorageError> {
+        let range = format!("bytes={}-", offset);
+        let headers: &[(&str, &str)] = if offset > 0 { &[("range", &range)] } else { &[] };
+
+        let response = self.send(Method::GET, key, &[], headers, Bytes::new()).await?;
+        if offset > 0 && response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+            return Ok(None);
+        }
+        let response = self.check(response, key).await?;
+        Ok(Some(response.into_body()))
+    }
+
+    /// Записать объект целиком
+    ///
+    /// Без `overwrite` запрос условный (`If-None-Match: *`): существующий
+    /// объект не заменяется, даже если появился после проверки.
+    pub async fn put_object(
+        &self,
+        key: &str,
+        data: Bytes,
+        content_type: &str,
+        overwrite: bool,
+    ) -> Result<(), StorageError> {
+        let mut headers = vec![("content-type", content_type)];
+        if !overwrite {
+            headers.push(("if-none-match", "*"));
+        }
+        let response = self.send(Method::PUT, key, &[], &headers, data).await?;
+        self.check(response, key).await?;
+        Ok(())
+    }
+
+    pub async fn delete_object(&self, key: &str) -> Result<(), StorageError> {
+        let response = self.send(Method::DELETE, key, &[], &[], Bytes::new()).await?;
+        self.check(response, key).await?;
+        Ok(())
+    }
+
+    /// Копировать объект внутри бакета на стороне сервера
+    pub async fn copy_object(&self, source: &str, destination: &str) -> Result<(), StorageError> {
+        let copy_source = format!("/{}/{}", self.bucket, uri_encode(source, false));
+        let response = self
+            .send(
+                Method::PUT,
+                destination,
+                &[],
+                &[("x-amz-copy-source", &copy_source)],
+                Bytes::new(),
+            )
+            .await?;
+        let response = self.check(response, source).await?;
+        self.check_body(response, source, "копирование").await?;
+        Ok(())
+    }
+
+    /// Начать составную загрузку; результат - её идентификатор
+    pub async fn create_multipart_upload(
+        &self,
+        key: &str,
+        content_type: &str,
+    ) -> Result<String, StorageError> {
+        let response = self
+            .send(
+                Method::POST,
+                key,
+                &[("uploads", "")],
+                &[("content-type", content_type)],
+                Bytes::new(),
+            )
+            .await?;
+        let response = self.check(response, key).await?;
+        let body = read_body(response.into_body()).await?;
+
+        xml::element(&String::from_utf8_lossy(&body), "UploadId").ok_or_else(|| {
+            StorageError::Io(io::Error::other("S3: в ответе нет UploadId составной загрузки"))
+        })
+    }
+
+    /// Загрузить часть `number` (с 1); результат - ETag части
+    pub async fn upload_part(
+        &self,
+        key: &str,
+        upload_id: &str,
+        number: u32,
+        data: Bytes,
+    ) -> Result<String, StorageError> {
+        let number = number.to_string();
+        let query = [("partNumber", number.as_str()), ("uploadId", upload_id)];
+        let response = self.send(Method::PUT, key, &query, &[], data).await?;
+        let response = self.check(response, key).await?;
+
+        response
+            .headers()
+            .get(ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+            .ok_or_else(|| StorageError::Io(io::Error::other("S3: в ответе нет ETag части")))
+    }
+
+    /// Завершить составную загрузку: объект собирается из частей `(номер, ETag)`
+    ///
+    /// Без `overwrite` завершение условное, как у `put_object`.
+    pub async fn complete_multipart_upload(
+        &self,
+        key: &str,
+        upload_id: &str,
+        parts: &[(u32, String)],
+        overwrite: bool,
+    ) -> Result<(), StorageError> {
+        let parts: String = parts
+            .iter()
+            .map(|(number, etag)| {
+                format!(
+                    "<Part><PartNumber>{}</PartNumber><ETag>{}</ETag></Part>",
+                    number,
+                    xml::escape(etag)
+                )
+            })
+            .collect();
+        let body = format!("<CompleteMultipartUpload>{}</CompleteMultipartUpload>", parts);
+
+        let mut headers = vec![("content-type", "application/xml")];
+        if !overwrite {
+            headers.push(("if-none-match", "*"));
+        }
+        let response = self
+            .send(Method::POST, key, &[("uploadId", upload_id)], &headers, Bytes::from(body))
+            .await?;
+        let response = self.check(response, key).await?;
+        self.check_body(response, key, "завершение загрузки").await
+    }
+
+    /// Отменить составную загрузку и удалить загруженные части
+    pub async fn abort_multipart_upload(
+        &self,
+        key: &str,
+        upload_id: &str,
+    ) -> Result<(), StorageError> {
+        let response = self
+            .send(Method::DELETE, key, &[("uploadId", upload_id)], &[], Bytes::new())
+            .await?;
+        self.check(response, key).await?;
+        Ok(())
+    }
+
+    /// Подписанная ссылка на скачивание объекта, действующая `expires_in` секунд
+    pub fn presigned_get_url(&self, key: &str, expires_in: u64) -> String {
+        let path = self.request_path(key);
+        let query = self.signer.presign("GET", &path, &self.host, expires_in, Utc::now());
+        format!("{}{}?{}", self.endpoint, path, query)
+    }
+
+    /// Все объекты с префиксом `prefix`
+    ///
+    /// * `recursive` - все уровни; иначе один уровень, а поддиректории
+    ///   попадают в `prefixes`
+    pub async fn list(&self, prefix: &str, recursive: bool) -> Result<ObjectList, StorageError> {
+        let mut list = ObjectList::default();
+        let mut token = None;
+        loop {
+            let (page, next) = self
+                .list_page(prefix, recursive, token.as_deref(), LIST_PAGE_SIZE)
+                .await?;
+            list.objects.extend(page.objects);
+            list.prefixes.extend(page.prefixes);
+            match next {
+                Some(next) => token = Some(next),
+                None => return Ok(list),
+            }
+        }
+    }
+
+    /// Есть ли хотя бы один объект с префиксом `prefix`
+    pub async fn has_prefix(&self, prefix: &str) -> Result<bool, StorageError> {
+        let (page, _) = self.list_page(prefix, true, None, "1").await?;
+        Ok(!page.objects.is_empty())
+    }
+
+    /// Одна страница `ListObjectsV2`; второе значение - токен следующей страницы
+    async fn list_page(
+        &self,
+        prefix: &str,
+        recursive: bool,
+        token: Option<&str>,
+        max_keys: &str,
+    ) -> Result<(ObjectList, Option<String>), StorageError> {
+        let mut query = vec![("list-type", "2"), ("prefix", prefix), ("max-keys", max_keys)];
+        if !recursive {
+            query.push(("delimiter", "/"));
+        }
+        if let Some(token) = token {
+            query.push(("continuation-token", token));
+        }
+
+        let response = self.send(Method::GET, "", &query, &[], Bytes::new()).await?;
+        let response = self.check(response, prefix).await?;
+        let body = read_body(response.into_body()).await?;
+        let body = String::from_utf8_lossy(&body);
+
+        let objects = xml::raw_elements(&body, "Contents")
+            .into_iter()
+            .filter_map(|contents| {
+                Some(ObjectSummary {
+                    key: xml::element(contents, "Key")?,
+                    size: xml::element(contents, "Size")
+                        .and_then(|size| size.parse().ok())
+                        .unwrap_or(0),
+                    modified_at: xml::element(contents, "LastModified")
+                        .and_then(|time| DateTime::parse_from_rfc3339(&time).ok())
+                        .map(|time| time.with_timezone(&Utc)),
+                })
+            })
+            .collect();
+        let prefixes = xml::raw_elements(&body, "CommonPrefixes")
+            .into_iter()
+            .filter_map(|common| xml::element(common, "Prefix"))
+            .collect();
+
+        let truncated = xml::element(&body, "IsTruncated").is_some_and(|value| value == "true");
+        let next = xml::element(&body, "NextContinuationToken").filter(|_| truncated);
+
+        Ok((ObjectList { objects, prefixes }, next))
+    }
+
+    /// Путь запроса к объекту `key` (пустой - к бакету), закодированный
+    fn request_path(&self, key: &str) -> String {
+        if key.is_empty() {
+            format!("/{}", uri_encode(&self.bucket, true))
+        } else {
+            format!("/{}/{}", uri_encode(&self.bucket, true), uri_encode(key, false))
+        }
+    }
+
+    /// Отправить подписанный запрос к объекту `key` (пустой - к бакету)
+    ///
+    /// Ответ возвращается с любым статусом, см. `check`.
+    async fn send(
+        &self,
+        method: Method,
+        key: &str,
+        query: &[(&str, &str)],
+        headers: &[(&str, &str)],
+        body: Bytes,
+    ) -> Result<Response<Incoming>, StorageError> {
+        let path = self.request_path(key);
+        let uri = if query.is_empty() {
+            format!("{}{}", self.endpoint, path)
+        } else {
+            format!("{}{}?{}", self.endpoint, path, encode_query(query))
+        };
+
+        let now = Utc::now();
+        let date = amz_date(now);
+        let payload_hash = if body.is_empty() {
+            EMPTY_PAYLOAD_HASH.to_string()
+        } else {
+            sha256_hex(&body)
+        };
+
+        let mut signed: Vec<(&str, &str)> = vec![
+            ("host", &self.host),
+            ("x-amz-date", &date),
+            ("x-amz-content-sha256", &payload_hash),
+        ];
+        signed.extend_from_slice(headers);
+        let authorization =
+            self.signer
+                .authorization(method.as_str(), &path, query, &signed, &payload_hash, now);
+
+        let mut request = Request::builder().method(method).uri(&uri);
+        for (name, value) in &signed {
+            request = request.header(*name, *value);
+        }
+        let request = request
+            .header("authorization", authorization)
+            .body(Full::new(body))
+            .map_err(|e| StorageError::InvalidData(format!("некорректный запрос S3: {}", e)))?;
+
+        self.http.request(request).await.map_err(|e| {
+            let kind = if e.is_connect() {
+                io::ErrorKind::ConnectionRefused
+            } else {
+                io::ErrorKind::ConnectionReset
+            };
+            StorageError::Io(io::Error::new(kind, format!("S3 недоступен: {}", e)))
+        })
+    }
+
+    /// Ошибка в теле успешного ответа
+    ///
+    /// Долгие операции (копирование, завершение составной загрузки) отвечают
+    /// статусом 200 сразу, а об ошибке сообщают в теле.
+    async fn check_body(
+        &self,
+        response: Response<Incoming>,
+        key: &str,
+        action: &str,
+    ) -> Result<(), StorageError> {
+        let body = read_body(response.into_body()).await?;
+        let body = String::from_utf8_lossy(&body);
+        if body.contains("<Error>") {
+            return Err(StorageError::Io(io::Error::other(format!(
+                "S3: {} {} не выполнено: {} {}",
+                action,
+                self.path(key),
+                xml::element(&body, "Code").unwrap_or_default(),
+                xml::element(&body, "Message").unwrap_or_default()
+            ))));
+        }
+        Ok(())
+    }
+
+    /// Ошибка по статусу ответа (текст - из XML-тела ошибки S3)
+    async fn check(
+        &self,
+        response: Response<Incoming>,
+        key: &str,
+    ) -> Result<Response<Incoming>, StorageError> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let body = read_body(response.into_body()).await.unwrap_or_default();
+        let body = String::from_utf8_lossy(&body);
+        let code = xml::element(&body, "Code").unwrap_or_default();
+        let message = xml::element(&body, "Message").unwrap_or_default();
+
+        Err(match status {
+            _ if code == "NoSuchBucket" => {
+                StorageError::Config(format!("бакет S3 не существует: {}", self.bucket))
+            }
+            StatusCode::NOT_FOUND => StorageError::NotFound(self.path(key)),
+            StatusCode::FORBIDDEN => StorageError::PermissionDenied(self.path(key)),
+            StatusCode::PRECONDITION_FAILED => StorageError::AlreadyExists(self.path(key)),
+            _ => StorageError::Io(io::Error::other(format!(
+                "S3 {}: {} {}",
+                status.as_u16(),
+                code,
+                message
+            ))),
+        })
+    }
+}
+
+/// Соединения с S3
+///
+/// Для `https` сертификат сервера проверяется по системным корневым
+/// сертификатам (своя CA для MinIO добавляется в системное хранилище).
+/// Для `http` они не загружаются: в образе без сертификатов работает
+/// и обычный endpoint.
+fn connector(tls: bool) -> Result<HttpsConnector<HttpConnector>, StorageError> {
+    let builder = HttpsConnectorBuilder::new();
+    let builder = if tls {
+        builder.with_native_roots().map_err(|e| {
+            StorageError::Config(format!("нет корневых сертификатов для https: {}", e))
+        })?
+    } else {
+        builder.with_tls_config(
+            ClientConfig::builder()
+                .with_root_certificates(RootCertStore::empty())
+                .with_no_client_auth(),
+        )
+    };
+    Ok(builder.https_or_http().enable_http1().build())
+}
+
+/// Прочитать тело ответа целиком
+pub async fn read_body(body: Incoming) -> Result<Bytes, StorageError> {
+    let collected = body.collect().await.map_err(body_error)?;
+    Ok(collected.to_bytes())
+}
+
+/// Ошибка чтения тела ответа (обрыв соединения)
+pub fn body_error(error: hyper::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::ConnectionReset, error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client(endpoint: &str) -> Result<S3Client, StorageError> {
+        let signer = Signer::new("key".into(), "secret".into(), "us-east-1".into());
+        S3Client::new(endpoint, "bucket".to_string(), signer)
+    }
+
+    #[test]
+    fn endpoint_scheme_is_kept() {
+        for endpoint in ["http://localhost:9000", "https://s3.example.com/"] {
+            let url = client(endpoint).unwrap().presigned_get_url("a/b.txt", 60);
+            let expected = format!("{}/bucket/a/b.txt?", endpoint.trim_end_matches('/'));
+            assert!(url.starts_with(&expected), "{}", url);
+        }
+    }
+
+    #[test]
+    fn invalid_endpoints_are_refused() {
+        for endpoint in ["ftp://localhost:9000", "localhost:9000", "https://s3.example.com/path"] {
+            assert!(
+                matches!(client(endpoint), Err(StorageError::Config(_))),
+                "{}",
+                endpoint
+            );
+        }
+    }
+}
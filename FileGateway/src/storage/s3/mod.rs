@@ -0,0 +1,595 @@
+//! Провайдер S3-совместимого хранилища (MinIO, AWS S3 и т.п.)
+//!
+//! Хранилище - один бакет (`s3_bucket`), он же единственный корневой путь:
+//! путь `/<бакет>/a/b.mp4` - объект с ключом `a/b.mp4`. Директорий в S3 нет,
+//! директорией считается общий префикс ключей до `/`. `create_directory`
+//! создаёт пустой объект-маркер `a/`, чтобы пустая директория была видна
+//! в листинге. Права unix (`mode`) в S3 не применяются и игнорируются.
+
+mod client;
+mod sign;
+mod writer;
+mod xml;
+
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use http_body_util::BodyExt;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_stream::StreamExt;
+use tokio_util::io::StreamReader;
+use uuid::Uuid;
+
+use self::client::{body_error, read_body, S3Client};
+use self::sign::Signer;
+use self::writer::S3Writer;
+use super::{
+    check_file_name, DirectoryListing, DirectoryStats, ProjectFolders, ProjectStructure,
+    StorageCapabilities, StorageConfig, StorageEntry, StorageError, StorageInfo,
+    StorageProvider, UploadResult,
+};
+
+/// Регион по умолчанию (MinIO принимает любой)
+const DEFAULT_REGION: &str = "us-east-1";
+
+/// Наибольший срок действия подписанной ссылки в S3 (7 дней), секунды
+const MAX_DOWNLOAD_URL_EXPIRY: u64 = 7 * 24 * 60 * 60;
+
+pub struct S3StorageProvider {
+    id: String,
+    client: Arc<S3Client>,
+    /// Показывать файлы и директории, начинающиеся с точки
+    show_hidden: bool,
+    default_projects_path: String,
+    /// MIME типы по расширению (без точки, в нижнем регистре)
+    mime_overrides: HashMap<String, String>,
+    /// Названия стандартных папок проекта
+    project_folders: ProjectFolders,
+}
+
+impl S3StorageProvider {
+    pub fn new(config: &StorageConfig) -> Result<Self, StorageError> {
+        let setting = |value: &Option<String>, name: &str| {
+            value
+                .clone()
+                .filter(|value| !value.is_empty())
+                .ok_or_else(|| StorageError::Config(format!("для хранилища S3 не задан {}", name)))
+        };
+        let endpoint = setting(&config.s3_endpoint, "s3_endpoint")?;
+        let bucket = setting(&config.s3_bucket, "s3_bucket")?;
+        let signer = Signer::new(
+            setting(&config.s3_access_key, "s3_access_key")?,
+            setting(&config.s3_secret_key, "s3_secret_key")?,
+            config
+                .s3_region
+                .clone()
+                .filter(|region| !region.is_empty())
+                .unwrap_or_else(|| DEFAULT_REGION.to_string()),
+        );
+        let client = S3Client::new(&endpoint, bucket, signer)?;
+
+        Ok(Self {
+            id: config.id.clone().unwrap_or_else(|| Uuid::new_v4().to_string()),
+            default_projects_path: config
+                .default_projects_path
+                .clone()
+                .unwrap_or_else(|| client.path("")),
+            client: Arc::new(client),
+            show_hidden: config.show_hidden,
+            mime_overrides: config
+                .extension_mime_overrides
+                .iter()
+                .map(|(ext, mime)| (ext.trim_start_matches('.').to_lowercase(), mime.clone()))
+                .collect(),
+            project_folders: config.project_folders.clone(),
+        })
+    }
+
+    /// Ключ объекта по пути `/<бакет>/<ключ>`; корень бакета - пустой ключ
+    ///
+    /// Пустой путь и `/` - корень бакета. Путь вне бакета не существует.
+    fn key(&self, path: &str) -> Result<String, StorageError> {
+        let normalized = path.replace('\\', "/");
+        let mut components = normalized
+            .split('/')
+            .filter(|component| !component.is_empty() && *component != ".");
+
+        match components.next() {
+            None => return Ok(String::new()),
+            Some(bucket) if bucket == self.client.bucket() => {}
+            Some(_) => return Err(StorageError::NotFound(path.to_string())),
+        }
+
+        let components: Vec<&str> = components.collect();
+        if components.contains(&"..") {
+            return Err(StorageError::InvalidName(path.to_string()));
+        }
+        Ok(components.join("/"))
+    }
+
+    /// Ключ файла: не корень бакета, имя файла допустимо
+    fn file_key(&self, path: &str) -> Result<String, StorageError> {
+        let key = self.key(path)?;
+        if key.is_empty() {
+            return Err(StorageError::NotAFile(path.to_string()));
+        }
+        check_file_name(entry_name(&key))?;
+        Ok(key)
+    }
+
+    /// Есть ли директория (объекты с префиксом `<ключ>/`); корень есть всегда
+    async fn directory_exists(&self, key: &str) -> Result<bool, StorageError> {
+        if key.is_empty() {
+            return Ok(true);
+        }
+        self.client.has_prefix(&directory_prefix(key)).await
+    }
+
+    /// MIME тип по расширению: сначала переопределения, затем `mime_guess`
+    fn guess_mime_type(&self, key: &str) -> String {
+        let extension = std::path::Path::new(key)
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        match extension.and_then(|extension| self.mime_overrides.get(&extension)) {
+            Some(mime) => mime.clone(),
+            None => mime_guess::from_path(key).first_or_octet_stream().to_string(),
+        }
+    }
+
+    fn file_entry(
+        &self,
+        key: &str,
+        size: u64,
+        modified_at: Option<DateTime<Utc>>,
+    ) -> StorageEntry {
+        StorageEntry {
+            name: entry_name(key).to_string(),
+            path: self.client.path(key),
+            is_directory: false,
+            size,
+            created_at: None,
+            modified_at,
+            mime_type: self.guess_mime_type(key),
+            metadata_error: None,
+            is_writable: None,
+            symlink_target: None,
+            metadata: HashMap::new(),
+        }
+    }
+
+    fn directory_entry(&self, key: &str) -> StorageEntry {
+        StorageEntry {
+            name: match entry_name(key) {
+                "" => self.client.bucket().to_string(),
+                name => name.to_string(),
+            },
+            path: self.client.path(key),
+            is_directory: true,
+            size: 0,
+            created_at: None,
+            modified_at: None,
+            mime_type: "inode/directory".to_string(),
+            metadata_error: None,
+            is_writable: None,
+            symlink_target: None,
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Создать маркер директории, если её ещё нет
+    async fn ensure_directory(&self, key: &str) -> Result<(), StorageError> {
+        if !self.directory_exists(key).await? {
+            self.client
+                .put_object(&directory_prefix(key), Bytes::new(), "application/x-directory", true)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl StorageProvider for S3StorageProvider {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    async fn get_info(&self) -> Result<StorageInfo, StorageError> {
+        Ok(StorageInfo {
+            id: self.id.clone(),
+            storage_type: "s3".to_string(),
+            hostname: self.client.host().to_string(),
+            os: String::new(),
+            home_directory: self.client.path(""),
+            default_projects_path: self.default_projects_path.clone(),
+            root_paths: vec![self.client.path("")],
+            total_space: 0,
+            free_space: 0,
+            quota_bytes: None,
+            quota_used_bytes: None,
+        })
+    }
+
+    fn capabilities(&self) -> StorageCapabilities {
+        StorageCapabilities {
+            streaming: true,
+            rename: true,
+            // Объект появляется в бакете только после завершения записи
+            atomic_writes: true,
+            ..Default::default()
+        }
+    }
+
+    async fn list_directory(&self, path: &str) -> Result<DirectoryListing, StorageError> {
+        let key = self.key(path)?;
+        let prefix = directory_prefix(&key);
+        let list = self.client.list(&prefix, false).await?;
+
+        if !key.is_empty() && list.objects.is_empty() && list.prefixes.is_empty() {
+            return Err(match self.client.head_object(&key).await? {
+                Some(_) => StorageError::NotADirectory(path.to_string()),
+                None => StorageError::NotFound(path.to_string()),
+            });
+        }
+
+        let directories = list
+            .prefixes
+            .iter()
+            .map(|prefix| self.directory_entry(prefix.trim_end_matches('/')));
+        let files = list
+            .objects
+            .iter()
+            // Маркер самой директории
+            .filter(|object| object.key != prefix)
+            .map(|object| self.file_entry(&object.key, object.size, object.modified_at));
+        let mut entries: Vec<StorageEntry> = directories
+            .chain(files)
+            .filter(|entry| self.show_hidden || !entry.name.starts_with('.'))
+            .collect();
+
+        // Сортировка как у локального хранилища: директории сверху, потом по имени
+        entries.sort_by(|a, b| {
+            b.is_directory.cmp(&a.is_directory).then_with(|| {
+                a.name
+                    .to_lowercase()
+                    .cmp(&b.name.to_lowercase())
+                    .then_with(|| a.name.cmp(&b.name))
+            })
+        });
+
+        let parent_path = match key.rsplit_once('/') {
+            Some((parent, _)) => self.client.path(parent),
+            None if key.is_empty() => String::new(),
+            None => self.client.path(""),
+        };
+
+        Ok(DirectoryListing {
+            current_path: self.client.path(&key),
+            parent_path,
+            entries,
+        })
+    }
+
+    async fn exists(&self, path: &str) -> Result<bool, StorageError> {
+        let key = match self.key(path) {
+            Ok(key) => key,
+            Err(StorageError::NotFound(_)) => return Ok(false),
+            Err(e) => return Err(e),
+        };
+        if !key.is_empty() && self.client.head_object(&key).await?.is_some() {
+            return Ok(true);
+        }
+        self.directory_exists(&key).await
+    }
+
+    async fn get_entry_info(&self, path: &str) -> Result<StorageEntry, StorageError> {
+        let key = self.key(path)?;
+        if !key.is_empty() {
+            if let Some(head) = self.client.head_object(&key).await? {
+                return Ok(self.file_entry(&key, head.size, head.modified_at));
+            }
+        }
+        if self.directory_exists(&key).await? {
+            return Ok(self.directory_entry(&key));
+        }
+        Err(StorageError::NotFound(path.to_string()))
+    }
+
+    async fn create_directory(
+        &self,
+        path: &str,
+        recursive: bool,
+        _mode: Option<u32>,
+    ) -> Result<String, StorageError> {
+        let key = self.key(path)?;
+        if key.is_empty() || self.directory_exists(&key).await? {
+            return Err(StorageError::AlreadyExists(path.to_string()));
+        }
+        if self.client.head_object(&key).await?.is_some() {
+            return Err(StorageError::AlreadyExists(path.to_string()));
+        }
+        check_file_name(entry_name(&key))?;
+
+        // Без `recursive` родительская директория должна существовать;
+        // с ним родители появляются сами - как общие префиксы ключа
+        if !recursive {
+            let parent = key.rsplit_once('/').map_or("", |(parent, _)| parent);
+            if !self.directory_exists(parent).await? {
+                return Err(StorageError::NotFound(self.client.path(parent)));
+            }
+        }
+
+        self.client
+            .put_object(&directory_prefix(&key), Bytes::new(), "application/x-directory", true)
+            .await?;
+        Ok(self.client.path(&key))
+    }
+
+    async fn get_directory_stats(&self, path: &str) -> Result<DirectoryStats, StorageError> {
+        let key = self.key(path)?;
+        let prefix = directory_prefix(&key);
+        let list = self.client.list(&prefix, true).await?;
+        if !key.is_empty() && list.objects.is_empty() {
+            return Err(StorageError::NotFound(path.to_string()));
+        }
+
+        // Поддиректории - все промежуточные префиксы ключей (и маркеры)
+        let mut directories = std::collections::HashSet::new();
+        let mut stats = DirectoryStats::default();
+        for object in &list.objects {
+            let relative = &object.key[prefix.len()..];
+            let mut end = 0;
+            while let Some(slash) = relative[end..].find('/') {
+                end += slash + 1;
+                directories.insert(&relative[..end]);
+            }
+            if !relative.is_empty() && !relative.ends_with('/') {
+                stats.file_count += 1;
+                stats.total_size += object.size;
+            }
+        }
+        stats.dir_count = directories.len() as u64;
+        Ok(stats)
+    }
+
+    async fn delete_directory(&self, path: &str, recursive: bool) -> Result<(), StorageError> {
+        let key = self.key(path)?;
+        if key.is_empty() {
+            return Err(StorageError::PermissionDenied(path.to_string()));
+        }
+        let prefix = directory_prefix(&key);
+        let list = self.client.list(&prefix, true).await?;
+
+        if list.objects.is_empty() {
+            return Err(match self.client.head_object(&key).await? {
+                Some(_) => StorageError::NotADirectory(path.to_string()),
+                None => StorageError::NotFound(path.to_string()),
+            });
+        }
+        if !recursive && list.objects.iter().any(|object| object.key != prefix) {
+            return Err(StorageError::Io(std::io::Error::new(
+                std::io::ErrorKind::DirectoryNotEmpty,
+                format!("директория не пуста: {}", path),
+            )));
+        }
+
+        // Содержимое раньше маркера: прерванное удаление оставит директорию видимой
+        for object in list.objects.iter().filter(|object| object.key != prefix) {
+            self.client.delete_object(&object.key).await?;
+        }
+        self.client.delete_object(&prefix).await
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+        let key = self.file_key(path)?;
+        // Удаление в S3 успешно и для несуществующего ключа
+        if self.client.head_object(&key).await?.is_none() {
+            return Err(if self.directory_exists(&key).await? {
+                StorageError::NotAFile(path.to_string())
+            } else {
+                StorageError::NotFound(path.to_string())
+            });
+        }
+        self.client.delete_object(&key).await
+    }
+
+    async fn upload_bytes(
+        &self,
+        destination: &str,
+        data: Bytes,
+        overwrite: bool,
+    ) -> Result<UploadResult, StorageError> {
+        let key = self.file_key(destination)?;
+        if self.directory_exists(&key).await? {
+            return Err(StorageError::NotAFile(destination.to_string()));
+        }
+
+        let size = data.len() as u64;
+        self.client
+            .put_object(&key, data, &self.guess_mime_type(&key), overwrite)
+            .await?;
+
+        Ok(UploadResult {
+            path: self.client.path(&key),
+            size,
+            checksum: None,
+        })
+    }
+
+    async fn download_bytes(&self, path: &str) -> Result<Bytes, StorageError> {
+        let key = self.file_key(path)?;
+        match self.client.get_object(&key, 0).await? {
+            Some(body) => read_body(body).await,
+            None => Ok(Bytes::new()),
+        }
+    }
+
+    async fn get_read_stream(
+        &self,
+        path: &str,
+    ) -> Result<Pin<Box<dyn AsyncRead + Send>>, StorageError> {
+        self.get_read_stream_at(path, 0).await
+    }
+
+    /// Чтение с позиции запросом диапазона (`Range`), без скачивания начала
+    async fn get_read_stream_at(
+        &self,
+        path: &str,
+        offset: u64,
+    ) -> Result<Pin<Box<dyn AsyncRead + Send>>, StorageError> {
+        let key = self.file_key(path)?;
+        match self.client.get_object(&key, offset).await? {
+            Some(body) => {
+                let stream = body.into_data_stream().map(|chunk| chunk.map_err(body_error));
+                Ok(Box::pin(StreamReader::new(stream)))
+            }
+            None => Ok(Box::pin(tokio::io::empty())),
+        }
+    }
+
+    /// Подписанная ссылка (presigned URL): S3 отдаёт файл по ней без ключей
+    async fn get_download_url(
+        &self,
+        path: &str,
+        expires_in: Duration,
+    ) -> Result<String, StorageError> {
+        let seconds = expires_in.as_secs();
+        if !(1..=MAX_DOWNLOAD_URL_EXPIRY).contains(&seconds) {
+            return Err(StorageError::InvalidData(format!(
+                "срок действия ссылки S3 - от 1 до {} секунд",
+                MAX_DOWNLOAD_URL_EXPIRY
+            )));
+        }
+
+        // Ссылка на несуществующий файл отдала бы ошибку только при скачивании
+        let key = self.file_key(path)?;
+        if self.client.head_object(&key).await?.is_none() {
+            return Err(StorageError::NotFound(path.to_string()));
+        }
+        Ok(self.client.presigned_get_url(&key, seconds))
+    }
+
+    async fn get_write_stream(
+        &self,
+        path: &str,
+        overwrite: bool,
+        _mode: Option<u32>,
+    ) -> Result<Pin<Box<dyn AsyncWrite + Send>>, StorageError> {
+        let key = self.file_key(path)?;
+        if self.directory_exists(&key).await? {
+            return Err(StorageError::NotAFile(path.to_string()));
+        }
+        // Проверка заранее, чтобы не принимать данные впустую; запись
+        // всё равно условная и не заменит файл, появившийся позже
+        if !overwrite && self.client.head_object(&key).await?.is_some() {
+            return Err(StorageError::AlreadyExists(path.to_string()));
+        }
+
+        let content_type = self.guess_mime_type(&key);
+        Ok(Box::pin(S3Writer::new(self.client.clone(), key, content_type, overwrite)))
+    }
+
+    async fn init_project_structure(
+        &self,
+        base_path: &str,
+        project_name: &str,
+        merge: bool,
+    ) -> Result<ProjectStructure, StorageError> {
+        check_file_name(project_name)?;
+        let base = self.key(base_path)?;
+        let project = if base.is_empty() {
+            project_name.to_string()
+        } else {
+            format!("{}/{}", base, project_name)
+        };
+
+        if self.client.head_object(&project).await?.is_some() {
+            return Err(StorageError::NotADirectory(self.client.path(&project)));
+        }
+        if !merge && self.directory_exists(&project).await? {
+            return Err(StorageError::AlreadyExists(self.client.path(&project)));
+        }
+
+        let folders = &self.project_folders;
+        let assets = format!("{}/{}", project, folders.assets);
+        let video = format!("{}/{}", assets, folders.video);
+        let audio = format!("{}/{}", assets, folders.audio);
+        let images = format!("{}/{}", assets, folders.images);
+        let timeline = format!("{}/{}", project, folders.timeline);
+        let exports = format!("{}/{}", project, folders.exports);
+
+        // Маркеры нужны всем папкам: пустые папки иначе не видны
+        for key in [&project, &assets, &video, &audio, &images, &timeline, &exports] {
+            self.ensure_directory(key).await?;
+        }
+
+        Ok(ProjectStructure {
+            project_path: self.client.path(&project),
+            assets_path: self.client.path(&assets),
+            video_path: self.client.path(&video),
+            audio_path: self.client.path(&audio),
+            images_path: self.client.path(&images),
+            timeline_path: self.client.path(&timeline),
+            exports_path: self.client.path(&exports),
+        })
+    }
+
+    /// Копирование на стороне сервера, без передачи данных через шлюз
+    async fn copy(
+        &self,
+        source: &str,
+        destination: &str,
+        overwrite: bool,
+    ) -> Result<(), StorageError> {
+        let source_key = self.file_key(source)?;
+        let destination_key = self.file_key(destination)?;
+
+        if self.client.head_object(&source_key).await?.is_none() {
+            return Err(StorageError::NotFound(source.to_string()));
+        }
+        if !overwrite && self.client.head_object(&destination_key).await?.is_some() {
+            return Err(StorageError::AlreadyExists(destination.to_string()));
+        }
+
+        self.client.copy_object(&source_key, &destination_key).await
+    }
+
+    /// Перемещение файла: копирование на сервере и удаление источника
+    ///
+    /// Директории не перемещаются: в S3 это перенос каждого объекта.
+    async fn rename(
+        &self,
+        source: &str,
+        destination: &str,
+        overwrite: bool,
+    ) -> Result<(), StorageError> {
+        let source_key = self.key(source)?;
+        if !source_key.is_empty()
+            && self.client.head_object(&source_key).await?.is_none()
+            && self.directory_exists(&source_key).await?
+        {
+            return Err(StorageError::NotSupported);
+        }
+
+        self.copy(source, destination, overwrite).await?;
+        self.delete_file(source).await
+    }
+}
+
+/// Префикс содержимого директории: `<ключ>/`, у корня - пустой
+fn directory_prefix(key: &str) -> String {
+    if key.is_empty() {
+        String::new()
+    } else {
+        format!("{}/", key)
+    }
+}
+
+/// Последний компонент ключа
+fn entry_name(key: &str) -> &str {
+    key.trim_end_matches('/').rsplit('/').next().unwrap_or_default()
+}
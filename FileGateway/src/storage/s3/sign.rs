@@ -0,0 +1,213 @@
+//! Подпись запросов S3 (AWS Signature Version 4)
+//!
+//! Подписываются метод, путь, параметры запроса, заголовки `host` и
+//! `x-amz-*` и SHA-256 тела. Подписанные ссылки (presigned URL) несут
+//! подпись в параметрах запроса. HMAC-SHA256 собран поверх `sha2`.
+
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+
+/// Размер блока SHA-256 для HMAC
+const HMAC_BLOCK_SIZE: usize = 64;
+
+/// SHA-256 пустого тела (GET, HEAD, DELETE)
+pub const EMPTY_PAYLOAD_HASH: &str =
+    "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+/// Хэш тела для подписанных ссылок: тело не подписывается
+const UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
+
+/// Ключи доступа и регион, которыми подписываются запросы
+pub struct Signer {
+    access_key: String,
+    secret_key: String,
+    region: String,
+}
+
+impl Signer {
+    pub fn new(access_key: String, secret_key: String, region: String) -> Self {
+        Self {
+            access_key,
+            secret_key,
+            region,
+        }
+    }
+
+    /// Значение заголовка `Authorization`
+    ///
+    /// * `path` - путь, уже закодированный `uri_encode`
+    /// * `query` - параметры запроса (кодируются здесь)
+    /// * `headers` - подписываемые заголовки: имена в нижнем регистре,
+    ///   среди них `host`, `x-amz-date` и `x-amz-content-sha256`
+    pub fn authorization(
+        &self,
+        method: &str,
+        path: &str,
+        query: &[(&str, &str)],
+        headers: &[(&str, &str)],
+        payload_hash: &str,
+        now: DateTime<Utc>,
+    ) -> String {
+        let (signed_headers, signature) =
+            self.signature(method, path, query, headers, payload_hash, now);
+
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.access_key,
+            self.scope(now),
+            signed_headers,
+            signature
+        )
+    }
+
+    /// Параметры подписанной ссылки: запрос по ней не требует ключей
+    ///
+    /// Подписывается только заголовок `host`, тело не подписывается.
+    /// Результат - закодированная строка параметров запроса.
+    pub fn presign(
+        &self,
+        method: &str,
+        path: &str,
+        host: &str,
+        expires_in: u64,
+        now: DateTime<Utc>,
+    ) -> String {
+        let credential = format!("{}/{}", self.access_key, self.scope(now));
+        let date = amz_date(now);
+        let expires = expires_in.to_string();
+
+        let mut query = vec![
+            ("X-Amz-Algorithm", "AWS4-HMAC-SHA256"),
+            ("X-Amz-Credential", &credential),
+            ("X-Amz-Date", &date),
+            ("X-Amz-Expires", &expires),
+            ("X-Amz-SignedHeaders", "host"),
+        ];
+        let (_, signature) =
+            self.signature(method, path, &query, &[("host", host)], UNSIGNED_PAYLOAD, now);
+        query.push(("X-Amz-Signature", &signature));
+        encode_query(&query)
+    }
+
+    /// Область ключа подписи: `<дата>/<регион>/s3/aws4_request`
+    fn scope(&self, now: DateTime<Utc>) -> String {
+        format!("{}/{}/s3/aws4_request", now.format("%Y%m%d"), self.region)
+    }
+
+    /// Подписываемые заголовки (через `;`) и подпись запроса (hex)
+    fn signature(
+        &self,
+        method: &str,
+        path: &str,
+        query: &[(&str, &str)],
+        headers: &[(&str, &str)],
+        payload_hash: &str,
+        now: DateTime<Utc>,
+    ) -> (String, String) {
+        let mut query: Vec<(String, String)> = query
+            .iter()
+            .map(|(name, value)| (uri_encode(name, true), uri_encode(value, true)))
+            .collect();
+        query.sort();
+        let canonical_query: Vec<String> = query
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+
+        let mut headers: Vec<(&str, &str)> = headers.to_vec();
+        headers.sort();
+        let canonical_headers: String = headers
+            .iter()
+            .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+            .collect();
+        let signed_headers: Vec<&str> = headers.iter().map(|(name, _)| *name).collect();
+        let signed_headers = signed_headers.join(";");
+
+        let canonical_request = format!(
+            "{}\n{}\n{}\n{}\n{}\n{}",
+            method,
+            path,
+            canonical_query.join("&"),
+            canonical_headers,
+            signed_headers,
+            payload_hash
+        );
+
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date(now),
+            self.scope(now),
+            sha256_hex(canonical_request.as_bytes())
+        );
+
+        let date = now.format("%Y%m%d").to_string();
+        let key = hmac_sha256(format!("AWS4{}", self.secret_key).as_bytes(), date.as_bytes());
+        let key = hmac_sha256(&key, self.region.as_bytes());
+        let key = hmac_sha256(&key, b"s3");
+        let key = hmac_sha256(&key, b"aws4_request");
+        (signed_headers, hex(&hmac_sha256(&key, string_to_sign.as_bytes())))
+    }
+}
+
+/// Время запроса в формате `x-amz-date`
+pub fn amz_date(now: DateTime<Utc>) -> String {
+    now.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// SHA-256 данных (hex)
+pub fn sha256_hex(data: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hex(&hasher.finalize())
+}
+
+/// Строка параметров запроса (`a=1&b=2`)
+pub fn encode_query(query: &[(&str, &str)]) -> String {
+    let query: Vec<String> = query
+        .iter()
+        .map(|(name, value)| format!("{}={}", uri_encode(name, true), uri_encode(value, true)))
+        .collect();
+    query.join("&")
+}
+
+/// Кодирование URI по правилам SigV4: не кодируются только `A-Za-z0-9-_.~`
+/// (и `/`, если `encode_slash` не задан)
+pub fn uri_encode(value: &str, encode_slash: bool) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b'/' if !encode_slash => encoded.push('/'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut block = [0u8; HMAC_BLOCK_SIZE];
+    if key.len() > HMAC_BLOCK_SIZE {
+        let mut hasher = Sha256::new();
+        hasher.update(key);
+        let digest = hasher.finalize();
+        block[..digest.len()].copy_from_slice(&digest);
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(block.map(|b| b ^ 0x36));
+    inner.update(data);
+    let inner = inner.finalize();
+
+    let mut outer = Sha256::new();
+    outer.update(block.map(|b| b ^ 0x5c));
+    outer.update(inner);
+    outer.finalize().to_vec()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -0,0 +1,275 @@
+//! Поток записи объекта S3
+//!
+//! Объект S3 нельзя дописывать, поэтому данные отправляются составной
+//! загрузкой (multipart upload) частями по `PART_SIZE`: часть уходит на
+//! сервер, пока принимается следующая, и в памяти не больше двух частей.
+//! Объект появляется в бакете только при закрытии потока (`shutdown`),
+//! `flush` лишь дожидается отправки начатой части. Файл не больше одной
+//! части отправляется одним `PUT`, без составной загрузки.
+//!
+//! Если поток уничтожен до успешного `shutdown`, составная загрузка
+//! отменяется: иначе загруженные части остались бы в бакете и занимали место.
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+
+use bytes::BytesMut;
+use tokio::io::AsyncWrite;
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+use super::client::S3Client;
+use crate::storage::StorageError;
+
+/// Размер части составной загрузки (S3 требует не меньше 5 МиБ, кроме последней)
+const PART_SIZE: usize = 8 * 1024 * 1024;
+
+/// Наибольшее число частей составной загрузки в S3
+const MAX_PARTS: u32 = 10_000;
+
+/// Результат запроса к S3
+enum Step {
+    /// Начата составная загрузка с этим идентификатором
+    Created(String),
+    /// Загружена часть (номер, ETag)
+    Uploaded(u32, String),
+    /// Объект записан
+    Completed,
+}
+
+/// Запрос к S3, выполняемый отдельной задачей
+type Operation = JoinHandle<Result<Step, StorageError>>;
+
+pub struct S3Writer {
+    client: Arc<S3Client>,
+    key: String,
+    content_type: String,
+    overwrite: bool,
+    /// Данные, ещё не отправленные на сервер (не больше `PART_SIZE`)
+    buffer: BytesMut,
+    upload_id: Option<String>,
+    /// Загруженные части (номер, ETag)
+    parts: Vec<(u32, String)>,
+    /// Выполняемый запрос; одновременно - не больше одного
+    operation: Option<Operation>,
+    /// Объект записан
+    finished: bool,
+    /// Запрос не удался: часть данных уже потеряна
+    failed: bool,
+}
+
+impl S3Writer {
+    pub fn new(client: Arc<S3Client>, key: String, content_type: String, overwrite: bool) -> Self {
+        Self {
+            client,
+            key,
+            content_type,
+            overwrite,
+            buffer: BytesMut::new(),
+            upload_id: None,
+            parts: Vec::new(),
+            operation: None,
+            finished: false,
+            failed: false,
+        }
+    }
+
+    fn spawn<F>(&mut self, operation: F)
+    where
+        F: Future<Output = Result<Step, StorageError>> + Send + 'static,
+    {
+        self.operation = Some(tokio::spawn(operation));
+    }
+
+    /// Дождаться выполняемого запроса и учесть его результат
+    fn poll_operation(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let Some(operation) = self.operation.as_mut() else {
+            return Poll::Ready(Ok(()));
+        };
+        let result = ready!(Pin::new(operation).poll(cx));
+        self.operation = None;
+
+        let step = match result {
+            Ok(Ok(step)) => step,
+            Ok(Err(e)) => {
+                self.failed = true;
+                return Poll::Ready(Err(into_io_error(e)));
+            }
+            Err(e) => {
+                self.failed = true;
+                return Poll::Ready(Err(io::Error::other(e)));
+            }
+        };
+        match step {
+            Step::Created(upload_id) => self.upload_id = Some(upload_id),
+            Step::Uploaded(number, etag) => self.parts.push((number, etag)),
+            Step::Completed => self.finished = true,
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    /// Отправить первые `len` байт буфера следующей частью
+    ///
+    /// Если составная загрузка ещё не начата, сначала начинается она,
+    /// а буфер остаётся как есть.
+    fn start_part(&mut self, len: usize) -> io::Result<()> {
+        let client = self.client.clone();
+        let key = self.key.clone();
+
+        let Some(upload_id) = self.upload_id.clone() else {
+            let content_type = self.content_type.clone();
+            self.spawn(async move {
+                let upload_id = client.create_multipart_upload(&key, &content_type).await?;
+                Ok(Step::Created(upload_id))
+            });
+            return Ok(());
+        };
+
+        let number = self.parts.len() as u32 + 1;
+        if number > MAX_PARTS {
+            self.failed = true;
+            return Err(io::Error::other(format!(
+                "Файл больше {} байт не помещается в составную загрузку S3",
+                PART_SIZE as u64 * MAX_PARTS as u64
+            )));
+        }
+
+        let data = self.buffer.split_to(len).freeze();
+        self.spawn(async move {
+            let etag = client.upload_part(&key, &upload_id, number, data).await?;
+            Ok(Step::Uploaded(number, etag))
+        });
+        Ok(())
+    }
+}
+
+impl AsyncWrite for S3Writer {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        if this.finished || this.failed {
+            return Poll::Ready(Err(io::Error::other("Запись уже завершена")));
+        }
+
+        // Полная часть отправляется, когда закончен предыдущий запрос
+        while this.buffer.len() >= PART_SIZE {
+            if this.operation.is_some() {
+                ready!(this.poll_operation(cx))?;
+            } else {
+                this.start_part(PART_SIZE)?;
+            }
+        }
+
+        let n = buf.len().min(PART_SIZE - this.buffer.len());
+        this.buffer.extend_from_slice(&buf[..n]);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().poll_operation(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if this.failed && this.operation.is_none() {
+            return Poll::Ready(Err(io::Error::other("Запись не удалась")));
+        }
+
+        loop {
+            if this.operation.is_some() {
+                ready!(this.poll_operation(cx))?;
+                continue;
+            }
+            if this.finished {
+                return Poll::Ready(Ok(()));
+            }
+
+            let client = this.client.clone();
+            let key = this.key.clone();
+            match this.upload_id.clone() {
+                // Файл не больше одной части - обычный PUT
+                None => {
+                    let data = this.buffer.split().freeze();
+                    let content_type = this.content_type.clone();
+                    let overwrite = this.overwrite;
+                    this.spawn(async move {
+                        client.put_object(&key, data, &content_type, overwrite).await?;
+                        Ok(Step::Completed)
+                    });
+                }
+                // Последняя часть может быть меньше `PART_SIZE`
+                Some(_) if !this.buffer.is_empty() => this.start_part(this.buffer.len())?,
+                Some(upload_id) => {
+                    let parts = this.parts.clone();
+                    let overwrite = this.overwrite;
+                    this.spawn(async move {
+                        client
+                            .complete_multipart_upload(&key, &upload_id, &parts, overwrite)
+                            .await?;
+                        Ok(Step::Completed)
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl Drop for S3Writer {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        let operation = self.operation.take();
+        let upload_id = self.upload_id.take();
+        if operation.is_none() && upload_id.is_none() {
+            return;
+        }
+
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            warn!("Составная загрузка {} не отменена: нет среды выполнения", self.key);
+            return;
+        };
+        let client = self.client.clone();
+        let key = self.key.clone();
+        runtime.spawn(async move {
+            // Выполнявшийся запрос мог успеть начать составную загрузку
+            let mut upload_id = upload_id;
+            if let Some(operation) = operation {
+                if let Ok(Ok(Step::Created(created))) = operation.await {
+                    upload_id = Some(created);
+                }
+            }
+            let Some(upload_id) = upload_id else {
+                return;
+            };
+
+            match client.abort_multipart_upload(&key, &upload_id).await {
+                // Загрузка уже завершена или отменена
+                Ok(()) | Err(StorageError::NotFound(_)) => {}
+                Err(e) => warn!("Не удалось отменить составную загрузку {}: {}", key, e),
+            }
+        });
+    }
+}
+
+/// Ошибка хранилища как ошибка потока записи
+pub fn into_io_error(error: StorageError) -> io::Error {
+    match error {
+        StorageError::Io(e) => e,
+        StorageError::AlreadyExists(path) => io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("Файл уже существует: {}", path),
+        ),
+        StorageError::PermissionDenied(path) => io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("Недостаточно прав: {}", path),
+        ),
+        other => io::Error::other(other),
+    }
+}
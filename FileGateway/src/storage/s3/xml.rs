@@ -0,0 +1,90 @@
+//! Разбор XML-ответов S3 и экранирование текста запросов
+//!
+//! Ответы S3 - плоские документы известной структуры, поэтому вместо
+//! XML-парсера элементы ищутся по имени тега. Вложенные элементы с тем же
+//! именем не поддерживаются (в ответах S3 их нет).
+
+/// Текст первого элемента `tag` (без экранирования)
+pub fn element(xml: &str, tag: &str) -> Option<String> {
+    elements(xml, tag).into_iter().next()
+}
+
+/// Текст всех элементов `tag` по порядку (без экранирования)
+pub fn elements(xml: &str, tag: &str) -> Vec<String> {
+    raw_elements(xml, tag).into_iter().map(unescape).collect()
+}
+
+/// Содержимое всех элементов `tag` как есть, для поиска вложенных элементов
+pub fn raw_elements<'a>(xml: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+
+    let mut found = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find(&open) {
+        let content = &rest[start + open.len()..];
+        let Some(end) = content.find(&close) else {
+            break;
+        };
+        found.push(&content[..end]);
+        rest = &content[end + close.len()..];
+    }
+    found
+}
+
+/// Экранировать текст для вставки в элемент
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Заменить ссылки на символы (`&amp;`, `&#13;` и т.п.) самими символами
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                unescaped.push(c);
+                rest = &rest[end + 1..];
+            }
+            // Неизвестная ссылка остаётся как есть
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
@@ -0,0 +1,251 @@
+//! Перенос файлов между провайдерами
+
+use std::io::ErrorKind;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use tokio::io::{AsyncRead, AsyncWriteExt, ReadBuf};
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+use super::{StorageError, StorageProvider};
+
+/// Результат переноса
+#[derive(Debug, Clone)]
+pub struct TransferResult {
+    /// Скопировано байт
+    pub bytes_transferred: u64,
+    /// Удалён ли исходный файл
+    pub source_deleted: bool,
+}
+
+/// Наблюдение за переносом и его отмена
+#[derive(Clone, Default)]
+pub struct TransferControl {
+    /// Прочитано байт; опрашивается снаружи, пока перенос идёт
+    pub progress: Arc<AtomicU64>,
+    /// После отмены копирование прерывается как при ошибке
+    pub cancel: CancellationToken,
+}
+
+/// Поток чтения, добавляющий прочитанные байты к общему счётчику
+struct CountingReader {
+    inner: Pin<Box<dyn AsyncRead + Send>>,
+    counter: Arc<AtomicU64>,
+}
+
+impl AsyncRead for CountingReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let before = buf.filled().len();
+        let poll = self.inner.as_mut().poll_read(cx, buf);
+        let read = (buf.filled().len() - before) as u64;
+        self.counter.fetch_add(read, Ordering::Relaxed);
+        poll
+    }
+}
+
+/// Перенести файл потоково из одного провайдера в другой
+///
+/// Работает через `get_read_stream`/`get_write_stream`, поэтому файл
+/// не загружается в память целиком. При ошибке посередине частично
+/// записанный файл назначения удаляется, а источник остаётся на месте.
+/// Источник удаляется (`delete_source`) только после полной записи копии.
+///
+/// Перемещение внутри одного хранилища идёт через `StorageProvider::rename`
+/// без копирования данных; копирование остаётся только для переноса между
+/// хранилищами или томами.
+pub async fn transfer(
+    source: &dyn StorageProvider,
+    source_path: &str,
+    destination: &dyn StorageProvider,
+    destination_path: &str,
+    overwrite: bool,
+    delete_source: bool,
+) -> Result<TransferResult, StorageError> {
+    transfer_with_progress(
+        source,
+        source_path,
+        destination,
+        destination_path,
+        overwrite,
+        delete_source,
+        TransferControl::default(),
+    )
+    .await
+}
+
+/// То же, что `transfer`, но прочитанные байты добавляются к `control.progress`
+///
+/// Счётчик опрашивается снаружи, пока перенос идёт (например, для
+/// периодических сообщений о прогрессе). После `control.cancel` копирование
+/// прерывается как при ошибке: неполная копия удаляется, источник остаётся.
+pub async fn transfer_with_progress(
+    source: &dyn StorageProvider,
+    source_path: &str,
+    destination: &dyn StorageProvider,
+    destination_path: &str,
+    overwrite: bool,
+    delete_source: bool,
+    control: TransferControl,
+) -> Result<TransferResult, StorageError> {
+    if delete_source && source.id() == destination.id() {
+        let entry = source.get_entry_info(source_path).await?;
+        if entry.is_directory {
+            return Err(StorageError::NotAFile(source_path.to_string()));
+        }
+
+        match source.rename(source_path, destination_path, overwrite).await {
+            Ok(()) => {
+                control.progress.fetch_add(entry.size, Ordering::Relaxed);
+                return Ok(TransferResult {
+                    bytes_transferred: entry.size,
+                    source_deleted: true,
+                });
+            }
+            // Другой том того же хранилища - переименование невозможно, копируем
+            Err(StorageError::Io(e)) if e.kind() == ErrorKind::CrossesDevices => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    let mut reader = CountingReader {
+        inner: source.get_read_stream(source_path).await?,
+        counter: control.progress,
+    };
+    let mut writer = destination.get_write_stream(destination_path, overwrite, None).await?;
+
+    let copy = async {
+        let bytes = tokio::io::copy(&mut reader, &mut writer).await?;
+        writer.shutdown().await?;
+        Ok::<_, std::io::Error>(bytes)
+    };
+    let copied = tokio::select! {
+        copied = copy => copied,
+        _ = control.cancel.cancelled() => {
+            Err(std::io::Error::new(ErrorKind::Interrupted, "перенос отменён"))
+        }
+    };
+
+    let bytes_transferred = match copied {
+        Ok(bytes) => bytes,
+        // При атомарной записи неполной копии нет, а под этим именем может
+        // лежать прежний файл
+        Err(e) if destination.capabilities().atomic_writes => return Err(e.into()),
+        Err(e) => {
+            drop(writer);
+            if let Err(cleanup) = destination.delete_file(destination_path).await {
+                warn!("Не удалось удалить неполную копию {}: {}", destination_path, cleanup);
+            }
+            return Err(e.into());
+        }
+    };
+
+    // Копия уже записана - ошибка удаления источника не отменяет перенос
+    let source_deleted = delete_source
+        && match source.delete_file(source_path).await {
+            Ok(()) => true,
+            Err(e) => {
+                warn!("Файл скопирован, но источник {} не удалён: {}", source_path, e);
+                false
+            }
+        };
+
+    Ok(TransferResult {
+        bytes_transferred,
+        source_deleted,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{LocalStorageProvider, MemoryStorageProvider, StorageConfig};
+
+    fn local_storage(root: &std::path::Path) -> LocalStorageProvider {
+        let root = root.to_string_lossy().to_string();
+        LocalStorageProvider::new(&StorageConfig {
+            root_path: Some(root.clone()),
+            default_projects_path: Some(root),
+            ..StorageConfig::local()
+        })
+        .unwrap()
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn move_within_local_storage_renames_file() {
+        use std::os::unix::fs::MetadataExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let storage = local_storage(dir.path());
+        let source = dir.path().join("clip.mp4");
+        let destination = dir.path().join("moved.mp4");
+        std::fs::write(&source, b"frames").unwrap();
+        let inode = std::fs::metadata(&source).unwrap().ino();
+
+        let result = transfer(
+            &storage,
+            &source.to_string_lossy(),
+            &storage,
+            &destination.to_string_lossy(),
+            false,
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.bytes_transferred, 6);
+        assert!(result.source_deleted);
+        assert!(!source.exists());
+        // Тот же inode - файл переименован, а не скопирован
+        assert_eq!(std::fs::metadata(&destination).unwrap().ino(), inode);
+    }
+
+    #[tokio::test]
+    async fn move_between_storages_copies_and_deletes_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let local = local_storage(dir.path());
+        let memory = MemoryStorageProvider::new(&StorageConfig::memory());
+        let source = dir.path().join("clip.mp4");
+        std::fs::write(&source, b"frames").unwrap();
+
+        let result = transfer(&local, &source.to_string_lossy(), &memory, "/clip.mp4", false, true)
+            .await
+            .unwrap();
+
+        assert_eq!(result.bytes_transferred, 6);
+        assert!(result.source_deleted);
+        assert!(!source.exists());
+        assert_eq!(memory.download_bytes("/clip.mp4").await.unwrap(), &b"frames"[..]);
+    }
+
+    #[tokio::test]
+    async fn move_within_storage_keeps_existing_destination() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = local_storage(dir.path());
+        let source = dir.path().join("clip.mp4");
+        let destination = dir.path().join("moved.mp4");
+        std::fs::write(&source, b"new").unwrap();
+        std::fs::write(&destination, b"old").unwrap();
+
+        let result = transfer(
+            &storage,
+            &source.to_string_lossy(),
+            &storage,
+            &destination.to_string_lossy(),
+            false,
+            true,
+        )
+        .await;
+
+        assert!(matches!(result, Err(StorageError::AlreadyExists(_))));
+        assert!(source.exists());
+        assert_eq!(std::fs::read(&destination).unwrap(), b"old");
+    }
+}
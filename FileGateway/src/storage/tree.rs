@@ -0,0 +1,124 @@
+//! Дерево директорий за один вызов
+//!
+//! Строится поверх `list_directory`, поэтому работает с любым провайдером.
+
+use std::future::Future;
+use std::path::Path;
+use std::pin::Pin;
+
+use tracing::warn;
+
+use super::walk::record_error;
+use super::{DirectoryListing, PathError, StorageEntry, StorageError, StorageProvider, TreeNode};
+
+/// Глубина по умолчанию
+pub const DEFAULT_TREE_DEPTH: u32 = 2;
+
+/// Максимальная глубина
+const MAX_TREE_DEPTH: u32 = 16;
+
+/// Максимальное число узлов в ответе - дальше директории не раскрываются
+const MAX_TREE_NODES: usize = 10_000;
+
+/// Построить дерево директории
+///
+/// * `max_depth` - сколько уровней вложенности включить (0 - по умолчанию).
+///   Директории на последнем уровне не раскрываются, но для них считаются
+///   файлы и поддиректории, чтобы показать их количество в свёрнутом узле.
+///
+/// Вместе с деревом возвращаются директории, которые не удалось прочитать
+/// (в дереве они не раскрыты).
+pub async fn get_tree(
+    provider: &dyn StorageProvider,
+    path: &str,
+    max_depth: u32,
+) -> Result<(TreeNode, Vec<PathError>), StorageError> {
+    let root = provider.get_entry_info(path).await?;
+    if !root.is_directory {
+        return Err(StorageError::NotADirectory(path.to_string()));
+    }
+
+    let listing = provider.list_directory(&root.path).await?;
+
+    let max_depth = match max_depth {
+        0 => DEFAULT_TREE_DEPTH,
+        depth => depth.min(MAX_TREE_DEPTH),
+    };
+    let mut budget = MAX_TREE_NODES;
+    let mut errors = Vec::new();
+
+    let tree = expand(provider, root, listing, 0, max_depth, &mut budget, &mut errors).await;
+    Ok((tree, errors))
+}
+
+fn leaf(entry: StorageEntry, truncated: bool) -> TreeNode {
+    TreeNode {
+        entry,
+        children: Vec::new(),
+        file_count: 0,
+        dir_count: 0,
+        truncated,
+    }
+}
+
+fn expand<'a>(
+    provider: &'a dyn StorageProvider,
+    entry: StorageEntry,
+    listing: DirectoryListing,
+    depth: u32,
+    max_depth: u32,
+    budget: &'a mut usize,
+    errors: &'a mut Vec<PathError>,
+) -> Pin<Box<dyn Future<Output = TreeNode> + Send + 'a>> {
+    Box::pin(async move {
+        let dir_count = listing.entries.iter().filter(|e| e.is_directory).count() as u64;
+        let file_count = listing.entries.len() as u64 - dir_count;
+
+        if depth >= max_depth || listing.entries.len() > *budget {
+            return TreeNode {
+                file_count,
+                dir_count,
+                ..leaf(entry, true)
+            };
+        }
+        *budget -= listing.entries.len();
+
+        let mut children = Vec::with_capacity(listing.entries.len());
+        for child in listing.entries {
+            if !child.is_directory {
+                children.push(leaf(child, false));
+                continue;
+            }
+
+            match provider.list_directory(&child.path).await {
+                Ok(child_listing) => {
+                    children.push(
+                        expand(
+                            provider,
+                            child,
+                            child_listing,
+                            depth + 1,
+                            max_depth,
+                            budget,
+                            errors,
+                        )
+                        .await,
+                    );
+                }
+                Err(e) => {
+                    warn!("Не удалось прочитать директорию {}: {}", child.path, e);
+                    record_error(errors, Path::new(&child.path), e);
+                    children.push(leaf(child, true));
+                }
+            }
+        }
+
+        TreeNode {
+            entry,
+            children,
+            file_count,
+            dir_count,
+            truncated: false,
+        }
+    })
+}
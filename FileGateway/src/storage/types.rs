@@ -1,5 +1,6 @@
 //! Типы данных для работы с хранилищем
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Информация о хранилище
@@ -23,6 +24,30 @@ pub struct StorageInfo {
     pub total_space: u64,
     /// Свободное место (байты)
     pub free_space: u64,
+    /// Квота на объём данных (байты), если задана
+    pub quota_bytes: Option<u64>,
+    /// Объём, учтённый в квоте (байты)
+    pub quota_used_bytes: Option<u64>,
+}
+
+/// Возможности провайдера хранилища
+///
+/// Позволяют клиенту заранее отключить неподдерживаемые действия,
+/// не дожидаясь `StorageError::NotSupported`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageCapabilities {
+    /// Потоковое чтение и запись (большие файлы)
+    pub streaming: bool,
+    /// Перемещение и переименование
+    pub rename: bool,
+    /// Отслеживание изменений
+    pub watch: bool,
+    /// Генерация миниатюр
+    pub thumbnails: bool,
+    /// Информация о размере и свободном месте
+    pub disk_space: bool,
+    /// Записанный файл появляется под своим именем только после закрытия потока
+    pub atomic_writes: bool,
 }
 
 /// Элемент директории/бакета
@@ -36,12 +61,21 @@ pub struct StorageEntry {
     pub is_directory: bool,
     /// Размер в байтах
     pub size: u64,
-    /// Время создания (unix timestamp)
-    pub created_at: i64,
-    /// Время изменения (unix timestamp)
-    pub modified_at: i64,
+    /// Время создания (None, если файловая система его не сообщает)
+    pub created_at: Option<DateTime<Utc>>,
+    /// Время изменения
+    pub modified_at: Option<DateTime<Utc>>,
     /// MIME тип
     pub mime_type: String,
+    /// Причина, если метаданные получить не удалось (размер и время не заполнены)
+    pub metadata_error: Option<String>,
+    /// Можно ли изменять элемент (для директории - создавать в ней файлы);
+    /// `None`, если не проверялось
+    #[serde(default)]
+    pub is_writable: Option<bool>,
+    /// Цель символической ссылки, если элемент - сама ссылка (сведения без перехода)
+    #[serde(default)]
+    pub symlink_target: Option<String>,
     /// Дополнительные метаданные
     pub metadata: std::collections::HashMap<String, String>,
 }
@@ -87,3 +121,99 @@ pub struct ProjectStructure {
     pub exports_path: String,
 }
 
+
+/// Ошибка одного пути при рекурсивном обходе
+///
+/// Обход не прерывается на недоступном или исчезнувшем элементе: элемент
+/// попадает в список ошибок, а результат считается по остальным.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathError {
+    pub path: String,
+    pub error: String,
+}
+
+/// Сводка по содержимому директории (рекурсивно)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DirectoryStats {
+    /// Суммарный размер файлов (байты)
+    pub total_size: u64,
+    /// Число файлов
+    pub file_count: u64,
+    /// Число поддиректорий
+    pub dir_count: u64,
+    /// Элементы, которые не удалось прочитать (в сводку не вошли)
+    #[serde(default)]
+    pub errors: Vec<PathError>,
+}
+
+/// Что удалило бы удаление (пробный запуск)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeletePreview {
+    /// Удаляемые пути: директория раньше своего содержимого, не больше лимита
+    pub paths: Vec<String>,
+    /// Путей больше, чем вошло в `paths`
+    pub truncated: bool,
+    /// Сколько байт освободится (по всем путям, а не только вошедшим в список)
+    pub total_size: u64,
+    /// Элементы, которые не удалось прочитать (в список не вошли)
+    #[serde(default)]
+    pub errors: Vec<PathError>,
+}
+
+/// Место на томе, где расположен путь
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct DiskSpace {
+    /// Общий размер тома (байты)
+    pub total_space: u64,
+    /// Доступное место (байты) с учётом квоты
+    pub free_space: u64,
+}
+
+/// Узел дерева директорий
+#[derive(Debug, Clone)]
+pub struct TreeNode {
+    /// Файл или директория
+    pub entry: StorageEntry,
+    /// Содержимое директории (пусто, если не раскрыта)
+    pub children: Vec<TreeNode>,
+    /// Число файлов в директории (без вложенных)
+    pub file_count: u64,
+    /// Число поддиректорий (без вложенных)
+    pub dir_count: u64,
+    /// Директория не раскрыта (предел глубины, размера или ошибка чтения)
+    pub truncated: bool,
+}
+
+/// Группа файлов с одинаковым содержимым
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateGroup {
+    /// SHA-256 содержимого (hex)
+    pub hash: String,
+    /// Размер каждого файла (байты)
+    pub size: u64,
+    /// Пути файлов группы
+    pub paths: Vec<String>,
+}
+
+/// EXIF-метаданные изображения
+///
+/// Если EXIF в файле нет, `has_exif == false`, остальные поля пустые.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImageMetadata {
+    /// Найден ли EXIF
+    pub has_exif: bool,
+    /// Ориентация (1-8 по спецификации EXIF)
+    pub orientation: Option<u32>,
+    /// Время съёмки в формате EXIF (`YYYY:MM:DD HH:MM:SS`, без часового пояса)
+    pub datetime_original: Option<String>,
+    /// Ширина в пикселях
+    pub width: Option<u32>,
+    /// Высота в пикселях
+    pub height: Option<u32>,
+    /// Производитель камеры
+    pub camera_make: Option<String>,
+    /// Модель камеры
+    pub camera_model: Option<String>,
+    /// Модель объектива
+    pub lens_model: Option<String>,
+}
@@ -0,0 +1,144 @@
+//! Рекурсивный обход директорий
+
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use super::path::display_path;
+use super::{DeletePreview, DirectoryStats, PathError};
+
+/// Сколько ошибок обхода попадает в ответ (остальные отбрасываются)
+pub const MAX_PATH_ERRORS: usize = 1000;
+
+/// Запомнить ошибку пути, если список ещё не заполнен
+pub fn record_error(errors: &mut Vec<PathError>, path: &Path, error: impl Display) {
+    if errors.len() < MAX_PATH_ERRORS {
+        errors.push(PathError {
+            path: display_path(path),
+            error: error.to_string(),
+        });
+    }
+}
+
+/// Подсчитать файлы, поддиректории и объём за один обход (блокирующая операция)
+///
+/// Символические ссылки не раскрываются, недоступные элементы попадают
+/// в `errors`. Возвращает `None`, если обход прерван флагом `cancelled`.
+pub fn directory_stats(path: &Path, cancelled: &AtomicBool) -> Option<DirectoryStats> {
+    let mut stats = DirectoryStats::default();
+    let mut errors = Vec::new();
+    let mut pending: Vec<PathBuf> = vec![path.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        if cancelled.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let Some(entries) = read_dir(&dir, &mut errors) else {
+            continue;
+        };
+
+        for (path, metadata) in entries {
+            if metadata.is_dir() {
+                stats.dir_count += 1;
+                pending.push(path);
+            } else {
+                stats.file_count += 1;
+                stats.total_size += metadata.len();
+            }
+        }
+    }
+
+    stats.errors = errors;
+    Some(stats)
+}
+
+/// Что удалит рекурсивное удаление директории `path` (блокирующая операция)
+///
+/// Символические ссылки не раскрываются: удаляется сама ссылка, а не то,
+/// на что она указывает. В список попадает не больше `limit` путей, объём
+/// считается по всем; недоступные элементы попадают в `errors`.
+/// Возвращает `None`, если обход прерван флагом `cancelled`.
+pub fn delete_preview(path: &Path, limit: usize, cancelled: &AtomicBool) -> Option<DeletePreview> {
+    let mut preview = DeletePreview::default();
+    let add = |preview: &mut DeletePreview, path: &Path| {
+        if preview.paths.len() < limit {
+            preview.paths.push(display_path(path));
+        } else {
+            preview.truncated = true;
+        }
+    };
+
+    add(&mut preview, path);
+    let mut errors = Vec::new();
+    let mut pending: Vec<PathBuf> = vec![path.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        if cancelled.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let Some(entries) = read_dir(&dir, &mut errors) else {
+            continue;
+        };
+
+        for (path, metadata) in entries {
+            add(&mut preview, &path);
+            if metadata.is_dir() {
+                pending.push(path);
+            } else if metadata.is_file() {
+                preview.total_size += metadata.len();
+            }
+        }
+    }
+
+    preview.errors = errors;
+    Some(preview)
+}
+
+/// Элементы директории с метаданными (без перехода по ссылкам)
+///
+/// Ошибки чтения директории и отдельных элементов записываются в `errors`;
+/// `None` - директорию прочитать не удалось.
+fn read_dir<'a>(
+    dir: &'a Path,
+    errors: &'a mut Vec<PathError>,
+) -> Option<impl Iterator<Item = (PathBuf, std::fs::Metadata)> + 'a> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            record_error(errors, dir, e);
+            return None;
+        }
+    };
+
+    Some(entries.filter_map(move |entry| {
+        let path = match entry {
+            Ok(entry) => entry.path(),
+            Err(e) => {
+                record_error(errors, dir, e);
+                return None;
+            }
+        };
+        match path.symlink_metadata() {
+            Ok(metadata) => Some((path, metadata)),
+            Err(e) => {
+                record_error(errors, &path, e);
+                None
+            }
+        }
+    }))
+}
+
+/// Флаг отмены, который выставляется при сбросе future
+///
+/// tonic сбрасывает future обработчика, когда клиент отключается, - так
+/// блокирующий обход узнаёт, что результат больше не нужен.
+pub struct CancelOnDrop(pub Arc<AtomicBool>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
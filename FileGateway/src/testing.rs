@@ -0,0 +1,167 @@
+//! Запуск FileGateway в процессе теста
+//!
+//! Сервис слушает свободный порт на loopback и работает с хранилищем во
+//! временной директории (или с переданным провайдером). Тесты FileGateway
+//! и сервисов, обращающихся к нему, получают подключённый клиент. Сервер
+//! останавливается, а директория удаляется вместе с `TestFileGateway`.
+
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+
+use director_common::bind;
+use director_common::health::HealthService;
+use tempfile::TempDir;
+use tokio::task::JoinHandle;
+use tokio_stream::StreamExt;
+use tonic::transport::{Channel, Server};
+use tonic::Status;
+
+use crate::proto::file_gateway_client::FileGatewayClient;
+use crate::proto::file_gateway_server::{self, FileGatewayServer};
+use crate::proto::{
+    download_file_response, upload_file_request, DownloadFileRequest, UploadFileMetadata,
+    UploadFileRequest, UploadFileResponse,
+};
+use crate::service::FileGatewayImpl;
+use crate::storage::{LocalStorageProvider, StorageConfig, StorageProvider};
+
+/// Размер чанка, которым тесты загружают файлы
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// FileGateway, запущенный тестом
+pub struct TestFileGateway {
+    /// Адрес для клиентов (`http://127.0.0.1:<порт>`)
+    pub address: String,
+    pub client: FileGatewayClient<Channel>,
+    /// Провайдер, с которым работает сервис
+    pub provider: Arc<dyn StorageProvider>,
+    /// Корень хранилища
+    root: String,
+    /// Временная директория локального хранилища
+    _dir: Option<TempDir>,
+    server: JoinHandle<()>,
+}
+
+impl TestFileGateway {
+    /// Локальное хранилище во временной директории
+    ///
+    /// Директория - и корень, и путь для проектов по умолчанию, поэтому
+    /// сервис не выходит за её пределы.
+    pub async fn start() -> Self {
+        let dir = tempfile::tempdir().expect("Не удалось создать временную директорию");
+        let root = dir.path().to_string_lossy().to_string();
+
+        let config = StorageConfig {
+            root_path: Some(root.clone()),
+            default_projects_path: Some(root.clone()),
+            ..StorageConfig::local()
+        };
+        let provider = LocalStorageProvider::new(&config)
+            .expect("Не удалось создать локальное хранилище");
+
+        Self::serve(Arc::new(provider), root, Some(dir)).await
+    }
+
+    /// Готовый провайдер (например, `MemoryStorageProvider` или заглушка)
+    pub async fn with_provider(provider: Arc<dyn StorageProvider>) -> Self {
+        Self::serve(provider, "/".to_string(), None).await
+    }
+
+    async fn serve(provider: Arc<dyn StorageProvider>, root: String, dir: Option<TempDir>) -> Self {
+        let (incoming, local_addr) = bind::listen(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)))
+            .await
+            .expect("Не удалось открыть сокет FileGateway");
+
+        let service = FileGatewayImpl::with_provider(provider.clone());
+        let server = tokio::spawn(async move {
+            let _ = Server::builder()
+                .add_service(FileGatewayServer::new(service))
+                .add_service(HealthService::server(file_gateway_server::SERVICE_NAME))
+                .serve_with_incoming(incoming)
+                .await;
+        });
+
+        let address = format!("http://{}", local_addr);
+        let client = FileGatewayClient::connect(address.clone())
+            .await
+            .expect("Не удалось подключиться к FileGateway");
+
+        Self {
+            address,
+            client,
+            provider,
+            root,
+            _dir: dir,
+            server,
+        }
+    }
+
+    /// Корень хранилища
+    pub fn root(&self) -> &str {
+        &self.root
+    }
+
+    /// Путь внутри хранилища (`relative` - через `/`)
+    pub fn path(&self, relative: &str) -> String {
+        format!("{}/{}", self.root.trim_end_matches('/'), relative)
+    }
+
+    /// Загрузить файл через `UploadFile`: метаданные, затем данные чанками
+    pub async fn upload(
+        &self,
+        destination_path: &str,
+        filename: &str,
+        data: &[u8],
+        overwrite: bool,
+    ) -> Result<UploadFileResponse, Status> {
+        let metadata = UploadFileMetadata {
+            destination_path: destination_path.to_string(),
+            filename: filename.to_string(),
+            total_size: data.len() as u64,
+            overwrite,
+            ..Default::default()
+        };
+        let messages: Vec<UploadFileRequest> =
+            std::iter::once(upload_file_request::Data::Metadata(metadata))
+                .chain(
+                    data.chunks(UPLOAD_CHUNK_SIZE)
+                        .map(|chunk| upload_file_request::Data::Chunk(chunk.to_vec())),
+                )
+                .map(|data| UploadFileRequest { data: Some(data) })
+                .collect();
+
+        let response = self
+            .client
+            .clone()
+            .upload_file(tokio_stream::iter(messages))
+            .await?;
+        Ok(response.into_inner())
+    }
+
+    /// Скачать файл через `DownloadFile` целиком
+    pub async fn download(&self, path: &str) -> Result<Vec<u8>, Status> {
+        let mut stream = self
+            .client
+            .clone()
+            .download_file(DownloadFileRequest {
+                path: path.to_string(),
+                ..Default::default()
+            })
+            .await?
+            .into_inner();
+
+        let mut data = Vec::new();
+        while let Some(message) = stream.next().await {
+            if let Some(download_file_response::Data::Chunk(chunk)) = message?.data {
+                data.extend_from_slice(&chunk);
+            }
+        }
+        Ok(data)
+    }
+}
+
+impl Drop for TestFileGateway {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}